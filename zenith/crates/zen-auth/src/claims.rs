@@ -39,6 +39,12 @@ impl ZenClaims {
         let threshold = Utc::now() + chrono::TimeDelta::seconds(buffer_secs);
         self.expires_at <= threshold
     }
+
+    /// Time remaining until the token expires (negative if already expired).
+    #[must_use]
+    pub fn expires_in(&self) -> chrono::TimeDelta {
+        self.expires_at - Utc::now()
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +96,20 @@ mod tests {
         assert!(!claims.is_near_expiry(60));
     }
 
+    #[test]
+    fn expires_in_reports_remaining_time() {
+        let claims = make_claims(Utc::now() + chrono::TimeDelta::minutes(5));
+        let remaining = claims.expires_in();
+        assert!(remaining.num_seconds() > 0);
+        assert!(remaining.num_minutes() <= 5);
+    }
+
+    #[test]
+    fn expires_in_negative_when_already_expired() {
+        let claims = make_claims(Utc::now() - chrono::TimeDelta::minutes(5));
+        assert!(claims.expires_in().num_seconds() < 0);
+    }
+
     #[test]
     fn to_identity_handles_none_org() {
         let claims = ZenClaims {
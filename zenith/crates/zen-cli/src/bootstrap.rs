@@ -22,7 +22,52 @@ pub async fn load_config(flags: &GlobalFlags) -> anyhow::Result<zen_config::ZenC
         }
     };
 
-    zen_config::ZenConfig::load_with_env_overrides(&env_overrides).map_err(anyhow::Error::from)
+    let profile = active_profile(flags);
+    let project_root = discover_project_root(flags);
+    zen_config::ZenConfig::load_with_profile_root_and_env_overrides(
+        profile.as_deref(),
+        project_root.as_deref(),
+        &env_overrides,
+    )
+    .map_err(anyhow::Error::from)
+}
+
+/// Soft-discover the project root for config loading purposes.
+///
+/// Unlike [`crate::context::resolve_project_root`], this never errors: if no
+/// project can be found, config loading falls back to the CWD-based
+/// discovery inside `zen_config` itself. This lets commands that run before
+/// `znt init` (e.g. `znt auth login`) still load config normally.
+fn discover_project_root(flags: &GlobalFlags) -> Option<PathBuf> {
+    if let Some(project) = &flags.project {
+        let project_path = PathBuf::from(project);
+        let root = if project_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name == ".zenith")
+        {
+            project_path
+                .parent()
+                .map(std::path::Path::to_path_buf)
+                .unwrap_or(project_path.clone())
+        } else {
+            project_path
+        };
+        return Some(root);
+    }
+
+    let cwd = std::env::current_dir().ok()?;
+    crate::context::find_project_root_or_child(&cwd)
+}
+
+/// Resolve the active config profile: `--profile` wins, falling back to
+/// `ZENITH_PROFILE`.
+fn active_profile(flags: &GlobalFlags) -> Option<String> {
+    flags.profile.clone().or_else(|| {
+        std::env::var(zen_config::PROFILE_ENV_VAR)
+            .ok()
+            .filter(|value| !value.is_empty())
+    })
 }
 
 fn is_ci() -> bool {
@@ -34,4 +34,7 @@ pub struct GlobalFlags {
     pub project: Option<String>,
     pub progress: ProgressMode,
     pub color: ColorMode,
+    /// Named config profile to layer over the base config, e.g. `work`.
+    /// Falls back to `ZENITH_PROFILE` when unset.
+    pub profile: Option<String>,
 }
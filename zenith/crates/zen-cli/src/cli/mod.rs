@@ -41,6 +41,11 @@ pub struct Cli {
     /// Table color mode: auto, always, never
     #[arg(long, global = true, default_value = "auto")]
     pub color: ColorMode,
+
+    /// Named config profile to layer over the base config (e.g. `work`).
+    /// Falls back to `ZENITH_PROFILE` when unset.
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
 }
 
 impl Cli {
@@ -55,6 +60,7 @@ impl Cli {
             project: self.project.clone(),
             progress: self.progress,
             color: self.color,
+            profile: self.profile.clone(),
         }
     }
 }
@@ -86,7 +92,7 @@ mod tests {
         assert_eq!(cli.format, OutputFormat::Table);
         assert_eq!(cli.limit, Some(10));
         assert!(cli.verbose);
-        assert!(matches!(cli.command, Commands::WhatsNext));
+        assert!(matches!(cli.command, Commands::WhatsNext(_)));
     }
 
     #[test]
@@ -96,7 +102,7 @@ mod tests {
 
         assert_eq!(cli.format, OutputFormat::Raw);
         assert!(cli.quiet);
-        assert!(matches!(cli.command, Commands::WhatsNext));
+        assert!(matches!(cli.command, Commands::WhatsNext(_)));
     }
 
     #[test]
@@ -110,7 +116,7 @@ mod tests {
         for value in ["json", "table", "raw"] {
             let cli = Cli::try_parse_from(["znt", "--format", value, "whats-next"])
                 .expect("cli should parse");
-            assert!(matches!(cli.command, Commands::WhatsNext));
+            assert!(matches!(cli.command, Commands::WhatsNext(_)));
         }
     }
 
@@ -1,9 +1,9 @@
 use clap::{Args, Subcommand};
 
 use crate::cli::subcommands::{
-    AuthCommands, CacheCommands, CompatCommands, FindingCommands, HookCommands, HypothesisCommands,
-    InsightCommands, IssueCommands, PrdCommands, ResearchCommands, SessionCommands, StudyCommands,
-    TaskCommands, TeamCommands,
+    AuthCommands, CacheCommands, CompatCommands, ConfigCommands, FindingCommands, HookCommands,
+    HypothesisCommands, InsightCommands, IssueCommands, LinkCommands, PrdCommands,
+    ResearchCommands, SessionCommands, StudyCommands, TaskCommands, TeamCommands,
 };
 
 /// Top-level command tree.
@@ -76,20 +76,25 @@ pub enum Commands {
         #[command(subcommand)]
         action: StudyCommands,
     },
-    /// Create an entity link.
-    Link(LinkArgs),
+    /// Entity linking.
+    Link {
+        #[command(subcommand)]
+        action: LinkCommands,
+    },
     /// Remove an entity link.
     Unlink(UnlinkArgs),
     /// View audit trail.
     Audit(AuditArgs),
     /// Project state and next steps.
     #[command(name = "whats-next")]
-    WhatsNext,
+    WhatsNext(WhatsNextArgs),
     /// End session and perform wrap-up flow.
     #[command(name = "wrap-up")]
     WrapUp(WrapUpArgs),
     /// Rebuild database from JSONL trail files.
     Rebuild(RebuildArgs),
+    /// Check trail file and database consistency.
+    Validate(ValidateArgs),
     /// Dump JSON schema for a registered type.
     Schema(SchemaArgs),
     /// Hook handler called by shell wrappers.
@@ -102,6 +107,11 @@ pub enum Commands {
         #[command(subcommand)]
         action: AuthCommands,
     },
+    /// Read and write Zenith config files.
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommands,
+    },
     /// Team management.
     Team {
         #[command(subcommand)]
@@ -109,6 +119,10 @@ pub enum Commands {
     },
     /// Index the current project for private cloud search.
     Index(IndexArgs),
+    /// Interactive session that keeps one context alive across many commands.
+    Repl(ReplArgs),
+    /// Run a JSON-RPC server for editor integrations.
+    Serve(ServeArgs),
 }
 
 /// Arguments for `znt index`.
@@ -180,6 +194,10 @@ pub struct SearchArgs {
     pub kind: Option<String>,
     #[arg(long)]
     pub mode: Option<String>,
+    /// Export format for `--mode graph` results: `dot` (Graphviz) or `json`
+    /// (D3-style `{nodes, links}`). Ignored for other modes.
+    #[arg(long)]
+    pub graph_format: Option<String>,
     #[arg(long)]
     pub version: Option<String>,
     #[arg(long)]
@@ -194,6 +212,12 @@ pub struct SearchArgs {
     pub max_total_bytes: Option<u32>,
     #[arg(long)]
     pub show_ref_graph: bool,
+    /// Exclude symbols marked deprecated in their source language.
+    #[arg(long)]
+    pub exclude_deprecated: bool,
+    /// Surface the scoring components (vector, FTS, blended) behind each result.
+    #[arg(long)]
+    pub explain: bool,
 }
 
 /// Arguments for `znt grep`.
@@ -254,6 +278,12 @@ pub struct LinkArgs {
     pub target_type: String,
     pub target_id: String,
     pub relation: String,
+    /// Also create the reverse link (target -> source) with the same relation.
+    #[arg(long)]
+    pub bidirectional: bool,
+    /// Allow a relation outside the known `Relation` set.
+    #[arg(long)]
+    pub allow_custom: bool,
 }
 
 /// Arguments for `znt unlink`.
@@ -281,6 +311,15 @@ pub struct AuditArgs {
     pub merge_timeline: bool,
 }
 
+/// Arguments for `znt whats-next`.
+#[derive(Clone, Debug, Args)]
+pub struct WhatsNextArgs {
+    /// Instead of the usual project summary, show audit activity grouped by
+    /// entity type and action since a relative duration ago (e.g. `24h`, `7d`).
+    #[arg(long)]
+    pub since: Option<String>,
+}
+
 /// Arguments for `znt wrap-up`.
 #[derive(Clone, Debug, Args)]
 pub struct WrapUpArgs {
@@ -305,8 +344,28 @@ pub struct RebuildArgs {
     pub dry_run: bool,
 }
 
+/// Arguments for `znt validate`.
+#[derive(Clone, Debug, Args)]
+pub struct ValidateArgs {}
+
+/// Arguments for `znt repl`.
+#[derive(Clone, Debug, Args)]
+pub struct ReplArgs {}
+
+/// Arguments for `znt serve`.
+#[derive(Clone, Debug, Args)]
+pub struct ServeArgs {
+    /// Speak JSON-RPC over stdin/stdout (currently the only supported mode).
+    #[arg(long)]
+    pub stdio: bool,
+}
+
 /// Arguments for `znt schema`.
 #[derive(Clone, Debug, Args)]
 pub struct SchemaArgs {
-    pub type_name: String,
+    pub type_name: Option<String>,
+    /// Print a full OpenAPI 3.1 spec for Zenith's entity commands instead of
+    /// a single type's JSON Schema.
+    #[arg(long)]
+    pub openapi: bool,
 }
@@ -11,6 +11,10 @@ pub enum AuthCommands {
     Status,
     /// Switch to a different Clerk organization.
     SwitchOrg(AuthSwitchOrgArgs),
+    /// Drop the cached external secrets, forcing the next resolve to refetch.
+    RefreshSecrets,
+    /// Push a secret from the resolved config to the external secrets backend.
+    PushSecret(AuthPushSecretArgs),
 }
 
 #[derive(Clone, Debug, Args)]
@@ -28,3 +32,12 @@ pub struct AuthSwitchOrgArgs {
     /// Organization slug to switch to.
     pub org_slug: String,
 }
+
+#[derive(Clone, Debug, Args)]
+pub struct AuthPushSecretArgs {
+    /// `ZENITH_*` env var key whose currently-resolved value gets pushed (e.g. `ZENITH_TURSO__AUTH_TOKEN`).
+    pub key: String,
+    /// Skip the interactive confirmation prompt.
+    #[arg(long)]
+    pub yes: bool,
+}
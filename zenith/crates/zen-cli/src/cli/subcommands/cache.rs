@@ -19,6 +19,12 @@ pub enum CacheCommands {
         /// Remove all cached data.
         #[arg(long)]
         all: bool,
+        /// Clean specific packages, given as `<ecosystem>:<name>@<version>`.
+        /// Repeatable. An alternative to --package/--ecosystem/--version for
+        /// clearing several packages (e.g. before selectively re-indexing
+        /// them) in one invocation.
+        #[arg(long = "packages")]
+        packages: Vec<String>,
     },
     /// Show cache statistics.
     Stats,
@@ -25,4 +25,6 @@ pub enum CompatCommands {
     },
     /// Get a compatibility record by ID.
     Get { id: String },
+    /// Render a full compatibility grid for the active session's packages.
+    Matrix,
 }
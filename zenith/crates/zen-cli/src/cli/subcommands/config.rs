@@ -0,0 +1,23 @@
+use clap::Subcommand;
+
+/// Configuration commands.
+#[derive(Clone, Debug, Subcommand)]
+pub enum ConfigCommands {
+    /// Set a dotted config key (e.g. `turso.url`) in a config file.
+    Set {
+        /// Dotted key, e.g. `turso.url`.
+        key: String,
+        /// Value to write.
+        value: String,
+        /// Write to the user-global config instead of the project-local one.
+        #[arg(long)]
+        global: bool,
+    },
+    /// Get the effective value of a config key, or the whole config.
+    Get {
+        /// Dotted key, e.g. `turso.url`. Omit to print the whole config.
+        key: Option<String>,
+    },
+    /// List `[profiles.*]` sections declared in the user-global config file.
+    Profiles,
+}
@@ -31,4 +31,13 @@ pub enum HookCommands {
     /// React to merge trail updates.
     #[command(name = "post-merge")]
     PostMerge { squash: Option<String> },
+    /// Block pushes carrying invalid trail files or unwrapped active sessions.
+    ///
+    /// Ref updates are read from stdin as `<local ref> <local oid> <remote
+    /// ref> <remote oid>` lines, matching git's `pre-push` hook protocol.
+    #[command(name = "pre-push")]
+    PrePush {
+        remote_name: Option<String>,
+        remote_url: Option<String>,
+    },
 }
@@ -0,0 +1,20 @@
+use clap::Subcommand;
+
+use crate::cli::root_commands::LinkArgs;
+
+/// Entity linking.
+#[derive(Clone, Debug, Subcommand)]
+pub enum LinkCommands {
+    /// Create a single entity link.
+    Create(LinkArgs),
+    /// Create a chain of links across three or more entities in one command:
+    /// `[A, B, C]` creates `A -> B` and `B -> C`, all with the same relation.
+    Chain {
+        /// Relation to use for every link in the chain.
+        #[arg(long)]
+        relation: String,
+        /// Entities to chain, in order, given as `<entity_type>:<id>`.
+        /// At least two required.
+        ids: Vec<String>,
+    },
+}
@@ -1,11 +1,13 @@
 pub mod auth;
 pub mod cache;
 pub mod compat;
+pub mod config;
 pub mod finding;
 pub mod hook;
 pub mod hypothesis;
 pub mod insight;
 pub mod issue;
+pub mod link;
 pub mod prd;
 pub mod research;
 pub mod session;
@@ -16,11 +18,13 @@ pub mod team;
 pub use auth::AuthCommands;
 pub use cache::CacheCommands;
 pub use compat::CompatCommands;
+pub use config::ConfigCommands;
 pub use finding::FindingCommands;
 pub use hook::{HookCommands, HookInstallStrategyArg};
 pub use hypothesis::HypothesisCommands;
 pub use insight::InsightCommands;
 pub use issue::IssueCommands;
+pub use link::LinkCommands;
 pub use prd::PrdCommands;
 pub use research::ResearchCommands;
 pub use session::SessionCommands;
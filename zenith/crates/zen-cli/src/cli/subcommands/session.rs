@@ -11,6 +11,11 @@ pub enum SessionCommands {
         #[arg(long)]
         summary: Option<String>,
     },
+    /// Reactivate an ended session (or the most recently ended one).
+    Resume {
+        /// Session to resume; defaults to the most recently ended session.
+        session_id: Option<String>,
+    },
     /// List sessions.
     List {
         /// Optional status filter.
@@ -19,5 +24,9 @@ pub enum SessionCommands {
         /// Maximum number of sessions.
         #[arg(long)]
         limit: Option<u32>,
+        /// Show retry metrics (operations/retries/failures against the
+        /// database) instead of the session list.
+        #[arg(long)]
+        metrics: bool,
     },
 }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use clap::Subcommand;
 
 /// Study workflow commands.
@@ -20,6 +22,12 @@ pub enum StudyCommands {
         #[arg(long)]
         content: String,
     },
+    /// Add many assumptions to a study at once from a JSON array file.
+    BulkAssume {
+        study_id: String,
+        #[arg(long)]
+        file: PathBuf,
+    },
     /// Record a test result for an assumption.
     Test {
         id: String,
@@ -1,5 +1,7 @@
 pub(crate) mod login;
 mod logout;
+mod push_secret;
+mod refresh_secrets;
 mod status;
 mod switch_org;
 
@@ -17,5 +19,7 @@ pub async fn handle(
         AuthCommands::Logout => logout::handle(flags).await,
         AuthCommands::Status => status::handle(flags, config).await,
         AuthCommands::SwitchOrg(args) => switch_org::handle(args, flags, config).await,
+        AuthCommands::RefreshSecrets => refresh_secrets::handle(flags).await,
+        AuthCommands::PushSecret(args) => push_secret::handle(args, flags, config).await,
     }
 }
@@ -0,0 +1,68 @@
+use std::io::{self, IsTerminal, Write};
+
+use serde::Serialize;
+
+use crate::cli::GlobalFlags;
+use crate::cli::subcommands::auth::AuthPushSecretArgs;
+use crate::output::output;
+
+#[derive(Serialize)]
+struct AuthPushSecretResponse {
+    key: String,
+    outcome: &'static str,
+}
+
+/// Handle `znt auth push-secret <key> [--yes]`.
+pub async fn handle(
+    args: &AuthPushSecretArgs,
+    flags: &GlobalFlags,
+    config: &zen_config::ZenConfig,
+) -> anyhow::Result<()> {
+    let dotted_key = zen_config::env_key_to_toml_path(&args.key)
+        .ok_or_else(|| anyhow::anyhow!("expected a ZENITH_* key, got '{}'", args.key))?;
+    let (section, field) = dotted_key
+        .split_once('.')
+        .ok_or_else(|| anyhow::anyhow!("'{}' does not map to a known config key", args.key))?;
+
+    let document = serde_json::to_value(config)?;
+    let value = document
+        .get(section)
+        .and_then(|s| s.get(field))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("no value set for '{}' in the resolved config", args.key))?
+        .to_string();
+
+    if !args.yes
+        && io::stdin().is_terminal()
+        && io::stdout().is_terminal()
+        && !prompt_yes_no(&format!(
+            "Push '{}' to the external secrets backend? [y/N] ",
+            args.key
+        ))
+    {
+        anyhow::bail!("aborted: not confirmed");
+    }
+
+    let outcome = zen_secrets::store_secret(&args.key, &value).await?;
+
+    output(
+        &AuthPushSecretResponse {
+            key: args.key.clone(),
+            outcome: match outcome {
+                zen_secrets::SecretWriteOutcome::Created => "created",
+                zen_secrets::SecretWriteOutcome::Updated => "updated",
+            },
+        },
+        flags.format,
+    )
+}
+
+fn prompt_yes_no(prompt: &str) -> bool {
+    print!("{prompt}");
+    let _ = io::stdout().flush();
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        return false;
+    }
+    matches!(input.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+use crate::cli::GlobalFlags;
+use crate::output::output;
+
+#[derive(Serialize)]
+struct AuthRefreshSecretsResponse {
+    cleared: bool,
+}
+
+pub async fn handle(flags: &GlobalFlags) -> anyhow::Result<()> {
+    zen_secrets::invalidate_cache()?;
+    output(&AuthRefreshSecretsResponse { cleared: true }, flags.format)
+}
@@ -36,6 +36,36 @@ struct CacheCleanResponse {
     scope: String,
 }
 
+/// A single `<ecosystem>:<name>@<version>` package reference, as accepted by
+/// `znt cache clean --packages`.
+struct PackageSpec {
+    ecosystem: String,
+    name: String,
+    version: String,
+}
+
+impl PackageSpec {
+    /// Parse `<ecosystem>:<name>@<version>`, e.g. `npm:left-pad@1.3.0`.
+    fn parse(raw: &str) -> anyhow::Result<Self> {
+        let (ecosystem, rest) = raw.split_once(':').ok_or_else(|| {
+            anyhow::anyhow!("invalid package spec {raw:?}, expected <ecosystem>:<name>@<version>")
+        })?;
+        let (name, version) = rest.split_once('@').ok_or_else(|| {
+            anyhow::anyhow!("invalid package spec {raw:?}, expected <ecosystem>:<name>@<version>")
+        })?;
+
+        if ecosystem.is_empty() || name.is_empty() || version.is_empty() {
+            bail!("invalid package spec {raw:?}, expected <ecosystem>:<name>@<version>");
+        }
+
+        Ok(Self {
+            ecosystem: ecosystem.to_string(),
+            name: name.to_string(),
+            version: version.to_string(),
+        })
+    }
+}
+
 /// Handle `znt cache`.
 pub async fn handle(
     action: &CacheCommands,
@@ -47,7 +77,7 @@ pub async fn handle(
             let mut packages = Vec::new();
             let mut total_size_bytes = 0i64;
 
-            for (ecosystem, package, version) in ctx.lake.list_indexed_packages()? {
+            for (ecosystem, package, version) in ctx.lake()?.list_indexed_packages()? {
                 let (file_count, size_bytes) =
                     source_stats_for(ctx, &ecosystem, &package, &version)?;
                 total_size_bytes += size_bytes;
@@ -70,7 +100,7 @@ pub async fn handle(
             )
         }
         CacheCommands::Stats => {
-            let total_packages = ctx.lake.count_indexed_packages()?;
+            let total_packages = ctx.lake()?.count_indexed_packages()?;
             let total_size_bytes = total_source_size(ctx)?;
             output(
                 &CacheStatsResponse {
@@ -85,12 +115,43 @@ pub async fn handle(
             ecosystem,
             version,
             all,
+            packages,
         } => {
+            if !packages.is_empty() {
+                let specs = packages
+                    .iter()
+                    .map(|raw| PackageSpec::parse(raw))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+
+                let mut removed_sources = 0usize;
+                for spec in &specs {
+                    let (file_count, _) =
+                        source_stats_for(ctx, &spec.ecosystem, &spec.name, &spec.version)?;
+                    removed_sources = removed_sources.saturating_add(usize::try_from(file_count)?);
+                    ctx.lake()?
+                        .delete_package(&spec.ecosystem, &spec.name, &spec.version)?;
+                    ctx.source_store()?.delete_package_sources(
+                        &spec.ecosystem,
+                        &spec.name,
+                        &spec.version,
+                    )?;
+                }
+
+                return output(
+                    &CacheCleanResponse {
+                        removed_packages: specs.len(),
+                        removed_sources,
+                        scope: packages.join(", "),
+                    },
+                    flags.format,
+                );
+            }
+
             if *all {
-                let removed_packages = ctx.lake.count_indexed_packages()?;
+                let removed_packages = ctx.lake()?.count_indexed_packages()?;
                 let removed_sources = count_source_files(ctx)?;
-                ctx.lake.clear()?;
-                ctx.source_store.clear()?;
+                ctx.lake()?.clear()?;
+                ctx.source_store()?.clear()?;
                 return output(
                     &CacheCleanResponse {
                         removed_packages,
@@ -110,7 +171,7 @@ pub async fn handle(
                 vec![v.clone()]
             } else {
                 let mut found = ctx
-                    .lake
+                    .lake()?
                     .list_indexed_packages()?
                     .into_iter()
                     .filter(|(eco, pkg, _)| eco == ecosystem && pkg == package)
@@ -128,8 +189,8 @@ pub async fn handle(
             for v in &versions {
                 let (file_count, _) = source_stats_for(ctx, ecosystem, package, v)?;
                 removed_sources = removed_sources.saturating_add(usize::try_from(file_count)?);
-                ctx.lake.delete_package(ecosystem, package, v)?;
-                ctx.source_store
+                ctx.lake()?.delete_package(ecosystem, package, v)?;
+                ctx.source_store()?
                     .delete_package_sources(ecosystem, package, v)?;
             }
 
@@ -151,7 +212,7 @@ fn source_stats_for(
     package: &str,
     version: &str,
 ) -> anyhow::Result<(i64, i64)> {
-    let conn = ctx.source_store.conn();
+    let conn = ctx.source_store()?.conn();
     let mut stmt = conn.prepare(
         "SELECT COUNT(*), COALESCE(SUM(size_bytes), 0)
          FROM source_files WHERE ecosystem = ? AND package = ? AND version = ?",
@@ -163,13 +224,13 @@ fn source_stats_for(
 }
 
 fn total_source_size(ctx: &AppContext) -> anyhow::Result<i64> {
-    let conn = ctx.source_store.conn();
+    let conn = ctx.source_store()?.conn();
     let mut stmt = conn.prepare("SELECT COALESCE(SUM(size_bytes), 0) FROM source_files")?;
     stmt.query_row([], |row| row.get(0)).map_err(Into::into)
 }
 
 fn count_source_files(ctx: &AppContext) -> anyhow::Result<usize> {
-    let conn = ctx.source_store.conn();
+    let conn = ctx.source_store()?.conn();
     let mut stmt = conn.prepare("SELECT COUNT(*) FROM source_files")?;
     let count: i64 = stmt.query_row([], |row| row.get(0))?;
     usize::try_from(count).map_err(|_| anyhow::anyhow!("source file count overflow"))
@@ -4,6 +4,8 @@ mod check;
 mod get;
 #[path = "compat/list.rs"]
 mod list;
+#[path = "compat/matrix.rs"]
+mod matrix;
 
 use crate::cli::GlobalFlags;
 use crate::cli::subcommands::CompatCommands;
@@ -40,5 +42,6 @@ pub async fn handle(
             limit,
         } => list::run(status.as_deref(), package.as_deref(), *limit, ctx, flags).await,
         CompatCommands::Get { id } => get::run(id, ctx, flags).await,
+        CompatCommands::Matrix => matrix::run(ctx, flags).await,
     }
 }
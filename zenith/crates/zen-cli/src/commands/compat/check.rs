@@ -1,5 +1,6 @@
 use zen_core::enums::CompatStatus;
 use zen_db::updates::compat::CompatUpdateBuilder;
+use zen_registry::{RegistryClient, compare_dependency_trees};
 
 use crate::cli::GlobalFlags;
 use crate::commands::shared::parse::parse_enum;
@@ -17,13 +18,22 @@ pub async fn run(
     flags: &GlobalFlags,
 ) -> anyhow::Result<()> {
     let session_id = require_active_session_id(ctx).await?;
+    let mut conditions = conditions;
 
     if let Some(existing) = ctx
         .service
         .get_compat_by_packages(package_a, package_b)
         .await?
     {
-        let status = resolve_status(status, Some(existing.status))?;
+        let status = resolve_status(
+            status,
+            Some(existing.status),
+            package_a,
+            package_b,
+            &mut conditions,
+            &ctx.registry,
+        )
+        .await?;
 
         let mut builder = CompatUpdateBuilder::new().status(status);
         if let Some(conditions) = conditions {
@@ -41,7 +51,15 @@ pub async fn run(
             .await?;
         output(&compat, flags.format)
     } else {
-        let status = resolve_status(status, None)?;
+        let status = resolve_status(
+            status,
+            None,
+            package_a,
+            package_b,
+            &mut conditions,
+            &ctx.registry,
+        )
+        .await?;
 
         let compat = ctx
             .service
@@ -58,31 +76,163 @@ pub async fn run(
     }
 }
 
-fn resolve_status(
+/// Resolve the status to record for this check.
+///
+/// An explicit `--status` always wins. Otherwise, if both packages are given
+/// as `ecosystem:name:version` triplets in the same ecosystem, this fetches
+/// each package's declared dependencies from the registry and derives the
+/// status from [`compare_dependency_trees`], filling in `conditions` with a
+/// summary of any conflicts when the caller didn't supply one. Falls back to
+/// the current status (or [`CompatStatus::Unknown`]) when auto-detection
+/// isn't possible.
+async fn resolve_status(
     input: Option<&str>,
     current: Option<CompatStatus>,
+    package_a: &str,
+    package_b: &str,
+    conditions: &mut Option<String>,
+    registry: &RegistryClient,
 ) -> anyhow::Result<CompatStatus> {
-    match input {
-        Some(value) => parse_enum::<CompatStatus>(value, "status"),
-        None => Ok(current.unwrap_or(CompatStatus::Unknown)),
+    if let Some(value) = input {
+        return parse_enum::<CompatStatus>(value, "status");
+    }
+
+    if let Some((detected, summary)) = detect_compat_status(registry, package_a, package_b).await {
+        if conditions.is_none() && !summary.is_empty() {
+            *conditions = Some(summary);
+        }
+        return Ok(detected);
     }
+
+    Ok(current.unwrap_or(CompatStatus::Unknown))
+}
+
+/// Parse an `ecosystem:name:version` triplet, e.g. `rust:tokio:1.40`.
+fn parse_package_triplet(package: &str) -> Option<(&str, &str, &str)> {
+    let mut parts = package.splitn(3, ':');
+    let ecosystem = parts.next()?;
+    let name = parts.next()?;
+    let version = parts.next()?;
+    if ecosystem.is_empty() || name.is_empty() || version.is_empty() {
+        return None;
+    }
+    Some((ecosystem, name, version))
+}
+
+/// Attempt to derive a [`CompatStatus`] by comparing the two packages'
+/// registry dependency trees, along with a human-readable conflict summary.
+///
+/// Returns `None` if either package isn't a well-formed triplet, the two
+/// triplets specify different ecosystems, or fetching dependencies fails.
+async fn detect_compat_status(
+    registry: &RegistryClient,
+    package_a: &str,
+    package_b: &str,
+) -> Option<(CompatStatus, String)> {
+    let (ecosystem_a, name_a, version_a) = parse_package_triplet(package_a)?;
+    let (ecosystem_b, name_b, version_b) = parse_package_triplet(package_b)?;
+    if ecosystem_a != ecosystem_b {
+        return None;
+    }
+
+    let deps_a = registry
+        .get_dependencies(ecosystem_a, name_a, version_a)
+        .await
+        .ok()?;
+    let deps_b = registry
+        .get_dependencies(ecosystem_b, name_b, version_b)
+        .await
+        .ok()?;
+
+    let result = compare_dependency_trees(&deps_a, &deps_b);
+    let status = if result.compatible {
+        CompatStatus::Compatible
+    } else {
+        CompatStatus::Incompatible
+    };
+    let summary = result
+        .conflicts
+        .iter()
+        .map(|c| {
+            format!(
+                "{} requires {} vs {}",
+                c.dependency, c.package_a_requirement, c.package_b_requirement
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    Some((status, summary))
 }
 
 #[cfg(test)]
 mod tests {
     use zen_core::enums::CompatStatus;
+    use zen_registry::RegistryClient;
 
-    use super::resolve_status;
+    use super::{parse_package_triplet, resolve_status};
 
-    #[test]
-    fn keeps_current_status_when_omitted() {
-        let status = resolve_status(None, Some(CompatStatus::Conditional)).expect("should resolve");
+    #[tokio::test]
+    async fn keeps_current_status_when_omitted() {
+        let registry = RegistryClient::new();
+        let mut conditions = None;
+        let status = resolve_status(
+            None,
+            Some(CompatStatus::Conditional),
+            "not-a-triplet",
+            "also-not-one",
+            &mut conditions,
+            &registry,
+        )
+        .await
+        .expect("should resolve");
         assert_eq!(status, CompatStatus::Conditional);
+        assert!(conditions.is_none());
     }
 
-    #[test]
-    fn defaults_to_unknown_when_creating_without_status() {
-        let status = resolve_status(None, None).expect("should resolve");
+    #[tokio::test]
+    async fn defaults_to_unknown_when_creating_without_status() {
+        let registry = RegistryClient::new();
+        let mut conditions = None;
+        let status = resolve_status(
+            None,
+            None,
+            "not-a-triplet",
+            "also-not-one",
+            &mut conditions,
+            &registry,
+        )
+        .await
+        .expect("should resolve");
         assert_eq!(status, CompatStatus::Unknown);
     }
+
+    #[tokio::test]
+    async fn explicit_status_wins_even_for_well_formed_triplets() {
+        let registry = RegistryClient::new();
+        let mut conditions = None;
+        let status = resolve_status(
+            Some("compatible"),
+            None,
+            "rust:tokio:1.40.0",
+            "rust:axum:0.8.0",
+            &mut conditions,
+            &registry,
+        )
+        .await
+        .expect("should resolve");
+        assert_eq!(status, CompatStatus::Compatible);
+    }
+
+    #[test]
+    fn parses_well_formed_triplet() {
+        let parsed = parse_package_triplet("rust:tokio:1.40.0");
+        assert_eq!(parsed, Some(("rust", "tokio", "1.40.0")));
+    }
+
+    #[test]
+    fn rejects_triplet_missing_a_segment() {
+        assert_eq!(parse_package_triplet("rust:tokio"), None);
+        assert_eq!(parse_package_triplet(""), None);
+    }
 }
@@ -0,0 +1,221 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use zen_core::entities::CompatCheck;
+use zen_core::enums::CompatStatus;
+
+use crate::cli::{GlobalFlags, OutputFormat};
+use crate::commands::shared::session::require_active_session_id;
+use crate::context::AppContext;
+use crate::output::output;
+use crate::output::table::{TableOptions, render_entity_table};
+use crate::ui;
+
+/// A single cell in a compatibility matrix, rendered as `✓`/`✗`/`?` in table
+/// mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompatCellResult {
+    Compatible,
+    Incompatible,
+    Unchecked,
+}
+
+impl CompatCellResult {
+    const fn symbol(self) -> char {
+        match self {
+            Self::Compatible => '✓',
+            Self::Incompatible => '✗',
+            Self::Unchecked => '?',
+        }
+    }
+}
+
+impl From<CompatStatus> for CompatCellResult {
+    fn from(status: CompatStatus) -> Self {
+        match status {
+            CompatStatus::Compatible => Self::Compatible,
+            CompatStatus::Incompatible => Self::Incompatible,
+            CompatStatus::Conditional | CompatStatus::Unknown => Self::Unchecked,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CompatMatrix {
+    packages: Vec<String>,
+    cells: Vec<Vec<CompatCellResult>>,
+}
+
+pub async fn run(ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
+    let session_id = require_active_session_id(ctx).await?;
+    let checks: Vec<CompatCheck> = ctx
+        .service
+        .list_compat(u32::MAX)
+        .await?
+        .into_iter()
+        .filter(|check| check.session_id.as_deref() == Some(session_id.as_str()))
+        .collect();
+
+    let matrix = build_matrix(&checks);
+
+    match flags.format {
+        OutputFormat::Json | OutputFormat::Raw => output(&matrix, flags.format),
+        OutputFormat::Table => {
+            println!("{}", render_grid(&matrix));
+            Ok(())
+        }
+    }
+}
+
+/// Build a symmetric compatibility matrix from a set of checks. Only the
+/// upper triangle (`row < col`) is populated with an actual result; the
+/// diagonal and lower triangle are left `Unchecked` since the relation is
+/// symmetric and a package is never checked against itself.
+fn build_matrix(checks: &[CompatCheck]) -> CompatMatrix {
+    let mut package_set: BTreeSet<&str> = BTreeSet::new();
+    for check in checks {
+        package_set.insert(check.package_a.as_str());
+        package_set.insert(check.package_b.as_str());
+    }
+    let packages: Vec<String> = package_set.into_iter().map(String::from).collect();
+
+    let mut cells = vec![vec![CompatCellResult::Unchecked; packages.len()]; packages.len()];
+    for check in checks {
+        let (Some(a), Some(b)) = (
+            packages.iter().position(|p| p == &check.package_a),
+            packages.iter().position(|p| p == &check.package_b),
+        ) else {
+            continue;
+        };
+        let (row, col) = if a < b { (a, b) } else { (b, a) };
+        if row == col {
+            continue;
+        }
+        cells[row][col] = CompatCellResult::from(check.status);
+    }
+
+    CompatMatrix { packages, cells }
+}
+
+fn render_grid(matrix: &CompatMatrix) -> String {
+    if matrix.packages.is_empty() {
+        return String::from("(no compatibility checks for the active session)");
+    }
+
+    let prefs = ui::prefs();
+    let options = TableOptions {
+        max_width: prefs.term_width,
+        color: prefs.table_color,
+    };
+
+    let mut headers = vec![String::new()];
+    headers.extend(matrix.packages.iter().cloned());
+    let header_refs: Vec<&str> = headers.iter().map(String::as_str).collect();
+
+    let rows = matrix
+        .packages
+        .iter()
+        .enumerate()
+        .map(|(row, package)| {
+            let mut cells = vec![package.clone()];
+            cells.extend(
+                matrix.cells[row]
+                    .iter()
+                    .map(|cell| cell.symbol().to_string()),
+            );
+            cells
+        })
+        .collect::<Vec<_>>();
+
+    render_entity_table(&header_refs, &rows, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::{CompatCellResult, build_matrix};
+    use zen_core::entities::CompatCheck;
+    use zen_core::enums::CompatStatus;
+
+    fn check(id: &str, package_a: &str, package_b: &str, status: CompatStatus) -> CompatCheck {
+        let now = Utc::now();
+        CompatCheck {
+            id: id.to_string(),
+            package_a: package_a.to_string(),
+            package_b: package_b.to_string(),
+            status,
+            conditions: None,
+            finding_id: None,
+            session_id: Some("ses-1".to_string()),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn builds_symmetric_matrix_with_upper_triangle_populated() {
+        let checks = vec![
+            check(
+                "c1",
+                "rust:tokio:1.40.0",
+                "rust:axum:0.8.0",
+                CompatStatus::Compatible,
+            ),
+            check(
+                "c2",
+                "rust:tokio:1.40.0",
+                "rust:hyper:1.4.0",
+                CompatStatus::Incompatible,
+            ),
+            check(
+                "c3",
+                "rust:axum:0.8.0",
+                "rust:hyper:1.4.0",
+                CompatStatus::Conditional,
+            ),
+            check(
+                "c4",
+                "rust:hyper:1.4.0",
+                "rust:tokio:1.40.0",
+                CompatStatus::Unknown,
+            ),
+            check(
+                "c5",
+                "rust:axum:0.8.0",
+                "rust:tokio:1.40.0",
+                CompatStatus::Compatible,
+            ),
+            check(
+                "c6",
+                "rust:hyper:1.4.0",
+                "rust:axum:0.8.0",
+                CompatStatus::Incompatible,
+            ),
+        ];
+
+        let matrix = build_matrix(&checks);
+
+        assert_eq!(matrix.packages.len(), 3);
+        assert_eq!(matrix.cells.len(), 3);
+        assert!(matrix.cells.iter().all(|row| row.len() == 3));
+
+        let json = serde_json::to_value(&matrix).expect("matrix should serialize");
+        let cells = json["cells"].as_array().expect("cells should be an array");
+        assert_eq!(cells.len(), 3);
+        let total_cells: usize = cells
+            .iter()
+            .map(|row| row.as_array().expect("row should be an array").len())
+            .sum();
+        assert_eq!(total_cells, 9);
+
+        for (row, row_cells) in matrix.cells.iter().enumerate() {
+            for (col, cell) in row_cells.iter().enumerate() {
+                if row >= col {
+                    assert_eq!(*cell, CompatCellResult::Unchecked);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::GlobalFlags;
+use crate::output::output;
+
+#[derive(Debug, Serialize)]
+struct ConfigGetResponse {
+    key: String,
+    value: Value,
+}
+
+/// Handle `znt config get [key]`.
+///
+/// With no key, prints the whole effective config (all sources merged).
+/// With a dotted key (e.g. `turso.url`), prints just that field's value.
+pub fn handle(
+    key: Option<&str>,
+    config: &zen_config::ZenConfig,
+    flags: &GlobalFlags,
+) -> anyhow::Result<()> {
+    let Some(key) = key else {
+        return output(config, flags.format);
+    };
+
+    let (section, field) = key
+        .split_once('.')
+        .ok_or_else(|| anyhow::anyhow!("expected a dotted key like 'turso.url', got '{key}'"))?;
+
+    let document = serde_json::to_value(config)?;
+    let value = document
+        .get(section)
+        .and_then(|s| s.get(field))
+        .ok_or_else(|| anyhow::anyhow!("'{key}' is not a known config key"))?
+        .clone();
+
+    output(
+        &ConfigGetResponse {
+            key: key.to_string(),
+            value,
+        },
+        flags.format,
+    )
+}
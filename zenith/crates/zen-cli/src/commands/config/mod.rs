@@ -0,0 +1,19 @@
+mod get;
+mod profiles;
+mod set;
+
+use crate::cli::GlobalFlags;
+use crate::cli::subcommands::ConfigCommands;
+
+/// Handle `znt config <subcommand>`.
+pub async fn handle(
+    action: &ConfigCommands,
+    flags: &GlobalFlags,
+    config: &zen_config::ZenConfig,
+) -> anyhow::Result<()> {
+    match action {
+        ConfigCommands::Set { key, value, global } => set::handle(key, value, *global, flags),
+        ConfigCommands::Get { key } => get::handle(key.as_deref(), config, flags),
+        ConfigCommands::Profiles => profiles::handle(flags),
+    }
+}
@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+use crate::cli::GlobalFlags;
+use crate::output::output;
+
+#[derive(Debug, Serialize)]
+struct ProfilesResponse {
+    profiles: Vec<String>,
+}
+
+/// Handle `znt config profiles`.
+pub fn handle(flags: &GlobalFlags) -> anyhow::Result<()> {
+    let profiles = zen_config::ZenConfig::list_profiles().map_err(anyhow::Error::from)?;
+    output(&ProfilesResponse { profiles }, flags.format)
+}
@@ -0,0 +1,77 @@
+use serde::Serialize;
+use toml_edit::Value;
+use zen_config::write::{ConfigScope, set_value};
+
+use crate::cli::GlobalFlags;
+use crate::output::output;
+
+#[derive(Debug, Serialize)]
+struct ConfigSetResponse {
+    key: String,
+    value: String,
+    scope: &'static str,
+}
+
+/// Handle `znt config set <key> <value> [--global]`.
+pub fn handle(key: &str, value: &str, global: bool, flags: &GlobalFlags) -> anyhow::Result<()> {
+    let scope = if global {
+        ConfigScope::Global
+    } else {
+        ConfigScope::Project
+    };
+
+    set_value(scope, key, parse_value(value))?;
+
+    output(
+        &ConfigSetResponse {
+            key: key.to_string(),
+            value: value.to_string(),
+            scope: if global { "global" } else { "project" },
+        },
+        flags.format,
+    )
+}
+
+/// Parse a raw CLI argument into the most specific TOML value it looks like:
+/// a bool, an integer, a float, and finally a plain string as the fallback.
+fn parse_value(raw: &str) -> Value {
+    if let Ok(b) = raw.parse::<bool>() {
+        return Value::from(b);
+    }
+    if let Ok(i) = raw.parse::<i64>() {
+        return Value::from(i);
+    }
+    if let Ok(f) = raw.parse::<f64>() {
+        return Value::from(f);
+    }
+    Value::from(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_value;
+    use toml_edit::Value;
+
+    #[test]
+    fn parses_bool() {
+        assert!(matches!(parse_value("true"), Value::Boolean(_)));
+    }
+
+    #[test]
+    fn parses_integer() {
+        assert!(matches!(parse_value("120"), Value::Integer(_)));
+    }
+
+    #[test]
+    fn parses_float() {
+        assert!(matches!(parse_value("1.5"), Value::Float(_)));
+    }
+
+    #[test]
+    fn falls_back_to_string() {
+        assert!(matches!(
+            parse_value("libsql://db.turso.io"),
+            Value::String(_)
+        ));
+    }
+}
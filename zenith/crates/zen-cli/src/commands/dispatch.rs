@@ -21,10 +21,10 @@ pub async fn dispatch(
         Commands::Log(args) => commands::log::handle(&args, ctx, flags).await,
         Commands::Compat { action } => commands::compat::handle(&action, ctx, flags).await,
         Commands::Study { action } => commands::study::handle(&action, ctx, flags).await,
-        Commands::Link(args) => commands::link::handle_link(&args, ctx, flags).await,
+        Commands::Link { action } => commands::link::handle_link(&action, ctx, flags).await,
         Commands::Unlink(args) => commands::link::handle_unlink(&args, ctx, flags).await,
         Commands::Audit(args) => commands::audit::handle(&args, ctx, flags).await,
-        Commands::WhatsNext => commands::whats_next::handle(ctx, flags).await,
+        Commands::WhatsNext(args) => commands::whats_next::handle(&args, ctx, flags).await,
         Commands::WrapUp(args) => commands::wrap_up::handle(&args, ctx, flags).await,
         Commands::Search(args) => commands::search::handle(&args, ctx, flags).await,
         Commands::Grep(args) => commands::grep::handle(&args, ctx, flags).await,
@@ -32,10 +32,17 @@ pub async fn dispatch(
         Commands::Install(args) => commands::install::handle(&args, ctx, flags).await,
         Commands::Onboard(args) => commands::onboard::handle(&args, ctx, flags).await,
         Commands::Rebuild(args) => commands::rebuild::handle(&args, ctx, flags).await,
+        Commands::Validate(args) => commands::validate::handle(&args, ctx, flags).await,
         Commands::Team { action } => commands::team::handle(&action, ctx, flags).await,
         Commands::Index(args) => commands::index::handle(&args, ctx, flags).await,
-        Commands::Init(_) | Commands::Hook { .. } | Commands::Schema(_) | Commands::Auth { .. } => {
-            unreachable!("init/hook/schema/auth are pre-dispatched in main")
+        Commands::Repl(args) => commands::repl::handle(&args, ctx, flags).await,
+        Commands::Serve(args) => commands::serve::handle(&args, ctx, flags).await,
+        Commands::Init(_)
+        | Commands::Hook { .. }
+        | Commands::Schema(_)
+        | Commands::Auth { .. }
+        | Commands::Config { .. } => {
+            unreachable!("init/hook/schema/auth/config are pre-dispatched in main")
         }
     }
 }
@@ -2,6 +2,7 @@ use serde::Serialize;
 use zen_core::entities::Finding;
 
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::context::AppContext;
 use crate::output::output;
 
@@ -12,7 +13,8 @@ struct FindingDetailResponse {
 }
 
 pub async fn run(id: &str, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
-    let finding = ctx.service.get_finding(id).await?;
-    let tags = ctx.service.get_finding_tags(id).await?;
+    let id = resolve_id(ctx, "findings", id).await?;
+    let finding = ctx.service.get_finding(&id).await?;
+    let tags = ctx.service.get_finding_tags(&id).await?;
     output(&FindingDetailResponse { finding, tags }, flags.format)
 }
@@ -68,8 +68,8 @@ pub async fn handle(
     let mut result = if args.all_packages || !args.packages.is_empty() {
         let packages = resolve_package_targets(args, ctx)?;
         GrepEngine::grep_package(
-            &ctx.source_store,
-            &ctx.lake,
+            ctx.source_store()?,
+            ctx.lake()?,
             &args.pattern,
             &packages,
             &opts,
@@ -130,7 +130,7 @@ fn resolve_package_targets(
     ctx: &AppContext,
 ) -> anyhow::Result<Vec<(String, String, String)>> {
     let indexed = ctx
-        .lake
+        .lake()?
         .list_indexed_packages()
         .context("failed to list indexed packages")?;
 
@@ -6,6 +6,8 @@ mod post_checkout;
 mod post_merge;
 #[path = "hook/pre_commit.rs"]
 mod pre_commit;
+#[path = "hook/pre_push.rs"]
+mod pre_push;
 #[path = "hook/rebuild_trigger.rs"]
 mod rebuild_trigger;
 #[path = "hook/status.rs"]
@@ -48,6 +50,18 @@ pub async fn handle(action: &HookCommands, flags: &GlobalFlags) -> anyhow::Resul
         HookCommands::PostMerge { squash } => {
             post_merge::run(&project_root, squash.as_deref(), flags).await
         }
+        HookCommands::PrePush {
+            remote_name,
+            remote_url,
+        } => {
+            pre_push::run(
+                &project_root,
+                remote_name.as_deref(),
+                remote_url.as_deref(),
+                flags,
+            )
+            .await
+        }
     }
 }
 
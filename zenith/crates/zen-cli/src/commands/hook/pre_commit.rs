@@ -12,6 +12,7 @@ struct PreCommitResponse {
     files_checked: usize,
     operations_checked: usize,
     errors: Vec<zen_hooks::TrailValidationError>,
+    details: Vec<zen_hooks::ValidationDetail>,
 }
 
 pub fn run(project_root: &Path, flags: &GlobalFlags) -> anyhow::Result<()> {
@@ -22,6 +23,7 @@ pub fn run(project_root: &Path, flags: &GlobalFlags) -> anyhow::Result<()> {
         files_checked: report.files_checked,
         operations_checked: report.operations_checked,
         errors: report.errors,
+        details: report.details,
     };
 
     output(&response, flags.format)?;
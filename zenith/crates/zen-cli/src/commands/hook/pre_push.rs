@@ -0,0 +1,111 @@
+use std::io::Read as _;
+use std::path::Path;
+
+use anyhow::bail;
+use serde::Serialize;
+use zen_core::enums::SessionStatus;
+use zen_db::service::ZenService;
+use zen_hooks::{ActiveSessionState, PrePushRefUpdate};
+
+use crate::cli::GlobalFlags;
+use crate::output::output;
+
+#[derive(Debug, Serialize)]
+struct PrePushResponse {
+    remote_name: Option<String>,
+    remote_url: Option<String>,
+    action: String,
+    reason: String,
+    validation: Option<zen_hooks::TrailValidationReport>,
+}
+
+pub async fn run(
+    project_root: &Path,
+    remote_name: Option<&str>,
+    remote_url: Option<&str>,
+    flags: &GlobalFlags,
+) -> anyhow::Result<()> {
+    let mut stdin = String::new();
+    std::io::stdin().read_to_string(&mut stdin)?;
+    let refs = parse_ref_updates(&stdin);
+
+    let active_session = active_session_state(project_root).await?;
+    let action = zen_hooks::analyze_pre_push(project_root, &refs, active_session.as_ref())?;
+
+    let mut response = PrePushResponse {
+        remote_name: remote_name.map(ToString::to_string),
+        remote_url: remote_url.map(ToString::to_string),
+        action: "allow".to_string(),
+        reason: String::new(),
+        validation: None,
+    };
+
+    let blocked = match action {
+        zen_hooks::PrePushAction::Allow { reason } => {
+            response.reason = reason;
+            false
+        }
+        zen_hooks::PrePushAction::Block { reason, validation } => {
+            response.action = "block".to_string();
+            response.reason = reason;
+            response.validation = validation;
+            true
+        }
+    };
+
+    output(&response, flags.format)?;
+    if blocked {
+        bail!("hook pre-push: {}", response.reason);
+    }
+    Ok(())
+}
+
+/// Look up the currently active session's DB state, if any, for
+/// [`zen_hooks::analyze_pre_push`]'s staleness check.
+///
+/// Returns `None` (skipping that check) when there's no active session or no
+/// `.zenith/zenith.db` yet — a project that's never synced its trail to a DB
+/// has nothing to be stale relative to.
+async fn active_session_state(project_root: &Path) -> anyhow::Result<Option<ActiveSessionState>> {
+    let db_path = project_root.join(".zenith").join("zenith.db");
+    if !db_path.exists() {
+        return Ok(None);
+    }
+
+    let service = ZenService::new_local(&db_path.to_string_lossy(), None, None).await?;
+    let Some(session) = service
+        .list_sessions(Some(SessionStatus::Active), 1)
+        .await?
+        .into_iter()
+        .next()
+    else {
+        return Ok(None);
+    };
+
+    let last_snapshot_at = service.latest_snapshot_at(&session.id).await?;
+    Ok(Some(ActiveSessionState {
+        session_id: session.id,
+        last_snapshot_at,
+    }))
+}
+
+/// Parse `<local ref> <local oid> <remote ref> <remote oid>` lines from
+/// git's `pre-push` hook stdin.
+fn parse_ref_updates(stdin: &str) -> Vec<PrePushRefUpdate> {
+    stdin
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let local_ref = fields.next()?.to_string();
+            let local_oid = fields.next()?.to_string();
+            let remote_ref = fields.next()?.to_string();
+            let remote_oid = fields.next()?.to_string();
+            Some(PrePushRefUpdate {
+                local_ref,
+                local_oid,
+                remote_ref,
+                remote_oid,
+            })
+        })
+        .collect()
+}
@@ -1,8 +1,10 @@
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::context::AppContext;
 use crate::output::output;
 
 pub async fn run(id: &str, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
-    let hypothesis = ctx.service.get_hypothesis(id).await?;
+    let id = resolve_id(ctx, "hypotheses", id).await?;
+    let hypothesis = ctx.service.get_hypothesis(&id).await?;
     output(&hypothesis, flags.format)
 }
@@ -2,6 +2,7 @@ use zen_core::enums::HypothesisStatus;
 use zen_db::updates::hypothesis::HypothesisUpdateBuilder;
 
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::commands::shared::parse::parse_enum;
 use crate::commands::shared::session::require_active_session_id;
 use crate::context::AppContext;
@@ -15,6 +16,7 @@ pub async fn run(
     ctx: &AppContext,
     flags: &GlobalFlags,
 ) -> anyhow::Result<()> {
+    let id = resolve_id(ctx, "hypotheses", id).await?;
     let session_id = require_active_session_id(ctx).await?;
 
     if content.is_none() && reason.is_none() && status.is_none() {
@@ -34,7 +36,7 @@ pub async fn run(
 
     let hypothesis = ctx
         .service
-        .update_hypothesis(&session_id, id, builder.build())
+        .update_hypothesis(&session_id, &id, builder.build())
         .await?;
     output(&hypothesis, flags.format)
 }
@@ -60,15 +60,16 @@ pub async fn handle(
     let version = chrono::Utc::now().format("%Y%m%d").to_string();
 
     let index = IndexingPipeline::index_directory_with(
-        &ctx.lake,
-        &ctx.source_store,
+        ctx.lake()?,
+        ctx.source_store()?,
         &project_root,
         &ecosystem,
         &package,
         &version,
         &mut ctx.embedder,
-        true,
+        ctx.config.index.skip_test_files,
         false,
+        &ctx.config.index,
     )
     .context("indexing pipeline failed")?;
 
@@ -79,7 +80,7 @@ pub async fn handle(
 
     if ctx.config.r2.is_configured() {
         match ctx
-            .lake
+            .lake()?
             .write_to_r2(
                 &ctx.config.r2,
                 &ecosystem,
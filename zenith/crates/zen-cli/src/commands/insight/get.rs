@@ -1,8 +1,10 @@
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::context::AppContext;
 use crate::output::output;
 
 pub async fn run(id: &str, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
-    let insight = ctx.service.get_insight(id).await?;
+    let id = resolve_id(ctx, "insights", id).await?;
+    let insight = ctx.service.get_insight(&id).await?;
     output(&insight, flags.format)
 }
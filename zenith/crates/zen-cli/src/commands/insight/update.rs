@@ -2,6 +2,7 @@ use zen_core::enums::Confidence;
 use zen_db::updates::insight::InsightUpdateBuilder;
 
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::commands::shared::parse::parse_enum;
 use crate::commands::shared::session::require_active_session_id;
 use crate::context::AppContext;
@@ -14,6 +15,7 @@ pub async fn run(
     ctx: &AppContext,
     flags: &GlobalFlags,
 ) -> anyhow::Result<()> {
+    let id = resolve_id(ctx, "insights", id).await?;
     let session_id = require_active_session_id(ctx).await?;
 
     if content.is_none() && confidence.is_none() {
@@ -30,7 +32,7 @@ pub async fn run(
 
     let insight = ctx
         .service
-        .update_insight(&session_id, id, builder.build())
+        .update_insight(&session_id, &id, builder.build())
         .await?;
     output(&insight, flags.format)
 }
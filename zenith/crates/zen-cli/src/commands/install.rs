@@ -70,7 +70,7 @@ pub async fn handle(
         .unwrap_or_else(|| resolved.version.clone());
 
     if ctx
-        .lake
+        .lake()?
         .is_package_indexed(&ecosystem, &args.package, &version)?
         && !args.force
     {
@@ -210,7 +210,10 @@ pub async fn handle(
     }
 
     if args.force {
-        if let Err(error) = ctx.lake.delete_package(&ecosystem, &args.package, &version) {
+        if let Err(error) = ctx
+            .lake()?
+            .delete_package(&ecosystem, &args.package, &version)
+        {
             tracing::warn!(
                 ecosystem = %ecosystem,
                 package = %args.package,
@@ -220,7 +223,7 @@ pub async fn handle(
             );
         }
         if let Err(error) =
-            ctx.source_store
+            ctx.source_store()?
                 .delete_package_sources(&ecosystem, &args.package, &version)
         {
             tracing::warn!(
@@ -290,8 +293,8 @@ pub async fn handle(
 
     progress.set_message("install: indexing package sources");
     let index = IndexingPipeline::index_directory_with(
-        &ctx.lake,
-        &ctx.source_store,
+        ctx.lake()?,
+        ctx.source_store()?,
         &source_path,
         &ecosystem,
         &args.package,
@@ -299,6 +302,7 @@ pub async fn handle(
         &mut ctx.embedder,
         !args.include_tests,
         ecosystem == "rust",
+        &ctx.config.index,
     )
     .context("indexing pipeline failed")?;
     progress.inc(1);
@@ -319,7 +323,7 @@ pub async fn handle(
     if ctx.config.turso.is_configured() && ctx.config.r2.is_configured() {
         progress.set_message("install: exporting indexed package to catalog");
         match ctx
-            .lake
+            .lake()?
             .write_to_r2(
                 &ctx.config.r2,
                 &ecosystem,
@@ -2,6 +2,7 @@ use serde::Serialize;
 use zen_core::entities::{Issue, Task};
 
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::context::AppContext;
 use crate::output::output;
 
@@ -13,9 +14,10 @@ struct IssueDetailResponse {
 }
 
 pub async fn run(id: &str, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
-    let issue = ctx.service.get_issue(id).await?;
-    let children = ctx.service.get_child_issues(id).await?;
-    let tasks = ctx.service.get_tasks_for_issue(id).await?;
+    let id = resolve_id(ctx, "issues", id).await?;
+    let issue = ctx.service.get_issue(&id).await?;
+    let children = ctx.service.get_child_issues(&id).await?;
+    let tasks = ctx.service.get_tasks_for_issue(&id).await?;
 
     output(
         &IssueDetailResponse {
@@ -1,19 +1,25 @@
+#[path = "link/chain.rs"]
+mod chain;
 #[path = "link/create.rs"]
 mod create;
 #[path = "link/delete.rs"]
 mod delete_cmd;
 
 use crate::cli::GlobalFlags;
-use crate::cli::root_commands::{LinkArgs, UnlinkArgs};
+use crate::cli::root_commands::UnlinkArgs;
+use crate::cli::subcommands::LinkCommands;
 use crate::context::AppContext;
 
 /// Handle `znt link`.
 pub async fn handle_link(
-    args: &LinkArgs,
+    action: &LinkCommands,
     ctx: &mut AppContext,
     flags: &GlobalFlags,
 ) -> anyhow::Result<()> {
-    create::run(args, ctx, flags).await
+    match action {
+        LinkCommands::Create(args) => create::run(args, ctx, flags).await,
+        LinkCommands::Chain { relation, ids } => chain::run(ids, relation, ctx, flags).await,
+    }
 }
 
 /// Handle `znt unlink`.
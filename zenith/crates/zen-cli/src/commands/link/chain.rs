@@ -0,0 +1,88 @@
+use anyhow::bail;
+use serde::Serialize;
+
+use crate::cli::GlobalFlags;
+use crate::commands::shared::parse::parse_enum;
+use crate::commands::shared::session::require_active_session_id;
+use crate::context::AppContext;
+use crate::output::output;
+use zen_core::enums::{EntityType, Relation};
+
+#[derive(Debug, Serialize)]
+struct ChainLinkResult {
+    links_created: u32,
+    link_ids: Vec<String>,
+}
+
+/// One endpoint of a `znt link chain` spec: `<entity_type>:<id>`.
+struct ChainNode {
+    entity_type: EntityType,
+    id: String,
+}
+
+impl ChainNode {
+    /// Parse `<entity_type>:<id>`, e.g. `hypothesis:hyp_abc123`.
+    fn parse(raw: &str) -> anyhow::Result<Self> {
+        let (entity_type, id) = raw.split_once(':').ok_or_else(|| {
+            anyhow::anyhow!("invalid chain entity {raw:?}, expected <entity_type>:<id>")
+        })?;
+
+        if id.is_empty() {
+            bail!("invalid chain entity {raw:?}, expected <entity_type>:<id>");
+        }
+
+        Ok(Self {
+            entity_type: parse_enum::<EntityType>(entity_type, "entity_type")?,
+            id: id.to_string(),
+        })
+    }
+}
+
+pub async fn run(
+    ids: &[String],
+    relation: &str,
+    ctx: &AppContext,
+    flags: &GlobalFlags,
+) -> anyhow::Result<()> {
+    if ids.len() < 2 {
+        bail!("link chain requires at least two entities");
+    }
+
+    let nodes = ids
+        .iter()
+        .map(|raw| ChainNode::parse(raw))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    for pair in nodes.windows(2) {
+        if pair[0].entity_type == pair[1].entity_type && pair[0].id == pair[1].id {
+            bail!("link chain cannot link entity {:?} to itself", pair[0].id);
+        }
+    }
+
+    let session_id = require_active_session_id(ctx).await?;
+    let relation = parse_enum::<Relation>(relation, "relation")?;
+
+    let mut link_ids = Vec::with_capacity(nodes.len() - 1);
+    for pair in nodes.windows(2) {
+        let link = ctx
+            .service
+            .create_link(
+                &session_id,
+                pair[0].entity_type,
+                &pair[0].id,
+                pair[1].entity_type,
+                &pair[1].id,
+                relation,
+            )
+            .await?;
+        link_ids.push(link.id);
+    }
+
+    output(
+        &ChainLinkResult {
+            links_created: u32::try_from(link_ids.len()).unwrap_or(u32::MAX),
+            link_ids,
+        },
+        flags.format,
+    )
+}
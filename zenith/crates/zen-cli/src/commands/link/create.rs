@@ -1,18 +1,27 @@
+use serde::Serialize;
+
 use crate::cli::GlobalFlags;
 use crate::cli::root_commands::LinkArgs;
 use crate::commands::shared::parse::parse_enum;
 use crate::commands::shared::session::require_active_session_id;
 use crate::context::AppContext;
 use crate::output::output;
+use zen_core::entities::EntityLink;
 use zen_core::enums::{EntityType, Relation};
 
+#[derive(Debug, Serialize)]
+struct BidirectionalLinkResponse {
+    forward: EntityLink,
+    reverse: EntityLink,
+}
+
 pub async fn run(args: &LinkArgs, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
     let session_id = require_active_session_id(ctx).await?;
     let source_type = parse_enum::<EntityType>(&args.source_type, "source_type")?;
     let target_type = parse_enum::<EntityType>(&args.target_type, "target_type")?;
-    let relation = parse_enum::<Relation>(&args.relation, "relation")?;
+    let relation = parse_relation(&args.relation, args.allow_custom)?;
 
-    let link = ctx
+    let forward = ctx
         .service
         .create_link(
             &session_id,
@@ -24,5 +33,87 @@ pub async fn run(args: &LinkArgs, ctx: &AppContext, flags: &GlobalFlags) -> anyh
         )
         .await?;
 
-    output(&link, flags.format)
+    if !args.bidirectional {
+        return output(&forward, flags.format);
+    }
+
+    let reverse = match ctx
+        .service
+        .create_link(
+            &session_id,
+            target_type,
+            &args.target_id,
+            source_type,
+            &args.source_id,
+            relation,
+        )
+        .await
+    {
+        Ok(reverse) => reverse,
+        Err(error) => {
+            let rollback_error = ctx
+                .service
+                .delete_link(&session_id, &forward.id)
+                .await
+                .err();
+            if let Some(rollback_error) = rollback_error {
+                anyhow::bail!(
+                    "Failed to create reverse link: {error}. Forward link '{}' left in place; rollback failed: {rollback_error}",
+                    forward.id
+                );
+            }
+            anyhow::bail!(
+                "Failed to create reverse link: {error}. Rolled back forward link '{}'",
+                forward.id
+            );
+        }
+    };
+
+    output(
+        &BidirectionalLinkResponse { forward, reverse },
+        flags.format,
+    )
+}
+
+/// Parse `raw` as a known [`Relation`]. `entity_links.relation` is backed by
+/// a fixed set of relation kinds, so `allow_custom` cannot bypass validation
+/// yet; when set, it only makes the rejection message explicit about that
+/// limitation instead of silently accepting an unrecognized relation.
+fn parse_relation(raw: &str, allow_custom: bool) -> anyhow::Result<Relation> {
+    parse_enum::<Relation>(raw, "relation").map_err(|error| {
+        if allow_custom {
+            anyhow::anyhow!(
+                "{error}; --allow-custom is not yet supported because entity_links.relation is a fixed set of relation kinds"
+            )
+        } else {
+            error
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_relation;
+
+    #[test]
+    fn rejects_unknown_relation_without_allow_custom() {
+        let error = parse_relation("valdiates", false).expect_err("typo should be rejected");
+        assert!(error.to_string().contains("invalid relation 'valdiates'"));
+    }
+
+    #[test]
+    fn rejects_unknown_relation_with_allow_custom() {
+        let error = parse_relation("valdiates", true).expect_err("typo should still be rejected");
+        assert!(
+            error
+                .to_string()
+                .contains("--allow-custom is not yet supported")
+        );
+    }
+
+    #[test]
+    fn accepts_known_relation_regardless_of_allow_custom() {
+        assert!(parse_relation("validates", false).is_ok());
+        assert!(parse_relation("validates", true).is_ok());
+    }
 }
@@ -2,6 +2,7 @@ pub mod audit;
 pub mod auth;
 pub mod cache;
 pub mod compat;
+pub mod config;
 pub mod dispatch;
 pub mod finding;
 pub mod grep;
@@ -17,13 +18,16 @@ pub mod log;
 pub mod onboard;
 pub mod prd;
 pub mod rebuild;
+pub mod repl;
 pub mod research;
 pub mod schema;
 pub mod search;
+pub mod serve;
 pub mod session;
 pub mod study;
 pub mod task;
 pub mod team;
+pub mod validate;
 pub mod whats_next;
 pub mod wrap_up;
 
@@ -596,7 +596,7 @@ async fn index_dependency(
     let version = resolved.version.clone();
 
     if ctx
-        .lake
+        .lake()?
         .is_package_indexed(&dep.ecosystem, &dep.name, &version)?
     {
         return Ok(IndexStatus::AlreadyIndexed);
@@ -628,15 +628,16 @@ async fn index_dependency(
     };
 
     let _ = IndexingPipeline::index_directory_with(
-        &ctx.lake,
-        &ctx.source_store,
+        ctx.lake()?,
+        ctx.source_store()?,
         &source_path,
         &dep.ecosystem,
         &dep.name,
         &version,
         &mut ctx.embedder,
-        true,
+        ctx.config.index.skip_test_files,
         dep.ecosystem == "rust",
+        &ctx.config.index,
     )?;
 
     ctx.service
@@ -652,7 +653,7 @@ async fn index_dependency(
 
     if ctx.config.turso.is_configured() && ctx.config.r2.is_configured() {
         match ctx
-            .lake
+            .lake()?
             .write_to_r2(
                 &ctx.config.r2,
                 &dep.ecosystem,
@@ -3,6 +3,7 @@ use zen_core::entities::{Finding, Hypothesis, Issue, Task};
 use zen_core::enums::{EntityType, HypothesisStatus, IssueType, TaskStatus};
 
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::context::AppContext;
 use crate::output::output;
 
@@ -25,7 +26,8 @@ struct TaskProgress {
 }
 
 pub async fn run(id: &str, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
-    let prd = ctx.service.get_issue(id).await?;
+    let id = resolve_id(ctx, "issues", id).await?;
+    let prd = ctx.service.get_issue(&id).await?;
     if prd.issue_type != IssueType::Epic {
         anyhow::bail!(
             "Issue '{id}' is not an epic (type: {}). Use 'znt issue get' for non-epic issues.",
@@ -33,10 +35,10 @@ pub async fn run(id: &str, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Res
         );
     }
 
-    let tasks = ctx.service.get_tasks_for_issue(id).await?;
+    let tasks = ctx.service.get_tasks_for_issue(&id).await?;
     let progress = TaskProgress::from_tasks(tasks);
 
-    let links = ctx.service.get_links_from(EntityType::Issue, id).await?;
+    let links = ctx.service.get_links_from(EntityType::Issue, &id).await?;
     let mut findings = Vec::new();
     let mut open_questions = Vec::new();
 
@@ -1,8 +1,10 @@
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::context::AppContext;
 use crate::output::output;
 
 pub async fn run(id: &str, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
-    let research = ctx.service.get_research(id).await?;
+    let id = resolve_id(ctx, "research_items", id).await?;
+    let research = ctx.service.get_research(&id).await?;
     output(&research, flags.format)
 }
@@ -2,6 +2,7 @@ use zen_core::enums::ResearchStatus;
 use zen_db::updates::research::ResearchUpdateBuilder;
 
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::commands::shared::parse::parse_enum;
 use crate::commands::shared::session::require_active_session_id;
 use crate::context::AppContext;
@@ -15,6 +16,7 @@ pub async fn run(
     ctx: &AppContext,
     flags: &GlobalFlags,
 ) -> anyhow::Result<()> {
+    let id = resolve_id(ctx, "research_items", id).await?;
     let session_id = require_active_session_id(ctx).await?;
 
     if title.is_none() && description.is_none() && status.is_none() {
@@ -34,7 +36,7 @@ pub async fn run(
 
     let updated = ctx
         .service
-        .update_research(&session_id, id, builder.build())
+        .update_research(&session_id, &id, builder.build())
         .await?;
     output(&updated, flags.format)
 }
@@ -5,9 +5,20 @@ use crate::cli::root_commands::SchemaArgs;
 /// Handle `znt schema`.
 pub fn handle(args: &SchemaArgs, flags: &GlobalFlags) -> anyhow::Result<()> {
     let registry = zen_schema::SchemaRegistry::new();
+
+    if args.openapi {
+        let spec = zen_schema::openapi::generate_spec(&registry);
+        println!("{}", serde_json::to_string_pretty(&spec)?);
+        return Ok(());
+    }
+
+    let type_name = args
+        .type_name
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("schema: TYPE_NAME is required unless --openapi is set"))?;
     let schema = registry
-        .get(&args.type_name)
-        .ok_or_else(|| anyhow::anyhow!(unknown_type_message(&args.type_name, &registry.list())))?;
+        .get(type_name)
+        .ok_or_else(|| anyhow::anyhow!(unknown_type_message(type_name, &registry.list())))?;
 
     match flags.format {
         OutputFormat::Raw => {
@@ -4,8 +4,8 @@ use anyhow::{Context, bail};
 use semver::Version;
 use serde::Serialize;
 use zen_search::{
-    RecursiveBudget, RecursiveQuery, RecursiveQueryEngine, SearchEngine, SearchFilters, SearchMode,
-    SearchResult, VectorSearchResult, VectorSource,
+    DecisionGraph, RecursiveBudget, RecursiveQuery, RecursiveQueryEngine, SearchEngine,
+    SearchFilters, SearchMode, SearchResult, VectorSearchResult, VectorSource,
 };
 
 use crate::cli::GlobalFlags;
@@ -47,13 +47,20 @@ pub async fn handle(
     ctx: &mut AppContext,
     flags: &GlobalFlags,
 ) -> anyhow::Result<()> {
-    let mode = parse_mode(args.mode.as_deref())?;
-    let limit = effective_limit(None, flags.limit, 20);
+    let search_config = ctx.config.search.clone();
+    let mode = parse_mode(args.mode.as_deref(), &search_config)?;
+    let limit = effective_limit(None, flags.limit, search_config.default_limit);
 
     if matches!(mode, SearchMode::Recursive) {
         return handle_recursive(args, ctx, flags, limit).await;
     }
 
+    if matches!(mode, SearchMode::Graph)
+        && let Some(graph_format) = args.graph_format.as_deref()
+    {
+        return handle_graph_export(graph_format, ctx, flags).await;
+    }
+
     if let Some(cloud_results) = try_cloud_vector_search(args, ctx, mode, limit).await? {
         let fetched_results = cloud_results.len();
         output(
@@ -71,8 +78,8 @@ pub async fn handle(
 
     let mut engine = SearchEngine::new(
         &ctx.service,
-        &ctx.lake,
-        &ctx.source_store,
+        ctx.lake()?,
+        ctx.source_store()?,
         &mut ctx.embedder,
     );
 
@@ -83,7 +90,9 @@ pub async fn handle(
         kind: args.kind.clone(),
         entity_types: Vec::new(),
         limit: Some(limit),
-        min_score: None,
+        min_score: search_config.min_score,
+        exclude_deprecated: args.exclude_deprecated,
+        explain: args.explain,
     };
 
     let mut results = engine.search(&args.query, mode, filters).await?;
@@ -118,9 +127,9 @@ async fn handle_recursive(
     let mut query = RecursiveQuery::from_text(&args.query);
     query.generate_summary = true;
 
-    let result = if let Some((eco, pkg, version)) = resolve_triplet(args, &ctx.lake)? {
+    let result = if let Some((eco, pkg, version)) = resolve_triplet(args, ctx.lake()?)? {
         let engine = RecursiveQueryEngine::from_source_store(
-            &ctx.source_store,
+            ctx.source_store()?,
             &eco,
             &pkg,
             &version,
@@ -161,11 +170,35 @@ async fn handle_recursive(
     )
 }
 
-fn parse_mode(raw: Option<&str>) -> anyhow::Result<SearchMode> {
-    match raw.unwrap_or("hybrid") {
+/// Handle `znt search --mode graph --graph-format <dot|json>`, bypassing the
+/// analysis summary in favor of a full graph export.
+async fn handle_graph_export(
+    graph_format: &str,
+    ctx: &AppContext,
+    flags: &GlobalFlags,
+) -> anyhow::Result<()> {
+    let graph = DecisionGraph::from_service(&ctx.service).await?;
+
+    match graph_format {
+        "dot" => {
+            println!("{}", graph.to_dot());
+            Ok(())
+        }
+        "json" => output(&graph.to_json(), flags.format),
+        other => bail!("search: invalid --graph-format '{other}'; expected one of: dot, json"),
+    }
+}
+
+pub(crate) fn parse_mode(
+    raw: Option<&str>,
+    search_config: &zen_config::SearchConfig,
+) -> anyhow::Result<SearchMode> {
+    match raw.unwrap_or(&search_config.default_mode) {
         "vector" => Ok(SearchMode::Vector),
         "fts" => Ok(SearchMode::Fts),
-        "hybrid" => Ok(SearchMode::Hybrid { alpha: 0.5 }),
+        "hybrid" => Ok(SearchMode::Hybrid {
+            alpha: search_config.default_alpha,
+        }),
         "recursive" => Ok(SearchMode::Recursive),
         "graph" => Ok(SearchMode::Graph),
         other => {
@@ -263,7 +296,7 @@ async fn try_cloud_vector_search(
                     );
                 } else {
                     match map_cloud_paths_to_search_results(
-                        &ctx.lake,
+                        ctx.lake()?,
                         ctx.config.r2.is_configured().then_some(&ctx.config.r2),
                         ecosystem,
                         package,
@@ -337,7 +370,7 @@ async fn try_cloud_vector_search(
     }
 
     let mapped = map_cloud_paths_to_search_results(
-        &ctx.lake,
+        ctx.lake()?,
         ctx.config.r2.is_configured().then_some(&ctx.config.r2),
         ecosystem,
         package,
@@ -412,17 +445,43 @@ fn canonical_lance_locators(paths: Vec<String>) -> Vec<String> {
 
 #[cfg(test)]
 mod tests {
+    use zen_config::SearchConfig;
+
     use super::{parse_mode, pretty_summary};
 
     #[test]
     fn parse_mode_defaults_to_hybrid() {
-        let mode = parse_mode(None).expect("mode should parse");
+        let mode = parse_mode(None, &SearchConfig::default()).expect("mode should parse");
         assert!(matches!(mode, zen_search::SearchMode::Hybrid { .. }));
     }
 
+    #[test]
+    fn parse_mode_uses_configured_default_mode() {
+        let config = SearchConfig {
+            default_mode: "vector".to_string(),
+            ..Default::default()
+        };
+        let mode = parse_mode(None, &config).expect("mode should parse");
+        assert!(matches!(mode, zen_search::SearchMode::Vector));
+    }
+
+    #[test]
+    fn parse_mode_uses_configured_default_alpha() {
+        let config = SearchConfig {
+            default_alpha: 0.3,
+            ..Default::default()
+        };
+        let mode = parse_mode(Some("hybrid"), &config).expect("mode should parse");
+        assert!(matches!(
+            mode,
+            zen_search::SearchMode::Hybrid { alpha } if (alpha - 0.3).abs() < f64::EPSILON
+        ));
+    }
+
     #[test]
     fn parse_mode_rejects_invalid_value() {
-        let err = parse_mode(Some("nope")).expect_err("invalid mode should fail");
+        let err = parse_mode(Some("nope"), &SearchConfig::default())
+            .expect_err("invalid mode should fail");
         assert!(err.to_string().contains("search: invalid --mode"));
     }
 
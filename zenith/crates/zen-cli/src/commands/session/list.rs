@@ -9,9 +9,14 @@ use crate::output::output;
 pub async fn run(
     status: Option<&str>,
     limit: Option<u32>,
+    metrics: bool,
     ctx: &AppContext,
     flags: &GlobalFlags,
 ) -> anyhow::Result<()> {
+    if metrics {
+        return output(&ctx.service.db().retry_metrics(), flags.format);
+    }
+
     let status = status
         .map(|value| parse_enum::<SessionStatus>(value, "status"))
         .transpose()?;
@@ -1,5 +1,6 @@
 mod end;
 mod list;
+mod resume;
 mod start;
 mod types;
 
@@ -16,8 +17,13 @@ pub async fn handle(
     match action {
         SessionCommands::Start => start::run(ctx, flags).await,
         SessionCommands::End { summary } => end::run(summary.as_deref(), ctx, flags).await,
-        SessionCommands::List { status, limit } => {
-            list::run(status.as_deref(), *limit, ctx, flags).await
+        SessionCommands::Resume { session_id } => {
+            resume::run(session_id.as_deref(), ctx, flags).await
         }
+        SessionCommands::List {
+            status,
+            limit,
+            metrics,
+        } => list::run(status.as_deref(), *limit, *metrics, ctx, flags).await,
     }
 }
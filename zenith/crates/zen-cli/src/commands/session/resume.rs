@@ -0,0 +1,12 @@
+use crate::cli::GlobalFlags;
+use crate::context::AppContext;
+use crate::output::output;
+
+pub async fn run(
+    session_id: Option<&str>,
+    ctx: &AppContext,
+    flags: &GlobalFlags,
+) -> anyhow::Result<()> {
+    let resumed = ctx.service.resume_session(session_id).await?;
+    output(&resumed, flags.format)
+}
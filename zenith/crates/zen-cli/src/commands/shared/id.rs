@@ -0,0 +1,11 @@
+use crate::context::AppContext;
+
+/// Resolve a possibly-partial ID fragment against `table` to a full ID.
+///
+/// Full IDs (e.g. `fnd-a3f8b2c1`) already contain a `-` and pass through
+/// unchanged without a database round trip. Bare fragments (e.g. `a3f8`) are
+/// matched against every id in `table`; the call fails if zero or more than
+/// one id matches.
+pub async fn resolve_id(ctx: &AppContext, table: &str, fragment: &str) -> anyhow::Result<String> {
+    Ok(ctx.service.resolve_partial_id(table, fragment).await?)
+}
@@ -1,3 +1,4 @@
+pub mod id;
 pub mod limit;
 pub mod parse;
 pub mod session;
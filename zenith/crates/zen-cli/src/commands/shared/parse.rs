@@ -1,3 +1,4 @@
+use chrono::Duration;
 use serde::de::DeserializeOwned;
 
 /// Parse a snake_case enum value using serde-deserialization.
@@ -10,11 +11,29 @@ where
     serde_json::from_str(&json).map_err(|error| anyhow::anyhow!("invalid {field} '{raw}': {error}"))
 }
 
+/// Parse a relative duration like `30s`, `24h`, or `7d` into a [`Duration`].
+pub fn parse_relative_duration(raw: &str) -> anyhow::Result<Duration> {
+    let (value, unit) = raw.split_at(raw.len() - 1);
+    let amount: i64 = value.parse().map_err(|_| {
+        anyhow::anyhow!("invalid duration '{raw}': expected a number followed by s/m/h/d")
+    })?;
+
+    match unit {
+        "s" => Ok(Duration::seconds(amount)),
+        "m" => Ok(Duration::minutes(amount)),
+        "h" => Ok(Duration::hours(amount)),
+        "d" => Ok(Duration::days(amount)),
+        _ => Err(anyhow::anyhow!(
+            "invalid duration '{raw}': unrecognized unit '{unit}', expected s/m/h/d"
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use zen_core::enums::{StudyMethodology, StudyStatus};
 
-    use super::parse_enum;
+    use super::{parse_enum, parse_relative_duration};
 
     #[test]
     fn parses_snake_case_enum() {
@@ -34,4 +53,28 @@ mod tests {
         let err = parse_enum::<StudyStatus>("done", "status").expect_err("should fail");
         assert!(err.to_string().contains("invalid status 'done'"));
     }
+
+    #[test]
+    fn parses_hours_and_days() {
+        assert_eq!(
+            parse_relative_duration("24h").unwrap(),
+            chrono::Duration::hours(24)
+        );
+        assert_eq!(
+            parse_relative_duration("7d").unwrap(),
+            chrono::Duration::days(7)
+        );
+    }
+
+    #[test]
+    fn errors_on_unknown_unit() {
+        let err = parse_relative_duration("5x").expect_err("should fail");
+        assert!(err.to_string().contains("unrecognized unit"));
+    }
+
+    #[test]
+    fn errors_on_non_numeric_amount() {
+        let err = parse_relative_duration("abch").expect_err("should fail");
+        assert!(err.to_string().contains("invalid duration"));
+    }
 }
@@ -1,16 +1,7 @@
-use zen_core::enums::SessionStatus;
-
 use crate::context::AppContext;
 
-/// Resolve the current active session ID.
+/// Resolve the current active session ID, starting a new session if none is
+/// active.
 pub async fn require_active_session_id(ctx: &AppContext) -> anyhow::Result<String> {
-    let sessions = ctx
-        .service
-        .list_sessions(Some(SessionStatus::Active), 1)
-        .await?;
-
-    sessions
-        .first()
-        .map(|session| session.id.clone())
-        .ok_or_else(|| anyhow::anyhow!("No active session. Run 'znt session start' first."))
+    Ok(ctx.service.active_or_create_session().await?.id)
 }
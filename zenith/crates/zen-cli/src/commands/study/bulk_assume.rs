@@ -0,0 +1,159 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli::GlobalFlags;
+use crate::commands::shared::session::require_active_session_id;
+use crate::context::AppContext;
+use crate::output::output;
+
+#[derive(Debug, Deserialize)]
+struct BulkAssumeItem {
+    content: String,
+}
+
+#[derive(Debug, Serialize)]
+struct BulkAssumeReport {
+    study_id: String,
+    created: u32,
+    skipped: u32,
+}
+
+pub async fn run(
+    study_id: &str,
+    file: &Path,
+    ctx: &AppContext,
+    flags: &GlobalFlags,
+) -> anyhow::Result<()> {
+    let session_id = require_active_session_id(ctx).await?;
+    let items = parse_bulk_assume_file(file)?;
+
+    let mut created_ids: Vec<String> = Vec::new();
+    let mut skipped = 0u32;
+
+    for item in &items {
+        let content = item.content.trim();
+        if content.is_empty() {
+            skipped += 1;
+            continue;
+        }
+
+        match ctx
+            .service
+            .add_assumption(&session_id, study_id, content)
+            .await
+        {
+            Ok(hyp_id) => created_ids.push(hyp_id),
+            Err(error) => {
+                let rollback_error = rollback_assumptions(ctx, &session_id, &created_ids)
+                    .await
+                    .err();
+                if let Some(rollback_error) = rollback_error {
+                    anyhow::bail!(
+                        "Failed to add assumption '{content}': {error}. Created assumption IDs before rollback: [{}]. Rollback failed: {rollback_error}",
+                        created_ids.join(", ")
+                    );
+                }
+                anyhow::bail!(
+                    "Failed to add assumption '{content}': {error}. Rolled back created assumption IDs: [{}]",
+                    created_ids.join(", ")
+                );
+            }
+        }
+    }
+
+    output(
+        &BulkAssumeReport {
+            study_id: study_id.to_string(),
+            created: u32::try_from(created_ids.len()).unwrap_or(u32::MAX),
+            skipped,
+        },
+        flags.format,
+    )
+}
+
+fn parse_bulk_assume_file(file: &Path) -> anyhow::Result<Vec<BulkAssumeItem>> {
+    let raw = std::fs::read_to_string(file)
+        .map_err(|error| anyhow::anyhow!("Failed to read --file {}: {error}", file.display()))?;
+
+    let items: Vec<BulkAssumeItem> = serde_json::from_str(&raw).map_err(|error| {
+        anyhow::anyhow!(
+            "Invalid --file JSON: {error}. Expected an array of {{ \"content\": \"...\" }} objects"
+        )
+    })?;
+
+    if items.is_empty() {
+        anyhow::bail!("--file array is empty. Provide at least one assumption.");
+    }
+
+    Ok(items)
+}
+
+async fn rollback_assumptions(
+    ctx: &AppContext,
+    session_id: &str,
+    hyp_ids: &[String],
+) -> anyhow::Result<()> {
+    let mut rollback_failures = Vec::new();
+    for hyp_id in hyp_ids.iter().rev() {
+        if let Err(error) = ctx.service.delete_hypothesis(session_id, hyp_id).await {
+            rollback_failures.push(format!("{hyp_id} ({error})"));
+        }
+    }
+
+    if rollback_failures.is_empty() {
+        return Ok(());
+    }
+
+    anyhow::bail!(
+        "Unable to rollback all created assumptions. Failed deletes: {}",
+        rollback_failures.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_bulk_assume_file;
+
+    #[test]
+    fn parses_non_empty_json_array() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("assumptions.json");
+        std::fs::write(
+            &path,
+            r#"[{"content":"a"},{"content":"b"},{"content":"c"}]"#,
+        )
+        .expect("write");
+
+        let items = parse_bulk_assume_file(&path).expect("json array should parse");
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].content, "a");
+    }
+
+    #[test]
+    fn rejects_missing_file() {
+        let error = parse_bulk_assume_file(std::path::Path::new("/nonexistent/assumptions.json"))
+            .expect_err("missing file should fail");
+        assert!(error.to_string().contains("Failed to read --file"));
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("assumptions.json");
+        std::fs::write(&path, "not-json").expect("write");
+
+        let error = parse_bulk_assume_file(&path).expect_err("invalid json should fail");
+        assert!(error.to_string().contains("Invalid --file JSON"));
+    }
+
+    #[test]
+    fn rejects_empty_array() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("assumptions.json");
+        std::fs::write(&path, "[]").expect("write");
+
+        let error = parse_bulk_assume_file(&path).expect_err("empty array should fail");
+        assert!(error.to_string().contains("--file array is empty"));
+    }
+}
@@ -1,4 +1,5 @@
 mod assume;
+mod bulk_assume;
 mod conclude;
 mod create;
 mod get;
@@ -33,6 +34,9 @@ pub async fn handle(
             .await
         }
         StudyCommands::Assume { id, content } => assume::run(id, content, ctx, flags).await,
+        StudyCommands::BulkAssume { study_id, file } => {
+            bulk_assume::run(study_id, file, ctx, flags).await
+        }
         StudyCommands::Test {
             id,
             assumption_id,
@@ -1,8 +1,10 @@
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::context::AppContext;
 use crate::output::output;
 
 pub async fn run(id: &str, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
-    let task = ctx.service.get_task(id).await?;
+    let id = resolve_id(ctx, "tasks", id).await?;
+    let task = ctx.service.get_task(&id).await?;
     output(&task, flags.format)
 }
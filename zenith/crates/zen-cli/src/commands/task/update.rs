@@ -2,6 +2,7 @@ use zen_core::enums::TaskStatus;
 use zen_db::updates::task::TaskUpdateBuilder;
 
 use crate::cli::GlobalFlags;
+use crate::commands::shared::id::resolve_id;
 use crate::commands::shared::parse::parse_enum;
 use crate::commands::shared::session::require_active_session_id;
 use crate::context::AppContext;
@@ -18,6 +19,7 @@ pub struct Params {
 
 pub async fn run(params: Params, ctx: &AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
     validate_update_params(&params)?;
+    let id = resolve_id(ctx, "tasks", &params.id).await?;
     let session_id = require_active_session_id(ctx).await?;
 
     let mut builder = TaskUpdateBuilder::new();
@@ -39,7 +41,7 @@ pub async fn run(params: Params, ctx: &AppContext, flags: &GlobalFlags) -> anyho
 
     let task = ctx
         .service
-        .update_task(&session_id, &params.id, builder.build())
+        .update_task(&session_id, &id, builder.build())
         .await?;
     output(&task, flags.format)
 }
@@ -0,0 +1,72 @@
+use anyhow::bail;
+use zen_core::responses::{ValidationCheck, ValidationReport};
+
+use crate::cli::GlobalFlags;
+use crate::cli::root_commands::ValidateArgs;
+use crate::context::AppContext;
+use crate::output::output;
+
+/// Handle `znt validate`.
+pub async fn handle(
+    _args: &ValidateArgs,
+    ctx: &mut AppContext,
+    flags: &GlobalFlags,
+) -> anyhow::Result<()> {
+    let trail_report = zen_hooks::validate_staged_trail_files(&ctx.project_root)?;
+    let trail_check = ValidationCheck {
+        name: "trail_files".to_string(),
+        passed: trail_report.is_valid(),
+        message: trail_check_message(&trail_report),
+    };
+
+    let dangling_links = ctx.service.check_dangling_links().await?;
+    let links_check = ValidationCheck {
+        name: "entity_links".to_string(),
+        passed: dangling_links.is_empty(),
+        message: problems_message(&dangling_links, "all entity links resolve"),
+    };
+
+    let dangling_audit = ctx.service.check_dangling_audit_entries().await?;
+    let audit_check = ValidationCheck {
+        name: "audit_trail".to_string(),
+        passed: dangling_audit.is_empty(),
+        message: problems_message(&dangling_audit, "all audit entries resolve"),
+    };
+
+    let fts_problems = ctx.service.check_fts_sync().await?;
+    let fts_check = ValidationCheck {
+        name: "fts_indexes".to_string(),
+        passed: fts_problems.is_empty(),
+        message: problems_message(&fts_problems, "all FTS indexes are in sync"),
+    };
+
+    let report = ValidationReport {
+        checks: vec![trail_check, links_check, audit_check, fts_check],
+    };
+    let valid = report.is_valid();
+
+    output(&report, flags.format)?;
+    if !valid {
+        bail!("validate: one or more consistency checks failed");
+    }
+    Ok(())
+}
+
+fn trail_check_message(report: &zen_hooks::TrailValidationReport) -> String {
+    if report.is_valid() {
+        format!(
+            "{} trail file(s), {} operation(s) checked",
+            report.files_checked, report.operations_checked
+        )
+    } else {
+        format!("{} trail validation error(s)", report.errors.len())
+    }
+}
+
+fn problems_message(problems: &[String], ok_message: &str) -> String {
+    if problems.is_empty() {
+        ok_message.to_string()
+    } else {
+        problems.join("; ")
+    }
+}
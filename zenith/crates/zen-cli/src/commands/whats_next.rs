@@ -2,11 +2,26 @@ use zen_db::repos::audit::AuditFilter;
 
 use crate::cli::GlobalFlags;
 use crate::cli::OutputFormat;
+use crate::cli::root_commands::WhatsNextArgs;
+use crate::commands::shared::parse::parse_relative_duration;
 use crate::context::AppContext;
 use crate::output::output;
 
 /// Handle `znt whats-next`.
-pub async fn handle(ctx: &mut AppContext, flags: &GlobalFlags) -> anyhow::Result<()> {
+pub async fn handle(
+    args: &WhatsNextArgs,
+    ctx: &mut AppContext,
+    flags: &GlobalFlags,
+) -> anyhow::Result<()> {
+    if let Some(since) = args.since.as_deref() {
+        let duration = parse_relative_duration(since)?;
+        let summaries = ctx
+            .service
+            .recent_activity(chrono::Utc::now() - duration)
+            .await?;
+        return output(&summaries, flags.format);
+    }
+
     match flags.format {
         OutputFormat::Raw => {
             let entries = ctx
@@ -14,13 +14,38 @@ use super::catalog_cache::CatalogCache;
 pub enum LakeAccessMode {
     ReadOnly,
     ReadWrite,
+    /// The lake was skipped entirely (`ZENITH_GENERAL__NO_LAKE=true`).
+    Disabled,
+}
+
+impl LakeAccessMode {
+    /// Overrides `self` to [`LakeAccessMode::Disabled`] when
+    /// `config.general.no_lake` is set (`ZENITH_GENERAL__NO_LAKE=true`) —
+    /// used both to decide whether to open `DuckDB` in [`AppContext::init`]
+    /// and whether the advisory write lock needs to be taken at all.
+    #[must_use]
+    pub fn effective(self, config: &ZenConfig) -> Self {
+        if config.general.no_lake {
+            Self::Disabled
+        } else {
+            self
+        }
+    }
 }
 
 impl From<LakeAccessMode> for OpenMode {
+    /// # Panics
+    ///
+    /// Panics if `value` is [`LakeAccessMode::Disabled`] — callers must check
+    /// for that case before opening anything, since there is no `DuckDB`
+    /// access mode that means "don't open".
     fn from(value: LakeAccessMode) -> Self {
         match value {
             LakeAccessMode::ReadOnly => OpenMode::ReadOnly,
             LakeAccessMode::ReadWrite => OpenMode::ReadWrite,
+            LakeAccessMode::Disabled => {
+                unreachable!("LakeAccessMode::Disabled has no corresponding OpenMode")
+            }
         }
     }
 }
@@ -29,8 +54,13 @@ impl From<LakeAccessMode> for OpenMode {
 pub struct AppContext {
     pub service: ZenService,
     pub config: ZenConfig,
-    pub lake: ZenLake,
-    pub source_store: SourceFileStore,
+    /// `None` when the lake was skipped (`ZENITH_GENERAL__NO_LAKE=true`).
+    /// Use [`AppContext::lake`] to get a lake-required error automatically.
+    pub lake: Option<ZenLake>,
+    /// `None` when the lake was skipped (`ZENITH_GENERAL__NO_LAKE=true`) —
+    /// `SourceFileStore` is a `DuckDB`-backed store like `ZenLake`, so it's
+    /// skipped for the same reason. Use [`AppContext::source_store`].
+    pub source_store: Option<SourceFileStore>,
     pub embedder: EmbeddingEngine,
     pub registry: RegistryClient,
     pub project_root: PathBuf,
@@ -117,31 +147,46 @@ impl AppContext {
                 .context("failed to initialize zen-db service")?
         };
 
-        let lake = match ZenLake::open_local_with_mode(&lake_path_str, lake_access_mode.into()) {
-            Ok(lake) => lake,
-            Err(error)
-                if lake_access_mode == LakeAccessMode::ReadOnly
-                    && error.to_string().contains("database does not exist") =>
-            {
+        // `ZENITH_GENERAL__NO_LAKE=true` overrides the caller's requested mode
+        // and skips opening DuckDB entirely — used by lightweight commands
+        // (or machines without DuckDB) that only need `zen-db`.
+        let effective_lake_mode = lake_access_mode.effective(&config);
+
+        let lake = match effective_lake_mode {
+            LakeAccessMode::Disabled => None,
+            LakeAccessMode::ReadOnly => Some(match ZenLake::open_read_only(&lake_path_str) {
+                Ok(lake) => lake,
+                Err(error) if error.to_string().contains("database does not exist") => {
+                    ZenLake::open_local_with_mode(&lake_path_str, OpenMode::ReadWrite)
+                        .context("failed to initialize local zen lake")?
+                }
+                Err(error) => return Err(error).context("failed to open local zen lake"),
+            }),
+            LakeAccessMode::ReadWrite => Some(
                 ZenLake::open_local_with_mode(&lake_path_str, OpenMode::ReadWrite)
-                    .context("failed to initialize local zen lake")?
-            }
-            Err(error) => return Err(error).context("failed to open local zen lake"),
+                    .context("failed to open local zen lake")?,
+            ),
         };
 
-        let source_store =
-            match SourceFileStore::open_with_mode(&source_path_str, lake_access_mode.into()) {
-                Ok(store) => store,
-                Err(error)
-                    if lake_access_mode == LakeAccessMode::ReadOnly
-                        && error.to_string().contains("database does not exist") =>
+        let source_store = match effective_lake_mode {
+            LakeAccessMode::Disabled => None,
+            LakeAccessMode::ReadOnly | LakeAccessMode::ReadWrite => Some(
+                match SourceFileStore::open_with_mode(&source_path_str, effective_lake_mode.into())
                 {
-                    SourceFileStore::open_with_mode(&source_path_str, OpenMode::ReadWrite)
-                        .context("failed to initialize source file store")?
-                }
-                Err(error) => return Err(error).context("failed to open source file store"),
-            };
-        let embedder = EmbeddingEngine::new().context("failed to initialize embedding engine")?;
+                    Ok(store) => store,
+                    Err(error)
+                        if effective_lake_mode == LakeAccessMode::ReadOnly
+                            && error.to_string().contains("database does not exist") =>
+                    {
+                        SourceFileStore::open_with_mode(&source_path_str, OpenMode::ReadWrite)
+                            .context("failed to initialize source file store")?
+                    }
+                    Err(error) => return Err(error).context("failed to open source file store"),
+                },
+            ),
+        };
+        let embedder = EmbeddingEngine::new_from_config(&config.embeddings)
+            .context("failed to initialize embedding engine")?;
         let registry = RegistryClient::new();
         let cache_ttl = std::env::var("ZENITH_CACHE__CATALOG_TTL_SECS")
             .ok()
@@ -173,6 +218,56 @@ impl AppContext {
             catalog_cache,
         })
     }
+
+    /// Liveness probe across the state database and the analytical lake.
+    ///
+    /// Delegates to [`ZenService::health_check`] for the database round-trip
+    /// (and, for synced replicas, sync staleness), then queries `ZenLake`'s
+    /// indexed package count as a second, independent signal that the lake
+    /// connection is alive. `None` when the lake was skipped
+    /// (`ZENITH_GENERAL__NO_LAKE=true`).
+    pub async fn health_check(&self) -> anyhow::Result<AppHealth> {
+        let db_latency = self
+            .service
+            .health_check()
+            .await
+            .context("zen-db health check failed")?;
+        let indexed_package_count = self
+            .lake
+            .as_ref()
+            .map(ZenLake::count_indexed_packages)
+            .transpose()
+            .context("zen-lake health check failed")?;
+
+        Ok(AppHealth {
+            db_latency,
+            indexed_package_count,
+        })
+    }
+
+    /// Returns the lake, or [`SearchError::LakeDisabled`] if it was skipped
+    /// at startup (`ZENITH_GENERAL__NO_LAKE=true`).
+    pub fn lake(&self) -> Result<&ZenLake, zen_search::SearchError> {
+        self.lake
+            .as_ref()
+            .ok_or(zen_search::SearchError::LakeDisabled)
+    }
+
+    /// Returns the source file store, or [`SearchError::LakeDisabled`] if it
+    /// was skipped at startup (`ZENITH_GENERAL__NO_LAKE=true`).
+    pub fn source_store(&self) -> Result<&SourceFileStore, zen_search::SearchError> {
+        self.source_store
+            .as_ref()
+            .ok_or(zen_search::SearchError::LakeDisabled)
+    }
+}
+
+/// Result of [`AppContext::health_check`].
+#[derive(Debug, Clone, Copy)]
+pub struct AppHealth {
+    pub db_latency: std::time::Duration,
+    /// `None` when the lake was skipped (`ZENITH_GENERAL__NO_LAKE=true`).
+    pub indexed_package_count: Option<usize>,
 }
 
 /// Resolve auth token with optional JWKS validation.
@@ -215,6 +310,7 @@ async fn resolve_auth(config: &ZenConfig) -> (Option<String>, Option<AuthIdentit
 
     match zen_auth::resolve_and_validate(secret_key).await {
         Ok(Some(claims)) => {
+            warn_if_expiring_soon(&claims);
             let identity = claims.to_identity();
             (Some(claims.raw_jwt), Some(identity))
         }
@@ -230,3 +326,139 @@ async fn resolve_auth(config: &ZenConfig) -> (Option<String>, Option<AuthIdentit
         }
     }
 }
+
+const DEFAULT_AUTH_WARN_THRESHOLD_MINUTES: i64 = 15;
+
+/// Warn if `claims` will expire soon, so the user can refresh before it
+/// interrupts an in-progress operation.
+///
+/// Threshold defaults to 15 minutes; override with
+/// `ZENITH_AUTH_WARN_THRESHOLD_MINUTES`.
+fn warn_if_expiring_soon(claims: &zen_auth::ZenClaims) {
+    let threshold_minutes = std::env::var("ZENITH_AUTH_WARN_THRESHOLD_MINUTES")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_AUTH_WARN_THRESHOLD_MINUTES);
+
+    if let Some(mins) = expiry_warning_minutes(claims, threshold_minutes) {
+        tracing::warn!("auth token expires in {mins} minutes — run 'znt auth login' to refresh");
+    }
+}
+
+/// Returns the number of minutes until `claims` expires when that is below
+/// `threshold_minutes`, or `None` if the token is not yet close to expiry.
+fn expiry_warning_minutes(claims: &zen_auth::ZenClaims, threshold_minutes: i64) -> Option<i64> {
+    let expires_in = claims.expires_in();
+    (expires_in < chrono::TimeDelta::minutes(threshold_minutes)).then(|| expires_in.num_minutes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use chrono::Utc;
+
+    use super::*;
+
+    fn make_claims(expires_at: chrono::DateTime<Utc>) -> zen_auth::ZenClaims {
+        zen_auth::ZenClaims {
+            raw_jwt: "test.jwt.token".into(),
+            user_id: "user_test".into(),
+            org_id: None,
+            org_slug: None,
+            org_role: None,
+            expires_at,
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for SharedBuf {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn captured_output(f: impl FnOnce()) -> String {
+        let buf = SharedBuf::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(buf.clone())
+            .with_ansi(false)
+            .finish();
+        tracing::subscriber::with_default(subscriber, f);
+        String::from_utf8(buf.0.lock().unwrap().clone()).unwrap()
+    }
+
+    #[test]
+    fn warns_when_token_expires_within_threshold() {
+        let claims = make_claims(Utc::now() + chrono::TimeDelta::minutes(5));
+        let output = captured_output(|| warn_if_expiring_soon(&claims));
+        assert!(output.contains("auth token expires in"));
+    }
+
+    #[test]
+    fn does_not_warn_when_token_far_from_expiry() {
+        let claims = make_claims(Utc::now() + chrono::TimeDelta::hours(1));
+        let output = captured_output(|| warn_if_expiring_soon(&claims));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn expiry_warning_minutes_respects_custom_threshold() {
+        let claims = make_claims(Utc::now() + chrono::TimeDelta::minutes(5));
+        assert_eq!(expiry_warning_minutes(&claims, 15), Some(4));
+        assert_eq!(expiry_warning_minutes(&claims, 2), None);
+    }
+
+    #[tokio::test]
+    async fn no_lake_config_skips_opening_the_lake() {
+        let project_root = tempfile::tempdir().unwrap();
+        let mut config = ZenConfig::default();
+        config.general.no_lake = true;
+
+        let ctx = AppContext::init(
+            project_root.path().to_path_buf(),
+            config,
+            LakeAccessMode::ReadWrite,
+        )
+        .await
+        .expect("init should succeed without opening the lake");
+
+        assert!(ctx.lake.is_none());
+        assert!(matches!(
+            ctx.lake(),
+            Err(zen_search::SearchError::LakeDisabled)
+        ));
+        assert!(
+            !project_root.path().join(".zenith/lake.duckdb").exists(),
+            "lake.duckdb should never be created when ZENITH_GENERAL__NO_LAKE is set"
+        );
+
+        assert!(ctx.source_store.is_none());
+        assert!(matches!(
+            ctx.source_store(),
+            Err(zen_search::SearchError::LakeDisabled)
+        ));
+        assert!(
+            !project_root
+                .path()
+                .join(".zenith/source_files.duckdb")
+                .exists(),
+            "source_files.duckdb should never be created when ZENITH_GENERAL__NO_LAKE is set"
+        );
+    }
+}
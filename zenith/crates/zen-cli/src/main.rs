@@ -27,14 +27,16 @@ mod spike_clap;
 
 #[tokio::main]
 async fn main() {
-    if let Err(error) = run().await {
-        eprintln!("znt error: {error:#}");
+    let cli = cli::Cli::parse();
+    let format = cli.global_flags().format;
+
+    if let Err(error) = run(cli).await {
+        output::emit_error(&error, format);
         std::process::exit(1);
     }
 }
 
-async fn run() -> anyhow::Result<()> {
-    let cli = cli::Cli::parse();
+async fn run(cli: cli::Cli) -> anyhow::Result<()> {
     init_tracing(cli.quiet, cli.verbose)?;
 
     let flags = cli.global_flags();
@@ -53,24 +55,24 @@ async fn run() -> anyhow::Result<()> {
         return commands::auth::handle(action, &flags, &config).await;
     }
 
+    if let cli::Commands::Config { action } = &cli.command {
+        return commands::config::handle(action, &flags, &config).await;
+    }
+
     let project_root = resolve_project_root(flags.project.as_deref())?;
     context::warn_unconfigured(&config);
 
     let command = cli.command;
-    let write_lock = if command_requires_write_lock(&command) {
-        Some(write_lock::acquire_for_project(&project_root).await?)
-    } else {
-        None
-    };
-
     let lake_access_mode = lake_access_mode_for_command(&command);
+    let lake_lock =
+        write_lock::acquire_for_project(&project_root, lake_access_mode.effective(&config)).await?;
 
     let mut ctx = context::AppContext::init(project_root, config, lake_access_mode)
         .await
         .context("failed to initialize zenith application context")?;
 
     let result = commands::dispatch::dispatch(command, &mut ctx, &flags).await;
-    drop(write_lock);
+    drop(lake_lock);
     result
 }
 
@@ -139,7 +141,8 @@ fn command_requires_write_lock(command: &cli::Commands) -> bool {
         cli::Commands::Search(_)
         | cli::Commands::Grep(_)
         | cli::Commands::Audit(_)
-        | cli::Commands::WhatsNext => false,
+        | cli::Commands::WhatsNext(_)
+        | cli::Commands::Validate(_) => false,
         cli::Commands::Session { action } => !matches!(action, SessionCommands::List { .. }),
         cli::Commands::Cache { action } => matches!(action, CacheCommands::Clean { .. }),
         cli::Commands::Research { action } => !matches!(
@@ -172,7 +175,7 @@ fn command_requires_write_lock(command: &cli::Commands) -> bool {
         }
         cli::Commands::Compat { action } => !matches!(
             action,
-            CompatCommands::List { .. } | CompatCommands::Get { .. }
+            CompatCommands::List { .. } | CompatCommands::Get { .. } | CompatCommands::Matrix
         ),
         cli::Commands::Study { action } => !matches!(
             action,
@@ -180,17 +183,20 @@ fn command_requires_write_lock(command: &cli::Commands) -> bool {
         ),
         cli::Commands::Team { action } => !matches!(action, TeamCommands::List),
         cli::Commands::Log(_)
-        | cli::Commands::Link(_)
+        | cli::Commands::Link { .. }
         | cli::Commands::Unlink(_)
         | cli::Commands::WrapUp(_)
         | cli::Commands::Install(_)
         | cli::Commands::Onboard(_)
         | cli::Commands::Rebuild(_)
-        | cli::Commands::Index(_) => true,
+        | cli::Commands::Index(_)
+        | cli::Commands::Repl(_)
+        | cli::Commands::Serve(_) => true,
         cli::Commands::Init(_)
         | cli::Commands::Hook { .. }
         | cli::Commands::Schema(_)
-        | cli::Commands::Auth { .. } => false,
+        | cli::Commands::Auth { .. }
+        | cli::Commands::Config { .. } => false,
     }
 }
 
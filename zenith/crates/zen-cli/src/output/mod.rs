@@ -1,5 +1,10 @@
 use serde::Serialize;
 use serde_json::Value;
+use zen_auth::AuthError;
+use zen_config::ConfigError;
+use zen_hooks::HookError;
+use zen_schema::SchemaError;
+use zen_secrets::SecretError;
 
 use crate::cli::OutputFormat;
 use crate::ui;
@@ -22,6 +27,57 @@ pub fn output<T: Serialize>(value: &T, format: OutputFormat) -> anyhow::Result<(
     Ok(())
 }
 
+/// Coarse machine-readable classification of a top-level command error, used
+/// only by the `json`/`raw` error shape in [`emit_error`]. Falls back to
+/// `"command_error"` when the failure doesn't downcast to one of Zenith's
+/// typed errors, which covers most CLI-level failures since they're raised
+/// as ad hoc `anyhow!(...)` messages rather than a dedicated error type.
+fn error_kind(error: &anyhow::Error) -> &'static str {
+    if error.downcast_ref::<SchemaError>().is_some() {
+        "schema_error"
+    } else if error.downcast_ref::<HookError>().is_some() {
+        "hook_error"
+    } else if error.downcast_ref::<ConfigError>().is_some() {
+        "config_error"
+    } else if error.downcast_ref::<AuthError>().is_some() {
+        "auth_error"
+    } else if error.downcast_ref::<SecretError>().is_some() {
+        "secret_error"
+    } else {
+        "command_error"
+    }
+}
+
+/// Render a top-level command failure to a string: the JSON shape
+/// `{ "error": { "message", "kind" } }` for `json`/`raw` formats, so
+/// machine consumers parsing stderr as JSON don't choke on plain text, or
+/// the familiar human-readable line for `table`.
+fn render_error(error: &anyhow::Error, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Table => format!("znt error: {error:#}"),
+        OutputFormat::Json | OutputFormat::Raw => {
+            let body = serde_json::json!({
+                "error": {
+                    "message": format!("{error:#}"),
+                    "kind": error_kind(error),
+                }
+            });
+            if matches!(format, OutputFormat::Json) {
+                serde_json::to_string_pretty(&body)
+            } else {
+                serde_json::to_string(&body)
+            }
+            .unwrap_or_else(|_| body.to_string())
+        }
+    }
+}
+
+/// Print a top-level command failure to stderr in the format described by
+/// [`render_error`].
+pub fn emit_error(error: &anyhow::Error, format: OutputFormat) {
+    eprintln!("{}", render_error(error, format));
+}
+
 fn render_table<T: Serialize>(value: &T) -> anyhow::Result<String> {
     let prefs = ui::prefs();
     let options = table::TableOptions {
@@ -106,21 +162,58 @@ fn render_array_table(items: &[Value]) -> anyhow::Result<String> {
     Ok(table::render_entity_table(&header_refs, &rows, options))
 }
 
+/// Cap on how many keys a nested-object cell summary lists before
+/// collapsing the rest into a `+N more` suffix.
+const OBJECT_SUMMARY_MAX_KEYS: usize = 3;
+
 fn value_to_cell(value: &Value) -> String {
     match value {
         Value::Null => String::from("null"),
         Value::Bool(v) => v.to_string(),
         Value::Number(v) => v.to_string(),
         Value::String(v) => v.clone(),
+        Value::Array(items) if !items.is_empty() && items.iter().all(Value::is_string) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(map) => summarize_object(map),
         other => serde_json::to_string(other).unwrap_or_else(|_| String::from("<invalid-json>")),
     }
 }
 
+/// Render a nested object as a compact `{key, key, +N more}` summary instead
+/// of escaped JSON, since a raw-escaped object is unreadable in a table cell.
+fn summarize_object(map: &serde_json::Map<String, Value>) -> String {
+    if map.is_empty() {
+        return String::from("{}");
+    }
+
+    let mut keys = map.keys().map(String::as_str).collect::<Vec<_>>();
+    keys.sort_unstable();
+
+    let shown = keys
+        .iter()
+        .take(OBJECT_SUMMARY_MAX_KEYS)
+        .copied()
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if keys.len() > OBJECT_SUMMARY_MAX_KEYS {
+        format!(
+            "{{{shown}, +{} more}}",
+            keys.len() - OBJECT_SUMMARY_MAX_KEYS
+        )
+    } else {
+        format!("{{{shown}}}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::Serialize;
 
-    use super::{render, table::render_entity_table};
+    use super::{render, render_error, table::render_entity_table};
     use crate::cli::OutputFormat;
 
     #[derive(Serialize)]
@@ -156,6 +249,60 @@ mod tests {
         assert!(out.contains("value"));
     }
 
+    #[test]
+    fn json_format_error_parses_as_error_shape() {
+        let error = anyhow::anyhow!("thing not found");
+        let rendered = render_error(&error, OutputFormat::Json);
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(&rendered).expect("stderr should parse as json");
+        assert_eq!(parsed["error"]["message"], "thing not found");
+        assert_eq!(parsed["error"]["kind"], "command_error");
+    }
+
+    #[test]
+    fn raw_format_error_is_single_line_json() {
+        let error = anyhow::anyhow!("thing not found");
+        let rendered = render_error(&error, OutputFormat::Raw);
+
+        assert!(!rendered.contains('\n'));
+        let parsed: serde_json::Value =
+            serde_json::from_str(&rendered).expect("stderr should parse as json");
+        assert_eq!(parsed["error"]["message"], "thing not found");
+    }
+
+    #[test]
+    fn table_format_error_stays_plain_text() {
+        let error = anyhow::anyhow!("thing not found");
+        let rendered = render_error(&error, OutputFormat::Table);
+
+        assert_eq!(rendered, "znt error: thing not found");
+        assert!(serde_json::from_str::<serde_json::Value>(&rendered).is_err());
+    }
+
+    #[test]
+    fn known_error_types_are_classified_by_kind() {
+        let error: anyhow::Error = zen_schema::SchemaError::NotFound("finding".into()).into();
+        let rendered = render_error(&error, OutputFormat::Json);
+        let parsed: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(parsed["error"]["kind"], "schema_error");
+    }
+
+    #[test]
+    fn array_table_joins_string_tags_and_summarizes_nested_objects() {
+        let value = serde_json::json!([{
+            "id": "finding-1",
+            "finding_tags": ["security", "high-severity"],
+            "location": { "file": "src/lib.rs", "line": 42 },
+        }]);
+        let out = render(&value, OutputFormat::Table).expect("table render should work");
+
+        assert!(out.contains("security, high-severity"));
+        assert!(!out.contains("[\"security\""));
+        assert!(out.contains("{file, line}"));
+        assert!(!out.contains("{\"file\""));
+    }
+
     #[test]
     fn table_alignment_handles_mixed_widths() {
         let headers = ["id", "status", "title"];
@@ -184,4 +331,25 @@ mod tests {
         assert!(lines[0].contains("title"));
         assert!(lines[1].chars().all(|c| c == '-'));
     }
+
+    #[test]
+    fn table_truncates_wide_columns_but_preserves_id_column() {
+        let headers = ["id", "content"];
+        let long_id = "fnd-abcdef1234567890";
+        let rows = vec![vec![long_id.to_string(), "x".repeat(200)]];
+
+        let table = render_entity_table(
+            &headers,
+            &rows,
+            super::table::TableOptions {
+                max_width: Some(40),
+                color: false,
+            },
+        );
+
+        for line in table.lines() {
+            assert!(line.chars().count() <= 40, "line too wide: {line:?}");
+        }
+        assert!(table.contains(long_id), "id column should stay intact");
+    }
 }
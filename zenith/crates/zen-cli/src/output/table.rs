@@ -68,6 +68,9 @@ pub fn render_entity_table(
     lines.join("\n")
 }
 
+/// Shrink `widths` to fit `max_width`, proportionally truncating the widest
+/// columns first. The `id` column (matched case-insensitively by header
+/// name) is never shrunk, even if the table can't fit as a result.
 fn fit_widths(widths: &mut [usize], headers: &[&str], max_width: Option<usize>) {
     let Some(max_width) = max_width else {
         return;
@@ -78,12 +81,47 @@ fn fit_widths(widths: &mut [usize], headers: &[&str], max_width: Option<usize>)
     }
 
     let separators = widths.len().saturating_sub(1) * 2;
-    let mut total = widths.iter().sum::<usize>() + separators;
+    let total = widths.iter().sum::<usize>() + separators;
     if total <= max_width {
         return;
     }
 
+    let id_index = headers
+        .iter()
+        .position(|header| header.eq_ignore_ascii_case("id"));
+    let min_widths = headers
+        .iter()
+        .map(|header| header.len().max(6))
+        .collect::<Vec<_>>();
+
+    let overflow = total - max_width;
+    let truncatable_slack = widths
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| Some(*idx) != id_index)
+        .map(|(idx, width)| width.saturating_sub(min_widths[idx]))
+        .sum::<usize>();
+
+    if truncatable_slack == 0 {
+        return;
+    }
+
+    // Shrink each truncatable column proportionally to its share of the
+    // total slack, then mop up any remainder (from integer rounding) below.
+    for (idx, width) in widths.iter_mut().enumerate() {
+        if Some(idx) == id_index {
+            continue;
+        }
+        let slack = width.saturating_sub(min_widths[idx]);
+        if slack == 0 {
+            continue;
+        }
+        let share = overflow * slack / truncatable_slack;
+        *width -= share.min(slack);
+    }
+
     loop {
+        let total = widths.iter().sum::<usize>() + separators;
         if total <= max_width {
             break;
         }
@@ -91,8 +129,10 @@ fn fit_widths(widths: &mut [usize], headers: &[&str], max_width: Option<usize>)
         let mut candidate_idx = None;
         let mut candidate_width = 0usize;
         for (idx, width) in widths.iter().enumerate() {
-            let min_width = headers[idx].len().max(6);
-            if *width > min_width && *width > candidate_width {
+            if Some(idx) == id_index {
+                continue;
+            }
+            if *width > min_widths[idx] && *width > candidate_width {
                 candidate_idx = Some(idx);
                 candidate_width = *width;
             }
@@ -103,7 +143,6 @@ fn fit_widths(widths: &mut [usize], headers: &[&str], max_width: Option<usize>)
         };
 
         widths[idx] = widths[idx].saturating_sub(1);
-        total = widths.iter().sum::<usize>() + separators;
     }
 }
 
@@ -5,11 +5,16 @@
 //! Orchestrates the end-to-end indexing of a local directory into the local DuckDB cache.
 //! The pipeline:
 //! 1. Walk files (using `zen-search::walk::build_walker`)
-//! 2. Parse each file with `zen-parser::extract_api`
-//! 3. Chunk documentation files with `zen-parser::chunk_document`
-//! 4. Generate embeddings with `zen-embeddings::EmbeddingEngine` (batch)
-//! 5. Store symbols, doc chunks, and source files in DuckDB via `ZenLake` and `SourceFileStore`
-//! 6. Register the package in `indexed_packages` and mark `source_cached = TRUE`
+//! 2. Process files in batches of `IndexConfig::embed_batch_size`, each batch:
+//!    a. Parse with `zen-parser::extract_api`
+//!    b. Chunk documentation files with `zen-parser::chunk_document`
+//!    c. Generate embeddings with `zen-embeddings::EmbeddingEngine` (batch)
+//!    d. Store symbols, doc chunks, and source files via `ZenLake` and `SourceFileStore`
+//! 3. Register the package in `indexed_packages` and mark `source_cached = TRUE`
+//!
+//! Batching bounds peak memory to roughly one batch's worth of parsed symbols,
+//! doc chunks, and embeddings rather than holding an entire package (which can
+//! run to hundreds of thousands of chunks) in memory at once.
 //!
 //! The pipeline is invoked by the CLI `zen index` command (to be implemented in Phase 5).
 
@@ -17,16 +22,24 @@ use std::path::Path;
 
 use crate::progress::Progress;
 use rayon::prelude::*;
+use zen_config::IndexConfig;
 use zen_embeddings::EmbeddingEngine;
 use zen_lake::{
     ApiSymbolRow, DocChunkRow, LakeError, ZenLake,
     source_files::{SourceFile, SourceFileStore},
 };
-use zen_parser::doc_chunker::chunk_document;
+use zen_parser::doc_chunker::chunk_document_with_limits;
 use zen_parser::{
-    DetectedLanguage, ParsedItem, SymbolKind, Visibility, detect_language_ext, extract_api,
+    DetectedLanguage, ParsedItem, SymbolKind, Visibility, detect_language_ext,
+    extract_api_skip_tests,
 };
 
+/// Rough characters-per-token ratio used to convert `IndexConfig::chunk_token_budget`
+/// and `IndexConfig::chunk_overlap` (tokens) into the character counts
+/// `chunk_document_with_limits` expects, matching the ~4 chars/token ratio
+/// `zen_parser::doc_chunker`'s own built-in defaults are documented against.
+const CHARS_PER_TOKEN: u32 = 4;
+
 /// Indexing pipeline for a single package.
 pub struct IndexingPipeline {
     lake: ZenLake,
@@ -66,10 +79,12 @@ impl IndexingPipeline {
     /// - `ecosystem`, `package`, `version`: Package identity.
     /// - `embedder`: Embedding engine (caller manages its lifecycle).
     /// - `skip_tests`: When true, test files and directories are skipped.
+    /// - `index_config`: Chunk sizing and file-size limits for indexing.
     ///
     /// # Errors
     ///
     /// Returns `LakeError` on storage failures or embedding failures.
+    #[allow(clippy::too_many_arguments)]
     pub fn index_directory(
         &self,
         dir: &Path,
@@ -79,6 +94,7 @@ impl IndexingPipeline {
         embedder: &mut EmbeddingEngine,
         skip_tests: bool,
         public_symbols_only: bool,
+        index_config: &IndexConfig,
     ) -> Result<IndexResult, LakeError> {
         Self::index_directory_with(
             &self.lake,
@@ -90,6 +106,7 @@ impl IndexingPipeline {
             embedder,
             skip_tests,
             public_symbols_only,
+            index_config,
         )
     }
 
@@ -108,14 +125,16 @@ impl IndexingPipeline {
         embedder: &mut EmbeddingEngine,
         skip_tests: bool,
         public_symbols_only: bool,
+        index_config: &IndexConfig,
     ) -> Result<IndexResult, LakeError> {
-        let stage_progress = Progress::bar(5, "index: scanning files");
-        let mut symbols = Vec::new();
-        let mut doc_chunks = Vec::new();
-        let mut source_files = Vec::new();
-        let mut file_count = 0i32;
-
-        // Step 1+2: Walk and parse
+        let chunk_max_chars = index_config
+            .chunk_token_budget
+            .saturating_mul(CHARS_PER_TOKEN) as usize;
+        let chunk_overlap_chars =
+            index_config.chunk_overlap.saturating_mul(CHARS_PER_TOKEN) as usize;
+        let batch_size = index_config.embed_batch_size.max(1) as usize;
+
+        // Step 1: Walk
         let mut file_paths = Vec::new();
         let walker = zen_search::walk::build_walker(
             dir,
@@ -132,188 +151,134 @@ impl IndexingPipeline {
             file_paths.push(entry.into_path());
         }
 
-        stage_progress.inc(1);
-        stage_progress.set_message("index: parsing source and docs");
-
-        let parse_progress = Progress::bar(
-            u64::try_from(file_paths.len()).unwrap_or(0),
-            "index: parsing files",
+        let stage_progress = Progress::bar(
+            u64::try_from(file_paths.len().div_ceil(batch_size)).unwrap_or(0),
+            "index: processing batches",
         );
 
-        let mut parsed_outputs: Vec<ParsedFileOutput> = file_paths
-            .par_iter()
-            .filter_map(|path| {
-                let parsed = (|| {
-                    let rel_path = path.strip_prefix(dir).unwrap_or(path.as_path());
-                    let rel_path_str = rel_path.to_string_lossy().to_string();
-
-                    let content = std::fs::read_to_string(path).ok()?;
-
-                    let lang = detect_language_ext(&rel_path_str);
-                    let lang_str = lang.as_ref().map(|l| match l {
-                        DetectedLanguage::Builtin(builtin) => format!("{builtin:?}").to_lowercase(),
-                        DetectedLanguage::Markdown => "markdown".to_string(),
-                        DetectedLanguage::Rst => "rst".to_string(),
-                        DetectedLanguage::Svelte => "svelte".to_string(),
-                        DetectedLanguage::Toml => "toml".to_string(),
-                        DetectedLanguage::Text => "text".to_string(),
-                    });
-
-                    let size_bytes = content.len() as i32;
-                    let line_count = content.lines().count() as i32;
-
-                    let mut file_symbols = Vec::new();
-                    let mut parsed_file_count = 0;
-                    if lang.is_some() {
-                        let items = extract_api(&content, &rel_path_str).unwrap_or_default();
-                        for item in &items {
-                            if public_symbols_only && !is_public_api_symbol(item) {
-                                continue;
-                            }
-                            file_symbols.push(parsed_item_to_row(
-                                item,
-                                ecosystem,
-                                package,
-                                version,
-                                &rel_path_str,
-                            ));
-                        }
-                        parsed_file_count = 1;
-                    }
-
-                    let mut file_doc_chunks = Vec::new();
-                    if is_doc_file(&rel_path_str) {
-                        let chunks = chunk_document(&content, &rel_path_str);
-                        for chunk in chunks {
-                            file_doc_chunks.push((
-                                chunk,
-                                ecosystem.to_string(),
-                                package.to_string(),
-                                version.to_string(),
-                            ));
-                        }
-                    }
-
-                    Some(ParsedFileOutput {
-                        file_path: rel_path_str.clone(),
-                        symbols: file_symbols,
-                        doc_chunks: file_doc_chunks,
-                        source_file: SourceFile {
-                            ecosystem: ecosystem.to_string(),
-                            package: package.to_string(),
-                            version: version.to_string(),
-                            file_path: rel_path_str,
-                            content,
-                            language: lang_str,
-                            size_bytes,
-                            line_count,
-                        },
-                        parsed_file_count,
-                    })
-                })();
-
-                parse_progress.inc(1);
-                parsed
-            })
-            .collect();
-
-        parsed_outputs.sort_by(|a, b| a.file_path.cmp(&b.file_path));
-        for output in parsed_outputs {
-            file_count += output.parsed_file_count;
-            symbols.extend(output.symbols);
-            doc_chunks.extend(output.doc_chunks);
-            source_files.push(output.source_file);
-        }
+        let mut file_count = 0i32;
+        let mut symbol_count = 0i32;
+        let mut doc_chunk_count = 0i32;
+        let mut source_file_count = 0i32;
+
+        // Steps 2-5: parse → embed → store one bounded batch of files at a
+        // time, so peak memory holds one batch's symbols/doc chunks/
+        // embeddings rather than the whole package.
+        for path_batch in file_paths.chunks(batch_size) {
+            let mut parsed_outputs: Vec<ParsedFileOutput> = path_batch
+                .par_iter()
+                .filter_map(|path| {
+                    parse_file(
+                        path,
+                        dir,
+                        ecosystem,
+                        package,
+                        version,
+                        skip_tests,
+                        public_symbols_only,
+                        index_config.max_file_size_bytes,
+                        chunk_max_chars,
+                        chunk_overlap_chars,
+                    )
+                })
+                .collect();
+
+            parsed_outputs.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+
+            let mut symbols = Vec::new();
+            let mut doc_chunks = Vec::new();
+            let mut source_files = Vec::new();
+            for output in parsed_outputs {
+                file_count += output.parsed_file_count;
+                symbols.extend(output.symbols);
+                doc_chunks.extend(output.doc_chunks);
+                source_files.push(output.source_file);
+            }
 
-        parse_progress.finish_clear();
-        stage_progress.inc(1);
-
-        // Step 4: Generate embeddings (batch)
-        stage_progress.set_message("index: embedding symbols");
-        let embed_texts: Vec<String> = symbols
-            .iter()
-            .map(|s| {
-                format!(
-                    "{} {} {}",
-                    s.name,
-                    s.signature.as_deref().unwrap_or(""),
-                    s.doc_comment.as_deref().unwrap_or("")
-                )
-            })
-            .collect();
-
-        let symbol_embeddings = if !embed_texts.is_empty() {
-            embedder
-                .embed_batch(embed_texts)
-                .map_err(|e| LakeError::Other(format!("Embedding failed: {e}")))?
-        } else {
-            Vec::new()
-        };
+            // Embed and store just this batch.
+            let embed_texts: Vec<String> = symbols
+                .iter()
+                .map(|s| {
+                    format!(
+                        "{} {} {}",
+                        s.name,
+                        s.signature.as_deref().unwrap_or(""),
+                        s.doc_comment.as_deref().unwrap_or("")
+                    )
+                })
+                .collect();
+
+            let symbol_embeddings = if embed_texts.is_empty() {
+                Vec::new()
+            } else {
+                embedder
+                    .embed_batch(embed_texts)
+                    .map_err(|e| LakeError::Other(format!("Embedding failed: {e}")))?
+            };
+
+            // Defensive check: ensure embedding count matches symbol count
+            if symbol_embeddings.len() != symbols.len() {
+                return Err(LakeError::Other(format!(
+                    "Embedding count mismatch: expected {}, got {}",
+                    symbols.len(),
+                    symbol_embeddings.len()
+                )));
+            }
 
-        // Defensive check: ensure embedding count matches symbol count
-        if symbol_embeddings.len() != symbols.len() {
-            return Err(LakeError::Other(format!(
-                "Embedding count mismatch: expected {}, got {}",
-                symbols.len(),
-                symbol_embeddings.len()
-            )));
-        }
+            for (sym, emb) in symbols.iter_mut().zip(symbol_embeddings) {
+                sym.embedding = emb;
+            }
 
-        for (sym, emb) in symbols.iter_mut().zip(symbol_embeddings.into_iter()) {
-            sym.embedding = emb;
-        }
-        stage_progress.inc(1);
-
-        stage_progress.set_message("index: embedding doc chunks");
-        let doc_embed_texts: Vec<String> = doc_chunks
-            .iter()
-            .map(|(c, _, _, _)| c.content.clone())
-            .collect();
-
-        let doc_embeddings = if !doc_embed_texts.is_empty() {
-            embedder
-                .embed_batch(doc_embed_texts)
-                .map_err(|e| LakeError::Other(format!("Embedding failed: {e}")))?
-        } else {
-            Vec::new()
-        };
+            let doc_embed_texts: Vec<String> = doc_chunks
+                .iter()
+                .map(|(c, _, _, _)| c.content.clone())
+                .collect();
+
+            let doc_embeddings = if doc_embed_texts.is_empty() {
+                Vec::new()
+            } else {
+                embedder
+                    .embed_batch(doc_embed_texts)
+                    .map_err(|e| LakeError::Other(format!("Embedding failed: {e}")))?
+            };
+
+            // Defensive check: ensure embedding count matches doc chunk count
+            if doc_embeddings.len() != doc_chunks.len() {
+                return Err(LakeError::Other(format!(
+                    "Doc embedding count mismatch: expected {}, got {}",
+                    doc_chunks.len(),
+                    doc_embeddings.len()
+                )));
+            }
 
-        // Defensive check: ensure embedding count matches doc chunk count
-        if doc_embeddings.len() != doc_chunks.len() {
-            return Err(LakeError::Other(format!(
-                "Doc embedding count mismatch: expected {}, got {}",
-                doc_chunks.len(),
-                doc_embeddings.len()
-            )));
+            let doc_chunk_rows: Vec<DocChunkRow> = doc_chunks
+                .into_iter()
+                .zip(doc_embeddings)
+                .map(|((chunk, eco, pkg, ver), emb)| DocChunkRow {
+                    id: String::new(), // DuckDB will generate via md5()
+                    ecosystem: eco,
+                    package: pkg,
+                    version: ver,
+                    chunk_index: chunk.chunk_index as i32,
+                    title: chunk.title,
+                    content: chunk.content,
+                    source_file: Some(chunk.source_file),
+                    format: Some(chunk.format),
+                    embedding: emb,
+                })
+                .collect();
+
+            symbol_count += symbols.len() as i32;
+            doc_chunk_count += doc_chunk_rows.len() as i32;
+            source_file_count += source_files.len() as i32;
+
+            lake.store_symbols(&symbols)?;
+            lake.store_doc_chunks(&doc_chunk_rows)?;
+            source_store.store_source_files(&source_files)?;
+
+            stage_progress.inc(1);
         }
-
-        let doc_chunk_rows: Vec<DocChunkRow> = doc_chunks
-            .into_iter()
-            .zip(doc_embeddings.into_iter())
-            .map(|((chunk, eco, pkg, ver), emb)| DocChunkRow {
-                id: String::new(), // DuckDB will generate via md5()
-                ecosystem: eco,
-                package: pkg,
-                version: ver,
-                chunk_index: chunk.chunk_index as i32,
-                title: chunk.title,
-                content: chunk.content,
-                source_file: Some(chunk.source_file),
-                format: Some(chunk.format),
-                embedding: emb,
-            })
-            .collect();
-        stage_progress.inc(1);
-
-        let symbol_count = symbols.len() as i32;
-        let doc_chunk_count = doc_chunk_rows.len() as i32;
-        let source_file_count = source_files.len() as i32;
-
-        // Step 5: Store in local DuckDB cache (temporary for Phase 3)
-        stage_progress.set_message("index: storing and registering package");
-        lake.store_symbols(&symbols)?;
-        lake.store_doc_chunks(&doc_chunk_rows)?;
-        source_store.store_source_files(&source_files)?;
+        stage_progress.finish_clear();
 
         // Step 6: Register package and mark source cached
         lake.register_package(
@@ -329,7 +294,6 @@ impl IndexingPipeline {
             doc_chunk_count,
         )?;
         lake.set_source_cached(ecosystem, package, version)?;
-        stage_progress.finish_clear();
 
         Ok(IndexResult {
             ecosystem: ecosystem.to_string(),
@@ -362,6 +326,100 @@ fn is_public_api_symbol(item: &ParsedItem) -> bool {
     )
 }
 
+/// Read, detect the language of, and extract symbols/doc chunks from a single
+/// file. Returns `None` if the file is unreadable or exceeds `max_file_size_bytes`.
+#[allow(clippy::too_many_arguments)]
+fn parse_file(
+    path: &Path,
+    dir: &Path,
+    ecosystem: &str,
+    package: &str,
+    version: &str,
+    skip_tests: bool,
+    public_symbols_only: bool,
+    max_file_size_bytes: u64,
+    chunk_max_chars: usize,
+    chunk_overlap_chars: usize,
+) -> Option<ParsedFileOutput> {
+    let rel_path = path.strip_prefix(dir).unwrap_or(path);
+    let rel_path_str = rel_path.to_string_lossy().to_string();
+
+    let metadata = std::fs::metadata(path).ok()?;
+    if metadata.len() > max_file_size_bytes {
+        return None;
+    }
+
+    let content = std::fs::read_to_string(path).ok()?;
+
+    let lang = detect_language_ext(&rel_path_str);
+    let lang_str = lang.as_ref().map(|l| match l {
+        DetectedLanguage::Builtin(builtin) => format!("{builtin:?}").to_lowercase(),
+        DetectedLanguage::Markdown => "markdown".to_string(),
+        DetectedLanguage::Rst => "rst".to_string(),
+        DetectedLanguage::Svelte => "svelte".to_string(),
+        DetectedLanguage::Toml => "toml".to_string(),
+        DetectedLanguage::Text => "text".to_string(),
+    });
+
+    let size_bytes = content.len() as i32;
+    let line_count = content.lines().count() as i32;
+
+    let mut file_symbols = Vec::new();
+    let mut parsed_file_count = 0;
+    if lang.is_some() {
+        let items = extract_api_skip_tests(&content, &rel_path_str, skip_tests).unwrap_or_default();
+        for item in &items {
+            if public_symbols_only && !is_public_api_symbol(item) {
+                continue;
+            }
+            file_symbols.push(parsed_item_to_row(
+                item,
+                ecosystem,
+                package,
+                version,
+                &rel_path_str,
+            ));
+        }
+        parsed_file_count = 1;
+    }
+
+    let mut file_doc_chunks = Vec::new();
+    if is_doc_file(&rel_path_str) {
+        let chunks = chunk_document_with_limits(
+            &content,
+            &rel_path_str,
+            chunk_max_chars,
+            chunk_overlap_chars,
+        );
+        for chunk in chunks {
+            file_doc_chunks.push((
+                chunk,
+                ecosystem.to_string(),
+                package.to_string(),
+                version.to_string(),
+            ));
+        }
+    }
+
+    Some(ParsedFileOutput {
+        file_path: rel_path_str.clone(),
+        symbols: file_symbols,
+        doc_chunks: file_doc_chunks,
+        source_file: SourceFile {
+            ecosystem: ecosystem.to_string(),
+            package: package.to_string(),
+            version: version.to_string(),
+            file_path: rel_path_str,
+            content,
+            language: lang_str,
+            size_bytes,
+            line_count,
+            content_hash: None,
+        },
+        parsed_file_count,
+    })
+}
+
 /// Convert a `ParsedItem` into an `ApiSymbolRow`.
 ///
 /// The `id` field is left empty (`String::new()`) so that DuckDB generates a
@@ -391,6 +449,7 @@ fn parsed_item_to_row(
         line_start: Some(item.start_line as i32),
         line_end: Some(item.end_line as i32),
         visibility: Some(item.visibility.to_string()),
+        is_deprecated: item.is_deprecated,
         is_async: item.metadata.is_async,
         is_unsafe: item.metadata.is_unsafe,
         is_error_type: item.metadata.is_error_type,
@@ -439,6 +498,7 @@ mod tests {
     #[test]
     fn parsed_item_to_row_mapping() {
         let item = ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Function,
             name: "test_fn".to_string(),
             signature: "pub fn test_fn()".to_string(),
@@ -525,6 +585,7 @@ mod tests {
                 &mut embedder,
                 false,
                 false,
+                &zen_config::IndexConfig::default(),
             )
             .expect("indexing should succeed");
 
@@ -585,4 +646,93 @@ mod tests {
             .unwrap();
         assert_eq!(src_count, 2);
     }
+
+    #[test]
+    fn pipeline_batches_many_files_without_dropping_any() {
+        // This test requires the fastembed model to be pre-cached at ~/.zenith/cache/fastembed/
+        let tmp = TempDir::new().unwrap();
+        std::fs::create_dir_all(tmp.path().join("src")).unwrap();
+
+        const FILE_COUNT: usize = 23;
+        for i in 0..FILE_COUNT {
+            std::fs::write(
+                tmp.path().join("src").join(format!("mod_{i}.rs")),
+                format!("/// Doc for item {i}\npub fn item_{i}() -> u32 {{ {i} }}"),
+            )
+            .unwrap();
+        }
+
+        let lake = ZenLake::open_in_memory().unwrap();
+        let source_store = SourceFileStore::open_in_memory().unwrap();
+        let pipeline = IndexingPipeline::new(lake, source_store);
+
+        let mut embedder = match zen_embeddings::EmbeddingEngine::new() {
+            Ok(e) => e,
+            Err(e) => {
+                eprintln!("Skipping batching test: embedding engine not available: {e}");
+                return;
+            }
+        };
+
+        // A batch size that doesn't evenly divide FILE_COUNT, so the run
+        // exercises multiple bounded parse → embed → store batches plus a
+        // partial final batch, not one pass over everything.
+        let index_config = zen_config::IndexConfig {
+            embed_batch_size: 6,
+            ..Default::default()
+        };
+
+        let result = pipeline
+            .index_directory(
+                tmp.path(),
+                "rust",
+                "many_files_crate",
+                "0.1.0",
+                &mut embedder,
+                false,
+                false,
+                &index_config,
+            )
+            .expect("batched indexing should succeed");
+
+        assert_eq!(result.file_count, FILE_COUNT as i32);
+        assert_eq!(result.symbol_count, FILE_COUNT as i32);
+        assert_eq!(result.source_file_count, FILE_COUNT as i32);
+
+        let symbol_count: i64 = pipeline
+            .lake
+            .conn()
+            .query_row(
+                "SELECT COUNT(*) FROM api_symbols WHERE ecosystem = 'rust' AND package = 'many_files_crate'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(symbol_count, FILE_COUNT as i64);
+
+        let embedded_count: i64 = pipeline
+            .lake
+            .conn()
+            .query_row(
+                "SELECT COUNT(*) FROM api_symbols WHERE ecosystem = 'rust' AND package = 'many_files_crate' AND embedding IS NOT NULL",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(
+            embedded_count, FILE_COUNT as i64,
+            "every symbol across every batch should have an embedding, not just the last batch"
+        );
+
+        let src_count: i64 = pipeline
+            .source_store
+            .conn()
+            .query_row(
+                "SELECT COUNT(*) FROM source_files WHERE ecosystem = 'rust' AND package = 'many_files_crate'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(src_count, FILE_COUNT as i64);
+    }
 }
@@ -1,11 +1,35 @@
 use std::fs::OpenOptions;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use chrono::Utc;
+
+use crate::context::LakeAccessMode;
+
+const WRITE_LOCK_FILE: &str = "lake.write.lock";
+const READ_LOCK_DIR: &str = "lake.read.lock.d";
 const LOCK_WAIT_TIMEOUT: Duration = Duration::from_secs(300);
 const LOCK_RETRY_DELAY: Duration = Duration::from_millis(250);
+const DEFAULT_LOCK_TTL_SECS: u64 = 3600;
+
+/// How long a lock file is honored after its recorded timestamp, even if the
+/// owning PID still looks alive. Overridable via `ZENITH_WRITE_LOCK__TTL_SECS`
+/// for environments where PID reuse or foreign-host locks make liveness
+/// checks unreliable.
+fn lock_ttl() -> Duration {
+    std::env::var("ZENITH_WRITE_LOCK__TTL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .map_or(
+            Duration::from_secs(DEFAULT_LOCK_TTL_SECS),
+            Duration::from_secs,
+        )
+}
 
+/// A held lease on the project's lake, either a shared reader slot or the
+/// exclusive writer slot. Dropping the guard releases it.
+#[derive(Debug)]
 pub struct WriteLockGuard {
     path: PathBuf,
 }
@@ -16,13 +40,30 @@ impl Drop for WriteLockGuard {
     }
 }
 
-pub async fn acquire_for_project(project_root: &Path) -> anyhow::Result<WriteLockGuard> {
-    let lock_path = project_root.join(".zenith").join("lake.write.lock");
+/// Acquire an advisory lease on the project's lake matching `mode`: multiple
+/// [`LakeAccessMode::ReadOnly`] leases may be held concurrently, a
+/// [`LakeAccessMode::ReadWrite`] lease excludes all readers and writers, and
+/// [`LakeAccessMode::Disabled`] needs no lease since no lake is opened.
+pub async fn acquire_for_project(
+    project_root: &Path,
+    mode: LakeAccessMode,
+) -> anyhow::Result<Option<WriteLockGuard>> {
+    let zenith_dir = project_root.join(".zenith");
+    match mode {
+        LakeAccessMode::ReadWrite => acquire_writer(&zenith_dir).await.map(Some),
+        LakeAccessMode::ReadOnly => acquire_reader(&zenith_dir).await.map(Some),
+        LakeAccessMode::Disabled => Ok(None),
+    }
+}
+
+async fn acquire_writer(zenith_dir: &Path) -> anyhow::Result<WriteLockGuard> {
+    let lock_path = zenith_dir.join(WRITE_LOCK_FILE);
+    let read_lock_dir = zenith_dir.join(READ_LOCK_DIR);
     let started = std::time::Instant::now();
 
-    loop {
+    let guard = loop {
         match try_acquire(&lock_path) {
-            Ok(guard) => return Ok(guard),
+            Ok(guard) => break guard,
             Err(LockState::HeldBy(pid)) => {
                 if started.elapsed() >= LOCK_WAIT_TIMEOUT {
                     anyhow::bail!(
@@ -31,7 +72,11 @@ pub async fn acquire_for_project(project_root: &Path) -> anyhow::Result<WriteLoc
                 }
                 tokio::time::sleep(LOCK_RETRY_DELAY).await;
             }
-            Err(LockState::Stale) => {
+            Err(LockState::Stale(reason)) => {
+                eprintln!(
+                    "warning: taking over write lock at {} ({reason})",
+                    lock_path.display()
+                );
                 let _ = std::fs::remove_file(&lock_path);
             }
             Err(LockState::Unknown) => {
@@ -44,13 +89,93 @@ pub async fn acquire_for_project(project_root: &Path) -> anyhow::Result<WriteLoc
                 tokio::time::sleep(LOCK_RETRY_DELAY).await;
             }
         }
+    };
+
+    while has_active_readers(&read_lock_dir) {
+        if started.elapsed() >= LOCK_WAIT_TIMEOUT {
+            anyhow::bail!(
+                "write lock at {} is waiting on active readers to finish",
+                lock_path.display()
+            );
+        }
+        tokio::time::sleep(LOCK_RETRY_DELAY).await;
     }
+
+    Ok(guard)
+}
+
+async fn acquire_reader(zenith_dir: &Path) -> anyhow::Result<WriteLockGuard> {
+    let lock_path = zenith_dir.join(WRITE_LOCK_FILE);
+    let read_lock_dir = zenith_dir.join(READ_LOCK_DIR);
+    let started = std::time::Instant::now();
+
+    loop {
+        match inspect_writer_lock(&lock_path) {
+            None => break,
+            Some(LockState::Stale(reason)) => {
+                eprintln!(
+                    "warning: taking over write lock at {} ({reason})",
+                    lock_path.display()
+                );
+                let _ = std::fs::remove_file(&lock_path);
+            }
+            Some(LockState::HeldBy(pid)) => {
+                if started.elapsed() >= LOCK_WAIT_TIMEOUT {
+                    anyhow::bail!(
+                        "a write operation is running (pid {pid}); try again after it finishes"
+                    );
+                }
+                tokio::time::sleep(LOCK_RETRY_DELAY).await;
+            }
+            Some(LockState::Unknown) => break,
+        }
+    }
+
+    std::fs::create_dir_all(&read_lock_dir)?;
+    let reader_path = read_lock_dir.join(reader_file_name());
+    std::fs::write(&reader_path, std::process::id().to_string())?;
+
+    Ok(WriteLockGuard { path: reader_path })
+}
+
+fn reader_file_name() -> String {
+    let unique = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    format!("{}-{unique}.lock", std::process::id())
+}
+
+/// Whether any reader lock file in `read_lock_dir` belongs to a still-running
+/// process, cleaning up stale entries from crashed readers along the way.
+fn has_active_readers(read_lock_dir: &Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(read_lock_dir) else {
+        return false;
+    };
+
+    let mut active = false;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let pid = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split('-').next())
+            .and_then(|pid| pid.parse::<i32>().ok());
+
+        match pid {
+            Some(pid) if is_process_running(pid) => active = true,
+            _ => {
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+
+    active
 }
 
 #[derive(Debug)]
 enum LockState {
     HeldBy(i32),
-    Stale,
+    Stale(String),
     Unknown,
 }
 
@@ -66,31 +191,59 @@ fn try_acquire(lock_path: &Path) -> Result<WriteLockGuard, LockState> {
     {
         Ok(mut file) => {
             let pid = std::process::id();
-            let _ = writeln!(file, "{pid}");
+            let _ = writeln!(file, "{pid}\n{}", Utc::now().to_rfc3339());
             Ok(WriteLockGuard {
                 path: lock_path.to_path_buf(),
             })
         }
         Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
-            let mut pid_buf = String::new();
-            if OpenOptions::new()
-                .read(true)
-                .open(lock_path)
-                .and_then(|mut file| file.read_to_string(&mut pid_buf))
-                .is_err()
-            {
-                return Err(LockState::Unknown);
-            }
+            Err(inspect_writer_lock(lock_path).unwrap_or(LockState::Unknown))
+        }
+        Err(_) => Err(LockState::Unknown),
+    }
+}
 
-            let pid = pid_buf.trim().parse::<i32>().ok();
-            match pid {
-                Some(pid) if is_process_running(pid) => Err(LockState::HeldBy(pid)),
-                Some(_) => Err(LockState::Stale),
-                None => Err(LockState::Unknown),
+/// Read and classify the write lock file at `lock_path` without creating it.
+/// Returns `None` if no lock file is present.
+fn inspect_writer_lock(lock_path: &Path) -> Option<LockState> {
+    let mut contents = String::new();
+    match OpenOptions::new().read(true).open(lock_path) {
+        Ok(mut file) => {
+            if file.read_to_string(&mut contents).is_err() {
+                return Some(LockState::Unknown);
             }
         }
-        Err(_) => Err(LockState::Unknown),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(_) => return Some(LockState::Unknown),
     }
+
+    let mut lines = contents.lines();
+    let pid = lines.next().and_then(|s| s.trim().parse::<i32>().ok());
+    let recorded_at = lines
+        .next()
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s.trim()).ok());
+
+    let Some(pid) = pid else {
+        return Some(LockState::Unknown);
+    };
+
+    if !is_process_running(pid) {
+        return Some(LockState::Stale(format!(
+            "owning pid {pid} is no longer running"
+        )));
+    }
+
+    if let Some(recorded_at) = recorded_at {
+        let age = Utc::now().signed_duration_since(recorded_at);
+        if age.to_std().is_ok_and(|age| age >= lock_ttl()) {
+            return Some(LockState::Stale(format!(
+                "lock held by pid {pid} exceeded TTL of {}s",
+                lock_ttl().as_secs()
+            )));
+        }
+    }
+
+    Some(LockState::HeldBy(pid))
 }
 
 fn is_process_running(pid: i32) -> bool {
@@ -104,7 +257,10 @@ fn is_process_running(pid: i32) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::try_acquire;
+    use std::fs;
+    use std::io::Write as _;
+
+    use super::{LockState, has_active_readers, try_acquire};
 
     #[test]
     fn acquires_and_releases_lock_file() {
@@ -116,4 +272,72 @@ mod tests {
         drop(guard);
         assert!(!lock_path.exists());
     }
+
+    #[test]
+    fn stale_lock_from_dead_pid_is_taken_over() {
+        let temp = tempfile::tempdir().expect("tempdir should create");
+        let lock_path = temp.path().join(".zenith/lake.write.lock");
+        fs::create_dir_all(lock_path.parent().unwrap()).unwrap();
+
+        // An implausibly large PID that is extremely unlikely to be alive.
+        let dead_pid = 999_999;
+        let mut file = fs::File::create(&lock_path).unwrap();
+        writeln!(file, "{dead_pid}\n{}", chrono::Utc::now().to_rfc3339()).unwrap();
+        drop(file);
+
+        match try_acquire(&lock_path) {
+            Err(LockState::Stale(reason)) => assert!(reason.contains(&dead_pid.to_string())),
+            other => panic!("expected LockState::Stale, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn fresh_lock_from_live_process_is_rejected() {
+        let temp = tempfile::tempdir().expect("tempdir should create");
+        let lock_path = temp.path().join(".zenith/lake.write.lock");
+        fs::create_dir_all(lock_path.parent().unwrap()).unwrap();
+
+        let live_pid = std::process::id();
+        let mut file = fs::File::create(&lock_path).unwrap();
+        writeln!(file, "{live_pid}\n{}", chrono::Utc::now().to_rfc3339()).unwrap();
+        drop(file);
+
+        match try_acquire(&lock_path) {
+            Err(LockState::HeldBy(pid)) => assert_eq!(pid, live_pid as i32),
+            other => panic!("expected LockState::HeldBy, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn two_readers_acquire_concurrently_but_writer_waits_for_them() {
+        let temp = tempfile::tempdir().expect("tempdir should create");
+        let zenith_dir = temp.path().join(".zenith");
+
+        let reader_one = super::acquire_reader(&zenith_dir)
+            .await
+            .expect("first reader should acquire");
+        let reader_two = super::acquire_reader(&zenith_dir)
+            .await
+            .expect("second reader should acquire concurrently");
+
+        let read_lock_dir = zenith_dir.join(super::READ_LOCK_DIR);
+        assert!(has_active_readers(&read_lock_dir));
+
+        let writer_zenith_dir = zenith_dir.clone();
+        let writer_task =
+            tokio::spawn(async move { super::acquire_writer(&writer_zenith_dir).await });
+
+        // Give the writer a moment to observe the active readers and start waiting.
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(!writer_task.is_finished(), "writer should block on readers");
+
+        drop(reader_one);
+        drop(reader_two);
+
+        let writer_guard = writer_task
+            .await
+            .expect("writer task should not panic")
+            .expect("writer should acquire once readers release");
+        drop(writer_guard);
+    }
 }
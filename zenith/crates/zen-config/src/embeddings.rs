@@ -0,0 +1,68 @@
+//! Embedding generation configuration.
+
+use serde::{Deserialize, Serialize};
+
+/// Default embedding model name.
+fn default_model() -> String {
+    "all-minilm-l6-v2".to_string()
+}
+
+/// Default embedding batch size, matching `fastembed`'s own default when
+/// `EmbeddingEngineBuilder::batch_size` is left unset.
+const fn default_batch_size() -> u32 {
+    256
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EmbeddingsConfig {
+    /// Name of the embedding model to use (e.g. `"all-minilm-l6-v2"`).
+    /// Selected via `zen_embeddings::EmbeddingEngine::new_from_config`.
+    #[serde(default = "default_model")]
+    pub model: String,
+
+    /// Number of texts to feed to the ONNX runtime per inference pass.
+    /// Lowering this bounds peak memory use on very large batches, at the
+    /// cost of more inference calls.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: u32,
+}
+
+impl Default for EmbeddingsConfig {
+    fn default() -> Self {
+        Self {
+            model: default_model(),
+            batch_size: default_batch_size(),
+        }
+    }
+}
+
+impl EmbeddingsConfig {
+    /// Check if any embeddings default has been customized away from the
+    /// built-in defaults.
+    #[must_use]
+    pub fn is_configured(&self) -> bool {
+        *self != Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_correct() {
+        let config = EmbeddingsConfig::default();
+        assert!(!config.is_configured());
+        assert_eq!(config.model, "all-minilm-l6-v2");
+        assert_eq!(config.batch_size, 256);
+    }
+
+    #[test]
+    fn configured_when_any_field_customized() {
+        let config = EmbeddingsConfig {
+            batch_size: 64,
+            ..Default::default()
+        };
+        assert!(config.is_configured());
+    }
+}
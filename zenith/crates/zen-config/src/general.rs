@@ -24,6 +24,22 @@ pub struct GeneralConfig {
     /// Whether wrap-up requires cloud sync success.
     #[serde(default)]
     pub wrap_up_require_sync: bool,
+
+    /// Extra glob patterns (beyond `zen-parser`'s built-in conventions) that
+    /// classify a matching path as a test file, e.g. `["**/*.feature"]`. Fed
+    /// into `zen_parser::TestFileMatcher::new`.
+    #[serde(default)]
+    pub test_globs: Vec<String>,
+
+    /// Skip opening `ZenLake` (`DuckDB`) in `AppContext::init`, settable via
+    /// `ZENITH_GENERAL__NO_LAKE=true`.
+    ///
+    /// For lightweight commands that only need `zen-db` (e.g. `znt audit`),
+    /// this avoids `DuckDB` startup cost, or lets `znt` run at all on
+    /// machines without `DuckDB` available. Commands that require the lake
+    /// return `SearchError::LakeDisabled`.
+    #[serde(default)]
+    pub no_lake: bool,
 }
 
 impl Default for GeneralConfig {
@@ -33,6 +49,8 @@ impl Default for GeneralConfig {
             default_ecosystem: String::new(),
             default_limit: default_limit(),
             wrap_up_require_sync: false,
+            test_globs: Vec::new(),
+            no_lake: false,
         }
     }
 }
@@ -48,5 +66,7 @@ mod tests {
         assert!(config.default_ecosystem.is_empty());
         assert_eq!(config.default_limit, 20);
         assert!(!config.wrap_up_require_sync);
+        assert!(config.test_globs.is_empty());
+        assert!(!config.no_lake);
     }
 }
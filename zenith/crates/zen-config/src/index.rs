@@ -0,0 +1,105 @@
+//! Indexing pipeline configuration.
+
+use serde::{Deserialize, Serialize};
+
+/// Default chunk size in tokens (~2048 characters at ~4 chars/token).
+const fn default_chunk_token_budget() -> u32 {
+    512
+}
+
+/// Default overlap in tokens when sub-chunking oversized sections
+/// (~10% of `default_chunk_token_budget`, matching `zen_parser`'s
+/// `OVERLAP_CHARS`).
+const fn default_chunk_overlap() -> u32 {
+    50
+}
+
+/// Default maximum file size to parse, in bytes.
+const fn default_max_file_size_bytes() -> u64 {
+    1_048_576
+}
+
+/// Default number of files processed per parse → embed → store batch.
+const fn default_embed_batch_size() -> u32 {
+    256
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IndexConfig {
+    /// Maximum chunk size, in tokens, passed to
+    /// `zen_parser::doc_chunker::chunk_document_with_limits` (as
+    /// `chunk_token_budget * 4` characters).
+    #[serde(default = "default_chunk_token_budget")]
+    pub chunk_token_budget: u32,
+
+    /// Overlap, in tokens, between adjacent sub-chunks of an oversized
+    /// section. Must be smaller than `chunk_token_budget`.
+    #[serde(default = "default_chunk_overlap")]
+    pub chunk_overlap: u32,
+
+    /// Files larger than this are skipped during indexing rather than
+    /// parsed.
+    #[serde(default = "default_max_file_size_bytes")]
+    pub max_file_size_bytes: u64,
+
+    /// Whether test files and directories are skipped during indexing by
+    /// default.
+    #[serde(default = "default_skip_test_files")]
+    pub skip_test_files: bool,
+
+    /// Number of files processed per parse → embed → store batch during
+    /// indexing. Bounds peak memory to roughly one batch's worth of parsed
+    /// symbols and doc chunks instead of the whole package at once.
+    #[serde(default = "default_embed_batch_size")]
+    pub embed_batch_size: u32,
+}
+
+const fn default_skip_test_files() -> bool {
+    true
+}
+
+impl Default for IndexConfig {
+    fn default() -> Self {
+        Self {
+            chunk_token_budget: default_chunk_token_budget(),
+            chunk_overlap: default_chunk_overlap(),
+            max_file_size_bytes: default_max_file_size_bytes(),
+            skip_test_files: default_skip_test_files(),
+            embed_batch_size: default_embed_batch_size(),
+        }
+    }
+}
+
+impl IndexConfig {
+    /// Check if any indexing default has been customized away from the
+    /// built-in defaults.
+    #[must_use]
+    pub fn is_configured(&self) -> bool {
+        *self != Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_correct() {
+        let config = IndexConfig::default();
+        assert!(!config.is_configured());
+        assert_eq!(config.chunk_token_budget, 512);
+        assert_eq!(config.chunk_overlap, 50);
+        assert_eq!(config.max_file_size_bytes, 1_048_576);
+        assert!(config.skip_test_files);
+        assert_eq!(config.embed_batch_size, 256);
+    }
+
+    #[test]
+    fn configured_when_any_field_customized() {
+        let config = IndexConfig {
+            chunk_overlap: 10,
+            ..Default::default()
+        };
+        assert!(config.is_configured());
+    }
+}
@@ -4,10 +4,24 @@
 //!
 //! Configuration sources (in priority order, highest wins):
 //! 1. Environment variables (`ZENITH_*` prefix, `__` as separator)
-//! 2. Project-level `.zenith/config.toml`
-//! 3. User-level `~/.config/zenith/config.toml`
+//! 2. Project-level `.zenith/config.toml` or `.zenith/config.yaml`
+//! 3. User-level `~/.config/zenith/config.toml` or `~/.config/zenith/config.yaml`
 //! 4. Built-in defaults
 //!
+//! # YAML Support
+//!
+//! Each config directory may hold either `config.toml` or `config.yaml`, not
+//! both -- having both in the same directory is a [`ConfigError::InvalidValue`]
+//! to avoid ambiguity about which one wins. YAML keys mirror the TOML
+//! structure exactly (`turso.url`, `r2.account_id`, etc.):
+//!
+//! ```yaml
+//! general:
+//!   default_limit: 50
+//! turso:
+//!   url: "libsql://db.turso.io"
+//! ```
+//!
 //! # Environment Variable Mapping
 //!
 //! Figment maps `ZENITH_TURSO__URL` -> `turso.url`, `ZENITH_R2__ACCOUNT_ID` -> `r2.account_id`, etc.
@@ -31,23 +45,36 @@
 
 mod axiom;
 mod clerk;
+mod embeddings;
 mod error;
 mod general;
+mod index;
 mod motherduck;
+mod profile;
 mod r2;
+mod search;
+#[cfg(feature = "secrets")]
+mod secrets;
 mod turso;
+mod validate;
+pub mod write;
 
 pub use axiom::AxiomConfig;
 pub use clerk::ClerkConfig;
+pub use embeddings::EmbeddingsConfig;
 pub use error::ConfigError;
 pub use general::GeneralConfig;
+pub use index::IndexConfig;
 pub use motherduck::MotherDuckConfig;
+pub use profile::PROFILE_ENV_VAR;
 pub use r2::R2Config;
+pub use search::SearchConfig;
 pub use turso::TursoConfig;
+pub use validate::{ConfigIssue, IssueSeverity};
 
 use figment::{
     Figment,
-    providers::{Env, Format, Serialized, Toml},
+    providers::{Env, Format, Serialized, Toml, Yaml},
 };
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -66,25 +93,32 @@ pub struct ZenConfig {
     pub axiom: AxiomConfig,
     #[serde(default)]
     pub general: GeneralConfig,
+    #[serde(default)]
+    pub search: SearchConfig,
+    #[serde(default)]
+    pub embeddings: EmbeddingsConfig,
+    #[serde(default)]
+    pub index: IndexConfig,
 }
 
 impl ZenConfig {
-    /// Load configuration from all sources (TOML files + environment variables).
+    /// Load configuration from all sources (TOML/YAML files + environment variables).
     ///
     /// Does NOT call `dotenvy` -- use [`load_with_dotenv`] if you need `.env` file loading.
     ///
     /// Precedence (highest to lowest):
     /// 1. Environment variables (`ZENITH_*` prefix)
-    /// 2. `.zenith/config.toml` (project-local)
-    /// 3. `~/.config/zenith/config.toml` (user-global)
+    /// 2. `.zenith/config.toml` or `.zenith/config.yaml` (project-local)
+    /// 3. `~/.config/zenith/config.toml` or `~/.config/zenith/config.yaml` (user-global)
     /// 4. Default values
     ///
     /// # Errors
     ///
     /// Returns [`ConfigError`] if figment extraction fails (e.g. malformed
-    /// TOML or environment variables that cannot be deserialized).
+    /// TOML/YAML or environment variables that cannot be deserialized), or if
+    /// a config directory contains both `config.toml` and `config.yaml`.
     pub fn load() -> Result<Self, Box<ConfigError>> {
-        Self::figment_with_env_overrides(&[])
+        Self::figment_with_env_overrides(&[])?
             .extract()
             .map_err(|e| Box::new(ConfigError::from(e)))
     }
@@ -97,17 +131,92 @@ impl ZenConfig {
     /// Precedence (highest to lowest):
     /// 1. Process env (`ZENITH_*`)
     /// 2. `env_overrides`
-    /// 3. `.zenith/config.toml`
-    /// 4. `~/.config/zenith/config.toml`
+    /// 3. `.zenith/config.toml` or `.zenith/config.yaml`
+    /// 4. `~/.config/zenith/config.toml` or `~/.config/zenith/config.yaml`
     /// 5. defaults
     pub fn load_with_env_overrides(
         env_overrides: &[(String, String)],
     ) -> Result<Self, Box<ConfigError>> {
-        Self::figment_with_env_overrides(env_overrides)
+        Self::load_with_profile_and_env_overrides(None, env_overrides)
+    }
+
+    /// Load configuration with a named profile (`[profiles.<name>]` in the
+    /// user-global config file) layered over the base config.
+    ///
+    /// See [`load_with_profile_and_env_overrides`] for full precedence and
+    /// external-override support.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if `profile` isn't a declared
+    /// profile, or if figment extraction fails.
+    ///
+    /// [`load_with_profile_and_env_overrides`]: Self::load_with_profile_and_env_overrides
+    pub fn load_with_profile(profile: &str) -> Result<Self, Box<ConfigError>> {
+        Self::load_with_profile_and_env_overrides(Some(profile), &[])
+    }
+
+    /// Load configuration with an optional named profile and external
+    /// env-style overrides.
+    ///
+    /// Precedence (highest to lowest):
+    /// 1. Process env (`ZENITH_*`)
+    /// 2. `env_overrides`
+    /// 3. `.zenith/config.toml` or `.zenith/config.yaml`
+    /// 4. `profile`'s `[profiles.<name>]` section, if given
+    /// 5. `~/.config/zenith/config.toml` or `~/.config/zenith/config.yaml`
+    /// 6. defaults
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if `profile` is `Some` and isn't
+    /// a declared profile, or if figment extraction fails.
+    pub fn load_with_profile_and_env_overrides(
+        profile: Option<&str>,
+        env_overrides: &[(String, String)],
+    ) -> Result<Self, Box<ConfigError>> {
+        Self::load_with_profile_root_and_env_overrides(profile, None, env_overrides)
+    }
+
+    /// Load configuration with an optional named profile, an explicit
+    /// project root, and external env-style overrides.
+    ///
+    /// `project_root` is the directory whose `.zenith/` subdirectory holds
+    /// the project-local config file. Pass `None` to discover it by walking
+    /// up from the current directory (see
+    /// [`figment_with_profile_root_and_env_overrides`]).
+    ///
+    /// See [`load_with_profile_and_env_overrides`] for precedence and
+    /// external-override support.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if `profile` is `Some` and isn't
+    /// a declared profile, or if figment extraction fails.
+    ///
+    /// [`load_with_profile_and_env_overrides`]: Self::load_with_profile_and_env_overrides
+    /// [`figment_with_profile_root_and_env_overrides`]: Self::figment_with_profile_root_and_env_overrides
+    pub fn load_with_profile_root_and_env_overrides(
+        profile: Option<&str>,
+        project_root: Option<&std::path::Path>,
+        env_overrides: &[(String, String)],
+    ) -> Result<Self, Box<ConfigError>> {
+        Self::figment_with_profile_root_and_env_overrides(profile, project_root, env_overrides)?
             .extract()
             .map_err(|e| Box::new(ConfigError::from(e)))
     }
 
+    /// Names of all `[profiles.*]` sections declared in the user-global
+    /// config file, sorted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if the user-global config file
+    /// exists but can't be read or parsed.
+    pub fn list_profiles() -> Result<Vec<String>, Box<ConfigError>> {
+        profile::list_profiles()
+    }
+
     /// Load configuration with `.env` file support.
     ///
     /// Calls `dotenvy` to load the `.env` file from the workspace root before
@@ -126,43 +235,94 @@ impl ZenConfig {
     ///
     /// This is public so tests can inspect the figment directly or add
     /// additional providers on top.
-    #[must_use]
-    pub fn figment() -> Figment {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if a config directory contains
+    /// both `config.toml` and `config.yaml`.
+    pub fn figment() -> Result<Figment, Box<ConfigError>> {
         Self::figment_with_env_overrides(&[])
     }
 
     /// Build the figment provider chain with external env-like overrides.
-    #[must_use]
-    pub fn figment_with_env_overrides(env_overrides: &[(String, String)]) -> Figment {
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if a config directory contains
+    /// both `config.toml` and `config.yaml`.
+    pub fn figment_with_env_overrides(
+        env_overrides: &[(String, String)],
+    ) -> Result<Figment, Box<ConfigError>> {
+        Self::figment_with_profile_and_env_overrides(None, env_overrides)
+    }
+
+    /// Build the figment provider chain with an optional named profile and
+    /// external env-like overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if a config directory contains
+    /// both `config.toml` and `config.yaml`, or if `profile` is `Some` and
+    /// isn't a declared profile.
+    pub fn figment_with_profile_and_env_overrides(
+        profile: Option<&str>,
+        env_overrides: &[(String, String)],
+    ) -> Result<Figment, Box<ConfigError>> {
+        Self::figment_with_profile_root_and_env_overrides(profile, None, env_overrides)
+    }
+
+    /// Build the figment provider chain with an optional named profile, an
+    /// explicit project root, and external env-like overrides.
+    ///
+    /// `project_root` is the directory whose `.zenith/` subdirectory holds
+    /// the project-local config file. Pass `None` to discover it by walking
+    /// up from the current directory looking for a `.zenith` directory,
+    /// mirroring zen-cli's own project root resolution -- this is what makes
+    /// the project-local config layer apply when `znt` is run from a
+    /// subdirectory of the project, not just from the project root itself.
+    /// Falls back to `.zenith` relative to the current directory (the
+    /// original, pre-walk-up behavior) when no `.zenith` directory is found
+    /// anywhere up the tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if a config directory contains
+    /// both `config.toml` and `config.yaml`, or if `profile` is `Some` and
+    /// isn't a declared profile.
+    pub fn figment_with_profile_root_and_env_overrides(
+        profile: Option<&str>,
+        project_root: Option<&std::path::Path>,
+        env_overrides: &[(String, String)],
+    ) -> Result<Figment, Box<ConfigError>> {
         let mut figment = Figment::from(Serialized::defaults(Self::default()));
 
         // Layer 1: User-global config
-        if let Some(global_path) = Self::global_config_path()
-            && global_path.exists()
-        {
-            figment = figment.merge(Toml::file(global_path));
+        if let Some(global_dir) = Self::global_config_dir() {
+            figment = merge_config_file(figment, &global_dir)?;
         }
 
-        // Layer 2: Project-local config
-        let local_path = PathBuf::from(".zenith/config.toml");
-        if local_path.exists() {
-            figment = figment.merge(Toml::file(local_path));
+        // Layer 2: Selected profile (from the user-global config file)
+        if let Some(name) = profile {
+            figment = profile::merge_profile(figment, name)?;
         }
 
-        // Layer 3: External env-style values (e.g., secret manager output)
+        // Layer 3: Project-local config
+        figment = merge_config_file(figment, &discover_zenith_dir(project_root))?;
+
+        // Layer 4: External env-style values (e.g., secret manager output)
         if let Some(doc) = env_overrides_to_toml(env_overrides) {
             figment = figment.merge(Toml::string(&doc));
         }
 
-        // Layer 4: Environment variables (highest priority)
+        // Layer 5: Environment variables (highest priority)
         figment = figment.merge(Env::prefixed("ZENITH_").split("__"));
 
-        figment
+        Ok(figment)
     }
 
-    /// Path to the user-global config file.
-    fn global_config_path() -> Option<PathBuf> {
-        dirs::config_dir().map(|p| p.join("zenith").join("config.toml"))
+    /// Directory holding the user-global config file.
+    fn global_config_dir() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("zenith"))
     }
 
     /// Load `.env` from the workspace root.
@@ -192,6 +352,70 @@ impl ZenConfig {
     }
 }
 
+/// Resolve the `.zenith` directory holding the project-local config file.
+///
+/// If `project_root` is given, uses `project_root/.zenith` directly. Otherwise
+/// walks up from the current directory looking for a `.zenith` directory,
+/// mirroring zen-cli's own project root resolution (`find_project_root`), so
+/// the project config layer applies regardless of which subdirectory `znt`
+/// is run from. Falls back to `.zenith` relative to the current directory
+/// (the original behavior) if no `.zenith` directory is found anywhere up
+/// the tree.
+fn discover_zenith_dir(project_root: Option<&std::path::Path>) -> PathBuf {
+    if let Some(root) = project_root {
+        return root.join(".zenith");
+    }
+
+    std::env::current_dir()
+        .ok()
+        .and_then(|cwd| find_zenith_dir_from(&cwd))
+        .unwrap_or_else(|| PathBuf::from(".zenith"))
+}
+
+/// Walk up from `start` looking for a `.zenith` directory, returning its
+/// path as soon as one is found.
+fn find_zenith_dir_from(start: &std::path::Path) -> Option<PathBuf> {
+    let mut current = start.to_path_buf();
+    loop {
+        let candidate = current.join(".zenith");
+        if candidate.is_dir() {
+            return Some(candidate);
+        }
+        if !current.pop() {
+            return None;
+        }
+    }
+}
+
+/// Merge `dir/config.toml` or `dir/config.yaml` into `figment`, whichever is
+/// present. Having both in the same directory is rejected outright, since
+/// there is no clear signal for which format the user meant to use.
+fn merge_config_file(figment: Figment, dir: &std::path::Path) -> Result<Figment, Box<ConfigError>> {
+    let toml_path = dir.join("config.toml");
+    let yaml_path = dir.join("config.yaml");
+    let toml_exists = toml_path.exists();
+    let yaml_exists = yaml_path.exists();
+
+    if toml_exists && yaml_exists {
+        return Err(Box::new(ConfigError::InvalidValue {
+            field: "config file".to_string(),
+            reason: format!(
+                "both {} and {} exist -- remove one to avoid ambiguity",
+                toml_path.display(),
+                yaml_path.display()
+            ),
+        }));
+    }
+
+    if toml_exists {
+        Ok(figment.merge(Toml::file(toml_path)))
+    } else if yaml_exists {
+        Ok(figment.merge(Yaml::file(yaml_path)))
+    } else {
+        Ok(figment)
+    }
+}
+
 fn env_overrides_to_toml(env_overrides: &[(String, String)]) -> Option<String> {
     let mut doc = String::new();
 
@@ -216,7 +440,13 @@ fn env_overrides_to_toml(env_overrides: &[(String, String)]) -> Option<String> {
     if doc.is_empty() { None } else { Some(doc) }
 }
 
-fn env_key_to_toml_path(key: &str) -> Option<String> {
+/// Map a `ZENITH_*` environment variable name to its dotted config key.
+///
+/// E.g. `ZENITH_TURSO__AUTH_TOKEN` -> `turso.auth_token`, matching the
+/// `Env::prefixed("ZENITH_").split("__")` figment mapping used for reads.
+/// Returns `None` if `key` doesn't start with `ZENITH_`.
+#[must_use]
+pub fn env_key_to_toml_path(key: &str) -> Option<String> {
     let suffix = key.strip_prefix("ZENITH_")?;
     let parts: Vec<String> = suffix
         .split("__")
@@ -242,16 +472,22 @@ mod tests {
         assert!(!config.motherduck.is_configured());
         assert!(!config.r2.is_configured());
         assert!(!config.general.auto_commit);
+        assert!(!config.search.is_configured());
+        assert!(!config.embeddings.is_configured());
+        assert!(!config.index.is_configured());
     }
 
     #[test]
     fn figment_builds_without_files() {
-        let figment = ZenConfig::figment();
+        let figment = ZenConfig::figment().expect("no ambiguous config files");
         let config: ZenConfig = figment.extract().expect("should extract defaults");
         assert!(!config.turso.is_configured());
         assert!(!config.motherduck.is_configured());
         assert!(!config.r2.is_configured());
         assert_eq!(config.general.default_limit, 20);
+        assert_eq!(config.search.default_mode, "hybrid");
+        assert_eq!(config.embeddings.model, "all-minilm-l6-v2");
+        assert_eq!(config.index.chunk_token_budget, 512);
     }
 
     #[test]
@@ -272,4 +508,31 @@ mod tests {
 
         assert!(doc.contains("clerk.secret_key = \"sk_test\""));
     }
+
+    #[test]
+    fn find_zenith_dir_from_walks_up_to_project_root() {
+        let temp = tempfile::TempDir::new().expect("tempdir should create");
+        std::fs::create_dir(temp.path().join(".zenith")).expect(".zenith should create");
+        std::fs::create_dir_all(temp.path().join("a/b/c")).expect("nested dirs should create");
+
+        let deep = temp.path().join("a/b/c");
+        let found = find_zenith_dir_from(&deep);
+        assert_eq!(found, Some(temp.path().join(".zenith")));
+    }
+
+    #[test]
+    fn find_zenith_dir_from_returns_none_when_not_found() {
+        let temp = tempfile::TempDir::new().expect("tempdir should create");
+        std::fs::create_dir_all(temp.path().join("a/b/c")).expect("nested dirs should create");
+
+        let found = find_zenith_dir_from(&temp.path().join("a/b/c"));
+        assert!(found.is_none());
+    }
+
+    #[test]
+    fn discover_zenith_dir_prefers_explicit_project_root() {
+        let temp = tempfile::TempDir::new().expect("tempdir should create");
+        let dir = discover_zenith_dir(Some(temp.path()));
+        assert_eq!(dir, temp.path().join(".zenith"));
+    }
 }
@@ -0,0 +1,223 @@
+//! Named `[profiles.<name>]` overrides in the user-global config file, for
+//! machines that juggle multiple accounts (e.g. work vs. personal Turso
+//! org) without hand-editing env vars every time.
+//!
+//! A profile is just a section shaped like the top-level config:
+//!
+//! ```toml
+//! [profiles.work.turso]
+//! url = "libsql://work-db.turso.io"
+//! auth_token = "..."
+//! ```
+//!
+//! Selected via the `ZENITH_PROFILE` env var or `--profile` CLI flag, it
+//! merges over the base config (defaults + user-global file) but below
+//! project-local config and environment variables.
+
+use std::fs;
+
+use figment::Figment;
+use figment::providers::{Format, Toml};
+
+use crate::ConfigError;
+use crate::write::ConfigScope;
+
+/// Env var carrying the active profile name, overridden by `--profile` on the CLI.
+pub const PROFILE_ENV_VAR: &str = "ZENITH_PROFILE";
+
+/// Merge the `name` profile's section from the user-global config file over
+/// `figment`.
+///
+/// # Errors
+///
+/// Returns [`ConfigError::InvalidValue`] if the user-global config file
+/// can't be read or parsed, or if `name` isn't a declared profile (the error
+/// message lists the profiles that are).
+pub fn merge_profile(figment: Figment, name: &str) -> Result<Figment, Box<ConfigError>> {
+    let profiles = profiles_table()?;
+
+    let Some(profile) = profiles.get(name) else {
+        let mut available: Vec<&str> = profiles.keys().map(String::as_str).collect();
+        available.sort_unstable();
+        return Err(Box::new(ConfigError::InvalidValue {
+            field: "profile".to_string(),
+            reason: if available.is_empty() {
+                format!("unknown profile '{name}'; no profiles are declared")
+            } else {
+                format!(
+                    "unknown profile '{name}'; available profiles: {}",
+                    available.join(", ")
+                )
+            },
+        }));
+    };
+
+    let doc = toml::to_string(profile).map_err(|error| {
+        Box::new(ConfigError::InvalidValue {
+            field: format!("profiles.{name}"),
+            reason: format!("failed to re-serialize profile: {error}"),
+        })
+    })?;
+
+    Ok(figment.merge(Toml::string(&doc)))
+}
+
+/// Names of all declared `[profiles.*]` sections, sorted.
+///
+/// # Errors
+///
+/// Returns [`ConfigError::InvalidValue`] if the user-global config file
+/// exists but can't be read or parsed.
+pub fn list_profiles() -> Result<Vec<String>, Box<ConfigError>> {
+    let mut names: Vec<String> = profiles_table()?
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    names.sort_unstable();
+    Ok(names)
+}
+
+/// The `[profiles]` table from the user-global config file, or empty if the
+/// file doesn't exist or declares no profiles.
+fn profiles_table() -> Result<toml::value::Table, Box<ConfigError>> {
+    let path = ConfigScope::Global.path()?;
+
+    let raw = match fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(toml::value::Table::new());
+        }
+        Err(error) => {
+            return Err(Box::new(ConfigError::InvalidValue {
+                field: "profile".to_string(),
+                reason: format!("failed to read {}: {error}", path.display()),
+            }));
+        }
+    };
+
+    let doc: toml::Value = toml::from_str(&raw).map_err(|error| {
+        Box::new(ConfigError::InvalidValue {
+            field: "profile".to_string(),
+            reason: format!("failed to parse {}: {error}", path.display()),
+        })
+    })?;
+
+    Ok(doc
+        .get("profiles")
+        .and_then(toml::Value::as_table)
+        .cloned()
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use figment::Jail;
+
+    use super::*;
+    use crate::ZenConfig;
+
+    fn global_config_dir_relative_to_jail(jail: &Jail) -> std::path::PathBuf {
+        // `dirs::config_dir()` respects `XDG_CONFIG_HOME` on Linux, which Jail
+        // lets us point at its sandboxed directory.
+        jail.directory().join("config")
+    }
+
+    #[test]
+    fn list_profiles_is_empty_without_a_global_config() {
+        Jail::expect_with(|jail| {
+            jail.set_env(
+                "XDG_CONFIG_HOME",
+                global_config_dir_relative_to_jail(jail).display(),
+            );
+            assert!(list_profiles().map_err(|e| e.to_string())?.is_empty());
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn list_profiles_reads_declared_profile_names() {
+        Jail::expect_with(|jail| {
+            let config_dir = global_config_dir_relative_to_jail(jail);
+            jail.set_env("XDG_CONFIG_HOME", config_dir.display());
+            jail.create_dir(config_dir.join("zenith").to_str().unwrap())?;
+            jail.create_file(
+                config_dir
+                    .join("zenith")
+                    .join("config.toml")
+                    .to_str()
+                    .unwrap(),
+                "[profiles.work.turso]\nurl = \"libsql://work.turso.io\"\n\n[profiles.personal.turso]\nurl = \"libsql://personal.turso.io\"\n",
+            )?;
+
+            assert_eq!(
+                list_profiles().map_err(|e| e.to_string())?,
+                vec!["personal".to_string(), "work".to_string()]
+            );
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn merge_profile_errors_with_available_list_on_unknown_name() {
+        Jail::expect_with(|jail| {
+            let config_dir = global_config_dir_relative_to_jail(jail);
+            jail.set_env("XDG_CONFIG_HOME", config_dir.display());
+            jail.create_dir(config_dir.join("zenith").to_str().unwrap())?;
+            jail.create_file(
+                config_dir
+                    .join("zenith")
+                    .join("config.toml")
+                    .to_str()
+                    .unwrap(),
+                "[profiles.work.turso]\nurl = \"libsql://work.turso.io\"\n",
+            )?;
+
+            let error = merge_profile(Figment::new(), "bogus").unwrap_err();
+            let ConfigError::InvalidValue { reason, .. } = *error else {
+                panic!("expected InvalidValue");
+            };
+            assert!(reason.contains("work"), "reason was: {reason}");
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn selected_profile_overrides_base_but_not_project_local() {
+        Jail::expect_with(|jail| {
+            let config_dir = global_config_dir_relative_to_jail(jail);
+            jail.set_env("XDG_CONFIG_HOME", config_dir.display());
+            jail.create_dir(config_dir.join("zenith").to_str().unwrap())?;
+            jail.create_file(
+                config_dir
+                    .join("zenith")
+                    .join("config.toml")
+                    .to_str()
+                    .unwrap(),
+                "[turso]\nurl = \"libsql://base.turso.io\"\n\n[profiles.work.turso]\nurl = \"libsql://work.turso.io\"\nauth_token = \"work-token\"\n",
+            )?;
+
+            let config = ZenConfig::load_with_profile("work").map_err(|e| e.to_string())?;
+            assert_eq!(config.turso.url, "libsql://work.turso.io");
+            assert_eq!(config.turso.auth_token, "work-token");
+
+            jail.create_dir(".zenith")?;
+            jail.create_file(
+                ".zenith/config.toml",
+                "[turso]\nurl = \"libsql://project.turso.io\"\n",
+            )?;
+
+            let config = ZenConfig::load_with_profile("work").map_err(|e| e.to_string())?;
+            assert_eq!(
+                config.turso.url, "libsql://project.turso.io",
+                "project-local config must win over a selected profile"
+            );
+            assert_eq!(
+                config.turso.auth_token, "work-token",
+                "profile fields not overridden by project-local config should stick"
+            );
+
+            Ok(())
+        });
+    }
+}
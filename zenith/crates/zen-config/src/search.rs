@@ -0,0 +1,93 @@
+//! Search command configuration.
+
+use serde::{Deserialize, Serialize};
+
+/// Default result limit for `znt search`.
+const fn default_limit() -> u32 {
+    20
+}
+
+/// Default hybrid blending weight (0.0 = pure FTS, 1.0 = pure vector).
+const fn default_alpha() -> f64 {
+    0.5
+}
+
+/// Default search mode.
+fn default_mode() -> String {
+    "hybrid".to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SearchConfig {
+    /// Default result limit, used when neither `--limit` nor the global
+    /// `--limit` flag is given.
+    #[serde(default = "default_limit")]
+    pub default_limit: u32,
+
+    /// Default hybrid blending weight, used when `--mode hybrid` is
+    /// selected without an explicit alpha.
+    #[serde(default = "default_alpha")]
+    pub default_alpha: f64,
+
+    /// Default search mode (`vector`, `fts`, `hybrid`, `recursive`, `graph`),
+    /// used when `--mode` is omitted.
+    #[serde(default = "default_mode")]
+    pub default_mode: String,
+
+    /// Minimum score below which results are dropped, applied when
+    /// `--min-score` is omitted.
+    #[serde(default)]
+    pub min_score: Option<f64>,
+
+    /// Whether search results should collapse to the highest-scoring hit per
+    /// package, hiding older versions. Not yet consumed by the search
+    /// engine -- reserved for when result collapsing lands.
+    #[serde(default)]
+    pub collapse_versions: bool,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            default_limit: default_limit(),
+            default_alpha: default_alpha(),
+            default_mode: default_mode(),
+            min_score: None,
+            collapse_versions: false,
+        }
+    }
+}
+
+impl SearchConfig {
+    /// Check if any search default has been customized away from the
+    /// built-in defaults.
+    #[must_use]
+    pub fn is_configured(&self) -> bool {
+        *self != Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_correct() {
+        let config = SearchConfig::default();
+        assert!(!config.is_configured());
+        assert_eq!(config.default_limit, 20);
+        assert!((config.default_alpha - 0.5).abs() < f64::EPSILON);
+        assert_eq!(config.default_mode, "hybrid");
+        assert_eq!(config.min_score, None);
+        assert!(!config.collapse_versions);
+    }
+
+    #[test]
+    fn configured_when_any_field_customized() {
+        let config = SearchConfig {
+            default_alpha: 0.3,
+            ..Default::default()
+        };
+        assert!(config.is_configured());
+    }
+}
@@ -0,0 +1,101 @@
+//! Optional bridge from `zen-secrets`' [`SecretOverrides`](zen_secrets::SecretOverrides)
+//! into the figment provider chain, gated behind the `secrets` feature so
+//! library users who don't use an external secrets backend don't pay for the
+//! dependency.
+
+use zen_secrets::SecretOverrides;
+
+use crate::{ConfigError, ZenConfig};
+
+impl ZenConfig {
+    /// Load configuration with externally-resolved secret overrides (e.g.
+    /// from Infisical) merged in.
+    ///
+    /// `overrides` uses the same `ZENITH_SECTION__FIELD` naming convention as
+    /// process env vars and is split on `__` identically -- see
+    /// [`figment_with_env_overrides`](Self::figment_with_env_overrides).
+    /// Precedence is unchanged from that method: secrets sit above TOML
+    /// config but below real process env vars, so an operator can always
+    /// override a resolved secret locally.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if figment extraction fails.
+    pub fn load_with_secrets(overrides: &SecretOverrides) -> Result<Self, Box<ConfigError>> {
+        Self::load_with_env_overrides(&secret_overrides_to_pairs(overrides))
+    }
+
+    /// Resolve secrets from the configured external backend (via
+    /// `zen_secrets::load_env_overrides`) and load configuration with them
+    /// merged in.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] if resolving secrets fails, or
+    /// if figment extraction fails.
+    pub async fn load_full() -> Result<Self, Box<ConfigError>> {
+        let overrides = zen_secrets::load_env_overrides().await.map_err(|error| {
+            Box::new(ConfigError::InvalidValue {
+                field: "secrets".to_string(),
+                reason: error.to_string(),
+            })
+        })?;
+        Self::load_with_secrets(&overrides)
+    }
+}
+
+fn secret_overrides_to_pairs(overrides: &SecretOverrides) -> Vec<(String, String)> {
+    match overrides {
+        SecretOverrides::Disabled => Vec::new(),
+        SecretOverrides::Values(values) => values.clone(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use figment::Jail;
+
+    use super::*;
+
+    #[test]
+    fn disabled_overrides_yield_defaults() {
+        Jail::expect_with(|_jail| {
+            let config = ZenConfig::load_with_secrets(&SecretOverrides::Disabled)
+                .map_err(|e| e.to_string())?;
+            assert!(!config.clerk.is_configured());
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn secret_values_override_toml_but_not_process_env() {
+        Jail::expect_with(|jail| {
+            jail.create_dir(".zenith")?;
+            jail.create_file(
+                ".zenith/config.toml",
+                "[clerk]\nsecret_key = \"sk_from_toml\"\npublishable_key = \"pk\"\n",
+            )?;
+
+            let overrides = SecretOverrides::Values(vec![(
+                "ZENITH_CLERK__SECRET_KEY".to_string(),
+                "sk_from_secrets".to_string(),
+            )]);
+
+            let config = ZenConfig::load_with_secrets(&overrides).map_err(|e| e.to_string())?;
+            assert_eq!(
+                config.clerk.secret_key, "sk_from_secrets",
+                "secret-backend overrides must win over project-local TOML"
+            );
+
+            jail.set_env("ZENITH_CLERK__SECRET_KEY", "sk_from_process_env");
+            let config = ZenConfig::load_with_secrets(&overrides).map_err(|e| e.to_string())?;
+            assert_eq!(
+                config.clerk.secret_key, "sk_from_process_env",
+                "real process env vars must win over secret-backend overrides"
+            );
+
+            Ok(())
+        });
+    }
+}
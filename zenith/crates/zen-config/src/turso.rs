@@ -12,6 +12,11 @@ const fn default_read_your_writes() -> bool {
     true
 }
 
+/// Default number of `ZenDb` connections to maintain.
+const fn default_connection_pool_size() -> u8 {
+    1
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TursoConfig {
     /// Database URL (e.g., `libsql://mydb.turso.io`).
@@ -42,6 +47,10 @@ pub struct TursoConfig {
     /// Local replica path for embedded replica mode.
     #[serde(default)]
     pub local_replica_path: String,
+
+    /// Number of `ZenDb` connections to maintain.
+    #[serde(default = "default_connection_pool_size")]
+    pub connection_pool_size: u8,
 }
 
 impl Default for TursoConfig {
@@ -54,6 +63,7 @@ impl Default for TursoConfig {
             sync_interval_secs: default_sync_interval_secs(),
             read_your_writes: default_read_your_writes(),
             local_replica_path: String::new(),
+            connection_pool_size: default_connection_pool_size(),
         }
     }
 }
@@ -102,6 +112,7 @@ mod tests {
         assert!(config.read_your_writes);
         assert!(!config.has_local_replica());
         assert!(!config.can_mint_tokens());
+        assert_eq!(config.connection_pool_size, 1);
     }
 
     #[test]
@@ -0,0 +1,347 @@
+//! Configuration validation and secret redaction.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ZenConfig;
+
+/// Embedding model names recognized by
+/// `zen_embeddings::EmbeddingEngine::new_from_config`. zen-config cannot
+/// depend on zen-embeddings (that crate already depends on zen-config), so
+/// this list is kept in sync by hand.
+const KNOWN_EMBEDDING_MODELS: &[&str] = &["all-minilm-l6-v2", "all-minilm-l12-v2"];
+
+/// Smallest and largest accepted `GeneralConfig::default_limit`.
+const DEFAULT_LIMIT_RANGE: std::ops::RangeInclusive<u32> = 1..=500;
+
+/// Smallest and largest accepted `EmbeddingsConfig::batch_size`.
+const BATCH_SIZE_RANGE: std::ops::RangeInclusive<u32> = 1..=2048;
+
+/// How serious a [`ConfigIssue`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueSeverity {
+    /// Suspicious but not guaranteed to break anything.
+    Warning,
+    /// Will fail the first time the affected subsystem is used.
+    Error,
+}
+
+/// A single configuration problem surfaced by [`ZenConfig::validate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigIssue {
+    /// Dotted path of the offending field, e.g. `"turso.url"`.
+    pub field: String,
+    pub severity: IssueSeverity,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn error(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            severity: IssueSeverity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            severity: IssueSeverity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+impl ZenConfig {
+    /// Check the config for values that would fail deep inside whatever
+    /// subsystem first touches them, and surface them up front instead.
+    ///
+    /// Returns an empty `Vec` when nothing is wrong. Does not check whether
+    /// credentials are actually valid -- only whether they are shaped
+    /// plausibly.
+    #[must_use]
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+
+        if !self.turso.url.is_empty()
+            && !self.turso.url.starts_with("libsql://")
+            && !self.turso.url.starts_with("https://")
+        {
+            issues.push(ConfigIssue::error(
+                "turso.url",
+                "must start with 'libsql://' or 'https://'",
+            ));
+        }
+
+        if !self.turso.url.is_empty() && self.turso.auth_token.is_empty() {
+            issues.push(ConfigIssue::error(
+                "turso.auth_token",
+                "turso.url is set but turso.auth_token is empty",
+            ));
+        }
+
+        let r2_fields = [
+            ("r2.account_id", &self.r2.account_id),
+            ("r2.access_key_id", &self.r2.access_key_id),
+            ("r2.secret_access_key", &self.r2.secret_access_key),
+        ];
+        let r2_set = r2_fields.iter().filter(|(_, v)| !v.is_empty()).count();
+        if r2_set > 0 && r2_set < r2_fields.len() {
+            for (field, value) in r2_fields {
+                if value.is_empty() {
+                    issues.push(ConfigIssue::error(
+                        field,
+                        "r2.account_id, r2.access_key_id, and r2.secret_access_key must all be set together",
+                    ));
+                }
+            }
+        }
+
+        if !self.motherduck.access_token.is_empty()
+            && self.motherduck.access_token.split('.').count() != 3
+        {
+            issues.push(ConfigIssue::warning(
+                "motherduck.access_token",
+                "does not look like a MotherDuck JWT (expected three dot-separated segments)",
+            ));
+        }
+
+        if !KNOWN_EMBEDDING_MODELS.contains(&self.embeddings.model.as_str()) {
+            issues.push(ConfigIssue::error(
+                "embeddings.model",
+                format!(
+                    "unknown embedding model '{}'; expected one of: {}",
+                    self.embeddings.model,
+                    KNOWN_EMBEDDING_MODELS.join(", ")
+                ),
+            ));
+        }
+
+        if !DEFAULT_LIMIT_RANGE.contains(&self.general.default_limit) {
+            issues.push(ConfigIssue::error(
+                "general.default_limit",
+                format!(
+                    "must be between {} and {}",
+                    DEFAULT_LIMIT_RANGE.start(),
+                    DEFAULT_LIMIT_RANGE.end()
+                ),
+            ));
+        }
+
+        if !BATCH_SIZE_RANGE.contains(&self.embeddings.batch_size) {
+            issues.push(ConfigIssue::error(
+                "embeddings.batch_size",
+                format!(
+                    "must be between {} and {}",
+                    BATCH_SIZE_RANGE.start(),
+                    BATCH_SIZE_RANGE.end()
+                ),
+            ));
+        }
+
+        if self.index.chunk_overlap >= self.index.chunk_token_budget {
+            issues.push(ConfigIssue::error(
+                "index.chunk_overlap",
+                "must be smaller than index.chunk_token_budget",
+            ));
+        }
+
+        issues
+    }
+
+    /// A copy of this config with secrets masked, safe to print in
+    /// `znt doctor`-style diagnostic output.
+    #[must_use]
+    pub fn redacted(&self) -> Self {
+        let mut copy = self.clone();
+        copy.turso.auth_token = redact_secret(&copy.turso.auth_token);
+        copy.turso.platform_api_key = redact_secret(&copy.turso.platform_api_key);
+        copy.r2.access_key_id = redact_secret(&copy.r2.access_key_id);
+        copy.r2.secret_access_key = redact_secret(&copy.r2.secret_access_key);
+        copy.clerk.secret_key = redact_secret(&copy.clerk.secret_key);
+        copy.axiom.token = redact_secret(&copy.axiom.token);
+        copy.motherduck.access_token = redact_secret(&copy.motherduck.access_token);
+        copy
+    }
+}
+
+/// Mask a secret to `{first 4}****{last 4}`, or `****` outright when it's too
+/// short to leave any real characters exposed on either side. Empty secrets
+/// stay empty -- there's nothing to redact.
+fn redact_secret(secret: &str) -> String {
+    if secret.is_empty() {
+        return String::new();
+    }
+
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+
+    let prefix: String = chars[..4].iter().collect();
+    let suffix: String = chars[chars.len() - 4..].iter().collect();
+    format!("{prefix}****{suffix}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_config() -> ZenConfig {
+        let mut config = ZenConfig {
+            turso: crate::TursoConfig {
+                url: "libsql://mydb.turso.io".to_string(),
+                auth_token: "token123".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        config.embeddings.model = "all-minilm-l6-v2".to_string();
+        config
+    }
+
+    #[test]
+    fn valid_config_has_no_issues() {
+        assert!(valid_config().validate().is_empty());
+    }
+
+    #[test]
+    fn rejects_bad_turso_url_scheme() {
+        let mut config = valid_config();
+        config.turso.url = "http://mydb.turso.io".to_string();
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| i.field == "turso.url"));
+    }
+
+    #[test]
+    fn rejects_url_without_auth_token() {
+        let mut config = valid_config();
+        config.turso.auth_token = String::new();
+        let issues = config.validate();
+        assert!(
+            issues
+                .iter()
+                .any(|i| i.field == "turso.auth_token" && i.severity == IssueSeverity::Error)
+        );
+    }
+
+    #[test]
+    fn rejects_partial_r2_config() {
+        let mut config = valid_config();
+        config.r2.account_id = "acc123".to_string();
+        config.r2.access_key_id = "key".to_string();
+        // secret_access_key left empty
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| i.field == "r2.secret_access_key"));
+    }
+
+    #[test]
+    fn accepts_fully_set_r2_config() {
+        let mut config = valid_config();
+        config.r2.account_id = "acc123".to_string();
+        config.r2.access_key_id = "key".to_string();
+        config.r2.secret_access_key = "secret".to_string();
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn warns_on_malformed_motherduck_token() {
+        let mut config = valid_config();
+        config.motherduck.access_token = "not-a-jwt".to_string();
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| {
+            i.field == "motherduck.access_token" && i.severity == IssueSeverity::Warning
+        }));
+    }
+
+    #[test]
+    fn accepts_jwt_shaped_motherduck_token() {
+        let mut config = valid_config();
+        config.motherduck.access_token = "header.payload.signature".to_string();
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_embedding_model() {
+        let mut config = valid_config();
+        config.embeddings.model = "made-up-model".to_string();
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| i.field == "embeddings.model"));
+    }
+
+    #[test]
+    fn rejects_out_of_range_default_limit() {
+        let mut config = valid_config();
+        config.general.default_limit = 0;
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| i.field == "general.default_limit"));
+
+        config.general.default_limit = 501;
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| i.field == "general.default_limit"));
+    }
+
+    #[test]
+    fn rejects_out_of_range_batch_size() {
+        let mut config = valid_config();
+        config.embeddings.batch_size = 0;
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| i.field == "embeddings.batch_size"));
+
+        config.embeddings.batch_size = 2049;
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| i.field == "embeddings.batch_size"));
+    }
+
+    #[test]
+    fn rejects_chunk_overlap_not_smaller_than_chunk_token_budget() {
+        let mut config = valid_config();
+        config.index.chunk_overlap = config.index.chunk_token_budget;
+        let issues = config.validate();
+        assert!(issues.iter().any(|i| i.field == "index.chunk_overlap"));
+    }
+
+    #[test]
+    fn accepts_chunk_overlap_smaller_than_chunk_token_budget() {
+        let mut config = valid_config();
+        config.index.chunk_overlap = config.index.chunk_token_budget - 1;
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn redacted_masks_secrets_but_keeps_length_hint() {
+        let mut config = valid_config();
+        config.turso.auth_token = "abcd1234efgh5678".to_string();
+        config.r2.secret_access_key = "topsecretvalue".to_string();
+
+        let redacted = config.redacted();
+        assert_eq!(redacted.turso.auth_token, "abcd****5678");
+        assert!(!redacted.turso.auth_token.contains("1234efgh"));
+        assert_eq!(redacted.r2.secret_access_key, "tops****alue");
+    }
+
+    #[test]
+    fn redacted_short_secret_is_fully_masked() {
+        let mut config = valid_config();
+        config.axiom.token = "xaat-1".to_string();
+        let redacted = config.redacted();
+        assert_eq!(redacted.axiom.token, "****");
+    }
+
+    #[test]
+    fn redacted_leaves_empty_secrets_empty() {
+        let config = valid_config();
+        let redacted = config.redacted();
+        assert!(redacted.clerk.secret_key.is_empty());
+    }
+
+    #[test]
+    fn redacted_never_contains_full_original_secret() {
+        let mut config = valid_config();
+        config.clerk.secret_key = "sk_live_super_secret_value_123456".to_string();
+        let redacted = config.redacted();
+        assert_ne!(redacted.clerk.secret_key, config.clerk.secret_key);
+        assert!(!redacted.clerk.secret_key.contains("super_secret_value"));
+    }
+}
@@ -0,0 +1,276 @@
+//! Programmatic, format-preserving writes to Zenith config files.
+//!
+//! Unlike [`crate::ZenConfig::load`], which merges configuration through
+//! figment for reading, [`set_value`] edits the on-disk TOML file directly
+//! via `toml_edit`, so hand-written comments and formatting in an existing
+//! `config.toml` survive a `znt config set`.
+
+use std::fs;
+use std::path::PathBuf;
+
+use toml_edit::{DocumentMut, Item, Table, Value};
+
+use crate::{ConfigError, ZenConfig};
+
+/// Which config file a write targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigScope {
+    /// Project-local `.zenith/config.toml`.
+    Project,
+    /// User-global `~/.config/zenith/config.toml`.
+    Global,
+}
+
+impl ConfigScope {
+    /// Resolve the config file path for this scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidValue`] for [`ConfigScope::Global`] if
+    /// the user's config directory cannot be determined.
+    pub fn path(self) -> Result<PathBuf, Box<ConfigError>> {
+        match self {
+            Self::Project => Ok(PathBuf::from(".zenith").join("config.toml")),
+            Self::Global => dirs::config_dir()
+                .map(|dir| dir.join("zenith").join("config.toml"))
+                .ok_or_else(|| {
+                    Box::new(ConfigError::InvalidValue {
+                        field: "config directory".to_string(),
+                        reason: "could not determine the user config directory".to_string(),
+                    })
+                }),
+        }
+    }
+}
+
+/// All `section.field` keys recognized by [`ZenConfig`].
+///
+/// Derived from a default-valued document rather than hand-maintained, so it
+/// can't drift from the actual schema as sections gain or lose fields.
+fn known_keys() -> Vec<String> {
+    let default = toml::Value::try_from(ZenConfig::default()).expect("ZenConfig always serializes");
+    let mut keys = Vec::new();
+    if let toml::Value::Table(sections) = default {
+        for (section, fields) in sections {
+            if let toml::Value::Table(fields) = fields {
+                for field in fields.keys() {
+                    keys.push(format!("{section}.{field}"));
+                }
+            }
+        }
+    }
+    keys.sort();
+    keys
+}
+
+/// Split `dotted_key` into its section and field, validating it against the
+/// schema.
+///
+/// # Errors
+///
+/// Returns [`ConfigError::InvalidValue`] if `dotted_key` isn't shaped like
+/// `section.field`, or doesn't match any known key -- the error message
+/// suggests the closest known key.
+fn validate_key(dotted_key: &str) -> Result<(&str, &str), Box<ConfigError>> {
+    let Some((section, field)) = dotted_key.split_once('.') else {
+        return Err(Box::new(ConfigError::InvalidValue {
+            field: dotted_key.to_string(),
+            reason: "expected a dotted key like 'turso.url'".to_string(),
+        }));
+    };
+
+    let keys = known_keys();
+    if keys.iter().any(|key| key == dotted_key) {
+        return Ok((section, field));
+    }
+
+    let suggestion = keys
+        .iter()
+        .min_by_key(|key| levenshtein(key, dotted_key))
+        .map(|key| format!(" -- did you mean '{key}'?"))
+        .unwrap_or_default();
+
+    Err(Box::new(ConfigError::InvalidValue {
+        field: dotted_key.to_string(),
+        reason: format!("'{dotted_key}' is not a known config key{suggestion}"),
+    }))
+}
+
+/// Set a single dotted config key (e.g. `"turso.url"`) to `value` in the
+/// `scope` config file, preserving existing formatting and comments.
+///
+/// Loads the current file (or starts from an empty document if it doesn't
+/// exist yet), applies the change, validates that the resulting document
+/// still deserializes into [`ZenConfig`], then writes it back atomically
+/// (temp file + rename).
+///
+/// # Errors
+///
+/// Returns [`ConfigError::InvalidValue`] if `dotted_key` isn't a recognized
+/// config key, if the existing file can't be read or parsed as TOML, or if
+/// the resulting document fails to deserialize into [`ZenConfig`].
+pub fn set_value(
+    scope: ConfigScope,
+    dotted_key: &str,
+    value: Value,
+) -> Result<(), Box<ConfigError>> {
+    let (section, field) = validate_key(dotted_key)?;
+    let path = scope.path()?;
+
+    let raw = match fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(error) => {
+            return Err(Box::new(ConfigError::InvalidValue {
+                field: dotted_key.to_string(),
+                reason: format!("failed to read {}: {error}", path.display()),
+            }));
+        }
+    };
+
+    let mut doc: DocumentMut = raw.parse().map_err(|error| {
+        Box::new(ConfigError::InvalidValue {
+            field: dotted_key.to_string(),
+            reason: format!("failed to parse {}: {error}", path.display()),
+        })
+    })?;
+
+    let section_table = doc
+        .entry(section)
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| {
+            Box::new(ConfigError::InvalidValue {
+                field: dotted_key.to_string(),
+                reason: format!("'{section}' is not a table in {}", path.display()),
+            })
+        })?;
+    section_table.insert(field, Item::Value(value));
+
+    let updated = doc.to_string();
+    toml::from_str::<ZenConfig>(&updated).map_err(|error| {
+        Box::new(ConfigError::InvalidValue {
+            field: dotted_key.to_string(),
+            reason: format!("resulting config is invalid: {error}"),
+        })
+    })?;
+
+    write_atomically(&path, &updated)
+}
+
+/// Write `contents` to `path` atomically via a temp file + rename in the same
+/// directory, creating parent directories as needed.
+fn write_atomically(path: &std::path::Path, contents: &str) -> Result<(), Box<ConfigError>> {
+    let to_config_error = |error: std::io::Error| {
+        Box::new(ConfigError::InvalidValue {
+            field: path.display().to_string(),
+            reason: format!("failed to write config: {error}"),
+        })
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(to_config_error)?;
+    }
+
+    let tmp_path = path.with_extension("toml.tmp");
+    fs::write(&tmp_path, contents).map_err(to_config_error)?;
+    fs::rename(&tmp_path, path).map_err(to_config_error)?;
+    Ok(())
+}
+
+/// Levenshtein edit distance, used to suggest the nearest known key on typos.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ac) in a.iter().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+        for (j, bc) in b.iter().enumerate() {
+            let cost = usize::from(ac != bc);
+            let new_val = (row[j] + 1).min(row[j + 1] + 1).min(prev_diag + cost);
+            prev_diag = row[j + 1];
+            row[j + 1] = new_val;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use figment::Jail;
+
+    #[test]
+    fn known_keys_include_turso_url() {
+        assert!(known_keys().contains(&"turso.url".to_string()));
+    }
+
+    #[test]
+    fn validate_key_rejects_unknown_section() {
+        let error = validate_key("bogus.field").unwrap_err();
+        assert!(matches!(*error, ConfigError::InvalidValue { .. }));
+    }
+
+    #[test]
+    fn validate_key_suggests_nearest_match() {
+        let error = validate_key("tursoo.url").unwrap_err();
+        let ConfigError::InvalidValue { reason, .. } = *error else {
+            panic!("expected InvalidValue");
+        };
+        assert!(reason.contains("turso.url"), "reason was: {reason}");
+    }
+
+    #[test]
+    fn set_value_creates_new_file_with_section() {
+        Jail::expect_with(|_jail| {
+            set_value(
+                ConfigScope::Project,
+                "turso.url",
+                Value::from("libsql://written.turso.io"),
+            )
+            .map_err(|error| error.to_string())?;
+
+            let contents = fs::read_to_string(".zenith/config.toml").map_err(|e| e.to_string())?;
+            assert!(contents.contains("[turso]"));
+            assert!(contents.contains("libsql://written.turso.io"));
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn set_value_preserves_existing_comments() {
+        Jail::expect_with(|jail| {
+            jail.create_dir(".zenith")?;
+            jail.create_file(
+                ".zenith/config.toml",
+                "# a hand-written comment\n[turso]\nurl = \"libsql://old.turso.io\"\n",
+            )?;
+
+            set_value(
+                ConfigScope::Project,
+                "turso.url",
+                Value::from("libsql://new.turso.io"),
+            )
+            .map_err(|error| error.to_string())?;
+
+            let contents = fs::read_to_string(".zenith/config.toml").map_err(|e| e.to_string())?;
+            assert!(contents.contains("# a hand-written comment"));
+            assert!(contents.contains("libsql://new.turso.io"));
+            assert!(!contents.contains("libsql://old.turso.io"));
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn set_value_rejects_unknown_key() {
+        Jail::expect_with(|_jail| {
+            let result = set_value(ConfigScope::Project, "nope.nope", Value::from("x"));
+            assert!(result.is_err());
+            Ok(())
+        });
+    }
+}
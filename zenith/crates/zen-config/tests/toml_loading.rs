@@ -150,6 +150,35 @@ endpoint = "https://custom-axiom.co"
     });
 }
 
+#[test]
+fn loads_search_config_from_toml() {
+    Jail::expect_with(|jail| {
+        jail.create_file(
+            "config.toml",
+            r#"
+[search]
+default_limit = 30
+default_alpha = 0.3
+default_mode = "vector"
+min_score = 0.2
+collapse_versions = true
+"#,
+        )?;
+
+        let config: ZenConfig = Figment::from(Serialized::defaults(ZenConfig::default()))
+            .merge(Toml::file("config.toml"))
+            .extract()?;
+
+        assert_eq!(config.search.default_limit, 30);
+        assert!((config.search.default_alpha - 0.3).abs() < f64::EPSILON);
+        assert_eq!(config.search.default_mode, "vector");
+        assert_eq!(config.search.min_score, Some(0.2));
+        assert!(config.search.collapse_versions);
+        assert!(config.search.is_configured());
+        Ok(())
+    });
+}
+
 #[test]
 fn loads_full_config_from_toml() {
     Jail::expect_with(|jail| {
@@ -243,6 +272,33 @@ fn env_var_overrides_default() {
     });
 }
 
+#[test]
+fn env_var_overrides_search_toml_value() {
+    Jail::expect_with(|jail| {
+        jail.set_env("ZENITH_SEARCH__DEFAULT_ALPHA", "0.7");
+
+        jail.create_file(
+            "config.toml",
+            r#"
+[search]
+default_alpha = 0.3
+default_mode = "vector"
+"#,
+        )?;
+
+        let config: ZenConfig = Figment::from(Serialized::defaults(ZenConfig::default()))
+            .merge(Toml::file("config.toml"))
+            .merge(Env::prefixed("ZENITH_").split("__"))
+            .extract()?;
+
+        // Env should win over TOML.
+        assert!((config.search.default_alpha - 0.7).abs() < f64::EPSILON);
+        // TOML value not overridden by env should remain.
+        assert_eq!(config.search.default_mode, "vector");
+        Ok(())
+    });
+}
+
 /// Documents the figment gotcha: typo'd env var keys are silently ignored.
 /// The value stays at its default because figment doesn't know "urll" should be "url".
 #[test]
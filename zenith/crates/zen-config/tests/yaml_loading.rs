@@ -0,0 +1,65 @@
+//! Integration tests for YAML configuration loading.
+//!
+//! Uses figment::Jail for safe, sandboxed env var and filesystem manipulation.
+
+use figment::Jail;
+use zen_config::ZenConfig;
+
+#[test]
+fn loads_general_config_from_yaml() {
+    Jail::expect_with(|jail| {
+        jail.create_dir(".zenith")?;
+        jail.create_file(
+            ".zenith/config.yaml",
+            r"
+general:
+  default_limit: 99
+turso:
+  url: libsql://yaml.turso.io
+",
+        )?;
+
+        let figment = ZenConfig::figment().expect("no ambiguous config files");
+        let config: ZenConfig = figment.extract().expect("should extract config");
+
+        assert_eq!(config.general.default_limit, 99);
+        assert_eq!(config.turso.url, "libsql://yaml.turso.io");
+        Ok(())
+    });
+}
+
+#[test]
+fn toml_wins_when_yaml_absent_and_toml_present() {
+    Jail::expect_with(|jail| {
+        jail.create_dir(".zenith")?;
+        jail.create_file(
+            ".zenith/config.toml",
+            r"
+[general]
+default_limit = 77
+",
+        )?;
+
+        let figment = ZenConfig::figment().expect("no ambiguous config files");
+        let config: ZenConfig = figment.extract().expect("should extract config");
+
+        assert_eq!(config.general.default_limit, 77);
+        Ok(())
+    });
+}
+
+#[test]
+fn both_toml_and_yaml_in_same_dir_is_rejected() {
+    Jail::expect_with(|jail| {
+        jail.create_dir(".zenith")?;
+        jail.create_file(".zenith/config.toml", "[general]\ndefault_limit = 1\n")?;
+        jail.create_file(".zenith/config.yaml", "general:\n  default_limit: 2\n")?;
+
+        let err = ZenConfig::figment().expect_err("both formats present should be rejected");
+        assert!(
+            err.to_string().contains("config.toml") && err.to_string().contains("config.yaml"),
+            "error should name both conflicting files: {err}"
+        );
+        Ok(())
+    });
+}
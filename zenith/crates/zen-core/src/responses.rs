@@ -8,6 +8,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::entities::{AuditEntry, Finding, Hypothesis, Session, Task};
+use crate::enums::{AuditAction, EntityType};
 
 /// Response from `znt finding create`.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -55,6 +56,15 @@ pub struct SearchResultsResponse {
     pub total_results: u32,
 }
 
+/// Count of audit entries for one entity type/action pair within a time
+/// window, as returned by `ZenService::recent_activity`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ActivitySummary {
+    pub entity_type: EntityType,
+    pub action: AuditAction,
+    pub count: u32,
+}
+
 /// Response from `znt rebuild`.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct RebuildResponse {
@@ -64,3 +74,24 @@ pub struct RebuildResponse {
     pub entities_created: u32,
     pub duration_ms: u64,
 }
+
+/// A single named check performed by `znt validate`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ValidationCheck {
+    pub name: String,
+    pub passed: bool,
+    pub message: String,
+}
+
+/// Response from `znt validate`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub checks: Vec<ValidationCheck>,
+}
+
+impl ValidationReport {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+}
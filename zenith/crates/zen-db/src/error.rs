@@ -21,6 +21,18 @@ pub enum DatabaseError {
     #[error("Invalid state: {0}")]
     InvalidState(String),
 
+    /// A partial ID fragment matched more than one entity.
+    #[error("Ambiguous ID: {} entities match ({})", .0.len(), .0.join(", "))]
+    Ambiguous(Vec<String>),
+
+    /// A synced replica's last successful sync is older than the allowed
+    /// staleness window.
+    #[error("replica sync is stale: last synced {last_sync_age:?} ago, threshold is {threshold:?}")]
+    StaleReplica {
+        last_sync_age: std::time::Duration,
+        threshold: std::time::Duration,
+    },
+
     /// Underlying libSQL error.
     #[error("libSQL error: {0}")]
     LibSql(#[from] libsql::Error),
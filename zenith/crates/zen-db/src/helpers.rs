@@ -102,3 +102,22 @@ pub const fn entity_type_to_table(entity: &zen_core::enums::EntityType) -> &'sta
         EntityType::Audit => "audit_trail",
     }
 }
+
+/// Every `EntityType` variant, for callers that need to sweep all entity
+/// tables (e.g. consistency checks). Kept in sync with the enum by hand,
+/// same as `entity_type_to_table`.
+pub const ALL_ENTITY_TYPES: &[zen_core::enums::EntityType] = &[
+    zen_core::enums::EntityType::Session,
+    zen_core::enums::EntityType::Research,
+    zen_core::enums::EntityType::Finding,
+    zen_core::enums::EntityType::Hypothesis,
+    zen_core::enums::EntityType::Insight,
+    zen_core::enums::EntityType::Issue,
+    zen_core::enums::EntityType::Task,
+    zen_core::enums::EntityType::ImplLog,
+    zen_core::enums::EntityType::Compat,
+    zen_core::enums::EntityType::Study,
+    zen_core::enums::EntityType::Decision,
+    zen_core::enums::EntityType::EntityLink,
+    zen_core::enums::EntityType::Audit,
+];
@@ -18,10 +18,13 @@ pub mod service;
 pub mod trail;
 pub mod updates;
 
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
 use error::DatabaseError;
 use libsql::Builder;
 use libsql::params::IntoParams;
-use retry::RetryConfig;
+use retry::{RetryConfig, RetryMetricSnapshot, RetryMetrics};
 
 /// Central database handle for all Zenith state operations.
 ///
@@ -33,6 +36,11 @@ pub struct ZenDb {
     conn: libsql::Connection,
     is_synced_replica: bool,
     retry: RetryConfig,
+    retry_metrics: RetryMetrics,
+    /// Unix epoch millis of the last successful [`Self::sync`], or `0` if
+    /// this handle has never synced. Only meaningful when
+    /// `is_synced_replica` is `true`.
+    last_synced_at_millis: AtomicU64,
 }
 
 impl ZenDb {
@@ -58,6 +66,8 @@ impl ZenDb {
             conn,
             is_synced_replica: false,
             retry: RetryConfig::default(),
+            retry_metrics: RetryMetrics::default(),
+            last_synced_at_millis: AtomicU64::new(0),
         };
         zen_db.run_migrations().await?;
         Ok(zen_db)
@@ -93,6 +103,8 @@ impl ZenDb {
             conn,
             is_synced_replica: true,
             retry: RetryConfig::default(),
+            retry_metrics: RetryMetrics::default(),
+            last_synced_at_millis: AtomicU64::new(now_millis()),
         };
         zen_db.run_migrations().await?;
         Ok(zen_db)
@@ -111,7 +123,10 @@ impl ZenDb {
             return Ok(());
         }
         self.retry_op(|| async { self.db.sync().await.map(|_| ()) })
-            .await
+            .await?;
+        self.last_synced_at_millis
+            .store(now_millis(), Ordering::Relaxed);
+        Ok(())
     }
 
     /// Execute SQL with automatic retry on transient Turso errors.
@@ -219,6 +234,39 @@ impl ZenDb {
         Ok(row.get::<String>(0)?)
     }
 
+    /// Liveness probe: execute `SELECT 1` and return its round-trip duration.
+    ///
+    /// For synced replicas, also checks that the last successful [`Self::sync`]
+    /// happened within `RetryConfig::max_delay * 3` — an old replica that
+    /// keeps answering queries locally but has stopped syncing is a failure
+    /// mode this alone wouldn't catch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError` if the query fails, or
+    /// [`DatabaseError::StaleReplica`] if a synced replica's last sync is
+    /// older than the allowed window.
+    pub async fn health_check(&self) -> Result<Duration, DatabaseError> {
+        let start = Instant::now();
+        self.query("SELECT 1", ()).await?;
+        let elapsed = start.elapsed();
+
+        if self.is_synced_replica {
+            let threshold = self.retry.max_delay * 3;
+            let last_synced = self.last_synced_at_millis.load(Ordering::Relaxed);
+            let last_sync_age = now_millis().saturating_sub(last_synced);
+            let last_sync_age = Duration::from_millis(last_sync_age);
+            if last_synced == 0 || last_sync_age > threshold {
+                return Err(DatabaseError::StaleReplica {
+                    last_sync_age,
+                    threshold,
+                });
+            }
+        }
+
+        Ok(elapsed)
+    }
+
     /// Internal: retry an async operation with exponential backoff on
     /// transient Turso infrastructure errors. Skipped for local DBs.
     async fn retry_op<T, F, Fut>(&self, mut f: F) -> Result<T, DatabaseError>
@@ -226,8 +274,13 @@ impl ZenDb {
         F: FnMut() -> Fut,
         Fut: std::future::Future<Output = Result<T, libsql::Error>>,
     {
+        self.retry_metrics.record_operation();
+
         if !self.is_synced_replica {
-            return Ok(f().await?);
+            return f().await.map_err(|e| {
+                self.retry_metrics.record_failure();
+                e.into()
+            });
         }
 
         let mut delay = self.retry.base_delay;
@@ -243,14 +296,35 @@ impl ZenDb {
                         delay_ms = delay.as_millis() as u64,
                         "Turso transient infra error, retrying: {e}"
                     );
+                    self.retry_metrics.record_retry();
                     tokio::time::sleep(delay).await;
                     delay = std::cmp::min(delay * 2, self.retry.max_delay);
                 }
-                Err(e) => return Err(e.into()),
+                Err(e) => {
+                    self.retry_metrics.record_failure();
+                    return Err(e.into());
+                }
             }
         }
         unreachable!()
     }
+
+    /// Snapshot of retry counters accumulated since this handle was opened.
+    ///
+    /// Useful for surfacing how often `retry_op` hits transient Turso
+    /// infrastructure errors in production (see `znt session list --metrics`).
+    #[must_use]
+    pub fn retry_metrics(&self) -> RetryMetricSnapshot {
+        self.retry_metrics.snapshot()
+    }
+}
+
+/// Current time as Unix epoch milliseconds, saturating to `0` if the clock
+/// is somehow set before the epoch.
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
 }
 
 #[cfg(test)]
@@ -397,6 +471,69 @@ mod tests {
         db.run_migrations().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn health_check_returns_sub_millisecond_duration_for_in_memory_db() {
+        let db = test_db().await;
+        let elapsed = db.health_check().await.unwrap();
+        assert!(
+            elapsed < std::time::Duration::from_millis(1),
+            "expected sub-millisecond round-trip for an in-memory database, got {elapsed:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn health_check_skips_stale_replica_check_for_local_db() {
+        let db = test_db().await;
+        // A local (non-replica) handle never syncs, so `health_check` must
+        // not treat that as staleness.
+        assert!(!db.is_synced_replica());
+        db.health_check().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn retry_metrics_count_operations_and_retries() {
+        let mut db = test_db().await;
+        // Simulate a synced replica so `retry_op` exercises the retry loop
+        // instead of taking the local-only fast path.
+        db.is_synced_replica = true;
+        db.retry.base_delay = std::time::Duration::from_millis(1);
+        db.retry.max_delay = std::time::Duration::from_millis(1);
+
+        let mut attempts = 0;
+        db.retry_op(|| {
+            attempts += 1;
+            async move {
+                if attempts < 2 {
+                    Err(libsql::Error::Hrana("unable to acquire shared lock".into()))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        let metrics = db.retry_metrics();
+        assert_eq!(metrics.total_operations, 1);
+        assert_eq!(metrics.total_retries, 1);
+        assert_eq!(metrics.total_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn retry_metrics_count_failures() {
+        let db = test_db().await;
+
+        let result: Result<(), DatabaseError> = db
+            .retry_op(|| async { Err(libsql::Error::Misuse("boom".into())) })
+            .await;
+
+        assert!(result.is_err());
+        let metrics = db.retry_metrics();
+        assert_eq!(metrics.total_operations, 1);
+        assert_eq!(metrics.total_retries, 0);
+        assert_eq!(metrics.total_failures, 1);
+    }
+
     #[tokio::test]
     async fn insert_and_select_session() {
         let db = test_db().await;
@@ -5,6 +5,7 @@
 
 use zen_core::entities::AuditEntry;
 use zen_core::enums::{AuditAction, EntityType};
+use zen_core::responses::ActivitySummary;
 
 use crate::error::DatabaseError;
 use crate::helpers::{get_opt_string, parse_datetime, parse_enum, parse_optional_json};
@@ -106,6 +107,40 @@ impl ZenService {
         Ok(entries)
     }
 
+    /// Summarize audit activity since a point in time, grouped by entity type
+    /// and action.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError` if the query fails.
+    pub async fn recent_activity(
+        &self,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<ActivitySummary>, DatabaseError> {
+        let mut rows = self
+            .db()
+            .query_with(
+                "SELECT entity_type, action, COUNT(*) AS count
+                 FROM audit_trail
+                 WHERE created_at >= ?1
+                 GROUP BY entity_type, action
+                 ORDER BY count DESC",
+                || libsql::params![since.to_rfc3339()],
+            )
+            .await?;
+
+        let mut summaries = Vec::new();
+        while let Some(row) = rows.next().await? {
+            summaries.push(ActivitySummary {
+                entity_type: parse_enum(&row.get::<String>(0)?)?,
+                action: parse_enum(&row.get::<String>(1)?)?,
+                count: row.get::<i64>(2)?.try_into().unwrap_or(u32::MAX),
+            });
+        }
+
+        Ok(summaries)
+    }
+
     /// FTS5 search across audit entries.
     ///
     /// # Errors
@@ -141,3 +176,51 @@ impl ZenService {
         Ok(entries)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use zen_core::entities::AuditEntry;
+    use zen_core::enums::{AuditAction, EntityType};
+
+    use crate::test_support::helpers::test_service;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn recent_activity_excludes_entries_before_since() {
+        let svc = test_service().await;
+        let now = Utc::now();
+
+        svc.append_audit(&AuditEntry {
+            id: "aud-old".to_string(),
+            session_id: None,
+            entity_type: EntityType::Task,
+            entity_id: "tsk-old".to_string(),
+            action: AuditAction::Created,
+            detail: None,
+            created_at: now - Duration::days(2),
+        })
+        .await
+        .unwrap();
+
+        svc.append_audit(&AuditEntry {
+            id: "aud-recent".to_string(),
+            session_id: None,
+            entity_type: EntityType::Task,
+            entity_id: "tsk-recent".to_string(),
+            action: AuditAction::Created,
+            detail: None,
+            created_at: now,
+        })
+        .await
+        .unwrap();
+
+        let summaries = svc.recent_activity(now - Duration::hours(1)).await.unwrap();
+
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].entity_type, EntityType::Task);
+        assert_eq!(summaries[0].action, AuditAction::Created);
+        assert_eq!(summaries[0].count, 1);
+    }
+}
@@ -0,0 +1,152 @@
+//! Database consistency checks used by `znt validate`.
+//!
+//! These queries look for corruption that can accumulate outside the normal
+//! write path (a hand-edited trail file replayed out of order, a Turso sync
+//! that raced a delete): entity links or audit entries pointing at rows that
+//! no longer exist, and FTS5 indexes that have drifted from their content
+//! tables.
+
+use crate::error::DatabaseError;
+use crate::helpers::{ALL_ENTITY_TYPES, entity_type_to_table};
+use crate::service::ZenService;
+
+/// Content-linked FTS5 tables and the base table each mirrors, as declared
+/// in `migrations/001_initial.sql`.
+const FTS_TABLES: &[(&str, &str)] = &[
+    ("findings", "findings_fts"),
+    ("hypotheses", "hypotheses_fts"),
+    ("insights", "insights_fts"),
+    ("research_items", "research_fts"),
+    ("tasks", "tasks_fts"),
+    ("issues", "issues_fts"),
+    ("studies", "studies_fts"),
+    ("audit_trail", "audit_fts"),
+];
+
+impl ZenService {
+    /// Find `entity_links` rows whose source or target no longer exists.
+    ///
+    /// Returns a human-readable description per dangling reference.
+    ///
+    /// # Errors
+    /// Returns `DatabaseError` if a query fails.
+    pub async fn check_dangling_links(&self) -> Result<Vec<String>, DatabaseError> {
+        let mut problems = Vec::new();
+        for &(role, type_col, id_col) in &[
+            ("source", "source_type", "source_id"),
+            ("target", "target_type", "target_id"),
+        ] {
+            for entity_type in ALL_ENTITY_TYPES {
+                let table = entity_type_to_table(entity_type);
+                let sql = format!(
+                    "SELECT id, {id_col} FROM entity_links \
+                     WHERE {type_col} = ?1 AND {id_col} NOT IN (SELECT id FROM {table})"
+                );
+                let mut rows = self
+                    .db()
+                    .query_with(&sql, || libsql::params![entity_type.as_str()])
+                    .await?;
+                while let Some(row) = rows.next().await? {
+                    let link_id: String = row.get(0)?;
+                    let missing_id: String = row.get(1)?;
+                    problems.push(format!(
+                        "entity_link {link_id}: {role} {} '{missing_id}' does not exist",
+                        entity_type.as_str()
+                    ));
+                }
+            }
+        }
+        Ok(problems)
+    }
+
+    /// Find `audit_trail` rows whose `entity_id` no longer exists in its
+    /// `entity_type`'s table.
+    ///
+    /// # Errors
+    /// Returns `DatabaseError` if a query fails.
+    pub async fn check_dangling_audit_entries(&self) -> Result<Vec<String>, DatabaseError> {
+        let mut problems = Vec::new();
+        for entity_type in ALL_ENTITY_TYPES {
+            let table = entity_type_to_table(entity_type);
+            let sql = format!(
+                "SELECT id, entity_id FROM audit_trail \
+                 WHERE entity_type = ?1 AND entity_id NOT IN (SELECT id FROM {table})"
+            );
+            let mut rows = self
+                .db()
+                .query_with(&sql, || libsql::params![entity_type.as_str()])
+                .await?;
+            while let Some(row) = rows.next().await? {
+                let audit_id: String = row.get(0)?;
+                let missing_id: String = row.get(1)?;
+                problems.push(format!(
+                    "audit_trail {audit_id}: {} '{missing_id}' does not exist",
+                    entity_type.as_str()
+                ));
+            }
+        }
+        Ok(problems)
+    }
+
+    /// Run FTS5's built-in `integrity-check` command against every
+    /// content-linked FTS5 table, reporting any that have drifted from
+    /// their base table.
+    ///
+    /// # Errors
+    /// Returns `DatabaseError` if a query fails for a reason other than the
+    /// integrity check itself failing.
+    pub async fn check_fts_sync(&self) -> Result<Vec<String>, DatabaseError> {
+        let mut problems = Vec::new();
+        for &(base_table, fts_table) in FTS_TABLES {
+            let sql = format!("INSERT INTO {fts_table}({fts_table}) VALUES('integrity-check')");
+            if let Err(error) = self.db().execute(&sql, ()).await {
+                problems.push(format!(
+                    "{fts_table} is out of sync with {base_table}: {error}"
+                ));
+            }
+        }
+        Ok(problems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zen_core::enums::{EntityType, Relation};
+
+    use crate::test_support::helpers::{start_test_session, test_service};
+
+    #[tokio::test]
+    async fn no_problems_on_empty_database() {
+        let svc = test_service().await;
+
+        assert!(svc.check_dangling_links().await.unwrap().is_empty());
+        assert!(svc.check_dangling_audit_entries().await.unwrap().is_empty());
+        assert!(svc.check_fts_sync().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn detects_dangling_link_target() {
+        let svc = test_service().await;
+        let ses = start_test_session(&svc).await;
+
+        let task = svc
+            .create_task(&ses, "Task with a link", None, None, None)
+            .await
+            .unwrap();
+
+        svc.create_link(
+            &ses,
+            EntityType::Task,
+            &task.id,
+            EntityType::Finding,
+            "fnd-does-not-exist",
+            Relation::RelatesTo,
+        )
+        .await
+        .unwrap();
+
+        let problems = svc.check_dangling_links().await.unwrap();
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("fnd-does-not-exist"));
+    }
+}
@@ -5,6 +5,7 @@
 pub mod audit;
 pub mod catalog;
 pub mod compat;
+pub mod consistency;
 pub mod finding;
 pub mod hypothesis;
 pub mod impl_log;
@@ -13,6 +14,7 @@ pub mod issue;
 pub mod link;
 pub mod project;
 pub mod research;
+pub mod resolve;
 pub mod session;
 pub mod study;
 pub mod task;
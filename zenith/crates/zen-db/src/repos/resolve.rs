@@ -0,0 +1,100 @@
+//! Fuzzy partial-ID resolution for CLI ergonomics.
+
+use crate::error::DatabaseError;
+use crate::service::ZenService;
+
+impl ZenService {
+    /// Resolve a possibly-partial ID fragment to the single matching full ID.
+    ///
+    /// If `fragment` already contains a `-` it's assumed to be a full,
+    /// already-prefixed ID and is returned unchanged without touching the
+    /// database. Otherwise every id in `table` containing `fragment` is
+    /// treated as a candidate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::NoResult` if no id matches, or
+    /// `DatabaseError::Ambiguous` (with every matching id) if more than one
+    /// matches.
+    pub async fn resolve_partial_id(
+        &self,
+        table: &str,
+        fragment: &str,
+    ) -> Result<String, DatabaseError> {
+        if fragment.contains('-') {
+            return Ok(fragment.to_string());
+        }
+
+        let pattern = format!("%{fragment}%");
+        let mut rows = self
+            .db()
+            .query(
+                &format!("SELECT id FROM {table} WHERE id LIKE ?1 ORDER BY id"),
+                [pattern],
+            )
+            .await?;
+
+        let mut candidates = Vec::new();
+        while let Some(row) = rows.next().await? {
+            candidates.push(row.get::<String>(0)?);
+        }
+
+        match candidates.len() {
+            0 => Err(DatabaseError::NoResult),
+            1 => Ok(candidates.into_iter().next().expect("length checked above")),
+            _ => Err(DatabaseError::Ambiguous(candidates)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zen_core::enums::Confidence;
+
+    use crate::test_support::helpers::{start_test_session, test_service};
+
+    #[tokio::test]
+    async fn resolves_unique_fragment() {
+        let svc = test_service().await;
+        let sid = start_test_session(&svc).await;
+        let finding = svc
+            .create_finding(&sid, "unique target", None, Confidence::High, None)
+            .await
+            .unwrap();
+
+        let fragment = &finding.id[4..8];
+        let resolved = svc.resolve_partial_id("findings", fragment).await.unwrap();
+        assert_eq!(resolved, finding.id);
+    }
+
+    #[tokio::test]
+    async fn errors_with_candidates_when_ambiguous() {
+        let svc = test_service().await;
+        let sid = start_test_session(&svc).await;
+        let a = svc
+            .create_finding(&sid, "a", None, Confidence::High, None)
+            .await
+            .unwrap();
+        let b = svc
+            .create_finding(&sid, "b", None, Confidence::High, None)
+            .await
+            .unwrap();
+
+        // "fnd" matches every finding id.
+        let result = svc.resolve_partial_id("findings", "fnd").await;
+        match result {
+            Err(crate::error::DatabaseError::Ambiguous(candidates)) => {
+                assert!(candidates.contains(&a.id));
+                assert!(candidates.contains(&b.id));
+            }
+            other => panic!("expected Ambiguous, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn errors_with_no_result_when_no_match() {
+        let svc = test_service().await;
+        let result = svc.resolve_partial_id("findings", "zzzzzzzz").await;
+        assert!(matches!(result, Err(crate::error::DatabaseError::NoResult)));
+    }
+}
@@ -261,6 +261,30 @@ impl ZenService {
         })
     }
 
+    /// Most recent snapshot's `created_at` for a session, or `None` if it has
+    /// never been snapshotted (e.g. `znt wrap-up` was never run).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError` if the query fails.
+    pub async fn latest_snapshot_at(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<chrono::DateTime<Utc>>, DatabaseError> {
+        let mut rows = self
+            .db()
+            .query(
+                "SELECT created_at FROM session_snapshots
+                 WHERE session_id = ?1 ORDER BY created_at DESC LIMIT 1",
+                [session_id],
+            )
+            .await?;
+        match rows.next().await? {
+            Some(row) => Ok(Some(parse_datetime(&row.get::<String>(0)?)?)),
+            None => Ok(None),
+        }
+    }
+
     /// Detect sessions in 'active' status (orphans from crashed sessions).
     async fn detect_orphan_sessions(&self) -> Result<Vec<Session>, DatabaseError> {
         self.list_sessions(Some(SessionStatus::Active), 10).await
@@ -363,6 +387,122 @@ impl ZenService {
         Ok(())
     }
 
+    /// Return the current active session, or start a new one if none exists.
+    ///
+    /// Used by write commands that don't require the caller to have run
+    /// `session start` explicitly first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError` if the lookup or session creation fails.
+    pub async fn active_or_create_session(&self) -> Result<Session, DatabaseError> {
+        let active = self.list_sessions(Some(SessionStatus::Active), 1).await?;
+        if let Some(session) = active.into_iter().next() {
+            return Ok(session);
+        }
+
+        let (session, _abandoned_previous) = self.start_session().await?;
+        Ok(session)
+    }
+
+    /// Reactivate a previously ended session, or the most recently ended one
+    /// if `session_id` is `None`.
+    ///
+    /// Unlike ordinary status transitions, resuming intentionally bypasses
+    /// [`SessionStatus::can_transition_to`] — `wrapped_up`/`abandoned` are
+    /// modeled as terminal, but a session can still be explicitly resumed on
+    /// request (e.g. wrap-up sync failed, or the session was abandoned by
+    /// mistake).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::NoResult` if `session_id` names a session that
+    /// doesn't exist, or if there is no ended session to resume when
+    /// `session_id` is `None`. Returns `DatabaseError::InvalidState` if the
+    /// resolved session is already active.
+    pub async fn resume_session(&self, session_id: Option<&str>) -> Result<Session, DatabaseError> {
+        let target = match session_id {
+            Some(id) => self.get_session(id).await?,
+            None => self
+                .most_recent_ended_session()
+                .await?
+                .ok_or(DatabaseError::NoResult)?,
+        };
+
+        if target.status == SessionStatus::Active {
+            return Err(DatabaseError::InvalidState(format!(
+                "Session {} is already active",
+                target.id
+            )));
+        }
+
+        let now = Utc::now();
+        let (org_filter, org_params) = self.org_id_filter(2);
+        let sql = format!(
+            "UPDATE sessions SET ended_at = NULL, status = 'active', summary = NULL WHERE id = ?1 {org_filter}"
+        );
+        let mut params: Vec<libsql::Value> = vec![target.id.clone().into()];
+        params.extend(org_params);
+        self.db()
+            .execute_with(&sql, || libsql::params_from_iter(params.clone()))
+            .await?;
+
+        let audit_id = self.db().generate_id(PREFIX_AUDIT).await?;
+        self.append_audit(&AuditEntry {
+            id: audit_id,
+            session_id: Some(target.id.clone()),
+            entity_type: EntityType::Session,
+            entity_id: target.id.clone(),
+            action: AuditAction::StatusChanged,
+            detail: Some(serde_json::json!({
+                "from": target.status.as_str(),
+                "to": "active",
+                "reason": "resumed",
+            })),
+            created_at: now,
+        })
+        .await?;
+
+        self.trail().append(&TrailOperation {
+            v: 1,
+            ts: now.to_rfc3339(),
+            ses: target.id.clone(),
+            op: TrailOp::Transition,
+            entity: EntityType::Session,
+            id: target.id.clone(),
+            data: serde_json::json!({
+                "from": target.status.as_str(),
+                "to": "active",
+                "reason": "resumed",
+            }),
+        })?;
+
+        Ok(Session {
+            ended_at: None,
+            status: SessionStatus::Active,
+            summary: None,
+            ..target
+        })
+    }
+
+    /// Most recently started session that has already ended (wrapped up or
+    /// abandoned), for `resume_session(None)`.
+    async fn most_recent_ended_session(&self) -> Result<Option<Session>, DatabaseError> {
+        let (org_filter, org_params) = self.org_id_filter(1);
+        let sql = format!(
+            "SELECT id, started_at, ended_at, status, summary FROM sessions
+             WHERE status != 'active' {org_filter} ORDER BY started_at DESC LIMIT 1"
+        );
+        let mut rows = self
+            .db()
+            .query_with(&sql, || libsql::params_from_iter(org_params.clone()))
+            .await?;
+        match rows.next().await? {
+            Some(row) => Ok(Some(row_to_session(&row)?)),
+            None => Ok(None),
+        }
+    }
+
     /// Count rows matching a status in a table.
     pub(crate) async fn count_by_status(
         &self,
@@ -9,6 +9,7 @@
 //! Local-only databases never encounter these errors — the retry
 //! path is gated on `ZenDb::is_synced_replica`.
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
 /// Configuration for retry behavior on transient Turso errors.
@@ -44,3 +45,47 @@ pub fn is_transient_turso_error(e: &libsql::Error) -> bool {
     let msg = e.to_string();
     msg.contains("unable to acquire shared lock") || msg.contains("deletion must be in progress")
 }
+
+/// Running counters for [`ZenDb::retry_op`](crate::ZenDb::retry_op) calls.
+///
+/// Cheap to update on every operation — `Ordering::Relaxed` is sufficient
+/// since these are independent counters, not used to synchronize other
+/// memory access.
+#[derive(Debug, Default)]
+pub struct RetryMetrics {
+    total_operations: AtomicU64,
+    total_retries: AtomicU64,
+    total_failures: AtomicU64,
+}
+
+impl RetryMetrics {
+    pub(crate) fn record_operation(&self) {
+        self.total_operations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_retry(&self) {
+        self.total_retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_failure(&self) {
+        self.total_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Take a point-in-time snapshot of the counters.
+    #[must_use]
+    pub fn snapshot(&self) -> RetryMetricSnapshot {
+        RetryMetricSnapshot {
+            total_operations: self.total_operations.load(Ordering::Relaxed),
+            total_retries: self.total_retries.load(Ordering::Relaxed),
+            total_failures: self.total_failures.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A point-in-time copy of [`RetryMetrics`], suitable for serialization.
+#[derive(Debug, Clone, Copy, Default, serde::Serialize)]
+pub struct RetryMetricSnapshot {
+    pub total_operations: u64,
+    pub total_retries: u64,
+    pub total_failures: u64,
+}
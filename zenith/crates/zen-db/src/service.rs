@@ -105,6 +105,15 @@ impl ZenService {
         &self.db
     }
 
+    /// Liveness probe for the underlying database. See [`ZenDb::health_check`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError` if the health check fails.
+    pub async fn health_check(&self) -> Result<std::time::Duration, DatabaseError> {
+        self.db.health_check().await
+    }
+
     /// Access the trail writer mutably (e.g., to disable during rebuild).
     pub const fn trail_mut(&mut self) -> &mut TrailWriter {
         &mut self.trail
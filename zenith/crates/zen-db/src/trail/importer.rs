@@ -0,0 +1,238 @@
+//! Bulk restore of a session's audit trail (and underlying entities) from a
+//! previously-exported JSONL trail file.
+//!
+//! Unlike [`super::replayer::TrailReplayer`], which only rebuilds entity
+//! tables from a directory of trail files, [`ZenDb::import_jsonl`] also
+//! restores the corresponding `audit_trail` rows, since it's meant for
+//! restoring a session's audit history onto a database that doesn't already
+//! have it (e.g. a fresh clone).
+
+use std::hash::{Hash, Hasher};
+use std::io::BufRead;
+use std::path::Path;
+
+use zen_core::enums::{AuditAction, TrailOp};
+use zen_core::trail::TrailOperation;
+
+use super::replayer::replay_operation;
+use crate::ZenDb;
+use crate::error::DatabaseError;
+
+/// Outcome of a call to [`ZenDb::import_jsonl`].
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    /// Non-blank lines read from the file.
+    pub lines_read: u64,
+    /// Audit trail rows newly inserted.
+    pub rows_inserted: u64,
+    /// Lines that parsed but whose audit row already existed (`INSERT OR IGNORE`).
+    pub rows_skipped: u64,
+    /// One message per line that failed to parse or apply.
+    pub errors: Vec<String>,
+}
+
+impl ZenDb {
+    /// Restore audit trail entries (and their underlying entities) from a
+    /// JSONL file of [`TrailOperation`] records, such as one produced by
+    /// exporting a session's trail.
+    ///
+    /// The whole import runs inside a single transaction: either every
+    /// parseable line is applied, or none are. Lines that fail to
+    /// deserialize are recorded in [`ImportReport::errors`] and don't count
+    /// toward `rows_inserted`/`rows_skipped`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError` if the file can't be read, or if a database
+    /// operation fails (deserialization failures are collected in the
+    /// report instead of failing the whole import).
+    pub async fn import_jsonl(
+        &self,
+        path: &Path,
+        session_id: &str,
+    ) -> Result<ImportReport, DatabaseError> {
+        let file = std::fs::File::open(path).map_err(|e| DatabaseError::Other(e.into()))?;
+        let reader = std::io::BufReader::new(file);
+
+        let mut report = ImportReport::default();
+
+        self.execute("BEGIN", ()).await?;
+        for line in reader.lines() {
+            let line = line.map_err(|e| DatabaseError::Other(e.into()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let op: TrailOperation = match serde_json::from_str(&line) {
+                Ok(op) => op,
+                Err(error) => {
+                    report.lines_read += 1;
+                    report.errors.push(error.to_string());
+                    continue;
+                }
+            };
+
+            match import_operation(self, session_id, &op).await {
+                Ok(true) => report.rows_inserted += 1,
+                Ok(false) => report.rows_skipped += 1,
+                Err(error) => {
+                    self.execute("ROLLBACK", ()).await?;
+                    return Err(error);
+                }
+            }
+            report.lines_read += 1;
+        }
+        self.execute("COMMIT", ()).await?;
+
+        Ok(report)
+    }
+}
+
+/// Replay `op`'s entity mutation and insert its `audit_trail` row.
+///
+/// Returns `true` if the audit row was newly inserted, `false` if it was
+/// already present (re-importing the same file is idempotent).
+async fn import_operation(
+    db: &ZenDb,
+    session_id: &str,
+    op: &TrailOperation,
+) -> Result<bool, DatabaseError> {
+    replay_operation(db, op).await?;
+
+    let audit_id = audit_id_for(op);
+    let detail = op.data.to_string();
+    let action = trail_op_to_audit_action(op.op).as_str();
+    let rows = db
+        .execute_with(
+            "INSERT OR IGNORE INTO audit_trail (id, session_id, entity_type, entity_id, action, detail, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            || {
+                libsql::params![
+                    audit_id.as_str(),
+                    session_id,
+                    op.entity.as_str(),
+                    op.id.as_str(),
+                    action,
+                    detail.as_str(),
+                    op.ts.as_str(),
+                ]
+            },
+        )
+        .await?;
+
+    Ok(rows > 0)
+}
+
+/// Deterministic `audit_trail.id` for `op`, so re-importing the same file
+/// hits `INSERT OR IGNORE` instead of creating duplicate audit rows.
+fn audit_id_for(op: &TrailOperation) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    op.ts.hash(&mut hasher);
+    op.ses.hash(&mut hasher);
+    op.entity.as_str().hash(&mut hasher);
+    op.id.hash(&mut hasher);
+    op.op.as_str().hash(&mut hasher);
+
+    format!("{}-{:016x}", zen_core::ids::PREFIX_AUDIT, hasher.finish())
+}
+
+const fn trail_op_to_audit_action(op: TrailOp) -> AuditAction {
+    match op {
+        TrailOp::Create => AuditAction::Created,
+        TrailOp::Update => AuditAction::Updated,
+        TrailOp::Delete => AuditAction::Deleted,
+        TrailOp::Link => AuditAction::Linked,
+        TrailOp::Unlink => AuditAction::Unlinked,
+        TrailOp::Tag => AuditAction::Tagged,
+        TrailOp::Untag => AuditAction::Untagged,
+        TrailOp::Transition => AuditAction::StatusChanged,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use zen_core::enums::EntityType;
+
+    use super::*;
+
+    async fn test_db() -> ZenDb {
+        ZenDb::open_local(":memory:").await.unwrap()
+    }
+
+    fn write_session_create(ts: &str, session_id: &str) -> TrailOperation {
+        TrailOperation {
+            v: 1,
+            ts: ts.to_string(),
+            ses: session_id.to_string(),
+            op: TrailOp::Create,
+            entity: EntityType::Session,
+            id: session_id.to_string(),
+            data: serde_json::json!({ "started_at": ts, "status": "active" }),
+        }
+    }
+
+    fn write_jsonl(ops: &[TrailOperation]) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        for op in ops {
+            writeln!(file, "{}", serde_json::to_string(op).unwrap()).unwrap();
+        }
+        file
+    }
+
+    #[tokio::test]
+    async fn imported_lines_split_cleanly_into_inserted_and_skipped() {
+        let db = test_db().await;
+
+        let ops: Vec<TrailOperation> = (0..50)
+            .map(|i| {
+                write_session_create(&format!("2026-01-01T00:00:{i:02}Z"), &format!("ses-{i}"))
+            })
+            .collect();
+        let file = write_jsonl(&ops);
+
+        let report = db.import_jsonl(file.path(), "ses-import").await.unwrap();
+
+        assert_eq!(report.lines_read, 50);
+        assert!(report.errors.is_empty());
+        assert_eq!(
+            report.rows_inserted + report.rows_skipped,
+            report.lines_read
+        );
+        assert_eq!(report.rows_inserted, 50);
+    }
+
+    #[tokio::test]
+    async fn reimporting_the_same_file_skips_every_row() {
+        let db = test_db().await;
+        let ops = vec![write_session_create("2026-01-01T00:00:00Z", "ses-dup")];
+        let file = write_jsonl(&ops);
+
+        db.import_jsonl(file.path(), "ses-import").await.unwrap();
+        let second = db.import_jsonl(file.path(), "ses-import").await.unwrap();
+
+        assert_eq!(second.lines_read, 1);
+        assert_eq!(second.rows_inserted, 0);
+        assert_eq!(second.rows_skipped, 1);
+    }
+
+    #[tokio::test]
+    async fn malformed_lines_are_reported_without_failing_the_import() {
+        let db = test_db().await;
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "not json").unwrap();
+        writeln!(
+            file,
+            "{}",
+            serde_json::to_string(&write_session_create("2026-01-01T00:00:00Z", "ses-ok")).unwrap()
+        )
+        .unwrap();
+
+        let report = db.import_jsonl(file.path(), "ses-import").await.unwrap();
+
+        assert_eq!(report.lines_read, 2);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.rows_inserted, 1);
+    }
+}
@@ -3,5 +3,6 @@
 //! The trail is the source of truth for all mutations. Per-session JSONL files
 //! live in `.zenith/trail/` and the database is rebuildable from them.
 
+pub mod importer;
 pub mod replayer;
 pub mod writer;
@@ -124,7 +124,7 @@ fn json_int_or_null(data: &serde_json::Value, field: &str) -> libsql::Value {
     }
 }
 
-async fn replay_operation(db: &ZenDb, op: &TrailOperation) -> Result<(), DatabaseError> {
+pub(crate) async fn replay_operation(db: &ZenDb, op: &TrailOperation) -> Result<(), DatabaseError> {
     match (&op.op, &op.entity) {
         (TrailOp::Create, EntityType::Session) => {
             db.conn()
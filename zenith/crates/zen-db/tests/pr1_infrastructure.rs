@@ -339,6 +339,66 @@ async fn session_list_by_status() {
     assert_eq!(all.len(), 2);
 }
 
+#[tokio::test]
+async fn active_or_create_session_creates_when_none_active() {
+    let tmp = TempDir::new().unwrap();
+    let svc = test_service_with_trail(tmp.path()).await;
+
+    let created = svc.active_or_create_session().await.unwrap();
+    assert_eq!(created.status, SessionStatus::Active);
+
+    let all = svc.list_sessions(None, 10).await.unwrap();
+    assert_eq!(all.len(), 1);
+}
+
+#[tokio::test]
+async fn active_or_create_session_reuses_existing() {
+    let tmp = TempDir::new().unwrap();
+    let svc = test_service_with_trail(tmp.path()).await;
+
+    let (started, _) = svc.start_session().await.unwrap();
+    let reused = svc.active_or_create_session().await.unwrap();
+
+    assert_eq!(reused.id, started.id);
+    let all = svc.list_sessions(None, 10).await.unwrap();
+    assert_eq!(all.len(), 1, "no extra session should have been created");
+}
+
+#[tokio::test]
+async fn resume_session_reactivates_most_recent_ended() {
+    let tmp = TempDir::new().unwrap();
+    let svc = test_service_with_trail(tmp.path()).await;
+
+    let (first, _) = svc.start_session().await.unwrap();
+    svc.end_session(&first.id, "Done").await.unwrap();
+
+    let resumed = svc.resume_session(None).await.unwrap();
+    assert_eq!(resumed.id, first.id);
+    assert_eq!(resumed.status, SessionStatus::Active);
+    assert!(resumed.ended_at.is_none());
+    assert!(resumed.summary.is_none());
+}
+
+#[tokio::test]
+async fn resume_session_by_id_rejects_already_active() {
+    let tmp = TempDir::new().unwrap();
+    let svc = test_service_with_trail(tmp.path()).await;
+
+    let (session, _) = svc.start_session().await.unwrap();
+    let result = svc.resume_session(Some(&session.id)).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn resume_session_none_errors_when_nothing_to_resume() {
+    let tmp = TempDir::new().unwrap();
+    let svc = test_service_with_trail(tmp.path()).await;
+
+    svc.start_session().await.unwrap();
+    let result = svc.resume_session(None).await;
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn session_snapshot_aggregates() {
     let svc = test_service().await;
@@ -14,4 +14,20 @@ pub enum EmbeddingError {
     /// Model returned zero embeddings for a non-empty input.
     #[error("Empty result from embedding model")]
     EmptyResult,
+
+    /// I/O error reading or writing the embedding store file.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Arrow IPC encode/decode error.
+    #[error("Arrow error: {0}")]
+    Arrow(#[from] arrow_schema::ArrowError),
+
+    /// The embedding store file exists but its contents are not in the expected shape.
+    #[error("Corrupt embedding store: {0}")]
+    Corrupt(String),
+
+    /// `EmbeddingsConfig::model` named a model this crate doesn't support.
+    #[error("Unsupported embedding model: {0}")]
+    UnsupportedModel(String),
 }
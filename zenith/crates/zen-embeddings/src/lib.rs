@@ -7,11 +7,17 @@
 //!
 //! ## Model
 //!
-//! Uses [`AllMiniLML6V2`](fastembed::EmbeddingModel::AllMiniLML6V2) (sentence-transformers/all-MiniLM-L6-v2):
+//! Defaults to [`AllMiniLML6V2`](fastembed::EmbeddingModel::AllMiniLML6V2)
+//! (sentence-transformers/all-MiniLM-L6-v2):
 //! - 384-dimensional output vectors
 //! - Mean pooling (no query/passage prefix needed)
 //! - ~80MB model size, cached at `~/.zenith/cache/fastembed/`
 //!
+//! [`EmbeddingEngine::new_from_config`] selects the model from
+//! `EmbeddingsConfig::model` instead (`"all-minilm-l6-v2"` or
+//! `"all-minilm-l12-v2"`); both variants produce 384-dimensional vectors.
+//! `EmbeddingsConfig::batch_size` is applied via [`EmbeddingEngineBuilder::batch_size`].
+//!
 //! ## Async usage
 //!
 //! The fastembed ONNX runtime is synchronous. When calling from async code,
@@ -24,9 +30,33 @@
 //! ```
 
 pub mod error;
+mod store;
 
 pub use error::EmbeddingError;
 use fastembed::{EmbeddingModel, TextEmbedding, TextInitOptions};
+pub use store::EmbeddingStore;
+use zen_config::EmbeddingsConfig;
+
+fn default_cache_dir() -> std::path::PathBuf {
+    dirs::home_dir().map_or_else(
+        || std::path::PathBuf::from(".fastembed_cache"),
+        |h| h.join(".zenith").join("cache").join("fastembed"),
+    )
+}
+
+/// Resolve `GeneralConfig::embedding_model` to a `fastembed` model variant.
+///
+/// # Errors
+///
+/// Returns [`EmbeddingError::UnsupportedModel`] if `name` isn't one of the
+/// models this crate supports.
+fn model_from_name(name: &str) -> Result<EmbeddingModel, EmbeddingError> {
+    match name {
+        "all-minilm-l6-v2" => Ok(EmbeddingModel::AllMiniLML6V2),
+        "all-minilm-l12-v2" => Ok(EmbeddingModel::AllMiniLML12V2),
+        other => Err(EmbeddingError::UnsupportedModel(other.to_string())),
+    }
+}
 
 /// Local embedding engine backed by fastembed (ONNX runtime).
 ///
@@ -40,30 +70,63 @@ use fastembed::{EmbeddingModel, TextEmbedding, TextInitOptions};
 /// [`tokio::task::spawn_blocking`] with a moved engine.
 pub struct EmbeddingEngine {
     model: TextEmbedding,
+    /// Number of texts to feed to the ONNX runtime per inference pass.
+    /// `None` uses fastembed's own default. Splitting large jobs (e.g.
+    /// 10,000+ texts) into smaller batches bounds peak memory use.
+    batch_size: Option<usize>,
 }
 
 impl EmbeddingEngine {
-    /// Create a new embedding engine with the `AllMiniLML6V2` model.
+    /// Create a new embedding engine with the `AllMiniLML6V2` model and
+    /// default settings.
     ///
     /// Downloads the model on first run (~80MB) to `~/.zenith/cache/fastembed/`.
+    /// For control over the cache directory, download progress, or batch
+    /// size, use [`Self::builder`] instead.
     ///
     /// # Errors
     ///
     /// Returns [`EmbeddingError::InitFailed`] if model download or ONNX initialization fails.
     pub fn new() -> Result<Self, EmbeddingError> {
-        let cache_dir = dirs::home_dir().map_or_else(
-            || std::path::PathBuf::from(".fastembed_cache"),
-            |h| h.join(".zenith").join("cache").join("fastembed"),
-        );
+        Self::builder().build()
+    }
 
-        let model = TextEmbedding::try_new(
-            TextInitOptions::new(EmbeddingModel::AllMiniLML6V2)
-                .with_cache_dir(cache_dir)
-                .with_show_download_progress(true),
-        )
-        .map_err(|e| EmbeddingError::InitFailed(e.to_string()))?;
+    /// Start building an [`EmbeddingEngine`] with non-default settings.
+    #[must_use]
+    pub fn builder() -> EmbeddingEngineBuilder {
+        EmbeddingEngineBuilder::default()
+    }
+
+    /// Create an [`EmbeddingEngine`] using `config.model` to select the
+    /// `fastembed` model variant and `config.batch_size` for inference
+    /// batching.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddingError::UnsupportedModel`] if `config.model`
+    /// doesn't name one of the models this crate supports, or
+    /// [`EmbeddingError::InitFailed`] if model download or ONNX initialization
+    /// fails.
+    pub fn new_from_config(config: &EmbeddingsConfig) -> Result<Self, EmbeddingError> {
+        let model = model_from_name(&config.model)?;
+        Self::builder()
+            .model(model)
+            .batch_size(config.batch_size as usize)
+            .build()
+    }
 
-        Ok(Self { model })
+    /// Pre-load the ONNX model into cache by embedding a sentinel string.
+    ///
+    /// The first call to [`Self::embed_single`]/[`Self::embed_batch`] can take
+    /// several seconds while the ONNX runtime initializes; calling this ahead
+    /// of time moves that pause somewhere it's expected (e.g. server startup).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddingError::EmbedFailed`] if inference fails.
+    pub fn warm_up(&mut self) -> Result<(), EmbeddingError> {
+        self.embed_single("warm-up")?;
+        Ok(())
     }
 
     /// Embed a batch of texts. Returns one 384-dim vector per input.
@@ -77,7 +140,7 @@ impl EmbeddingEngine {
     /// Returns [`EmbeddingError::EmbedFailed`] if the ONNX inference fails.
     pub fn embed_batch(&mut self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, EmbeddingError> {
         self.model
-            .embed(texts, None)
+            .embed(texts, self.batch_size)
             .map_err(|e| EmbeddingError::EmbedFailed(e.to_string()))
     }
 
@@ -99,6 +162,143 @@ impl EmbeddingEngine {
     pub const fn dimension() -> usize {
         384
     }
+
+    /// Embed a batch of `(id, text)` pairs, reusing cached vectors from `store`
+    /// and persisting any newly computed ones back to it.
+    ///
+    /// Returns one 384-dim vector per input, in the same order as `texts_with_ids`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddingError`] if the store cannot be read/written or if
+    /// embedding the uncached texts fails.
+    pub fn embed_batch_cached(
+        &mut self,
+        texts_with_ids: Vec<(String, String)>,
+        store: &mut EmbeddingStore,
+    ) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        Self::cached_lookup(texts_with_ids, store, |texts| self.embed_batch(texts))
+    }
+
+    /// Core of [`Self::embed_batch_cached`], parameterized over the embed
+    /// function so it can be exercised without the ONNX model in tests.
+    fn cached_lookup(
+        texts_with_ids: Vec<(String, String)>,
+        store: &mut EmbeddingStore,
+        mut embed_fn: impl FnMut(Vec<String>) -> Result<Vec<Vec<f32>>, EmbeddingError>,
+    ) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let mut cache = store.load()?;
+
+        let misses: Vec<(String, String)> = texts_with_ids
+            .iter()
+            .filter(|(id, _)| !cache.contains_key(id))
+            .cloned()
+            .collect();
+
+        if !misses.is_empty() {
+            let texts = misses.iter().map(|(_, text)| text.clone()).collect();
+            let embedded = embed_fn(texts)?;
+            for ((id, _), vector) in misses.into_iter().zip(embedded) {
+                cache.insert(id, vector);
+            }
+            store.save(&cache)?;
+        }
+
+        texts_with_ids
+            .into_iter()
+            .map(|(id, _)| cache.get(&id).cloned().ok_or(EmbeddingError::EmptyResult))
+            .collect()
+    }
+}
+
+/// Builder for [`EmbeddingEngine`], for cases that need a non-default
+/// cache directory, download progress setting, or inference batch size.
+///
+/// # Examples
+///
+/// ```ignore
+/// let engine = EmbeddingEngine::builder()
+///     .batch_size(64)
+///     .show_download_progress(false)
+///     .build()?;
+/// ```
+pub struct EmbeddingEngineBuilder {
+    cache_dir: std::path::PathBuf,
+    show_download_progress: bool,
+    batch_size: Option<usize>,
+    model: EmbeddingModel,
+}
+
+impl Default for EmbeddingEngineBuilder {
+    fn default() -> Self {
+        Self {
+            cache_dir: default_cache_dir(),
+            show_download_progress: true,
+            batch_size: None,
+            model: EmbeddingModel::AllMiniLML6V2,
+        }
+    }
+}
+
+impl EmbeddingEngineBuilder {
+    /// Set the `fastembed` model variant to use.
+    ///
+    /// Defaults to [`EmbeddingModel::AllMiniLML6V2`]. Prefer
+    /// [`EmbeddingEngine::new_from_config`] over calling this directly when
+    /// the model should come from `EmbeddingsConfig::model`.
+    #[must_use]
+    pub fn model(mut self, model: EmbeddingModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Set the directory the ONNX model is downloaded to and cached in.
+    #[must_use]
+    pub fn cache_dir(mut self, cache_dir: std::path::PathBuf) -> Self {
+        self.cache_dir = cache_dir;
+        self
+    }
+
+    /// Whether to print a progress bar while downloading the model.
+    #[must_use]
+    pub const fn show_download_progress(mut self, show_download_progress: bool) -> Self {
+        self.show_download_progress = show_download_progress;
+        self
+    }
+
+    /// Number of texts to feed to the ONNX runtime per inference pass.
+    /// Lowering this bounds peak memory use on very large batches (e.g.
+    /// tens of thousands of texts), at the cost of more inference calls.
+    #[must_use]
+    pub const fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// Build the [`EmbeddingEngine`], downloading the model if not already cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddingError::InitFailed`] if model download or ONNX initialization fails.
+    pub fn build(self) -> Result<EmbeddingEngine, EmbeddingError> {
+        let model = TextEmbedding::try_new(
+            TextInitOptions::new(self.model)
+                .with_cache_dir(self.cache_dir)
+                .with_show_download_progress(self.show_download_progress),
+        )
+        .map_err(|e| EmbeddingError::InitFailed(e.to_string()))?;
+
+        let mut engine = EmbeddingEngine {
+            model,
+            batch_size: self.batch_size,
+        };
+
+        if std::env::var("ZENITH_EMBEDDING_WARMUP").as_deref() == Ok("true") {
+            engine.warm_up()?;
+        }
+
+        Ok(engine)
+    }
 }
 
 #[cfg(test)]
@@ -219,4 +419,147 @@ mod tests {
     fn dimension_constant() {
         assert_eq!(EmbeddingEngine::dimension(), 384);
     }
+
+    #[test]
+    fn model_from_name_selects_l6_by_default() {
+        let config = EmbeddingsConfig::default();
+        assert_eq!(config.model, "all-minilm-l6-v2");
+        assert_eq!(
+            model_from_name(&config.model).unwrap(),
+            EmbeddingModel::AllMiniLML6V2
+        );
+    }
+
+    #[test]
+    fn model_from_name_selects_l12() {
+        assert_eq!(
+            model_from_name("all-minilm-l12-v2").unwrap(),
+            EmbeddingModel::AllMiniLML12V2
+        );
+    }
+
+    #[test]
+    fn model_from_name_rejects_unknown() {
+        assert!(matches!(
+            model_from_name("bge-small-en"),
+            Err(EmbeddingError::UnsupportedModel(name)) if name == "bge-small-en"
+        ));
+    }
+
+    #[test]
+    fn builder_batch_size_chunks_large_batches_without_error() {
+        let mut engine = EmbeddingEngine::builder()
+            .batch_size(10)
+            .build()
+            .expect("engine should init");
+
+        let texts: Vec<String> = (0..200)
+            .map(|i| format!("sample text number {i}"))
+            .collect();
+        let embeddings = engine
+            .embed_batch(texts)
+            .expect("batched embed should succeed");
+
+        assert_eq!(
+            embeddings.len(),
+            200,
+            "should return one embedding per input"
+        );
+        for (i, emb) in embeddings.iter().enumerate() {
+            assert_eq!(emb.len(), 384, "embedding {i} should have 384 dimensions");
+        }
+    }
+
+    #[test]
+    fn warm_up_speeds_up_first_embed() {
+        let mut cold = EmbeddingEngine::new().expect("cold engine should init");
+
+        let mut warm = EmbeddingEngine::new().expect("warm engine should init");
+        warm.warm_up().expect("warm-up should succeed");
+
+        let cold_start = std::time::Instant::now();
+        cold.embed_single("first embed on a cold engine")
+            .expect("cold embed should succeed");
+        let cold_elapsed = cold_start.elapsed();
+
+        let warm_start = std::time::Instant::now();
+        warm.embed_single("first embed on a warmed-up engine")
+            .expect("warm embed should succeed");
+        let warm_elapsed = warm_start.elapsed();
+
+        assert!(
+            warm_elapsed < cold_elapsed,
+            "warmed-up engine's first embed ({warm_elapsed:?}) should be faster than a cold engine's ({cold_elapsed:?})"
+        );
+    }
+
+    #[test]
+    fn cached_lookup_skips_model_on_cache_hit() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let mut store = EmbeddingStore::new(dir.path().join("embeddings.arrow"));
+
+        let calls = std::cell::RefCell::new(0);
+
+        let inputs = vec![
+            ("sym-a".to_string(), "fn a()".to_string()),
+            ("sym-b".to_string(), "fn b()".to_string()),
+        ];
+
+        let first = EmbeddingEngine::cached_lookup(inputs.clone(), &mut store, |texts| {
+            *calls.borrow_mut() += 1;
+            Ok(texts.into_iter().map(|_| vec![1.0, 2.0, 3.0]).collect())
+        })
+        .expect("first lookup should succeed");
+        assert_eq!(first, vec![vec![1.0, 2.0, 3.0], vec![1.0, 2.0, 3.0]]);
+        assert_eq!(*calls.borrow(), 1, "should embed once for the cache miss");
+
+        let second = EmbeddingEngine::cached_lookup(inputs, &mut store, |texts| {
+            *calls.borrow_mut() += 1;
+            Ok(texts.into_iter().map(|_| vec![1.0, 2.0, 3.0]).collect())
+        })
+        .expect("second lookup should succeed");
+        assert_eq!(second, vec![vec![1.0, 2.0, 3.0], vec![1.0, 2.0, 3.0]]);
+        assert_eq!(
+            *calls.borrow(),
+            1,
+            "second call with the same IDs should not invoke the embed function again"
+        );
+    }
+
+    #[test]
+    fn cached_lookup_only_embeds_new_ids() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let mut store = EmbeddingStore::new(dir.path().join("embeddings.arrow"));
+
+        let calls = std::cell::RefCell::new(0);
+
+        EmbeddingEngine::cached_lookup(
+            vec![("sym-a".to_string(), "fn a()".to_string())],
+            &mut store,
+            |texts| {
+                *calls.borrow_mut() += 1;
+                Ok(texts.into_iter().map(|_| vec![9.0, 9.0]).collect())
+            },
+        )
+        .expect("first lookup should succeed");
+
+        EmbeddingEngine::cached_lookup(
+            vec![
+                ("sym-a".to_string(), "fn a()".to_string()),
+                ("sym-b".to_string(), "fn b()".to_string()),
+            ],
+            &mut store,
+            |texts| {
+                *calls.borrow_mut() += 1;
+                Ok(texts.into_iter().map(|_| vec![9.0, 9.0]).collect())
+            },
+        )
+        .expect("second lookup should succeed");
+
+        assert_eq!(
+            *calls.borrow(),
+            2,
+            "should embed once per newly seen ID, skipping already cached ones"
+        );
+    }
 }
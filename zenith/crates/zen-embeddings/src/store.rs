@@ -0,0 +1,138 @@
+//! Arrow IPC-backed cache of previously computed embeddings, keyed by symbol ID.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use arrow_array::{Array, FixedSizeListArray, Float32Array, RecordBatch, StringArray};
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::EmbeddingError;
+
+const ID_FIELD: &str = "id";
+const EMBEDDING_FIELD: &str = "embedding";
+
+fn schema(dimension: i32) -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new(ID_FIELD, DataType::Utf8, false),
+        Field::new(
+            EMBEDDING_FIELD,
+            DataType::FixedSizeList(
+                Arc::new(Field::new("item", DataType::Float32, true)),
+                dimension,
+            ),
+            false,
+        ),
+    ]))
+}
+
+/// Persists embeddings to an Arrow IPC file so repeat index runs can reuse them
+/// instead of re-invoking the embedding model.
+pub struct EmbeddingStore {
+    path: PathBuf,
+}
+
+impl EmbeddingStore {
+    /// Create a store backed by the Arrow IPC file at `path`.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Load all cached embeddings, keyed by symbol ID.
+    ///
+    /// Returns an empty map if the file does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddingError::Io`] if the file exists but cannot be read, or
+    /// [`EmbeddingError::Arrow`] if the Arrow IPC data is malformed.
+    pub fn load(&self) -> Result<HashMap<String, Vec<f32>>, EmbeddingError> {
+        if !self.path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let file = File::open(&self.path)?;
+        let reader = FileReader::try_new(BufReader::new(file), None)?;
+
+        let mut out = HashMap::new();
+        for batch in reader {
+            let batch = batch?;
+            merge_batch_into(&batch, &mut out)?;
+        }
+        Ok(out)
+    }
+
+    /// Write `embeddings` to the store, overwriting any existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddingError::Io`] if the file cannot be created, or
+    /// [`EmbeddingError::Arrow`] if the batch cannot be encoded.
+    pub fn save(&self, embeddings: &HashMap<String, Vec<f32>>) -> Result<(), EmbeddingError> {
+        let width = embeddings.values().next().map_or(0, Vec::len);
+        let dimension = i32::try_from(width)
+            .map_err(|e| EmbeddingError::Corrupt(format!("embedding dimension too large: {e}")))?;
+        let schema = schema(dimension);
+
+        let mut ids = Vec::with_capacity(embeddings.len());
+        let mut flat = Vec::with_capacity(embeddings.len() * width);
+        for (id, vector) in embeddings {
+            ids.push(id.as_str());
+            flat.extend_from_slice(vector);
+        }
+
+        let id_array = StringArray::from(ids);
+        let values = Arc::new(Float32Array::from(flat));
+        let embedding_field = Arc::new(Field::new("item", DataType::Float32, true));
+        let embedding_array =
+            FixedSizeListArray::try_new(embedding_field, dimension, values, None)?;
+
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(id_array), Arc::new(embedding_array)],
+        )?;
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = File::create(&self.path)?;
+        let mut writer = FileWriter::try_new(file, &schema)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+fn merge_batch_into(
+    batch: &RecordBatch,
+    out: &mut HashMap<String, Vec<f32>>,
+) -> Result<(), EmbeddingError> {
+    let ids = batch
+        .column_by_name(ID_FIELD)
+        .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+        .ok_or_else(|| EmbeddingError::Corrupt("missing or malformed 'id' column".to_string()))?;
+    let embeddings = batch
+        .column_by_name(EMBEDDING_FIELD)
+        .and_then(|c| c.as_any().downcast_ref::<FixedSizeListArray>())
+        .ok_or_else(|| {
+            EmbeddingError::Corrupt("missing or malformed 'embedding' column".to_string())
+        })?;
+
+    for row in 0..batch.num_rows() {
+        let id = ids.value(row).to_string();
+        let vector = embeddings.value(row);
+        let vector = vector
+            .as_any()
+            .downcast_ref::<Float32Array>()
+            .ok_or_else(|| EmbeddingError::Corrupt("embedding values are not float32".to_string()))?
+            .values()
+            .to_vec();
+        out.insert(id, vector);
+    }
+    Ok(())
+}
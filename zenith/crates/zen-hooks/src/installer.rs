@@ -7,7 +7,7 @@ use crate::error::HookError;
 use crate::repo::{RepoContext, discover_repo_context};
 use crate::scripts::write_default_scripts;
 
-const HOOK_NAMES: [&str; 3] = ["pre-commit", "post-checkout", "post-merge"];
+const HOOK_NAMES: [&str; 4] = ["pre-commit", "post-checkout", "post-merge", "pre-push"];
 const ZENITH_CHAIN_MARKER: &str = "# Zenith managed hook (chain)";
 
 #[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq)]
@@ -22,6 +22,7 @@ pub mod checkout;
 pub mod error;
 pub mod installer;
 pub mod merge;
+pub mod pre_push;
 pub mod repo;
 pub mod scripts;
 pub mod session_tags;
@@ -34,7 +35,11 @@ pub use installer::{
     install_hooks, status_hooks, uninstall_hooks,
 };
 pub use merge::{PostMergeAction, analyze_post_merge};
-pub use validator::{TrailValidationError, TrailValidationReport, validate_staged_trail_files};
+pub use pre_push::{ActiveSessionState, PrePushAction, PrePushRefUpdate, analyze_pre_push};
+pub use validator::{
+    TrailValidationError, TrailValidationReport, ValidationDetail, validate_staged_trail_files,
+    validate_trail_files_in_tree,
+};
 
 #[cfg(test)]
 #[allow(warnings)]
@@ -14,6 +14,55 @@ pub enum PostMergeAction {
     ConflictDetected { files: Vec<String> },
 }
 
+/// A single unresolved git merge conflict marker span (`<<<<<<<` through
+/// `>>>>>>>`), as 1-based line numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ConflictRegion {
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+/// Scan `path` for unresolved git merge conflict markers, returning one
+/// [`ConflictRegion`] per `<<<<<<<`/`>>>>>>>` span found.
+///
+/// A dangling `<<<<<<<` with no matching `>>>>>>>` (a truncated or malformed
+/// conflict) is reported as a region ending at the last line of the file.
+pub fn detect_merge_conflicts(path: &Path) -> Result<Vec<ConflictRegion>, HookError> {
+    let content = fs::read_to_string(path)?;
+    Ok(scan_for_conflict_regions(&content))
+}
+
+fn scan_for_conflict_regions(content: &str) -> Vec<ConflictRegion> {
+    let mut regions = Vec::new();
+    let mut open_start = None;
+    let mut last_line = 0;
+
+    for (idx, line) in content.lines().enumerate() {
+        let line_no = idx + 1;
+        last_line = line_no;
+
+        if line.starts_with("<<<<<<<") {
+            open_start.get_or_insert(line_no);
+        } else if line.starts_with(">>>>>>>")
+            && let Some(start_line) = open_start.take()
+        {
+            regions.push(ConflictRegion {
+                start_line,
+                end_line: line_no,
+            });
+        }
+    }
+
+    if let Some(start_line) = open_start {
+        regions.push(ConflictRegion {
+            start_line,
+            end_line: last_line,
+        });
+    }
+
+    regions
+}
+
 pub fn analyze_post_merge(project_root: &Path) -> Result<PostMergeAction, HookError> {
     let trail_dir = project_root.join(".zenith").join("trail");
     if !trail_dir.exists() {
@@ -72,3 +121,48 @@ pub fn analyze_post_merge(project_root: &Path) -> Result<PostMergeAction, HookEr
         Ok(PostMergeAction::Rebuild { changed_files })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_a_synthetic_conflict_region() {
+        let dir = tempfile::TempDir::new().expect("create tempdir");
+        let path = dir.path().join("session.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                "{\"v\":1,\"op\":\"create\"}\n",
+                "<<<<<<< HEAD\n",
+                "{\"v\":1,\"op\":\"ours\"}\n",
+                "=======\n",
+                "{\"v\":1,\"op\":\"theirs\"}\n",
+                ">>>>>>> branch\n",
+                "{\"v\":1,\"op\":\"create\"}\n",
+            ),
+        )
+        .unwrap();
+
+        let regions = detect_merge_conflicts(&path).expect("scan should succeed");
+
+        assert_eq!(
+            regions,
+            vec![ConflictRegion {
+                start_line: 2,
+                end_line: 6,
+            }]
+        );
+    }
+
+    #[test]
+    fn no_conflict_markers_yields_no_regions() {
+        let dir = tempfile::TempDir::new().expect("create tempdir");
+        let path = dir.path().join("session.jsonl");
+        std::fs::write(&path, "{\"v\":1,\"op\":\"create\"}\n").unwrap();
+
+        let regions = detect_merge_conflicts(&path).expect("scan should succeed");
+
+        assert!(regions.is_empty());
+    }
+}
@@ -0,0 +1,419 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::error::HookError;
+use crate::validator::{TrailValidationReport, validate_trail_files_in_tree};
+
+/// DB-persisted state of the currently active session, if any.
+///
+/// `zen-hooks` stays gix-only (no `zen-db` dependency), so the caller looks
+/// this up itself (`ZenService::list_sessions` + `latest_snapshot_at`) and
+/// passes it in. This lets [`analyze_pre_push`] catch a session that's
+/// genuinely still active in the DB with no snapshot covering the outgoing
+/// commits, even if the trail JSONL on disk happens to read `wrapped_up`
+/// (e.g. a wrap-up that updated the trail but crashed before syncing to the DB).
+#[derive(Debug, Clone)]
+pub struct ActiveSessionState {
+    pub session_id: String,
+    pub last_snapshot_at: Option<DateTime<Utc>>,
+}
+
+/// One `<local ref> <local oid> <remote ref> <remote oid>` line read from
+/// git's `pre-push` hook stdin.
+#[derive(Debug, Clone)]
+pub struct PrePushRefUpdate {
+    pub local_ref: String,
+    pub local_oid: String,
+    pub remote_ref: String,
+    pub remote_oid: String,
+}
+
+const ZERO_OID: &str = "0000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum PrePushAction {
+    Allow {
+        reason: String,
+    },
+    Block {
+        reason: String,
+        validation: Option<TrailValidationReport>,
+    },
+}
+
+/// Decide whether a `pre-push` should proceed.
+///
+/// Rejects the push (returns [`PrePushAction::Block`]) if any of the following:
+/// - `.zenith/trail/*.jsonl` at the tip of an outgoing ref fails the same
+///   validation `pre-commit` runs (reused via [`validate_trail_files_in_tree`]
+///   against the pushed tree, not the index),
+/// - a session's most recent `session` trail record (by `ts`) still reports
+///   `status: "active"`, meaning `znt wrap-up` was never run for it, or
+/// - `active_session` names a session the DB still considers active whose
+///   last snapshot predates the newest outgoing commit (or has no snapshot
+///   at all), meaning wrap-up hasn't caught up with the work being pushed.
+///
+/// # Errors
+///
+/// Returns [`HookError::Git`] if the repository or its commits can't be read.
+pub fn analyze_pre_push(
+    project_root: &Path,
+    refs: &[PrePushRefUpdate],
+    active_session: Option<&ActiveSessionState>,
+) -> Result<PrePushAction, HookError> {
+    let updates: Vec<&PrePushRefUpdate> = refs
+        .iter()
+        .filter(|update| update.local_oid != ZERO_OID)
+        .collect();
+
+    if updates.is_empty() {
+        return Ok(PrePushAction::Allow {
+            reason: "no ref updates to push (delete-only push)".to_string(),
+        });
+    }
+
+    let repo = gix::discover(project_root)
+        .map_err(|_| HookError::NotGitRepo(project_root.to_path_buf()))?;
+
+    let mut trees = Vec::with_capacity(updates.len());
+    let mut newest_commit_at: Option<DateTime<Utc>> = None;
+    for update in &updates {
+        let oid: gix::ObjectId = update
+            .local_oid
+            .parse()
+            .map_err(|error| HookError::Git(format!("parse local oid: {error}")))?;
+        let commit = repo.find_commit(oid).map_err(|error| {
+            HookError::Git(format!("find commit {}: {error}", update.local_oid))
+        })?;
+        let tree = commit.tree().map_err(|error| {
+            HookError::Git(format!("load tree for {}: {error}", update.local_ref))
+        })?;
+        let commit_at = commit_timestamp(&commit)?;
+        newest_commit_at = Some(newest_commit_at.map_or(commit_at, |newest| newest.max(commit_at)));
+
+        let report = validate_trail_files_in_tree(&tree)?;
+        if !report.is_valid() {
+            return Ok(PrePushAction::Block {
+                reason: format!(
+                    "trail files on '{}' fail validation; fix them before pushing",
+                    update.local_ref
+                ),
+                validation: Some(report),
+            });
+        }
+
+        trees.push(tree);
+    }
+
+    for tree in &trees {
+        let files = collect_trail_contents(tree)?;
+        if let Some(session_id) = stale_active_session(&files) {
+            return Ok(PrePushAction::Block {
+                reason: format!(
+                    "session '{session_id}' is still active and wasn't wrapped up before this push; run `znt wrap-up` first"
+                ),
+                validation: None,
+            });
+        }
+    }
+
+    if let Some(active) = active_session {
+        let newest_commit_at = newest_commit_at.unwrap_or_else(Utc::now);
+        let is_stale = active
+            .last_snapshot_at
+            .is_none_or(|snapshot_at| snapshot_at < newest_commit_at);
+        if is_stale {
+            return Ok(PrePushAction::Block {
+                reason: format!(
+                    "session '{}' is still active in the database and its last snapshot predates this push; run `znt wrap-up` first",
+                    active.session_id
+                ),
+                validation: None,
+            });
+        }
+    }
+
+    Ok(PrePushAction::Allow {
+        reason: "trail files valid and no unwrapped active session".to_string(),
+    })
+}
+
+/// Convert a commit's committer timestamp to UTC.
+fn commit_timestamp(commit: &gix::Commit<'_>) -> Result<DateTime<Utc>, HookError> {
+    let time = commit
+        .time()
+        .map_err(|error| HookError::Git(format!("read commit time: {error}")))?;
+    DateTime::from_timestamp(time.seconds, 0)
+        .ok_or_else(|| HookError::Git(format!("commit timestamp out of range: {}", time.seconds)))
+}
+
+fn collect_trail_contents(tree: &gix::Tree<'_>) -> Result<Vec<(String, String)>, HookError> {
+    let Some(trail_entry) = tree
+        .lookup_entry_by_path(".zenith/trail")
+        .map_err(|error| HookError::Git(format!("lookup .zenith/trail: {error}")))?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let trail_object = trail_entry
+        .object()
+        .map_err(|error| HookError::Git(format!("load .zenith/trail: {error}")))?;
+    if !trail_object.kind.is_tree() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+    for entry in trail_object.into_tree().iter() {
+        let entry = entry.map_err(|error| HookError::Git(format!("read trail entry: {error}")))?;
+        let name = entry.filename().to_string();
+        if !name.ends_with(".jsonl") {
+            continue;
+        }
+        let blob = entry
+            .object()
+            .map_err(|error| HookError::Git(format!("load trail blob '{name}': {error}")))?;
+        files.push((name, String::from_utf8_lossy(&blob.data).into_owned()));
+    }
+    Ok(files)
+}
+
+/// Find a session whose most recent `entity: "session"` trail record (by
+/// `ts`) reports `status: "active"`, meaning `znt wrap-up` was never run for
+/// it before this push.
+fn stale_active_session(files: &[(String, String)]) -> Option<String> {
+    let mut latest: HashMap<String, (DateTime<chrono::FixedOffset>, String)> = HashMap::new();
+
+    for (_, content) in files {
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(trimmed) else {
+                continue;
+            };
+            if value.get("entity").and_then(serde_json::Value::as_str) != Some("session") {
+                continue;
+            }
+            let Some(session_id) = value.get("ses").and_then(serde_json::Value::as_str) else {
+                continue;
+            };
+            let Some(status) = value
+                .get("data")
+                .and_then(|data| data.get("status"))
+                .and_then(serde_json::Value::as_str)
+            else {
+                continue;
+            };
+            let Some(ts) = value
+                .get("ts")
+                .and_then(serde_json::Value::as_str)
+                .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            else {
+                continue;
+            };
+
+            latest
+                .entry(session_id.to_string())
+                .and_modify(|(existing_ts, existing_status)| {
+                    if ts > *existing_ts {
+                        *existing_ts = ts;
+                        *existing_status = status.to_string();
+                    }
+                })
+                .or_insert_with(|| (ts, status.to_string()));
+        }
+    }
+
+    latest
+        .into_iter()
+        .find(|(_, (_, status))| status == "active")
+        .map(|(session_id, _)| session_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::process::Command;
+
+    use super::*;
+
+    fn run_git(repo_path: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .output()
+            .unwrap_or_else(|e| panic!("git {} failed: {e}", args.join(" ")));
+        assert!(
+            output.status.success(),
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    fn init_repo() -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().expect("create tempdir");
+        run_git(dir.path(), &["init", "--initial-branch=main"]);
+        run_git(dir.path(), &["config", "user.email", "test@zenith.dev"]);
+        run_git(dir.path(), &["config", "user.name", "Zenith Test"]);
+        dir
+    }
+
+    fn commit_trail(repo_path: &Path, lines: &[&str]) -> String {
+        let trail_dir = repo_path.join(".zenith/trail");
+        fs::create_dir_all(&trail_dir).unwrap();
+        fs::write(trail_dir.join("ses-001.jsonl"), lines.join("\n") + "\n").unwrap();
+        run_git(repo_path, &["add", "."]);
+        run_git(repo_path, &["commit", "-m", "trail update"]);
+        run_git(repo_path, &["rev-parse", "HEAD"])
+    }
+
+    fn push_update(local_oid: &str) -> Vec<PrePushRefUpdate> {
+        vec![PrePushRefUpdate {
+            local_ref: "refs/heads/main".to_string(),
+            local_oid: local_oid.to_string(),
+            remote_ref: "refs/heads/main".to_string(),
+            remote_oid: ZERO_OID.to_string(),
+        }]
+    }
+
+    fn session_line(session_id: &str, ts: &str, status: &str) -> String {
+        format!(
+            r#"{{"v":1,"ts":"{ts}","ses":"{session_id}","op":"update","entity":"session","id":"{session_id}","data":{{"status":"{status}"}}}}"#
+        )
+    }
+
+    #[test]
+    fn allows_push_when_session_is_wrapped_up() {
+        let dir = init_repo();
+        let created = session_line("ses-00000001", "2026-02-08T12:00:00Z", "active");
+        let wrapped_up = session_line("ses-00000001", "2026-02-08T13:00:00Z", "wrapped_up");
+        let oid = commit_trail(dir.path(), &[&created, &wrapped_up]);
+
+        let action = analyze_pre_push(dir.path(), &push_update(&oid), None).expect("should run");
+        assert!(matches!(action, PrePushAction::Allow { .. }));
+    }
+
+    #[test]
+    fn blocks_push_when_session_is_still_active() {
+        let dir = init_repo();
+        let active = session_line("ses-00000002", "2026-02-08T12:00:00Z", "active");
+        let oid = commit_trail(dir.path(), &[&active]);
+
+        let action = analyze_pre_push(dir.path(), &push_update(&oid), None).expect("should run");
+        assert!(matches!(
+            action,
+            PrePushAction::Block {
+                validation: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn blocks_push_when_latest_record_reverts_to_active() {
+        let dir = init_repo();
+        let wrapped_up = session_line("ses-00000003", "2026-02-08T12:00:00Z", "wrapped_up");
+        let reopened = session_line("ses-00000003", "2026-02-08T13:00:00Z", "active");
+        let oid = commit_trail(dir.path(), &[&wrapped_up, &reopened]);
+
+        let action = analyze_pre_push(dir.path(), &push_update(&oid), None).expect("should run");
+        assert!(matches!(
+            action,
+            PrePushAction::Block {
+                validation: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn blocks_push_when_trail_json_is_invalid() {
+        let dir = init_repo();
+        let bad = r#"{"v":1,"ts":"2026-02-08T12:00:00Z","ses":"ses-00000004","op":"not_a_real_op","entity":"finding","id":"fnd-test1234","data":{}}"#;
+        let oid = commit_trail(dir.path(), &[bad]);
+
+        let action = analyze_pre_push(dir.path(), &push_update(&oid), None).expect("should run");
+        assert!(matches!(
+            action,
+            PrePushAction::Block {
+                validation: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn blocks_push_when_db_session_has_no_snapshot_covering_the_push() {
+        let dir = init_repo();
+        let wrapped_up = session_line("ses-00000006", "2026-02-08T12:00:00Z", "wrapped_up");
+        let oid = commit_trail(dir.path(), &[&wrapped_up]);
+
+        let active = ActiveSessionState {
+            session_id: "ses-00000006".to_string(),
+            last_snapshot_at: None,
+        };
+        let action =
+            analyze_pre_push(dir.path(), &push_update(&oid), Some(&active)).expect("should run");
+        assert!(matches!(
+            action,
+            PrePushAction::Block {
+                validation: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn blocks_push_when_db_snapshot_predates_the_newest_commit() {
+        let dir = init_repo();
+        let wrapped_up = session_line("ses-00000007", "2026-02-08T12:00:00Z", "wrapped_up");
+        let oid = commit_trail(dir.path(), &[&wrapped_up]);
+
+        let active = ActiveSessionState {
+            session_id: "ses-00000007".to_string(),
+            last_snapshot_at: Some(DateTime::from_timestamp(0, 0).unwrap()),
+        };
+        let action =
+            analyze_pre_push(dir.path(), &push_update(&oid), Some(&active)).expect("should run");
+        assert!(matches!(action, PrePushAction::Block { .. }));
+    }
+
+    #[test]
+    fn allows_push_when_db_snapshot_covers_the_newest_commit() {
+        let dir = init_repo();
+        let wrapped_up = session_line("ses-00000008", "2026-02-08T12:00:00Z", "wrapped_up");
+        let oid = commit_trail(dir.path(), &[&wrapped_up]);
+
+        let active = ActiveSessionState {
+            session_id: "ses-00000008".to_string(),
+            last_snapshot_at: Some(Utc::now()),
+        };
+        let action =
+            analyze_pre_push(dir.path(), &push_update(&oid), Some(&active)).expect("should run");
+        assert!(matches!(action, PrePushAction::Allow { .. }));
+    }
+
+    #[test]
+    fn allows_delete_only_push() {
+        let dir = init_repo();
+        commit_trail(
+            dir.path(),
+            &[&session_line(
+                "ses-00000005",
+                "2026-02-08T12:00:00Z",
+                "wrapped_up",
+            )],
+        );
+
+        let action =
+            analyze_pre_push(dir.path(), &push_update(ZERO_OID), None).expect("should run");
+        assert!(matches!(action, PrePushAction::Allow { .. }));
+    }
+}
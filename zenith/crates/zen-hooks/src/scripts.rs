@@ -34,6 +34,16 @@ else
 fi
 "#;
 
+const PRE_PUSH_SCRIPT: &str = r#"#!/bin/bash
+# Zenith pre-push hook (generated by znt)
+if command -v znt >/dev/null 2>&1; then
+    exec znt hook pre-push "$@"
+else
+    echo "zenith: 'znt' not in PATH - skipping trail/session checks" >&2
+    exit 0
+fi
+"#;
+
 pub fn write_default_scripts(hooks_dir: &Path) -> Result<Vec<PathBuf>, HookError> {
     fs::create_dir_all(hooks_dir)?;
 
@@ -41,6 +51,7 @@ pub fn write_default_scripts(hooks_dir: &Path) -> Result<Vec<PathBuf>, HookError
         ("pre-commit", PRE_COMMIT_SCRIPT),
         ("post-checkout", POST_CHECKOUT_SCRIPT),
         ("post-merge", POST_MERGE_SCRIPT),
+        ("pre-push", PRE_PUSH_SCRIPT),
     ];
 
     let mut written = Vec::new();
@@ -1,7 +1,12 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use crate::error::HookError;
 
+/// Prefix under which per-branch session tags live, e.g.
+/// `zenith/session/{session_id}/{branch}`.
+const SESSION_BRANCH_TAG_PREFIX: &str = "refs/tags/zenith/session/";
+
 pub fn create_session_tag(
     project_root: &Path,
     session_id: &str,
@@ -39,3 +44,152 @@ pub fn create_session_tag(
 
     Ok(())
 }
+
+/// List the branch segments of every `zenith/session/{session_id}/*` tag for
+/// the given session, e.g. `["feature/foo", "main"]`.
+pub fn list_branches_for_session(
+    repo: &gix::Repository,
+    session_id: &str,
+) -> Result<Vec<String>, HookError> {
+    let prefix = format!("{SESSION_BRANCH_TAG_PREFIX}{session_id}/");
+    let refs = repo
+        .references()
+        .map_err(|e| HookError::Git(format!("list references: {e}")))?;
+
+    let mut branches: Vec<String> = refs
+        .prefixed(prefix.as_str())
+        .map_err(|e| HookError::Git(format!("filter references by prefix: {e}")))?
+        .filter_map(Result::ok)
+        .filter_map(|reference| {
+            reference
+                .name()
+                .as_bstr()
+                .to_string()
+                .strip_prefix(&prefix)
+                .map(ToString::to_string)
+        })
+        .collect();
+
+    branches.sort_unstable();
+    Ok(branches)
+}
+
+/// Build a `session_id -> [branch, ...]` map from every
+/// `zenith/session/{session_id}/{branch}` tag in the repository.
+pub fn list_all_session_branches(
+    repo: &gix::Repository,
+) -> Result<HashMap<String, Vec<String>>, HookError> {
+    let refs = repo
+        .references()
+        .map_err(|e| HookError::Git(format!("list references: {e}")))?;
+
+    let mut by_session: HashMap<String, Vec<String>> = HashMap::new();
+    for reference in refs
+        .prefixed(SESSION_BRANCH_TAG_PREFIX)
+        .map_err(|e| HookError::Git(format!("filter references by prefix: {e}")))?
+        .filter_map(Result::ok)
+    {
+        let name = reference.name().as_bstr().to_string();
+        let Some(rest) = name.strip_prefix(SESSION_BRANCH_TAG_PREFIX) else {
+            continue;
+        };
+        let Some((session_id, branch)) = rest.split_once('/') else {
+            continue;
+        };
+        by_session
+            .entry(session_id.to_string())
+            .or_default()
+            .push(branch.to_string());
+    }
+
+    for branches in by_session.values_mut() {
+        branches.sort_unstable();
+    }
+
+    Ok(by_session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn run_git(repo_path: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .output()
+            .unwrap_or_else(|e| panic!("git {} failed: {e}", args.join(" ")));
+        assert!(
+            output.status.success(),
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn init_repo_with_session_tags(tags: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().expect("create tempdir");
+        let repo_path = dir.path();
+
+        run_git(repo_path, &["init", "--initial-branch=main"]);
+        run_git(repo_path, &["config", "user.email", "test@zenith.dev"]);
+        run_git(repo_path, &["config", "user.name", "Zenith Test"]);
+        std::fs::write(repo_path.join("README.md"), "seed\n").expect("write seed file");
+        run_git(repo_path, &["add", "."]);
+        run_git(repo_path, &["commit", "-m", "seed"]);
+
+        for (session_id, branch) in tags {
+            let tag = format!("zenith/session/{session_id}/{branch}");
+            run_git(repo_path, &["tag", &tag]);
+        }
+
+        dir
+    }
+
+    #[test]
+    fn list_branches_for_session_returns_matching_branches() {
+        let dir = init_repo_with_session_tags(&[
+            ("ses-alpha", "main"),
+            ("ses-alpha", "feature/foo"),
+            ("ses-beta", "main"),
+        ]);
+        let repo = gix::discover(dir.path()).expect("discover repo");
+
+        let branches = list_branches_for_session(&repo, "ses-alpha").expect("lookup should work");
+
+        assert_eq!(
+            branches,
+            vec!["feature/foo".to_string(), "main".to_string()]
+        );
+    }
+
+    #[test]
+    fn list_branches_for_session_returns_empty_for_unknown_session() {
+        let dir = init_repo_with_session_tags(&[("ses-alpha", "main")]);
+        let repo = gix::discover(dir.path()).expect("discover repo");
+
+        let branches = list_branches_for_session(&repo, "ses-unknown").expect("lookup should work");
+
+        assert!(branches.is_empty());
+    }
+
+    #[test]
+    fn list_all_session_branches_maps_every_session_bidirectionally() {
+        let dir = init_repo_with_session_tags(&[
+            ("ses-alpha", "main"),
+            ("ses-alpha", "feature/foo"),
+            ("ses-beta", "main"),
+        ]);
+        let repo = gix::discover(dir.path()).expect("discover repo");
+
+        let mapping = list_all_session_branches(&repo).expect("lookup should work");
+
+        assert_eq!(
+            mapping.get("ses-alpha"),
+            Some(&vec!["feature/foo".to_string(), "main".to_string()])
+        );
+        assert_eq!(mapping.get("ses-beta"), Some(&vec!["main".to_string()]));
+        assert_eq!(mapping.len(), 2);
+    }
+}
@@ -1,15 +1,47 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde::Serialize;
-use zen_schema::SchemaRegistry;
+use zen_schema::{SchemaRegistry, ValidationMode};
 
 use crate::error::HookError;
+use crate::merge::{self, ConflictRegion};
 
 #[derive(Debug, Clone, Serialize)]
-pub struct TrailValidationError {
-    pub file: String,
-    pub line: usize,
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TrailValidationError {
+    /// The line could not be parsed as JSON, or was rejected before parsing
+    /// (BOM, unresolved git conflict marker).
+    ParseError {
+        file: String,
+        line: usize,
+        raw_line: String,
+        message: String,
+    },
+    /// The line parsed as JSON but failed schema validation.
+    SchemaError {
+        file: String,
+        line: usize,
+        message: String,
+    },
+    /// The staged file itself contains one or more unresolved git merge
+    /// conflict regions, most likely from two agents writing to the same
+    /// trail file concurrently. Detected up front via
+    /// [`merge::detect_merge_conflicts`], before per-line parsing runs.
+    MergeConflict {
+        file: String,
+        regions: Vec<ConflictRegion>,
+    },
+}
+
+/// A single schema validation failure, pinpointed to a line and (best-effort)
+/// column in a JSONL trail file.
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationDetail {
+    pub file: PathBuf,
+    pub line: u64,
+    pub column: Option<u64>,
+    pub operation_id: Option<String>,
     pub message: String,
 }
 
@@ -18,6 +50,7 @@ pub struct TrailValidationReport {
     pub files_checked: usize,
     pub operations_checked: usize,
     pub errors: Vec<TrailValidationError>,
+    pub details: Vec<ValidationDetail>,
 }
 
 impl TrailValidationReport {
@@ -49,68 +82,292 @@ pub fn validate_staged_trail_files(
         })
         .collect::<Vec<_>>();
 
+    let mut conflict_errors = Vec::new();
+    let mut files = Vec::with_capacity(staged.len());
+    for rel in staged {
+        let full_path = project_root.join(&rel);
+        let regions = merge::detect_merge_conflicts(&full_path)?;
+        if !regions.is_empty() {
+            conflict_errors.push(TrailValidationError::MergeConflict { file: rel, regions });
+            continue;
+        }
+        let content = fs::read_to_string(&full_path)?;
+        files.push((rel, content));
+    }
+
+    let mut report = validate_trail_contents(&files)?;
+    report.files_checked += conflict_errors.len();
+    report.errors.splice(0..0, conflict_errors);
+    Ok(report)
+}
+
+/// Validate `.zenith/trail/*.jsonl` blobs as they exist in `tree`, rather
+/// than in the working tree or index.
+///
+/// Used by the `pre-push` hook to check the outgoing commit's tree, which
+/// may differ from what's currently staged or checked out locally.
+///
+/// # Errors
+///
+/// Returns [`HookError::Git`] if the tree can't be traversed, or
+/// [`HookError::Schema`] if schema validation itself fails to run.
+pub fn validate_trail_files_in_tree(
+    tree: &gix::Tree<'_>,
+) -> Result<TrailValidationReport, HookError> {
+    let files = collect_trail_blobs(tree)?;
+    validate_trail_contents(&files)
+}
+
+/// Read every `.jsonl` blob directly under `.zenith/trail/` in `tree`.
+fn collect_trail_blobs(tree: &gix::Tree<'_>) -> Result<Vec<(String, String)>, HookError> {
+    let Some(trail_entry) = tree
+        .lookup_entry_by_path(".zenith/trail")
+        .map_err(|error| HookError::Git(format!("lookup .zenith/trail: {error}")))?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let trail_object = trail_entry
+        .object()
+        .map_err(|error| HookError::Git(format!("load .zenith/trail: {error}")))?;
+    if !trail_object.kind.is_tree() {
+        return Ok(Vec::new());
+    }
+    let trail_tree = trail_object.into_tree();
+
+    let mut files = Vec::new();
+    for entry in trail_tree.iter() {
+        let entry = entry.map_err(|error| HookError::Git(format!("read trail entry: {error}")))?;
+        let name = entry.filename().to_string();
+        if !name.ends_with(".jsonl") {
+            continue;
+        }
+        let blob = entry
+            .object()
+            .map_err(|error| HookError::Git(format!("load trail blob '{name}': {error}")))?;
+        let content = String::from_utf8_lossy(&blob.data).into_owned();
+        files.push((format!(".zenith/trail/{name}"), content));
+    }
+    Ok(files)
+}
+
+fn validate_trail_contents(files: &[(String, String)]) -> Result<TrailValidationReport, HookError> {
     let schema = SchemaRegistry::new();
     let mut errors = Vec::new();
+    let mut details = Vec::new();
     let mut operations_checked = 0usize;
 
-    for rel in &staged {
-        let full_path = project_root.join(rel);
-        let content = fs::read_to_string(&full_path)?;
+    for (rel, content) in files {
+        let full_path = PathBuf::from(rel);
 
         for (line_idx, line) in content.lines().enumerate() {
-            let line_no = line_idx + 1;
             let trimmed = line.trim();
             if trimmed.is_empty() {
                 continue;
             }
             operations_checked += 1;
 
-            if trimmed.starts_with('\u{feff}') {
-                errors.push(TrailValidationError {
-                    file: rel.clone(),
-                    line: line_no,
-                    message: "BOM detected".to_string(),
-                });
-                continue;
-            }
-
-            if trimmed.starts_with("<<<<<<<")
-                || trimmed.starts_with("=======")
-                || trimmed.starts_with(">>>>>>>")
-            {
-                errors.push(TrailValidationError {
-                    file: rel.clone(),
-                    line: line_no,
-                    message: "git conflict marker detected".to_string(),
-                });
-                continue;
-            }
-
-            let value: serde_json::Value = match serde_json::from_str(trimmed) {
-                Ok(value) => value,
-                Err(error) => {
-                    errors.push(TrailValidationError {
-                        file: rel.clone(),
-                        line: line_no,
-                        message: format!("invalid JSON: {error}"),
-                    });
-                    continue;
-                }
-            };
-
-            if let Err(error) = schema.validate("trail_operation", &value) {
-                errors.push(TrailValidationError {
-                    file: rel.clone(),
-                    line: line_no,
-                    message: format!("schema validation failed: {error}"),
-                });
-            }
+            validate_line(
+                &schema,
+                rel,
+                &full_path,
+                line_idx + 1,
+                trimmed,
+                &mut errors,
+                &mut details,
+            )?;
         }
     }
 
     Ok(TrailValidationReport {
-        files_checked: staged.len(),
+        files_checked: files.len(),
         operations_checked,
         errors,
+        details,
     })
 }
+
+/// Validate a single non-empty JSONL line, appending to `errors` and (for
+/// schema failures) `details`.
+fn validate_line(
+    schema: &SchemaRegistry,
+    rel: &str,
+    full_path: &Path,
+    line_no: usize,
+    trimmed: &str,
+    errors: &mut Vec<TrailValidationError>,
+    details: &mut Vec<ValidationDetail>,
+) -> Result<(), HookError> {
+    if trimmed.starts_with('\u{feff}') {
+        errors.push(TrailValidationError::ParseError {
+            file: rel.to_string(),
+            line: line_no,
+            raw_line: trimmed.to_string(),
+            message: "BOM detected".to_string(),
+        });
+        return Ok(());
+    }
+
+    if trimmed.starts_with("<<<<<<<")
+        || trimmed.starts_with("=======")
+        || trimmed.starts_with(">>>>>>>")
+    {
+        errors.push(TrailValidationError::ParseError {
+            file: rel.to_string(),
+            line: line_no,
+            raw_line: trimmed.to_string(),
+            message: "git conflict marker detected".to_string(),
+        });
+        return Ok(());
+    }
+
+    let value: serde_json::Value = match serde_json::from_str(trimmed) {
+        Ok(value) => value,
+        Err(error) => {
+            errors.push(TrailValidationError::ParseError {
+                file: rel.to_string(),
+                line: line_no,
+                raw_line: trimmed.to_string(),
+                message: format!("invalid JSON: {error}"),
+            });
+            return Ok(());
+        }
+    };
+
+    let schema_errors = match ValidationMode::from_env() {
+        ValidationMode::Strict => schema.validate_detailed_strict("trail_operation", &value),
+        ValidationMode::Permissive => schema.validate_detailed("trail_operation", &value),
+    }
+    .map_err(|error| HookError::Schema(error.to_string()))?;
+    if schema_errors.is_empty() {
+        return Ok(());
+    }
+
+    let operation_id = value
+        .get("id")
+        .and_then(serde_json::Value::as_str)
+        .map(str::to_string);
+
+    let message = schema_errors
+        .iter()
+        .map(|error| error.message.as_str())
+        .collect::<Vec<_>>()
+        .join("; ");
+    errors.push(TrailValidationError::SchemaError {
+        file: rel.to_string(),
+        line: line_no,
+        message: format!("schema validation failed: {message}"),
+    });
+
+    for schema_error in &schema_errors {
+        details.push(ValidationDetail {
+            file: full_path.to_path_buf(),
+            line: line_no as u64,
+            column: locate_column(trimmed, &schema_error.instance_path),
+            operation_id: operation_id.clone(),
+            message: schema_error.message.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Approximate the 1-based byte column of a schema error's instance path
+/// within the raw JSONL line.
+///
+/// `jsonschema` only reports a structural JSON Pointer (e.g. `/data/confidence`),
+/// not a text offset, so this locates the deepest path segment's key in the
+/// source text as a best-effort pointer back into the line. Returns `None`
+/// for the root path (`""`), which has no key to search for.
+fn locate_column(line: &str, instance_path: &str) -> Option<u64> {
+    let segment = instance_path.rsplit('/').next().filter(|s| !s.is_empty())?;
+    let needle = format!("\"{segment}\"");
+    line.find(&needle).map(|byte_idx| (byte_idx + 1) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn run_git(repo_path: &Path, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .output()
+            .unwrap_or_else(|e| panic!("git {} failed: {e}", args.join(" ")));
+        assert!(
+            output.status.success(),
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn init_repo_with_staged_trail(lines: &[&str]) -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().expect("create tempdir");
+        let repo_path = dir.path();
+
+        run_git(repo_path, &["init", "--initial-branch=main"]);
+        run_git(repo_path, &["config", "user.email", "test@zenith.dev"]);
+        run_git(repo_path, &["config", "user.name", "Zenith Test"]);
+
+        let trail_dir = repo_path.join(".zenith/trail");
+        fs::create_dir_all(&trail_dir).unwrap();
+        fs::write(trail_dir.join("ses-001.jsonl"), lines.join("\n") + "\n").unwrap();
+
+        run_git(repo_path, &["add", "."]);
+        dir
+    }
+
+    #[test]
+    fn schema_failure_reports_line_level_detail() {
+        let valid = r#"{"v":1,"ts":"2026-02-08T12:00:00Z","ses":"ses-00000000","op":"create","entity":"finding","id":"fnd-test1234","data":{}}"#;
+        let bad = r#"{"v":1,"ts":"2026-02-08T12:00:00Z","ses":"ses-00000000","op":"not_a_real_op","entity":"finding","id":"fnd-test1234","data":{}}"#;
+        let dir = init_repo_with_staged_trail(&[valid, valid, bad]);
+
+        let report = validate_staged_trail_files(dir.path()).expect("validation should run");
+
+        assert!(!report.is_valid());
+        let detail = report
+            .details
+            .iter()
+            .find(|detail| detail.line == 3)
+            .expect("expected a detail entry at line 3");
+        assert_eq!(detail.operation_id.as_deref(), Some("fnd-test1234"));
+        assert!(detail.column.is_some());
+    }
+
+    #[test]
+    fn staged_file_with_conflict_markers_reports_merge_conflict() {
+        let valid = r#"{"v":1,"ts":"2026-02-08T12:00:00Z","ses":"ses-00000000","op":"create","entity":"finding","id":"fnd-test1234","data":{}}"#;
+        let dir = init_repo_with_staged_trail(&[
+            valid,
+            "<<<<<<< HEAD",
+            valid,
+            "=======",
+            valid,
+            ">>>>>>> branch",
+        ]);
+
+        let report = validate_staged_trail_files(dir.path()).expect("validation should run");
+
+        assert!(!report.is_valid());
+        assert!(report.errors.iter().any(|error| matches!(
+            error,
+            TrailValidationError::MergeConflict { regions, .. }
+                if regions == &[ConflictRegion { start_line: 2, end_line: 6 }]
+        )));
+    }
+
+    #[test]
+    fn locate_column_finds_offending_key() {
+        let line = r#"{"op":"bogus"}"#;
+        assert_eq!(locate_column(line, "/op"), Some(2));
+    }
+
+    #[test]
+    fn locate_column_returns_none_for_root() {
+        assert_eq!(locate_column("{}", ""), None);
+    }
+}
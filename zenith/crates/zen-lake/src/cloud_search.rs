@@ -774,6 +774,7 @@ mod tests {
             line_start: Some(1),
             line_end: Some(1),
             visibility: Some("public".to_string()),
+            is_deprecated: false,
             is_async: false,
             is_unsafe: false,
             is_error_type: false,
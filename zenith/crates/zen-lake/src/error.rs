@@ -30,6 +30,15 @@ pub enum LakeError {
     #[error("libSQL error: {0}")]
     LibSql(#[from] libsql::Error),
 
+    /// An embedding's length doesn't match the lake's fixed embedding dimension.
+    #[error("embedding dimension mismatch: expected {expected}, got {got}")]
+    DimensionMismatch {
+        /// Dimension every embedding in this lake must have.
+        expected: usize,
+        /// Dimension of the offending embedding.
+        got: usize,
+    },
+
     /// Catch-all for other errors.
     #[error("{0}")]
     Other(String),
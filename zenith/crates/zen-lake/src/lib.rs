@@ -26,7 +26,8 @@ pub use cloud_search::CloudVectorSearchResult;
 pub use error::LakeError;
 pub use r2_write::R2WriteResult;
 pub use schemas::{ApiSymbolRow, DocChunkRow};
-pub use source_files::{SourceFile, SourceFileStore};
+pub use source_files::{SourceFile, SourceFileHashStore, SourceFileStore};
+pub use store::SymbolLocation;
 
 use duckdb::{AccessMode, Config, Connection};
 
@@ -81,6 +82,19 @@ impl ZenLake {
         Ok(lake)
     }
 
+    /// Open an existing local `DuckDB` lake file for read-only access.
+    ///
+    /// Skips schema initialization (read-only connections can't create
+    /// tables) and lets `DuckDB` reject the open if `path` doesn't already
+    /// exist, so concurrent readers never race a writer's schema setup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LakeError::DuckDb`] if `path` doesn't exist or can't be opened.
+    pub fn open_read_only(path: &str) -> Result<Self, LakeError> {
+        Self::open_local_with_mode(path, OpenMode::ReadOnly)
+    }
+
     /// Open an in-memory lake (for testing).
     ///
     /// # Errors
@@ -108,8 +122,47 @@ impl ZenLake {
         self.conn.execute_batch(schemas::CREATE_API_SYMBOLS)?;
         self.conn.execute_batch(schemas::CREATE_DOC_CHUNKS)?;
         self.conn.execute_batch(schemas::CREATE_INDEXES)?;
+        self.conn.execute_batch(schemas::CREATE_LAKE_META)?;
+        self.seed_lake_meta_if_empty()?;
         Ok(())
     }
+
+    /// Seed `lake_meta` with [`schemas::EMBEDDING_DIM`] the first time this
+    /// lake is created. A no-op on subsequent opens of an existing file.
+    fn seed_lake_meta_if_empty(&self) -> Result<(), LakeError> {
+        let count: i64 = self
+            .conn
+            .query_row("SELECT count(*) FROM lake_meta", [], |row| row.get(0))?;
+        if count == 0 {
+            self.conn.execute(
+                "INSERT INTO lake_meta (embedding_dim) VALUES (?)",
+                duckdb::params![schemas::EMBEDDING_DIM as i64],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// The embedding dimension this lake was built with, as persisted in
+    /// `lake_meta` when the lake was first created.
+    ///
+    /// `store_symbols`/`store_doc_chunks` validate new embeddings against
+    /// this instead of the process-wide [`schemas::EMBEDDING_DIM`] default,
+    /// so a lake built with a different-dimension model still rejects the
+    /// right thing: embeddings that don't match *this* lake.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LakeError::DuckDb`] if `lake_meta` doesn't exist or is
+    /// empty — expected only for a read-only lake opened against a file
+    /// created before this table existed.
+    pub fn embedding_dim(&self) -> Result<usize, LakeError> {
+        let dim: i64 =
+            self.conn
+                .query_row("SELECT embedding_dim FROM lake_meta LIMIT 1", [], |row| {
+                    row.get(0)
+                })?;
+        Ok(dim as usize)
+    }
 }
 
 #[cfg(test)]
@@ -147,6 +200,7 @@ mod tests {
             line_start: Some(1),
             line_end: Some(10),
             visibility: Some("public".to_string()),
+            is_deprecated: false,
             is_async: true,
             is_unsafe: false,
             is_error_type: false,
@@ -248,6 +302,26 @@ mod tests {
         assert!(is_async);
     }
 
+    #[test]
+    fn store_symbols_rejects_mismatched_embedding_dimension() {
+        let lake = ZenLake::open_in_memory().expect("open lake");
+
+        let ok = vec![sample_symbol("sym-001", "spawn", synthetic_embedding(1))];
+        lake.store_symbols(&ok).expect("store 384-dim symbol");
+
+        let mismatched = vec![sample_symbol("sym-002", "block_on", vec![0.0_f32; 768])];
+        let err = lake
+            .store_symbols(&mismatched)
+            .expect_err("768-dim embedding should be rejected");
+        assert!(matches!(
+            err,
+            LakeError::DimensionMismatch {
+                expected: 384,
+                got: 768
+            }
+        ));
+    }
+
     #[test]
     fn store_and_query_doc_chunks() {
         let lake = ZenLake::open_in_memory().expect("open lake");
@@ -277,6 +351,53 @@ mod tests {
         assert!(content.contains("async runtimes"));
     }
 
+    #[test]
+    fn list_doc_chunks_for_package_orders_by_chunk_index() {
+        let lake = ZenLake::open_in_memory().expect("open lake");
+
+        // Insert out of order to verify the query, not the insert, does the sorting.
+        let chunks = vec![
+            sample_chunk("chk-003", 3, synthetic_embedding(3)),
+            sample_chunk("chk-001", 1, synthetic_embedding(1)),
+            sample_chunk("chk-004", 4, synthetic_embedding(4)),
+            sample_chunk("chk-000", 0, synthetic_embedding(0)),
+            sample_chunk("chk-002", 2, synthetic_embedding(2)),
+        ];
+        lake.store_doc_chunks(&chunks).expect("store chunks");
+
+        assert_eq!(
+            lake.count_doc_chunks_for_package("rust", "tokio", "1.49.0")
+                .unwrap(),
+            5
+        );
+
+        let page = lake
+            .list_doc_chunks_for_package("rust", "tokio", "1.49.0", 0, 10)
+            .expect("list chunks");
+        let indices: Vec<i32> = page.iter().map(|c| c.chunk_index).collect();
+        assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+
+        let page = lake
+            .list_doc_chunks_for_package("rust", "tokio", "1.49.0", 2, 2)
+            .expect("list chunks page");
+        let indices: Vec<i32> = page.iter().map(|c| c.chunk_index).collect();
+        assert_eq!(
+            indices,
+            vec![2, 3],
+            "offset/limit should paginate the ordered set"
+        );
+    }
+
+    #[test]
+    fn count_doc_chunks_for_package_is_zero_for_unknown_package() {
+        let lake = ZenLake::open_in_memory().expect("open lake");
+        assert_eq!(
+            lake.count_doc_chunks_for_package("rust", "nonexistent", "0.0.0")
+                .unwrap(),
+            0
+        );
+    }
+
     #[test]
     fn register_and_check_package() {
         let lake = ZenLake::open_in_memory().expect("open lake");
@@ -378,6 +499,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn find_similar_to_symbol_excludes_source_and_ranks_by_similarity() {
+        let lake = ZenLake::open_in_memory().expect("open lake");
+
+        let symbols = vec![
+            sample_symbol("sym-a", "func_a", synthetic_embedding(1)),
+            sample_symbol("sym-b", "func_b", synthetic_embedding(2)),
+            sample_symbol("sym-c", "func_c", synthetic_embedding(90)),
+        ];
+        lake.store_symbols(&symbols).expect("store");
+
+        let results = lake
+            .find_similar_to_symbol("sym-a", 10, -1.0)
+            .expect("find similar");
+
+        assert_eq!(results.len(), 2, "should exclude the source symbol itself");
+        assert!(results.iter().all(|(row, _)| row.id != "sym-a"));
+        assert!(
+            results[0].1 >= results[1].1,
+            "results should be ordered by descending similarity"
+        );
+    }
+
+    #[test]
+    fn find_similar_to_symbol_applies_min_score_and_limit() {
+        let lake = ZenLake::open_in_memory().expect("open lake");
+
+        let symbols = vec![
+            sample_symbol("sym-a", "func_a", synthetic_embedding(1)),
+            sample_symbol("sym-b", "func_b", synthetic_embedding(2)),
+            sample_symbol("sym-c", "func_c", synthetic_embedding(90)),
+        ];
+        lake.store_symbols(&symbols).expect("store");
+
+        let results = lake
+            .find_similar_to_symbol("sym-a", 1, -1.0)
+            .expect("find similar");
+        assert_eq!(results.len(), 1, "limit should cap the result count");
+
+        let results = lake
+            .find_similar_to_symbol("sym-a", 10, 2.0)
+            .expect("find similar");
+        assert!(
+            results.is_empty(),
+            "an unreachable min_score should exclude every candidate"
+        );
+    }
+
+    #[test]
+    fn find_similar_to_symbol_errors_when_symbol_is_unknown() {
+        let lake = ZenLake::open_in_memory().expect("open lake");
+
+        let err = lake
+            .find_similar_to_symbol("sym-missing", 10, -1.0)
+            .expect_err("unknown symbol should error");
+        assert!(matches!(err, LakeError::Other(_)));
+    }
+
     #[test]
     fn delete_package() {
         let lake = ZenLake::open_in_memory().expect("open lake");
@@ -405,6 +584,65 @@ mod tests {
         assert_eq!(count, 0);
     }
 
+    #[test]
+    fn delete_symbols_for_file_only_removes_matching_file() {
+        let lake = ZenLake::open_in_memory().expect("open lake");
+
+        let mut symbols = vec![
+            sample_symbol("sym-a1", "a1", synthetic_embedding(1)),
+            sample_symbol("sym-a2", "a2", synthetic_embedding(2)),
+            sample_symbol("sym-a3", "a3", synthetic_embedding(3)),
+            sample_symbol("sym-b1", "b1", synthetic_embedding(4)),
+            sample_symbol("sym-b2", "b2", synthetic_embedding(5)),
+        ];
+        for symbol in &mut symbols[3..] {
+            symbol.file_path = "src/runtime/park.rs".to_string();
+        }
+        lake.store_symbols(&symbols).expect("store symbols");
+
+        let deleted = lake
+            .delete_symbols_for_file("rust", "tokio", "1.49.0", "src/runtime/mod.rs")
+            .expect("delete symbols for file");
+        assert_eq!(deleted, 3);
+
+        let count: i64 = lake
+            .conn()
+            .query_row("SELECT count(*) FROM api_symbols", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+
+        let remaining_file: String = lake
+            .conn()
+            .query_row("SELECT DISTINCT file_path FROM api_symbols", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(remaining_file, "src/runtime/park.rs");
+    }
+
+    #[test]
+    fn find_definition_returns_all_overloads_ordered_by_file_path() {
+        let lake = ZenLake::open_in_memory().expect("open lake");
+
+        let mut symbols = vec![
+            sample_symbol("sym-new-b", "new", synthetic_embedding(1)),
+            sample_symbol("sym-new-a", "new", synthetic_embedding(2)),
+            sample_symbol("sym-other", "other", synthetic_embedding(3)),
+        ];
+        symbols[0].file_path = "src/b.rs".to_string();
+        symbols[1].file_path = "src/a.rs".to_string();
+        lake.store_symbols(&symbols).expect("store symbols");
+
+        let locations = lake
+            .find_definition("rust", "tokio", "1.49.0", "new")
+            .expect("find definition");
+
+        assert_eq!(locations.len(), 2);
+        assert_eq!(locations[0].file_path, "src/a.rs");
+        assert_eq!(locations[1].file_path, "src/b.rs");
+        assert_eq!(locations[0].kind, "function");
+    }
+
     #[test]
     fn list_and_count_indexed_packages() {
         let lake = ZenLake::open_in_memory().expect("open lake");
@@ -499,6 +737,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn open_read_only_rejects_missing_file() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let db_path = tmpdir.path().join("missing.duckdb");
+
+        let error = ZenLake::open_read_only(db_path.to_str().unwrap())
+            .expect_err("read-only open of a missing file should fail");
+        assert!(matches!(error, LakeError::DuckDb(_)));
+    }
+
+    #[test]
+    fn open_read_only_can_query_but_not_write() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let db_path = tmpdir.path().join("test_lake.duckdb");
+        let db_str = db_path.to_str().unwrap();
+
+        {
+            let lake = ZenLake::open_local(db_str).expect("open file-backed lake");
+            lake.store_symbols(&[sample_symbol("sym-ro", "spawn", synthetic_embedding(1))])
+                .expect("store");
+        }
+
+        let lake = ZenLake::open_read_only(db_str).expect("open read-only lake");
+        let name: String = lake
+            .conn()
+            .query_row(
+                "SELECT name FROM api_symbols WHERE id = 'sym-ro'",
+                [],
+                |row| row.get(0),
+            )
+            .expect("read-only handle should be able to query");
+        assert_eq!(name, "spawn");
+
+        let error = lake
+            .store_symbols(&[sample_symbol("sym-ro2", "block_on", synthetic_embedding(1))])
+            .expect_err("write on a read-only handle should fail");
+        assert!(matches!(error, LakeError::DuckDb(_)));
+    }
+
     #[test]
     fn index_existence() {
         let lake = ZenLake::open_in_memory().expect("open lake");
@@ -569,6 +846,7 @@ mod tests {
                 language: Some("rust".to_string()),
                 size_bytes: 17,
                 line_count: 1,
+                content_hash: None,
             },
             SourceFile {
                 ecosystem: "rust".to_string(),
@@ -579,6 +857,7 @@ mod tests {
                 language: Some("rust".to_string()),
                 size_bytes: 20,
                 line_count: 1,
+                content_hash: None,
             },
         ];
 
@@ -654,6 +933,7 @@ mod tests {
             language: Some("rust".to_string()),
             size_bytes: 13,
             line_count: 1,
+            content_hash: None,
         }];
         store.store_source_files(&files).unwrap();
 
@@ -681,6 +961,7 @@ mod tests {
                 language: Some("rust".to_string()),
                 size_bytes: 12,
                 line_count: 1,
+                content_hash: None,
             }])
             .unwrap();
 
@@ -692,4 +973,56 @@ mod tests {
             .unwrap();
         assert_eq!(count, 0);
     }
+
+    #[test]
+    fn hash_store_reports_none_before_first_extraction() {
+        let store = SourceFileStore::open_in_memory().expect("open");
+        store
+            .store_source_files(&[SourceFile {
+                ecosystem: "rust".to_string(),
+                package: "tokio".to_string(),
+                version: "1.49.0".to_string(),
+                file_path: "src/lib.rs".to_string(),
+                content: "fn main() {}".to_string(),
+                language: Some("rust".to_string()),
+                size_bytes: 12,
+                line_count: 1,
+                content_hash: None,
+            }])
+            .unwrap();
+
+        let hash_store = SourceFileHashStore::new(&store, "rust", "tokio", "1.49.0");
+        assert!(hash_store.get("src/lib.rs").unwrap().is_none());
+    }
+
+    #[test]
+    fn hash_store_round_trips_hash_and_items() {
+        let store = SourceFileStore::open_in_memory().expect("open");
+        store
+            .store_source_files(&[SourceFile {
+                ecosystem: "rust".to_string(),
+                package: "tokio".to_string(),
+                version: "1.49.0".to_string(),
+                file_path: "src/lib.rs".to_string(),
+                content: "fn main() {}".to_string(),
+                language: Some("rust".to_string()),
+                size_bytes: 12,
+                line_count: 1,
+                content_hash: None,
+            }])
+            .unwrap();
+
+        let mut extractor =
+            SourceFileHashStore::new(&store, "rust", "tokio", "1.49.0").into_extractor();
+
+        let first = extractor
+            .extract_if_changed("src/lib.rs", "fn main() {}")
+            .unwrap();
+        assert!(matches!(first, zen_parser::ExtractionOutcome::Changed(_)));
+
+        let second = extractor
+            .extract_if_changed("src/lib.rs", "fn main() {}")
+            .unwrap();
+        assert!(matches!(second, zen_parser::ExtractionOutcome::Unchanged));
+    }
 }
@@ -128,7 +128,7 @@ impl ZenLake {
             "SELECT
                 id, ecosystem, package, version, file_path, kind, name,
                 signature, source, doc_comment, line_start, line_end,
-                visibility, is_async, is_unsafe, is_error_type, returns_result,
+                visibility, is_deprecated, is_async, is_unsafe, is_error_type, returns_result,
                 return_type, generics, attributes, metadata, embedding::VARCHAR
              FROM api_symbols
              WHERE ecosystem = ? AND package = ? AND version = ?",
@@ -137,7 +137,7 @@ impl ZenLake {
         let mut rows = stmt.query(duckdb::params![ecosystem, package, version])?;
         let mut out = Vec::new();
         while let Some(row) = rows.next()? {
-            let embedding = parse_embedding_sql(row.get::<_, Option<String>>(21)?)?;
+            let embedding = parse_embedding_sql(row.get::<_, Option<String>>(22)?)?;
             out.push(ApiSymbolRow {
                 id: row.get(0)?,
                 ecosystem: row.get(1)?,
@@ -152,14 +152,15 @@ impl ZenLake {
                 line_start: row.get(10)?,
                 line_end: row.get(11)?,
                 visibility: row.get(12)?,
-                is_async: row.get(13)?,
-                is_unsafe: row.get(14)?,
-                is_error_type: row.get(15)?,
-                returns_result: row.get(16)?,
-                return_type: row.get(17)?,
-                generics: row.get(18)?,
-                attributes: row.get(19)?,
-                metadata: row.get(20)?,
+                is_deprecated: row.get(13)?,
+                is_async: row.get(14)?,
+                is_unsafe: row.get(15)?,
+                is_error_type: row.get(16)?,
+                returns_result: row.get(17)?,
+                return_type: row.get(18)?,
+                generics: row.get(19)?,
+                attributes: row.get(20)?,
+                metadata: row.get(21)?,
                 embedding,
             });
         }
@@ -7,6 +7,15 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Default embedding dimension (fastembed default model), seeded into a
+/// freshly created lake's `lake_meta` row.
+///
+/// Each lake persists its own dimension in `lake_meta` rather than assuming
+/// this constant everywhere, so a lake built with a different-dimension
+/// model (e.g. 768) validates against the dimension it was actually built
+/// with. See [`crate::ZenLake::embedding_dim`].
+pub const EMBEDDING_DIM: usize = 384;
+
 // ── Table DDL (local cache.duckdb) ─────────────────────────────────────────
 
 /// Indexed packages tracking table.
@@ -47,6 +56,7 @@ CREATE TABLE IF NOT EXISTS api_symbols (
     line_start INTEGER,
     line_end INTEGER,
     visibility TEXT,
+    is_deprecated BOOLEAN DEFAULT FALSE,
     is_async BOOLEAN DEFAULT FALSE,
     is_unsafe BOOLEAN DEFAULT FALSE,
     is_error_type BOOLEAN DEFAULT FALSE,
@@ -93,6 +103,14 @@ CREATE INDEX IF NOT EXISTS idx_doc_chunks_pkg
     ON doc_chunks(ecosystem, package, version);
 ";
 
+/// Per-lake metadata — currently just the embedding dimension this lake was
+/// built with, seeded from [`EMBEDDING_DIM`] at creation time. A single row.
+pub const CREATE_LAKE_META: &str = "
+CREATE TABLE IF NOT EXISTS lake_meta (
+    embedding_dim INTEGER NOT NULL
+);
+";
+
 // ── Row structs ────────────────────────────────────────────────────────────
 
 /// A row in the `api_symbols` table. Used for insertion and query results.
@@ -128,6 +146,8 @@ pub struct ApiSymbolRow {
     pub line_end: Option<i32>,
     /// Visibility: `pub`, `pub(crate)`, `private`, `export`.
     pub visibility: Option<String>,
+    /// Whether the symbol is marked deprecated in its source language.
+    pub is_deprecated: bool,
     /// Whether the symbol is async.
     pub is_async: bool,
     /// Whether the symbol is unsafe.
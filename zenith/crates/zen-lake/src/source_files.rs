@@ -7,7 +7,9 @@
 //!
 //! Used by `znt grep` (Phase 4) to search source code content with Rust regex.
 
-use duckdb::{AccessMode, Config, Connection, params};
+use duckdb::{AccessMode, Config, Connection, OptionalExt, params};
+use zen_parser::types::ParsedItem;
+use zen_parser::{Extractor, HashStore};
 
 use crate::{LakeError, OpenMode};
 
@@ -22,6 +24,8 @@ CREATE TABLE IF NOT EXISTS source_files (
     language TEXT,
     size_bytes INTEGER,
     line_count INTEGER,
+    content_hash TEXT,
+    parsed_items_json TEXT,
     PRIMARY KEY (ecosystem, package, version, file_path)
 );
 CREATE INDEX IF NOT EXISTS idx_source_pkg
@@ -49,6 +53,8 @@ pub struct SourceFile {
     pub size_bytes: i32,
     /// Number of lines in the file.
     pub line_count: i32,
+    /// Content hash recorded by [`SourceFileHashStore`], if extraction has run for this file.
+    pub content_hash: Option<String>,
 }
 
 /// Manages source file storage in a separate `DuckDB` file.
@@ -121,7 +127,9 @@ impl SourceFileStore {
                 f.content,
                 f.language,
                 f.size_bytes,
-                f.line_count
+                f.line_count,
+                f.content_hash,
+                None::<String>
             ])?;
         }
         appender.flush()?;
@@ -162,3 +170,82 @@ impl SourceFileStore {
         &self.conn
     }
 }
+
+/// A [`zen_parser::HashStore`] backed by `source_files`'s `content_hash` and
+/// `parsed_items_json` columns, scoped to a single package version.
+///
+/// Rows must already exist (via [`SourceFileStore::store_source_files`])
+/// before `set` can persist a hash for them, since `content_hash` lives on
+/// the same row as the file's content rather than in a separate table.
+pub struct SourceFileHashStore<'a> {
+    store: &'a SourceFileStore,
+    ecosystem: String,
+    package: String,
+    version: String,
+}
+
+impl<'a> SourceFileHashStore<'a> {
+    /// Scope a hash store to `(ecosystem, package, version)` within `store`.
+    #[must_use]
+    pub fn new(store: &'a SourceFileStore, ecosystem: &str, package: &str, version: &str) -> Self {
+        Self {
+            store,
+            ecosystem: ecosystem.to_string(),
+            package: package.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    /// Wrap this store in a [`zen_parser::Extractor`] for incremental extraction.
+    #[must_use]
+    pub fn into_extractor(self) -> Extractor<Self> {
+        Extractor::new(self)
+    }
+}
+
+impl HashStore for SourceFileHashStore<'_> {
+    type Error = LakeError;
+
+    fn get(&self, file_path: &str) -> Result<Option<(String, Vec<ParsedItem>)>, Self::Error> {
+        let row: Option<(Option<String>, Option<String>)> = self
+            .store
+            .conn
+            .query_row(
+                "SELECT content_hash, parsed_items_json FROM source_files
+                 WHERE ecosystem = ? AND package = ? AND version = ? AND file_path = ?",
+                params![self.ecosystem, self.package, self.version, file_path],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        let Some((Some(hash), Some(items_json))) = row else {
+            return Ok(None);
+        };
+        let items: Vec<ParsedItem> =
+            serde_json::from_str(&items_json).map_err(|err| LakeError::Other(err.to_string()))?;
+        Ok(Some((hash, items)))
+    }
+
+    fn set(
+        &mut self,
+        file_path: &str,
+        hash: &str,
+        items: &[ParsedItem],
+    ) -> Result<(), Self::Error> {
+        let items_json =
+            serde_json::to_string(items).map_err(|err| LakeError::Other(err.to_string()))?;
+        self.store.conn.execute(
+            "UPDATE source_files SET content_hash = ?, parsed_items_json = ?
+             WHERE ecosystem = ? AND package = ? AND version = ? AND file_path = ?",
+            params![
+                hash,
+                items_json,
+                self.ecosystem,
+                self.package,
+                self.version,
+                file_path
+            ],
+        )?;
+        Ok(())
+    }
+}
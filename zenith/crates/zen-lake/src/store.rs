@@ -8,11 +8,37 @@
 //! `::FLOAT[]` cast is the validated approach (spike 0.4). For tables without
 //! embeddings (e.g., `source_files`), the Appender is used — see [`super::source_files`].
 
-use duckdb::params;
+use duckdb::{OptionalExt, params};
+use serde::{Deserialize, Serialize};
 
 use crate::schemas::{ApiSymbolRow, DocChunkRow};
 use crate::{LakeError, ZenLake};
 
+/// Validate that `embedding` is either empty (not yet embedded) or exactly
+/// `expected_dim` long — the dimension this lake was built with, from
+/// [`ZenLake::embedding_dim`] — returning [`LakeError::DimensionMismatch`]
+/// otherwise.
+fn check_embedding_dim(expected_dim: usize, embedding: &[f32]) -> Result<(), LakeError> {
+    if embedding.is_empty() || embedding.len() == expected_dim {
+        Ok(())
+    } else {
+        Err(LakeError::DimensionMismatch {
+            expected: expected_dim,
+            got: embedding.len(),
+        })
+    }
+}
+
+/// A single definition site for a symbol name, as returned by
+/// [`ZenLake::find_definition`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolLocation {
+    pub file_path: String,
+    pub line_start: Option<i32>,
+    pub line_end: Option<i32>,
+    pub kind: String,
+}
+
 /// Format a `Vec<f32>` as a `DuckDB` array literal string: `[0.1, 0.2, ...]`.
 fn vec_to_sql(v: &[f32]) -> String {
     use std::fmt::Write;
@@ -41,13 +67,20 @@ impl ZenLake {
     ///
     /// # Errors
     ///
+    /// Returns [`LakeError::DimensionMismatch`] if any symbol's embedding
+    /// isn't empty and doesn't match this lake's [`ZenLake::embedding_dim`].
     /// Returns [`LakeError::DuckDb`] if any INSERT fails.
     pub fn store_symbols(&self, symbols: &[ApiSymbolRow]) -> Result<(), LakeError> {
+        let expected_dim = self.embedding_dim()?;
+        for sym in symbols {
+            check_embedding_dim(expected_dim, &sym.embedding)?;
+        }
+
         let mut stmt = self.conn.prepare(
             "INSERT OR REPLACE INTO api_symbols (
                 id, ecosystem, package, version, file_path, kind, name,
                 signature, source, doc_comment, line_start, line_end,
-                visibility, is_async, is_unsafe, is_error_type, returns_result,
+                visibility, is_deprecated, is_async, is_unsafe, is_error_type, returns_result,
                 return_type, generics, attributes, metadata, embedding
             ) VALUES (
                 COALESCE(
@@ -62,7 +95,7 @@ impl ZenLake {
                 ?, ?, ?, ?, ?, ?,
                 ?, ?, ?, ?, ?,
                 ?, ?, ?, ?, ?,
-                ?, ?, ?, ?, ?::FLOAT[]
+                ?, ?, ?, ?, ?, ?::FLOAT[]
             )",
         )?;
 
@@ -97,15 +130,16 @@ impl ZenLake {
                 sym.line_start,     // 20
                 sym.line_end,       // 21
                 sym.visibility,     // 22
-                sym.is_async,       // 23
-                sym.is_unsafe,      // 24
-                sym.is_error_type,  // 25
-                sym.returns_result, // 26
-                sym.return_type,    // 27
-                sym.generics,       // 28
-                sym.attributes,     // 29
-                sym.metadata,       // 30
-                embedding_sql,      // 31
+                sym.is_deprecated,  // 23
+                sym.is_async,       // 24
+                sym.is_unsafe,      // 25
+                sym.is_error_type,  // 26
+                sym.returns_result, // 27
+                sym.return_type,    // 28
+                sym.generics,       // 29
+                sym.attributes,     // 30
+                sym.metadata,       // 31
+                embedding_sql,      // 32
             ])?;
         }
 
@@ -120,8 +154,15 @@ impl ZenLake {
     ///
     /// # Errors
     ///
+    /// Returns [`LakeError::DimensionMismatch`] if any chunk's embedding
+    /// isn't empty and doesn't match this lake's [`ZenLake::embedding_dim`].
     /// Returns [`LakeError::DuckDb`] if any INSERT fails.
     pub fn store_doc_chunks(&self, chunks: &[DocChunkRow]) -> Result<(), LakeError> {
+        let expected_dim = self.embedding_dim()?;
+        for chunk in chunks {
+            check_embedding_dim(expected_dim, &chunk.embedding)?;
+        }
+
         let mut stmt = self.conn.prepare(
             "INSERT OR REPLACE INTO doc_chunks (
                 id, ecosystem, package, version, chunk_index,
@@ -279,6 +320,65 @@ impl ZenLake {
         Ok(())
     }
 
+    /// Delete symbols for a single file within a package version.
+    ///
+    /// Used by incremental indexing to remove stale symbols from a modified
+    /// file before re-inserting the freshly parsed ones, without touching
+    /// symbols from other files in the same package.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LakeError::DuckDb`] if the DELETE fails.
+    pub fn delete_symbols_for_file(
+        &self,
+        ecosystem: &str,
+        package: &str,
+        version: &str,
+        file_path: &str,
+    ) -> Result<u64, LakeError> {
+        let deleted = self.conn.execute(
+            "DELETE FROM api_symbols WHERE ecosystem = ? AND package = ? AND version = ? AND file_path = ?",
+            params![ecosystem, package, version, file_path],
+        )?;
+        u64::try_from(deleted)
+            .map_err(|_| LakeError::Other("deleted row count overflow".to_string()))
+    }
+
+    /// Find definition locations for a symbol name within a package version.
+    ///
+    /// Returns one [`SymbolLocation`] per matching symbol, ordered by
+    /// `file_path`, so overloaded/shadowed names (e.g. a `new` on more than
+    /// one type) all come back rather than just the first match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LakeError::DuckDb`] if query execution fails.
+    pub fn find_definition(
+        &self,
+        ecosystem: &str,
+        package: &str,
+        version: &str,
+        symbol_name: &str,
+    ) -> Result<Vec<SymbolLocation>, LakeError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT file_path, line_start, line_end, kind
+             FROM api_symbols
+             WHERE ecosystem = ? AND package = ? AND version = ? AND name = ?
+             ORDER BY file_path ASC",
+        )?;
+
+        let rows = stmt.query_map(params![ecosystem, package, version, symbol_name], |row| {
+            Ok(SymbolLocation {
+                file_path: row.get(0)?,
+                line_start: row.get(1)?,
+                line_end: row.get(2)?,
+                kind: row.get(3)?,
+            })
+        })?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(LakeError::from)
+    }
+
     /// List all indexed package triplets (`ecosystem`, `package`, `version`).
     ///
     /// Sorted by ecosystem, package, then version.
@@ -317,6 +417,79 @@ impl ZenLake {
             .map_err(|_| LakeError::Other("indexed package count overflow".to_string()))
     }
 
+    /// List doc chunks for a package, ordered by `chunk_index` ascending.
+    ///
+    /// Returned rows carry an empty `embedding` -- the doc generation
+    /// pipeline this feeds wants chunk text and metadata, not the raw
+    /// vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LakeError::DuckDb`] if query execution fails.
+    pub fn list_doc_chunks_for_package(
+        &self,
+        ecosystem: &str,
+        package: &str,
+        version: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<DocChunkRow>, LakeError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, ecosystem, package, version, chunk_index, title, content,
+                    source_file, format
+             FROM doc_chunks
+             WHERE ecosystem = ? AND package = ? AND version = ?
+             ORDER BY chunk_index ASC
+             LIMIT ? OFFSET ?",
+        )?;
+
+        let rows = stmt.query_map(
+            params![
+                ecosystem,
+                package,
+                version,
+                i64::from(limit),
+                i64::from(offset)
+            ],
+            |row| {
+                Ok(DocChunkRow {
+                    id: row.get(0)?,
+                    ecosystem: row.get(1)?,
+                    package: row.get(2)?,
+                    version: row.get(3)?,
+                    chunk_index: row.get(4)?,
+                    title: row.get(5)?,
+                    content: row.get(6)?,
+                    source_file: row.get(7)?,
+                    format: row.get(8)?,
+                    embedding: Vec::new(),
+                })
+            },
+        )?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(LakeError::from)
+    }
+
+    /// Count doc chunks stored for a package.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LakeError::DuckDb`] if query execution fails, or
+    /// [`LakeError::Other`] if the count overflows `u64`.
+    pub fn count_doc_chunks_for_package(
+        &self,
+        ecosystem: &str,
+        package: &str,
+        version: &str,
+    ) -> Result<u64, LakeError> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM doc_chunks WHERE ecosystem = ? AND package = ? AND version = ?",
+            params![ecosystem, package, version],
+            |row| row.get(0),
+        )?;
+        u64::try_from(count).map_err(|_| LakeError::Other("doc chunk count overflow".to_string()))
+    }
+
     /// Clear all local lake tables.
     ///
     /// Deletes rows from `api_symbols`, `doc_chunks`, and `indexed_packages`.
@@ -330,4 +503,100 @@ impl ZenLake {
         self.conn.execute("DELETE FROM indexed_packages", [])?;
         Ok(())
     }
+
+    /// Find symbols whose embedding is most similar to `symbol_id`'s, for
+    /// similarity-expanded code navigation (e.g. "find related APIs").
+    ///
+    /// Excludes `symbol_id` itself, ranks by descending cosine similarity via
+    /// `array_cosine_similarity`, drops results below `min_score`, and caps
+    /// the result at `limit`. Returned rows carry an empty `embedding` — call
+    /// sites want the symbol metadata and score, not the raw vector back.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LakeError::Other`] if `symbol_id` has no stored embedding.
+    /// Returns [`LakeError::DuckDb`] if query execution fails.
+    pub fn find_similar_to_symbol(
+        &self,
+        symbol_id: &str,
+        limit: u32,
+        min_score: f64,
+    ) -> Result<Vec<(ApiSymbolRow, f64)>, LakeError> {
+        let has_embedding: Option<bool> = self
+            .conn
+            .query_row(
+                "SELECT embedding IS NOT NULL FROM api_symbols WHERE id = ?",
+                params![symbol_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        match has_embedding {
+            None => {
+                return Err(LakeError::Other(format!("symbol not found: {symbol_id}")));
+            }
+            Some(false) => {
+                return Err(LakeError::Other(format!(
+                    "symbol has no embedding: {symbol_id}"
+                )));
+            }
+            Some(true) => {}
+        }
+
+        let mut stmt = self.conn.prepare(
+            "WITH scored AS (
+                SELECT
+                    id, ecosystem, package, version, file_path, kind, name,
+                    signature, source, doc_comment, line_start, line_end,
+                    visibility, is_deprecated, is_async, is_unsafe, is_error_type,
+                    returns_result, return_type, generics, attributes, metadata,
+                    array_cosine_similarity(
+                        embedding::FLOAT[384],
+                        (SELECT embedding::FLOAT[384] FROM api_symbols WHERE id = ?)
+                    ) AS similarity
+                FROM api_symbols
+                WHERE id != ? AND embedding IS NOT NULL
+            )
+            SELECT * FROM scored
+            WHERE similarity >= ?
+            ORDER BY similarity DESC
+            LIMIT ?",
+        )?;
+
+        let rows = stmt.query_map(
+            params![symbol_id, symbol_id, min_score, i64::from(limit)],
+            |row| {
+                Ok((
+                    ApiSymbolRow {
+                        id: row.get(0)?,
+                        ecosystem: row.get(1)?,
+                        package: row.get(2)?,
+                        version: row.get(3)?,
+                        file_path: row.get(4)?,
+                        kind: row.get(5)?,
+                        name: row.get(6)?,
+                        signature: row.get(7)?,
+                        source: row.get(8)?,
+                        doc_comment: row.get(9)?,
+                        line_start: row.get(10)?,
+                        line_end: row.get(11)?,
+                        visibility: row.get(12)?,
+                        is_deprecated: row.get(13)?,
+                        is_async: row.get(14)?,
+                        is_unsafe: row.get(15)?,
+                        is_error_type: row.get(16)?,
+                        returns_result: row.get(17)?,
+                        return_type: row.get(18)?,
+                        generics: row.get(19)?,
+                        attributes: row.get(20)?,
+                        metadata: row.get(21)?,
+                        embedding: Vec::new(),
+                    },
+                    row.get(22)?,
+                ))
+            },
+        )?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(LakeError::from)
+    }
 }
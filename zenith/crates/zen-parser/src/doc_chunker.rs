@@ -69,6 +69,11 @@ pub struct DocChunk {
 
     /// Character length of `content`.
     pub char_len: usize,
+
+    /// Languages of fenced code blocks (```` ```rust ````) found in `content`,
+    /// in order of first appearance. Empty if the chunk has no fenced code, or
+    /// for non-markdown formats.
+    pub code_langs: Vec<String>,
 }
 
 /// Chunk a documentation file into embedding-ready pieces.
@@ -95,6 +100,29 @@ pub struct DocChunk {
 /// ```
 #[must_use]
 pub fn chunk_document(content: &str, source_file: &str) -> Vec<DocChunk> {
+    chunk_document_with_limits(content, source_file, MAX_CHUNK_CHARS, OVERLAP_CHARS)
+}
+
+/// Chunk a documentation file, overriding the default max chunk size and
+/// overlap (see [`chunk_document`] for the rest of the behavior).
+///
+/// `zen-cli`'s indexing pipeline uses this to apply
+/// `IndexConfig::chunk_token_budget`/`IndexConfig::chunk_overlap` instead of
+/// the built-in defaults.
+///
+/// # Arguments
+///
+/// * `content` — Full document text.
+/// * `source_file` — Relative file path (used for format detection and stored on chunks).
+/// * `max_chars` — Maximum chunk size in characters.
+/// * `overlap_chars` — Overlap in characters when sub-chunking oversized sections.
+#[must_use]
+pub fn chunk_document_with_limits(
+    content: &str,
+    source_file: &str,
+    max_chars: usize,
+    overlap_chars: usize,
+) -> Vec<DocChunk> {
     let format = detect_doc_format(source_file);
     let sections = match format.as_str() {
         "markdown" => split_markdown(content),
@@ -111,9 +139,16 @@ pub fn chunk_document(content: &str, source_file: &str) -> Vec<DocChunk> {
             continue;
         }
 
-        let sub_chunks = split_to_max_size(trimmed_body, section.byte_offset);
+        let sub_chunks =
+            split_to_max_size(trimmed_body, section.byte_offset, max_chars, overlap_chars);
 
         for sub in &sub_chunks {
+            let code_langs = if format == "markdown" {
+                extract_code_fence_languages(&sub.text)
+            } else {
+                Vec::new()
+            };
+
             chunks.push(DocChunk {
                 title: section.title.clone(),
                 section_path: section.path.clone(),
@@ -123,6 +158,7 @@ pub fn chunk_document(content: &str, source_file: &str) -> Vec<DocChunk> {
                 format: format.clone(),
                 byte_offset: sub.byte_offset,
                 char_len: sub.text.chars().count(),
+                code_langs,
             });
             chunk_index += 1;
         }
@@ -413,6 +449,38 @@ fn md_heading_level(raw: &str) -> Option<usize> {
     }
 }
 
+// ── Code fence language capture ──────────────────────────────
+
+/// Extract the languages of fenced code blocks (```` ```rust ````, `~~~python`)
+/// in `text`, in order of first appearance, deduplicated.
+fn extract_code_fence_languages(text: &str) -> Vec<String> {
+    let mut langs = Vec::new();
+    let mut in_fence = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let Some(rest) = trimmed
+            .strip_prefix("```")
+            .or_else(|| trimmed.strip_prefix("~~~"))
+        else {
+            continue;
+        };
+
+        if in_fence {
+            in_fence = false;
+            continue;
+        }
+        in_fence = true;
+
+        let lang = rest.split_whitespace().next().unwrap_or("");
+        if !lang.is_empty() && !langs.iter().any(|l: &String| l == lang) {
+            langs.push(lang.to_string());
+        }
+    }
+
+    langs
+}
+
 /// Extract clean title text from a raw heading node.
 fn md_heading_text(raw: &str) -> String {
     let first_line = raw.lines().next().unwrap_or_default().trim();
@@ -725,15 +793,20 @@ fn split_by_double_blanks(content: &str) -> Vec<Section> {
 
 // ── Sub-chunking with overlap ────────────────────────────────
 
-/// Split text into sub-chunks of at most `MAX_CHUNK_CHARS` characters,
-/// with `OVERLAP_CHARS` overlap when splitting is needed.
+/// Split text into sub-chunks of at most `max_chars` characters, with
+/// `overlap_chars` overlap when splitting is needed.
 ///
 /// Split points are chosen at the nearest paragraph break (`\n\n`) within
 /// the overlap zone, falling back to the nearest line break (`\n`), and
 /// finally to an exact position if no break is found.
-fn split_to_max_size(text: &str, base_byte_offset: usize) -> Vec<SubChunk> {
+fn split_to_max_size(
+    text: &str,
+    base_byte_offset: usize,
+    max_chars: usize,
+    overlap_chars: usize,
+) -> Vec<SubChunk> {
     let total_chars = text.chars().count();
-    if total_chars <= MAX_CHUNK_CHARS {
+    if total_chars <= max_chars {
         return vec![SubChunk {
             text: text.to_string(),
             byte_offset: base_byte_offset,
@@ -746,7 +819,7 @@ fn split_to_max_size(text: &str, base_byte_offset: usize) -> Vec<SubChunk> {
 
     while start < chars.len() {
         let remaining = chars.len() - start;
-        if remaining <= MAX_CHUNK_CHARS {
+        if remaining <= max_chars {
             let chunk_text: String = chars[start..].iter().collect();
             let byte_off = byte_offset_of_char_index(text, start);
             sub_chunks.push(SubChunk {
@@ -756,9 +829,9 @@ fn split_to_max_size(text: &str, base_byte_offset: usize) -> Vec<SubChunk> {
             break;
         }
 
-        let stride = MAX_CHUNK_CHARS.saturating_sub(OVERLAP_CHARS);
+        let stride = max_chars.saturating_sub(overlap_chars);
         let search_start = start + stride;
-        let search_end = (start + MAX_CHUNK_CHARS).min(chars.len());
+        let search_end = (start + max_chars).min(chars.len());
 
         let split_at = find_paragraph_break(&chars, search_start, search_end)
             .or_else(|| find_line_break(&chars, search_start, search_end))
@@ -773,7 +846,7 @@ fn split_to_max_size(text: &str, base_byte_offset: usize) -> Vec<SubChunk> {
 
         // Advance with overlap, ensuring forward progress
         let prev_start = start;
-        start = split_at.saturating_sub(OVERLAP_CHARS);
+        start = split_at.saturating_sub(overlap_chars);
         if start <= prev_start {
             start = split_at;
         }
@@ -1001,7 +1074,7 @@ Body 2.
     #[test]
     fn small_section_is_single_chunk() {
         let text = "Short content.";
-        let subs = split_to_max_size(text, 0);
+        let subs = split_to_max_size(text, 0, MAX_CHUNK_CHARS, OVERLAP_CHARS);
         assert_eq!(subs.len(), 1);
         assert_eq!(subs[0].text, "Short content.");
     }
@@ -1012,7 +1085,7 @@ Body 2.
         let content = paragraph.repeat(10);
         assert!(content.chars().count() > MAX_CHUNK_CHARS);
 
-        let subs = split_to_max_size(&content, 0);
+        let subs = split_to_max_size(&content, 0, MAX_CHUNK_CHARS, OVERLAP_CHARS);
         assert!(
             subs.len() >= 2,
             "expected multiple sub-chunks, got {}",
@@ -1041,7 +1114,7 @@ Body 2.
         let part2 = "Y".repeat(500);
         let content = format!("{part1}\n\n{part2}\n");
 
-        let subs = split_to_max_size(&content, 0);
+        let subs = split_to_max_size(&content, 0, MAX_CHUNK_CHARS, OVERLAP_CHARS);
         assert!(subs.len() >= 2);
 
         assert!(
@@ -1063,6 +1136,47 @@ Body 2.
         assert!(chunks[1].byte_offset > chunks[0].byte_offset);
     }
 
+    // ── Code fence language capture ───────────────────────────
+
+    #[test]
+    fn code_fence_languages_captured_per_chunk() {
+        let md = "\
+# Examples
+
+```rust
+fn main() {}
+```
+
+Some prose in between.
+
+```bash
+echo hi
+```
+";
+        let chunks = chunk_document(md, "guide.md");
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(
+            chunks[0].code_langs,
+            vec!["rust".to_string(), "bash".to_string()]
+        );
+    }
+
+    #[test]
+    fn chunk_without_code_fences_has_empty_langs() {
+        let md = "# Intro\n\nJust prose, no code.\n";
+        let chunks = chunk_document(md, "guide.md");
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].code_langs.is_empty());
+    }
+
+    #[test]
+    fn non_markdown_chunks_do_not_capture_code_langs() {
+        let txt = "```python\nprint(1)\n```\n";
+        let chunks = chunk_document(txt, "notes.txt");
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].code_langs.is_empty());
+    }
+
     // ── Char length tracking ─────────────────────────────────
 
     #[test]
@@ -1146,6 +1260,24 @@ Edit config.toml to set options.
         );
     }
 
+    #[test]
+    fn chunk_document_with_limits_honors_custom_max_chars() {
+        let section = "A".repeat(150) + "\n\n";
+        let md = format!("# Section\n\n{}", section.repeat(3));
+
+        let default_chunks = chunk_document(&md, "README.md");
+        assert_eq!(default_chunks.len(), 1);
+
+        let limited_chunks = chunk_document_with_limits(&md, "README.md", 200, 20);
+        assert!(
+            limited_chunks.len() > 1,
+            "expected a smaller max_chars to force sub-chunking"
+        );
+        for chunk in &limited_chunks {
+            assert!(chunk.char_len <= 200);
+        }
+    }
+
     // ── Smart text routing ───────────────────────────────────
 
     #[test]
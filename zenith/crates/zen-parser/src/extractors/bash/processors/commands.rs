@@ -54,6 +54,7 @@ fn process_alias<D: ast_grep_core::Doc>(
         .map_or_else(String::new, |(_, v)| v.to_string());
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Static,
         name: alias_name,
         signature: format!("alias {alias_def}"),
@@ -112,6 +113,7 @@ fn process_trap<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature,
@@ -150,6 +152,7 @@ fn process_source<D: ast_grep_core::Doc>(
         .map_or_else(|| "unknown".to_string(), |n| n.text().to_string());
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name: file_path.clone(),
         signature: format!("{command} {file_path}"),
@@ -42,6 +42,7 @@ pub(in super::super) fn process_if_statement<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Enum,
         name,
         signature,
@@ -98,6 +99,7 @@ pub(in super::super) fn process_case_statement<D: ast_grep_core::Doc>(
     let signature = format!("case {expr} in ... esac");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Enum,
         name,
         signature,
@@ -167,6 +169,7 @@ pub(in super::super) fn process_for_statement<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature,
@@ -205,6 +208,7 @@ pub(in super::super) fn process_while_statement<D: ast_grep_core::Doc>(
     let signature = format!("{keyword} {condition_short}; do ... done");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name,
         signature,
@@ -239,6 +243,7 @@ pub(in super::super) fn process_c_style_for<D: ast_grep_core::Doc>(
     let signature = format!("{name}; do ... done");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: truncate_text(&name, 80),
         signature,
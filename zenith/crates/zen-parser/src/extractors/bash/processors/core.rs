@@ -13,6 +13,7 @@ pub(in super::super) fn process_shebang<D: ast_grep_core::Doc>(
     let interpreter = text.trim_start_matches("#!").trim().to_string();
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: "shebang".to_string(),
         signature: text.clone(),
@@ -57,6 +58,7 @@ pub(in super::super) fn process_function<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature,
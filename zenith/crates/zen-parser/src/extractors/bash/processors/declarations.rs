@@ -39,6 +39,7 @@ pub(in super::super) fn process_variable_assignment<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind,
         name: var_name,
         signature,
@@ -125,6 +126,7 @@ fn process_export_declaration<D: ast_grep_core::Doc>(
         let signature = format!("export {flag} {target}");
 
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Const,
             name: target,
             signature,
@@ -149,6 +151,7 @@ fn process_export_declaration<D: ast_grep_core::Doc>(
         // Plain `export VAR` without assignment — still emit
         if let Some(word) = children.iter().find(|c| c.kind().as_ref() == "word") {
             items.push(ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Const,
                 name: word.text().to_string(),
                 signature: node.text().to_string(),
@@ -237,6 +240,7 @@ fn process_declare_command<D: ast_grep_core::Doc>(
         }
 
         items.push(ParsedItem {
+            is_deprecated: false,
             kind,
             name: var_name,
             signature,
@@ -38,6 +38,7 @@ pub(in super::super) fn process_pipeline<D: ast_grep_core::Doc>(
     let sig_short = truncate_text(&signature, 80);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name,
         signature: sig_short,
@@ -62,6 +63,7 @@ pub(in super::super) fn process_subshell<D: ast_grep_core::Doc>(
     let short = truncate_text(&text.replace('\n', " "), 60);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: format!("subshell {short}"),
         signature: short,
@@ -85,6 +87,7 @@ pub(in super::super) fn process_command_group<D: ast_grep_core::Doc>(
     let short = truncate_text(&text.replace('\n', " "), 60);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: format!("command_group {short}"),
         signature: short,
@@ -152,6 +155,7 @@ pub(in super::super) fn process_redirected_statement<D: ast_grep_core::Doc>(
     let signature = format!("{cmd} {operator}{delimiter}");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Const,
         name,
         signature,
@@ -177,6 +181,7 @@ pub(in super::super) fn process_negated_command<D: ast_grep_core::Doc>(
     let short = truncate_text(inner, 60);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: format!("! {short}"),
         signature: truncate_text(&text, 80),
@@ -201,6 +206,7 @@ pub(in super::super) fn process_test_command<D: ast_grep_core::Doc>(
     let short = truncate_text(&text, 60);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: format!("test {short}"),
         signature: text.clone(),
@@ -249,6 +255,7 @@ pub(in super::super) fn process_unset_command<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind,
         name: format!("unset {target}"),
         signature: node.text().to_string(),
@@ -275,6 +282,7 @@ pub(in super::super) fn process_list<D: ast_grep_core::Doc>(
     let short = truncate_text(&normalized, 80);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: truncate_text(&normalized, 60),
         signature: short,
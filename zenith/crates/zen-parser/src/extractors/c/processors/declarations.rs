@@ -10,7 +10,8 @@ use super::core::{
 };
 use super::helpers::{
     extract_array_declarator_name, extract_declarator_name, extract_init_declarator_name,
-    extract_parameters, extract_pointer_declarator_name, extract_return_type,
+    extract_kr_parameter_names, extract_kr_parameters, extract_parameters,
+    extract_pointer_declarator_name, extract_return_type, is_kr_style_parameter_list,
 };
 use super::{extract_signature, extract_source_limited};
 
@@ -41,8 +42,22 @@ pub(super) fn process_function_definition<D: ast_grep_core::Doc>(
     // Extract return type
     let return_type = extract_return_type(&children);
 
-    // Extract parameters
-    let parameters = extract_parameters(func_decl);
+    // Extract parameters. K&R-style definitions (`add(a, b)\n    int a;\n    int
+    // b;\n{ ... }`) put bare names in the declarator and declare their types in
+    // `declaration` nodes between the declarator and the body, instead of typed
+    // `parameter_declaration`s inside the parameter list.
+    let parameters = if is_kr_style_parameter_list(func_decl) {
+        let kr_declarations: Vec<_> = children
+            .iter()
+            .skip_while(|c| c.kind().as_ref() != "function_declarator")
+            .skip(1)
+            .take_while(|c| c.kind().as_ref() == "declaration")
+            .cloned()
+            .collect();
+        extract_kr_parameters(&extract_kr_parameter_names(func_decl), &kr_declarations)
+    } else {
+        extract_parameters(func_decl)
+    };
 
     // Check for variadic
     let is_variadic = func_decl.text().as_ref().contains("...");
@@ -58,6 +73,7 @@ pub(super) fn process_function_definition<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature: extract_signature(node),
@@ -129,6 +145,7 @@ pub(super) fn process_declaration<D: ast_grep_core::Doc>(
                     metadata.push_attribute("array");
                 }
                 items.push(ParsedItem {
+                    is_deprecated: false,
                     kind,
                     name,
                     signature: extract_signature(node),
@@ -173,7 +190,10 @@ pub(super) fn process_declaration<D: ast_grep_core::Doc>(
         return;
     }
 
-    // Plain identifier declarations: `extern int shared;` or `int x, y, z;`
+    // Plain identifier declarations: `extern int shared;` or `int x, y, z;`.
+    // A trailing `gnu_asm_expression` register binding (`register int x asm("eax");`)
+    // is a sibling here, not a declarator — it's skipped by the `identifier` filter
+    // below rather than being misread as part of the declared name or type.
     let identifiers: Vec<_> = children
         .iter()
         .filter(|c| c.kind().as_ref() == "identifier")
@@ -184,6 +204,7 @@ pub(super) fn process_declaration<D: ast_grep_core::Doc>(
         let (kind, visibility) = classify_variable(&q);
 
         items.push(ParsedItem {
+            is_deprecated: false,
             kind,
             name,
             signature: extract_signature(node),
@@ -250,6 +271,7 @@ fn process_function_prototype<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature: extract_signature(node),
@@ -294,6 +316,7 @@ fn process_array_declaration<D: ast_grep_core::Doc>(
     metadata.push_attribute("array");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature: extract_signature(node),
@@ -330,6 +353,7 @@ fn process_pointer_variable<D: ast_grep_core::Doc>(
     let (kind, visibility) = classify_variable(q);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature: extract_signature(node),
@@ -366,6 +390,7 @@ fn process_function_pointer_var<D: ast_grep_core::Doc>(
     metadata.push_attribute("function_pointer");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Static,
         name,
         signature: extract_signature(node),
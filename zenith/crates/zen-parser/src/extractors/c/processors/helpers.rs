@@ -123,3 +123,63 @@ pub(super) fn extract_parameters<D: ast_grep_core::Doc>(func_decl: &Node<D>) ->
         })
         .collect()
 }
+
+/// Whether `func_decl`'s `parameter_list` is old-style K&R (bare identifiers,
+/// e.g. `add(a, b)`) rather than modern typed `parameter_declaration`s.
+pub(super) fn is_kr_style_parameter_list<D: ast_grep_core::Doc>(func_decl: &Node<D>) -> bool {
+    func_decl
+        .children()
+        .find(|c| c.kind().as_ref() == "parameter_list")
+        .is_some_and(|param_list| {
+            param_list
+                .children()
+                .any(|c| c.kind().as_ref() == "identifier")
+        })
+}
+
+/// Extract the bare parameter names from a K&R-style `parameter_list`.
+pub(super) fn extract_kr_parameter_names<D: ast_grep_core::Doc>(
+    func_decl: &Node<D>,
+) -> Vec<String> {
+    let Some(param_list) = func_decl
+        .children()
+        .find(|c| c.kind().as_ref() == "parameter_list")
+    else {
+        return Vec::new();
+    };
+
+    param_list
+        .children()
+        .filter(|c| c.kind().as_ref() == "identifier")
+        .map(|c| c.text().to_string())
+        .collect()
+}
+
+/// Build typed parameter strings for a K&R-style function definition by
+/// matching `names` against the `declaration` nodes that follow the
+/// declarator, e.g. `int add(a, b)\n    int a;\n    int b;\n{ ... }`. Names
+/// left undeclared default to implicit `int`, per K&R semantics.
+pub(super) fn extract_kr_parameters<D: ast_grep_core::Doc>(
+    names: &[String],
+    kr_declarations: &[Node<D>],
+) -> Vec<String> {
+    let mut types = std::collections::HashMap::new();
+    for decl in kr_declarations {
+        let decl_children: Vec<_> = decl.children().collect();
+        let type_text = extract_return_type(&decl_children).unwrap_or_else(|| "int".to_string());
+        for id in decl_children
+            .iter()
+            .filter(|c| c.kind().as_ref() == "identifier")
+        {
+            types.insert(id.text().to_string(), type_text.clone());
+        }
+    }
+
+    names
+        .iter()
+        .map(|name| {
+            let ty = types.get(name).map_or("int", String::as_str);
+            format!("{ty} {name}")
+        })
+        .collect()
+}
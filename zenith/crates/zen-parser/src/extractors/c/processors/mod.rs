@@ -227,6 +227,5 @@ fn extract_signature<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
         (None, Some(s)) => s,
         (None, None) => text.len(),
     };
-    let sig = text[..end].trim();
-    sig.split_whitespace().collect::<Vec<_>>().join(" ")
+    crate::extractors::helpers::normalize_signature(&text[..end], "c")
 }
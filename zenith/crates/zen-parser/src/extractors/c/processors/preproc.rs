@@ -46,6 +46,7 @@ pub(super) fn process_preproc_include<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name: path,
         signature: node.text().to_string().trim().to_string(),
@@ -97,6 +98,7 @@ pub(super) fn process_preproc_def<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature,
@@ -156,6 +158,7 @@ pub(super) fn process_preproc_function_def<D: ast_grep_core::Doc>(
         });
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name,
         signature,
@@ -196,6 +199,7 @@ pub(super) fn process_preproc_ifdef<D: ast_grep_core::Doc>(
     let directive = if is_ifndef { "#ifndef" } else { "#ifdef" };
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: condition_name.clone(),
         signature: format!("{directive} {condition_name}"),
@@ -252,6 +256,7 @@ pub(super) fn process_preproc_if<D: ast_grep_core::Doc>(
 
     if !condition.is_empty() {
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Macro,
             name: condition.clone(),
             signature: format!("#if {condition}"),
@@ -316,6 +321,7 @@ fn process_preproc_elif<D: ast_grep_core::Doc>(
 
     if !condition.is_empty() {
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Macro,
             name: condition.clone(),
             signature: format!("#elif {condition}"),
@@ -445,6 +451,7 @@ pub(super) fn process_preproc_call<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name,
         signature,
@@ -467,8 +474,19 @@ pub(super) fn process_expression_statement<D: ast_grep_core::Doc>(
     items: &mut Vec<ParsedItem>,
     doc_comment: &str,
 ) {
-    // Look for call_expression with _Static_assert
     let children: Vec<_> = node.children().collect();
+
+    // Top-level inline assembly (`asm("nop");`, `__asm__ volatile (...);`) parses
+    // as a gnu_asm_expression, not a call_expression — skip it explicitly rather
+    // than letting it fall through unnoticed, since it never denotes a symbol.
+    if children
+        .iter()
+        .any(|c| c.kind().as_ref() == "gnu_asm_expression")
+    {
+        return;
+    }
+
+    // Look for call_expression with _Static_assert
     let Some(call) = children
         .iter()
         .find(|c| c.kind().as_ref() == "call_expression")
@@ -486,6 +504,7 @@ pub(super) fn process_expression_statement<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: "_Static_assert".to_string(),
         signature: node
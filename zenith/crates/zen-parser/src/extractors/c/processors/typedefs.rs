@@ -108,6 +108,7 @@ fn push_simple_typedef_alias<D: ast_grep_core::Doc>(
     name: String,
 ) {
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::TypeAlias,
         name,
         signature: extract_signature(node),
@@ -136,6 +137,7 @@ fn process_typedef_struct<D: ast_grep_core::Doc>(
         .map_or_else(Vec::new, |s| extract_struct_fields(s));
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Struct,
         name: name.to_string(),
         signature: extract_signature(node),
@@ -165,6 +167,7 @@ fn process_typedef_enum<D: ast_grep_core::Doc>(
         .map_or_else(Vec::new, |e| extract_enum_variants(e));
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Enum,
         name: name.to_string(),
         signature: extract_signature(node),
@@ -194,6 +197,7 @@ fn process_typedef_union<D: ast_grep_core::Doc>(
         .map_or_else(Vec::new, |u| extract_struct_fields(u));
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Union,
         name: name.to_string(),
         signature: extract_signature(node),
@@ -217,6 +221,7 @@ fn process_typedef_function_pointer<D: ast_grep_core::Doc>(
     name: &str,
 ) {
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::TypeAlias,
         name: name.to_string(),
         signature: extract_signature(node),
@@ -256,6 +261,7 @@ pub(super) fn process_top_level_struct<D: ast_grep_core::Doc>(
     if has_body {
         let fields = extract_struct_fields(node);
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Struct,
             name,
             signature: extract_signature(node),
@@ -272,6 +278,7 @@ pub(super) fn process_top_level_struct<D: ast_grep_core::Doc>(
     } else {
         // Forward declaration: struct Foo;
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Struct,
             name,
             signature: format!(
@@ -308,6 +315,7 @@ pub(super) fn process_top_level_union<D: ast_grep_core::Doc>(
     let fields = extract_struct_fields(node);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Union,
         name,
         signature: extract_signature(node),
@@ -344,6 +352,7 @@ pub(super) fn process_top_level_enum<D: ast_grep_core::Doc>(
     if has_body {
         let variants = extract_enum_variants(node);
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Enum,
             name,
             signature: extract_signature(node),
@@ -360,6 +369,7 @@ pub(super) fn process_top_level_enum<D: ast_grep_core::Doc>(
     } else {
         // Forward declaration: enum Foo;
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Enum,
             name,
             signature: format!("enum {}", node.text().as_ref().trim_end_matches(';').trim()),
@@ -0,0 +1,71 @@
+use super::*;
+
+// ── K&R function definitions and inline assembly guards ─────────
+
+#[test]
+fn kr_function_extracted_as_function() {
+    let source = include_str!("../../../../tests/fixtures/sample.c");
+    let items = parse_and_extract(source);
+    let kr_add = items
+        .iter()
+        .find(|i| i.name == "kr_add")
+        .expect("should find kr_add");
+    assert_eq!(kr_add.kind, SymbolKind::Function);
+}
+
+#[test]
+fn kr_function_parameters_collected_from_trailing_declarations() {
+    let source = include_str!("../../../../tests/fixtures/sample.c");
+    let items = parse_and_extract(source);
+    let kr_add = items
+        .iter()
+        .find(|i| i.name == "kr_add")
+        .expect("should find kr_add");
+    assert_eq!(
+        kr_add.metadata.parameters,
+        vec!["int a".to_string(), "int b".to_string()],
+        "K&R parameter types should come from the trailing declarations, not the empty parameter list"
+    );
+}
+
+#[test]
+fn kr_function_implicit_int_parameter() {
+    // `b` has no matching trailing declaration, so it falls back to implicit `int`.
+    let items = parse_and_extract("int legacy(a, b)\n    int a;\n{\n    return a + b;\n}\n");
+    let legacy = items
+        .iter()
+        .find(|i| i.name == "legacy")
+        .expect("should find legacy");
+    assert_eq!(
+        legacy.metadata.parameters,
+        vec!["int a".to_string(), "int b".to_string()]
+    );
+}
+
+#[test]
+fn top_level_inline_asm_produces_no_item() {
+    let source = include_str!("../../../../tests/fixtures/sample.c");
+    let items = parse_and_extract(source);
+    assert!(
+        items.iter().all(|i| !i.signature.contains("GNU-stack")),
+        "top-level inline assembly should not be extracted as a symbol"
+    );
+}
+
+#[test]
+fn top_level_inline_asm_inline_source() {
+    let items = parse_and_extract("__asm__(\"nop\");\n");
+    assert!(
+        items.is_empty(),
+        "a bare top-level asm statement should not produce any item, got {items:?}"
+    );
+}
+
+#[test]
+fn register_asm_binding_extracts_plain_variable() {
+    let items = parse_and_extract("register int counter asm(\"eax\");\n");
+    assert_eq!(items.len(), 1, "should extract exactly one item: {items:?}");
+    let counter = &items[0];
+    assert_eq!(counter.name, "counter");
+    assert_eq!(counter.kind, SymbolKind::Static);
+}
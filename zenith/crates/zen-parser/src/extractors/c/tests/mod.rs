@@ -27,6 +27,7 @@ mod gap_6_extended_c11_qualifier_variations;
 mod gap_7_anonymous_struct_union_in_fields;
 mod gap_7_extended_anonymous_aggregates;
 mod inline_edge_case_tests;
+mod kr_and_asm_tests;
 mod line_number_tests;
 mod pointer_to_pointer;
 mod preprocessor_tests;
@@ -75,6 +75,7 @@ pub(super) fn process_c_function_definition<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature: extract_signature(node),
@@ -109,6 +110,7 @@ pub(super) fn process_c_declaration<D: ast_grep_core::Doc>(
             metadata.push_attribute("prototype");
 
             items.push(ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Function,
                 name,
                 signature: extract_signature(node),
@@ -150,6 +152,7 @@ pub(super) fn process_c_declaration<D: ast_grep_core::Doc>(
         metadata.set_return_type(return_type);
 
         items.push(ParsedItem {
+            is_deprecated: false,
             kind,
             name,
             signature: extract_signature(node),
@@ -167,6 +170,7 @@ pub(super) fn process_c_declaration<D: ast_grep_core::Doc>(
         for child in &children {
             if child.kind().as_ref() == "identifier" {
                 items.push(ParsedItem {
+                    is_deprecated: false,
                     kind: SymbolKind::Static,
                     name: child.text().to_string(),
                     signature: extract_signature(node),
@@ -205,6 +209,7 @@ pub(super) fn process_c_struct<D: ast_grep_core::Doc>(
         metadata.set_methods(methods);
 
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Struct,
             name,
             signature: extract_signature(node),
@@ -238,6 +243,7 @@ pub(super) fn process_c_enum<D: ast_grep_core::Doc>(
         metadata.push_attribute("scoped_enum");
     }
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Enum,
         name,
         signature: extract_signature(node),
@@ -268,6 +274,7 @@ pub(super) fn process_c_typedef<D: ast_grep_core::Doc>(
     metadata.push_attribute("typedef");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::TypeAlias,
         name,
         signature: extract_signature(node),
@@ -297,6 +304,7 @@ pub(super) fn process_c_union<D: ast_grep_core::Doc>(
     metadata.set_fields(fields);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Union,
         name,
         signature: extract_signature(node),
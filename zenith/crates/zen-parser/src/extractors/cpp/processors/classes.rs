@@ -7,16 +7,24 @@ use crate::types::{CppMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visib
 use super::c_nodes::{process_c_enum, process_c_struct};
 use super::helpers::find_identifier_recursive;
 use super::templates::process_template_declaration;
-use super::{extract_signature, extract_source_limited, process_alias_declaration};
+use super::{
+    extract_signature, extract_source_limited, join_owner_path, process_alias_declaration,
+};
 
 // ── Class processing ───────────────────────────────────────────────
 
+/// Process a `class_specifier`. `owner_path` is the fully qualified path
+/// (namespace chain + enclosing classes joined with `::`) of the scope this
+/// class is nested in, or `None` at file scope — it's prepended to the
+/// class's own name to qualify the `owner_name` of its members and any
+/// further-nested types.
 #[allow(clippy::too_many_lines)]
 pub(super) fn process_class<D: ast_grep_core::Doc>(
     node: &Node<D>,
     items: &mut Vec<ParsedItem>,
     doc_comment: &str,
     template_params: Option<&str>,
+    owner_path: Option<&str>,
 ) {
     let children: Vec<_> = node.children().collect();
 
@@ -68,6 +76,7 @@ pub(super) fn process_class<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Class,
         name: name.clone(),
         signature: extract_signature(node),
@@ -79,16 +88,18 @@ pub(super) fn process_class<D: ast_grep_core::Doc>(
         metadata,
     });
 
-    emit_class_member_items(node, &name, items);
+    let qualified_name = join_owner_path(owner_path, &name);
+    emit_class_member_items(node, &name, &qualified_name, items);
 
     // Emit nested types (nested classes, structs, enums, aliases) as
     // separate ParsedItems.
-    extract_nested_types(node, items);
+    extract_nested_types(node, items, &qualified_name);
 }
 
 fn emit_class_member_items<D: ast_grep_core::Doc>(
     node: &Node<D>,
     class_name: &str,
+    owner_path: &str,
     items: &mut Vec<ParsedItem>,
 ) {
     let Some(body) = node
@@ -113,7 +124,15 @@ fn emit_class_member_items<D: ast_grep_core::Doc>(
             }
             "function_definition" => {
                 if let Some(name) = extract_method_name(&child) {
-                    push_cpp_member_item(items, class_name, &name, &current_access, &child, true);
+                    push_cpp_member_item(
+                        items,
+                        class_name,
+                        owner_path,
+                        &name,
+                        &current_access,
+                        &child,
+                        true,
+                    );
                 }
             }
             "field_declaration" => {
@@ -127,6 +146,7 @@ fn emit_class_member_items<D: ast_grep_core::Doc>(
                         push_cpp_member_item(
                             items,
                             class_name,
+                            owner_path,
                             &name,
                             &current_access,
                             &child,
@@ -139,6 +159,7 @@ fn emit_class_member_items<D: ast_grep_core::Doc>(
                             push_cpp_member_item(
                                 items,
                                 class_name,
+                                owner_path,
                                 fc.text().as_ref(),
                                 &current_access,
                                 &child,
@@ -153,9 +174,11 @@ fn emit_class_member_items<D: ast_grep_core::Doc>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn push_cpp_member_item<D: ast_grep_core::Doc>(
     items: &mut Vec<ParsedItem>,
     class_name: &str,
+    owner_path: &str,
     member_name: &str,
     visibility: &Visibility,
     node: &Node<D>,
@@ -177,13 +200,15 @@ fn push_cpp_member_item<D: ast_grep_core::Doc>(
     };
 
     let metadata = SymbolMetadata {
-        owner_name: Some(class_name.to_string()),
+        owner_name: Some(owner_path.to_string()),
         owner_kind: Some(SymbolKind::Class),
+        owner_local_name: Some(class_name.to_string()),
         is_static_member: node.children().any(|c| c.text().as_ref() == "static"),
         ..Default::default()
     };
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind,
         name: format!("{class_name}::{simple_name}"),
         signature: extract_signature(node),
@@ -198,7 +223,13 @@ fn push_cpp_member_item<D: ast_grep_core::Doc>(
 
 /// Walk a class/struct body and emit separate `ParsedItem`s for nested
 /// type definitions (classes, structs, enums, aliases, templates).
-fn extract_nested_types<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<ParsedItem>) {
+/// `owner_path` is this class's own fully qualified path, passed down so
+/// nested classes can qualify their members' `owner_name` in turn.
+fn extract_nested_types<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    items: &mut Vec<ParsedItem>,
+    owner_path: &str,
+) {
     let Some(body) = node
         .children()
         .find(|c| c.kind().as_ref() == "field_declaration_list")
@@ -206,7 +237,7 @@ fn extract_nested_types<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<P
         return;
     };
     for child in body.children() {
-        dispatch_nested_type(&child, items);
+        dispatch_nested_type(&child, items, owner_path);
     }
 }
 
@@ -214,10 +245,14 @@ fn extract_nested_types<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<P
 /// appropriate nested-type handler.  Also handles `field_declaration`
 /// nodes that wrap type specifiers (tree-sitter-cpp wraps nested
 /// `enum class E { … };` inside a `field_declaration`).
-fn dispatch_nested_type<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<ParsedItem>) {
+fn dispatch_nested_type<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    items: &mut Vec<ParsedItem>,
+    owner_path: &str,
+) {
     match node.kind().as_ref() {
         "class_specifier" => {
-            process_class(node, items, "", None);
+            process_class(node, items, "", None, Some(owner_path));
         }
         "struct_specifier" => {
             process_c_struct(node, items, "");
@@ -229,7 +264,7 @@ fn dispatch_nested_type<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<P
             process_alias_declaration(node, items, "");
         }
         "template_declaration" => {
-            process_template_declaration(node, items, "", "");
+            process_template_declaration(node, items, "", "", Some(owner_path));
         }
         "field_declaration" => {
             // field_declaration may wrap a nested type specifier, e.g.:
@@ -242,7 +277,7 @@ fn dispatch_nested_type<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<P
                     || k.as_ref() == "struct_specifier"
                     || k.as_ref() == "union_specifier"
                 {
-                    dispatch_nested_type(&inner, items);
+                    dispatch_nested_type(&inner, items, owner_path);
                 }
             }
         }
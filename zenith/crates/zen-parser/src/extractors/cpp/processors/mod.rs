@@ -59,30 +59,39 @@ fn collect_cpp_nodes<D: ast_grep_core::Doc>(
 ) {
     let children: Vec<_> = node.children().collect();
     for (idx, child) in children.iter().enumerate() {
-        dispatch_cpp_node(child, &children, idx, items, source);
+        dispatch_cpp_node(child, &children, idx, items, source, None);
     }
 }
 
+/// Join an enclosing scope's fully qualified path with a nested name,
+/// e.g. `join_owner_path(Some("Outer"), "Inner")` -> `"Outer::Inner"`.
+/// Used to propagate namespace chains and enclosing classes into member
+/// `owner_name` metadata.
+pub(super) fn join_owner_path(owner_path: Option<&str>, name: &str) -> String {
+    owner_path.map_or_else(|| name.to_string(), |p| format!("{p}::{name}"))
+}
+
 fn dispatch_cpp_node<D: ast_grep_core::Doc>(
     child: &Node<D>,
     siblings: &[Node<D>],
     idx: usize,
     items: &mut Vec<ParsedItem>,
     source: &str,
+    owner_path: Option<&str>,
 ) {
     let kind = child.kind();
     match kind.as_ref() {
         "namespace_definition" => {
             let doc = collect_doc_comment(siblings, idx, source);
-            process_namespace(child, items, source, &doc);
+            process_namespace(child, items, source, &doc, owner_path);
         }
         "class_specifier" => {
             let doc = collect_doc_comment(siblings, idx, source);
-            process_class(child, items, &doc, None);
+            process_class(child, items, &doc, None, owner_path);
         }
         "template_declaration" => {
             let doc = collect_doc_comment(siblings, idx, source);
-            process_template_declaration(child, items, source, &doc);
+            process_template_declaration(child, items, source, &doc, owner_path);
         }
         "alias_declaration" => {
             let doc = collect_doc_comment(siblings, idx, source);
@@ -117,7 +126,7 @@ fn dispatch_cpp_node<D: ast_grep_core::Doc>(
             let inner_children: Vec<_> = child.children().collect();
             for ic in &inner_children {
                 if ic.kind().as_ref() != "attribute_declaration" {
-                    dispatch_cpp_node(ic, siblings, idx, items, source);
+                    dispatch_cpp_node(ic, siblings, idx, items, source, owner_path);
                     dispatch_c_node(ic, siblings, idx, items, source);
                 }
             }
@@ -138,9 +147,11 @@ fn dispatch_cpp_node<D: ast_grep_core::Doc>(
     }
 }
 
-/// Emit a `ParsedItem` for top-level operator function definitions that
-/// the C extractor could not name (user-defined literals, free operator
-/// overloads).  Skips functions already present in `items` by start line.
+/// Emit a `ParsedItem` for top-level operator function definitions and
+/// out-of-class qualified method definitions (`void Outer::Inner::f() {}`)
+/// that the C extractor could not name, since its declarator-name lookup
+/// only handles plain `identifier`s, not `qualified_identifier`s.  Skips
+/// functions already present in `items` by start line.
 fn maybe_process_operator_function<D: ast_grep_core::Doc>(
     node: &Node<D>,
     siblings: &[Node<D>],
@@ -180,7 +191,23 @@ fn maybe_process_operator_function<D: ast_grep_core::Doc>(
     metadata.set_parameters(parameters);
     metadata.push_attribute("operator");
 
+    // Out-of-class method definition (`void Outer::Inner::f() {}`): the
+    // qualified_identifier declarator name carries the enclosing owner
+    // path, so resolve it to the same owner_name/owner_local_name an
+    // in-class member of Outer::Inner would get.
+    if let Some((owner, _member)) = name.rsplit_once("::") {
+        metadata.owner_name = Some(owner.to_string());
+        metadata.owner_kind = Some(SymbolKind::Class);
+        metadata.owner_local_name = Some(
+            owner
+                .rsplit_once("::")
+                .map_or(owner, |(_, local)| local)
+                .to_string(),
+        );
+    }
+
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature: extract_signature(node),
@@ -200,6 +227,7 @@ fn process_namespace<D: ast_grep_core::Doc>(
     items: &mut Vec<ParsedItem>,
     source: &str,
     doc_comment: &str,
+    owner_path: Option<&str>,
 ) {
     let children: Vec<_> = node.children().collect();
 
@@ -226,6 +254,7 @@ fn process_namespace<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name: name.clone(),
         signature: if is_inline {
@@ -253,10 +282,18 @@ fn process_namespace<D: ast_grep_core::Doc>(
         return;
     };
 
+    let qualified_name = join_owner_path(owner_path, &name);
     let inner_children: Vec<_> = decl_list.children().collect();
     for (idx, child) in inner_children.iter().enumerate() {
         // Dispatch both C-style and C++ nodes inside namespaces
-        dispatch_cpp_node(child, &inner_children, idx, items, source);
+        dispatch_cpp_node(
+            child,
+            &inner_children,
+            idx,
+            items,
+            source,
+            Some(&qualified_name),
+        );
         dispatch_c_node(child, &inner_children, idx, items, source);
     }
 }
@@ -278,6 +315,7 @@ fn process_namespace_alias<D: ast_grep_core::Doc>(
     metadata.push_attribute("namespace_alias");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature: extract_signature(node),
@@ -368,6 +406,7 @@ fn process_alias_declaration<D: ast_grep_core::Doc>(
     metadata.push_attribute("using");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::TypeAlias,
         name,
         signature: extract_signature(node),
@@ -407,9 +446,10 @@ fn process_using_declaration<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut
     metadata.push_attribute(attr);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
-        signature: node.text().to_string().trim().to_string(),
+        signature: crate::extractors::helpers::normalize_signature(&node.text(), "cpp"),
         source: Some(node.text().to_string()),
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
@@ -431,6 +471,7 @@ fn process_static_assert<D: ast_grep_core::Doc>(
     metadata.push_attribute("static_assert");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name: "static_assert".to_string(),
         signature: sig,
@@ -457,6 +498,7 @@ fn process_linkage_spec<D: ast_grep_core::Doc>(
     metadata.push_attribute("extern_c");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name: "extern \"C\"".to_string(),
         signature: "extern \"C\"".to_string(),
@@ -560,8 +602,7 @@ fn extract_signature<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
         (None, Some(s)) => s,
         (None, None) => text.len(),
     };
-    let sig = text[..end].trim();
-    sig.split_whitespace().collect::<Vec<_>>().join(" ")
+    crate::extractors::helpers::normalize_signature(&text[..end], "cpp")
 }
 
 #[allow(clippy::unnecessary_wraps)]
@@ -17,6 +17,7 @@ pub(super) fn process_template_declaration<D: ast_grep_core::Doc>(
     items: &mut Vec<ParsedItem>,
     source: &str,
     doc_comment: &str,
+    owner_path: Option<&str>,
 ) {
     let children: Vec<_> = node.children().collect();
 
@@ -36,7 +37,13 @@ pub(super) fn process_template_declaration<D: ast_grep_core::Doc>(
     for child in &children {
         match child.kind().as_ref() {
             "class_specifier" => {
-                process_class(child, items, doc_comment, template_params.as_deref());
+                process_class(
+                    child,
+                    items,
+                    doc_comment,
+                    template_params.as_deref(),
+                    owner_path,
+                );
             }
             "struct_specifier" => {
                 // Template struct — emit with template attribute
@@ -54,6 +61,7 @@ pub(super) fn process_template_declaration<D: ast_grep_core::Doc>(
                     metadata.push_attribute("template");
 
                     items.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Struct,
                         name,
                         signature: extract_signature(node),
@@ -99,6 +107,7 @@ pub(super) fn process_template_declaration<D: ast_grep_core::Doc>(
                     metadata.push_attribute("using");
 
                     items.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::TypeAlias,
                         name: alias_name,
                         signature: extract_signature(node),
@@ -117,7 +126,7 @@ pub(super) fn process_template_declaration<D: ast_grep_core::Doc>(
             "template_declaration" => {
                 // Nested template — recurse
                 let inner_doc = doc_comment.to_string();
-                process_template_declaration(child, items, source, &inner_doc);
+                process_template_declaration(child, items, source, &inner_doc, owner_path);
             }
             _ => {}
         }
@@ -179,6 +188,7 @@ pub(super) fn process_template_instantiation<D: ast_grep_core::Doc>(
     metadata.push_attribute("explicit_instantiation");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Class,
         name,
         signature: sig,
@@ -236,6 +246,7 @@ fn process_template_function<D: ast_grep_core::Doc>(
     }
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature: extract_signature(template_node),
@@ -270,6 +281,7 @@ fn process_template_function_decl<D: ast_grep_core::Doc>(
                     metadata.push_attribute("template");
 
                     items.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Static,
                         name,
                         signature: extract_signature(template_node),
@@ -301,6 +313,7 @@ fn process_template_function_decl<D: ast_grep_core::Doc>(
     metadata.push_attribute("prototype");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature: extract_signature(template_node),
@@ -332,6 +345,7 @@ fn process_concept<D: ast_grep_core::Doc>(
     metadata.push_attribute("concept");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Trait,
         name,
         signature: extract_signature(node),
@@ -37,3 +37,56 @@ fn nested_class_inner() {
         .find(|i| i.kind == SymbolKind::Class && i.name == "Inner");
     assert!(inner.is_some(), "nested class Inner should be extracted");
 }
+
+#[test]
+fn nested_class_member_owner_name_is_fully_qualified() {
+    let items = fixture_items();
+    let value = find_by_name(&items, "Inner::value");
+    assert_eq!(
+        value.metadata.owner_name,
+        Some("Outer::Inner".to_string()),
+        "Inner::value's owner_name should include the enclosing Outer class"
+    );
+    assert_eq!(
+        value.metadata.owner_local_name,
+        Some("Inner".to_string()),
+        "Inner::value's owner_local_name should be just the immediate owner"
+    );
+}
+
+#[test]
+fn nested_class_constructor_owner_name_is_fully_qualified() {
+    let items = fixture_items();
+    let ctor = items
+        .iter()
+        .find(|i| i.kind == SymbolKind::Constructor && i.name == "Inner::Inner")
+        .expect("Inner's constructor should be extracted");
+    assert_eq!(ctor.metadata.owner_name, Some("Outer::Inner".to_string()));
+    assert_eq!(ctor.metadata.owner_local_name, Some("Inner".to_string()));
+}
+
+#[test]
+fn top_level_class_member_owner_name_equals_local_name() {
+    // NestingDemo isn't nested in anything, so its qualified owner path is
+    // just its own name.
+    let items = fixture_items();
+    let config = find_by_name(&items, "NestingDemo::config_");
+    assert_eq!(config.metadata.owner_name, Some("NestingDemo".to_string()));
+    assert_eq!(
+        config.metadata.owner_local_name,
+        Some("NestingDemo".to_string())
+    );
+}
+
+#[test]
+fn out_of_class_nested_method_definition_resolves_owner_path() {
+    let items = parse_and_extract(
+        "class Outer {\npublic:\n    class Inner {\n    public:\n        void f();\n    };\n};\nvoid Outer::Inner::f() {}\n",
+    );
+    let f = items
+        .iter()
+        .find(|i| i.kind == SymbolKind::Function && i.name.contains('f'))
+        .expect("out-of-class Outer::Inner::f definition should be extracted");
+    assert_eq!(f.metadata.owner_name, Some("Outer::Inner".to_string()));
+    assert_eq!(f.metadata.owner_local_name, Some("Inner".to_string()));
+}
@@ -67,6 +67,7 @@ pub(super) fn owner_from_ancestors<D: ast_grep_core::Doc>(
     while let Some(parent) = current {
         let kind = parent.kind();
         let symbol_kind = match kind.as_ref() {
+            "record_declaration" if is_record_struct(&parent) => SymbolKind::Struct,
             "class_declaration" | "record_declaration" => SymbolKind::Class,
             "struct_declaration" => SymbolKind::Struct,
             "interface_declaration" => SymbolKind::Interface,
@@ -85,7 +86,14 @@ pub(super) fn owner_from_ancestors<D: ast_grep_core::Doc>(
 }
 
 pub(super) fn extract_parameters<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
-    let Some(params) = node.field("parameters").or_else(|| node.field("parameter")) else {
+    let params = node
+        .field("parameters")
+        .or_else(|| node.field("parameter"))
+        .or_else(|| {
+            node.children()
+                .find(|child| child.kind().as_ref() == "parameter_list")
+        });
+    let Some(params) = params else {
         return Vec::new();
     };
 
@@ -100,6 +108,36 @@ pub(super) fn extract_parameters<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<S
         .collect()
 }
 
+/// Positional (primary-constructor) parameters of a `record`/`record struct`
+/// declaration, as `(name, type)` pairs, in declaration order.
+pub(super) fn extract_record_parameters<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+) -> Vec<(String, String)> {
+    let Some(params) = node
+        .children()
+        .find(|child| child.kind().as_ref() == "parameter_list")
+    else {
+        return Vec::new();
+    };
+
+    params
+        .children()
+        .filter(|child| child.kind().as_ref() == "parameter")
+        .filter_map(|param| {
+            let name = param.field("name")?.text().to_string();
+            let param_type = param.field("type").map(|t| t.text().to_string());
+            Some((name, param_type.unwrap_or_default()))
+        })
+        .collect()
+}
+
+/// Whether a `record` declaration is a `record struct` (as opposed to the
+/// default `record class`), signalled by a bare `struct` keyword child.
+pub(super) fn is_record_struct<D: ast_grep_core::Doc>(node: &Node<D>) -> bool {
+    node.children()
+        .any(|child| child.kind().as_ref() == "struct")
+}
+
 pub(super) fn extract_base_types<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
     node.children()
         .find(|child| child.kind().as_ref() == "base_list")
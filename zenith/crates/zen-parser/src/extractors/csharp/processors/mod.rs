@@ -18,12 +18,14 @@ pub(super) fn process_using_directive<D: ast_grep_core::Doc>(node: &Node<D>) ->
     namespaces::process_using_directive(node)
 }
 
-pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(
-    node: &Node<D>,
-) -> Option<ParsedItem> {
+pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<ParsedItem> {
     types::process_type_declaration(node)
 }
 
+pub(super) fn merge_partial_types(items: Vec<ParsedItem>) -> Vec<ParsedItem> {
+    types::merge_partial_types(items)
+}
+
 pub(super) fn build_item<D: ast_grep_core::Doc>(
     node: &Node<D>,
     kind: crate::types::SymbolKind,
@@ -33,9 +35,10 @@ pub(super) fn build_item<D: ast_grep_core::Doc>(
     doc_comment: String,
 ) -> ParsedItem {
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
-        signature: crate::extractors::helpers::extract_signature(node),
+        signature: crate::extractors::helpers::extract_signature(node, "csharp"),
         source: crate::extractors::helpers::extract_source(node, 40),
         doc_comment,
         start_line: node.start_pos().line() as u32 + 1,
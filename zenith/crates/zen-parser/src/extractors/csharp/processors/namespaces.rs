@@ -6,7 +6,7 @@ use super::super::cs_helpers;
 use super::build_item;
 
 pub(super) fn process_using_directive<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
-    let signature = crate::extractors::helpers::extract_signature(node);
+    let signature = crate::extractors::helpers::extract_signature(node, "csharp");
     let name = signature
         .trim_start_matches("using")
         .trim()
@@ -5,19 +5,21 @@ use crate::types::{ParsedItem, SymbolKind, SymbolMetadata};
 use super::super::cs_helpers;
 use super::build_item;
 
-pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(
-    node: &Node<D>,
-) -> Option<ParsedItem> {
+pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<ParsedItem> {
+    let is_record = node.kind().as_ref() == "record_declaration";
     let kind = match node.kind().as_ref() {
+        "record_declaration" if cs_helpers::is_record_struct(node) => SymbolKind::Struct,
         "class_declaration" | "record_declaration" => SymbolKind::Class,
         "struct_declaration" => SymbolKind::Struct,
         "interface_declaration" => SymbolKind::Interface,
         "enum_declaration" => SymbolKind::Enum,
         "delegate_declaration" => SymbolKind::TypeAlias,
-        _ => return None,
+        _ => return Vec::new(),
     };
 
-    let name = node.field("name").map(|n| n.text().to_string())?;
+    let Some(name) = node.field("name").map(|n| n.text().to_string()) else {
+        return Vec::new();
+    };
     let modifiers = cs_helpers::extract_modifiers(node);
     let visibility = cs_helpers::visibility_from_modifiers(&modifiers);
     let mut metadata = SymbolMetadata {
@@ -35,15 +37,88 @@ pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(
         metadata.return_type = node.field("type").map(|t| t.text().to_string());
         metadata.parameters = cs_helpers::extract_parameters(node);
     }
+    if modifiers.iter().any(|m| m == "partial") {
+        metadata.attributes.push("csharp:partial".to_string());
+    }
+
+    let record_fields = if is_record {
+        cs_helpers::extract_record_parameters(node)
+    } else {
+        Vec::new()
+    };
+    metadata.fields = record_fields
+        .iter()
+        .map(|(field_name, _)| field_name.clone())
+        .collect();
 
-    Some(build_item(
+    let mut items = vec![build_item(
         node,
         kind,
-        name,
-        visibility,
+        name.clone(),
+        visibility.clone(),
         metadata,
         cs_helpers::extract_csharp_doc_before(node),
-    ))
+    )];
+
+    items.extend(record_fields.into_iter().map(|(field_name, field_type)| {
+        let field_metadata = SymbolMetadata {
+            owner_name: Some(name.clone()),
+            owner_kind: Some(kind),
+            return_type: (!field_type.is_empty()).then_some(field_type),
+            ..Default::default()
+        };
+        build_item(
+            node,
+            SymbolKind::Field,
+            field_name,
+            visibility.clone(),
+            field_metadata,
+            String::new(),
+        )
+    }));
+
+    items
+}
+
+/// Merge same-named `partial class`/`partial record` declarations found in a
+/// single file into one item, so a type split across `partial` blocks shows
+/// up as a single searchable symbol instead of one per block.
+pub(super) fn merge_partial_types(items: Vec<ParsedItem>) -> Vec<ParsedItem> {
+    let mut merged: Vec<ParsedItem> = Vec::new();
+
+    for item in items {
+        let is_partial_type = matches!(item.kind, SymbolKind::Class | SymbolKind::Struct)
+            && item
+                .metadata
+                .attributes
+                .iter()
+                .any(|attr| attr == "csharp:partial");
+
+        if is_partial_type
+            && let Some(existing) = merged.iter_mut().find(|existing| {
+                existing.kind == item.kind
+                    && existing.name == item.name
+                    && existing
+                        .metadata
+                        .attributes
+                        .iter()
+                        .any(|attr| attr == "csharp:partial")
+            })
+        {
+            existing.start_line = existing.start_line.min(item.start_line);
+            existing.end_line = existing.end_line.max(item.end_line);
+            for base in item.metadata.base_classes {
+                if !existing.metadata.base_classes.contains(&base) {
+                    existing.metadata.base_classes.push(base);
+                }
+            }
+            continue;
+        }
+
+        merged.push(item);
+    }
+
+    merged
 }
 
 fn extract_enum_variants<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
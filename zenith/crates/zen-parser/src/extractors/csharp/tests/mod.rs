@@ -6,6 +6,7 @@ pub(super) use crate::types::{SymbolKind, Visibility};
 mod docs_signatures_lines;
 mod events_indexers_operators;
 mod members;
+mod records_and_partial_classes;
 mod types_and_namespaces;
 mod using_directives;
 mod visibility_modifiers;
@@ -0,0 +1,87 @@
+use super::*;
+
+#[test]
+fn record_positional_parameters_become_owned_fields() {
+    let items = fixture_items();
+
+    let point = find_by_name(&items, "Point");
+    assert_eq!(point.kind, SymbolKind::Class);
+    assert_eq!(
+        point.metadata.fields,
+        vec!["X".to_string(), "Y".to_string()]
+    );
+
+    let x = items
+        .iter()
+        .find(|i| {
+            i.kind == SymbolKind::Field
+                && i.name == "X"
+                && i.metadata.owner_name.as_deref() == Some("Point")
+        })
+        .expect("should find Point.X field");
+    assert_eq!(x.metadata.return_type.as_deref(), Some("int"));
+
+    let y = items
+        .iter()
+        .find(|i| {
+            i.kind == SymbolKind::Field
+                && i.name == "Y"
+                && i.metadata.owner_name.as_deref() == Some("Point")
+        })
+        .expect("should find Point.Y field");
+    assert_eq!(y.metadata.return_type.as_deref(), Some("int"));
+}
+
+#[test]
+fn record_struct_is_extracted_as_struct_with_fields() {
+    let items = fixture_items();
+
+    let vector2 = find_by_name(&items, "Vector2");
+    assert_eq!(vector2.kind, SymbolKind::Struct);
+    assert_eq!(
+        vector2.metadata.fields,
+        vec!["X".to_string(), "Y".to_string()]
+    );
+}
+
+#[test]
+fn nullable_annotations_survive_into_return_type_and_parameters() {
+    let items = fixture_items();
+
+    let find_tag = find_by_name(&items, "FindTag");
+    assert_eq!(find_tag.metadata.return_type.as_deref(), Some("string?"));
+    assert_eq!(
+        find_tag.metadata.parameters,
+        vec!["string? key".to_string()]
+    );
+}
+
+#[test]
+fn same_file_partial_classes_merge_into_one_class_item() {
+    let items = fixture_items();
+
+    let loggers: Vec<_> = items
+        .iter()
+        .filter(|i| i.kind == SymbolKind::Class && i.name == "Logger")
+        .collect();
+    assert_eq!(
+        loggers.len(),
+        1,
+        "partial Logger blocks should merge into one item"
+    );
+
+    let logger = loggers[0];
+    assert!(
+        logger
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == "csharp:partial")
+    );
+
+    let write = find_by_name(&items, "Write");
+    assert_eq!(write.metadata.owner_name.as_deref(), Some("Logger"));
+
+    let line_count = find_by_name(&items, "LineCount");
+    assert_eq!(line_count.metadata.owner_name.as_deref(), Some("Logger"));
+}
@@ -87,6 +87,7 @@ fn process_rule_set<D: ast_grep_core::Doc>(
         metadata.mark_custom_property();
 
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Const,
             name: prop_name.clone(),
             signature: format!("{prop_name}: {prop_value}"),
@@ -110,6 +111,7 @@ fn process_rule_set<D: ast_grep_core::Doc>(
     metadata.set_css_properties(properties);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: symbol_kind,
         name,
         signature,
@@ -136,6 +138,7 @@ fn process_media_statement<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Ve
     metadata.set_media_query(query.clone());
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
@@ -175,6 +178,7 @@ fn process_keyframes<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<Pars
     metadata.set_at_rule_name("keyframes");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature,
@@ -199,6 +203,7 @@ fn process_import<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<ParsedI
     metadata.set_at_rule_name("import");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
@@ -232,6 +237,7 @@ fn process_charset<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<Parsed
     metadata.set_at_rule_name("charset");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Const,
         name,
         signature,
@@ -262,6 +268,7 @@ fn process_namespace<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<Pars
     metadata.set_at_rule_name("namespace");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
@@ -293,6 +300,7 @@ fn process_supports<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<Parse
     metadata.set_media_query(query.clone());
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
@@ -362,6 +370,7 @@ fn process_scope<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<ParsedIt
     metadata.set_at_rule_name("scope");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
@@ -430,6 +439,7 @@ fn process_font_face<D: ast_grep_core::Doc>(
     metadata.set_css_properties(properties);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Struct,
         name,
         signature,
@@ -459,6 +469,7 @@ fn process_layer<D: ast_grep_core::Doc>(
     metadata.set_at_rule_name("layer");
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
@@ -508,6 +519,7 @@ fn process_container<D: ast_grep_core::Doc>(
     metadata.set_media_query(query);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
@@ -559,6 +571,7 @@ fn process_generic_at_rule<D: ast_grep_core::Doc>(
     metadata.set_css_properties(properties);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
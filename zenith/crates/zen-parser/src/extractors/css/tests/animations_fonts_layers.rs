@@ -110,6 +110,25 @@ fn layer_nested_rules() {
     );
 }
 
+#[test]
+fn custom_properties_and_layer_coexist() {
+    let items = parse_and_extract(
+        ":root {\n  --gap: 10px;\n  --brand-color: #333;\n}\n\n@layer base {\n  body { margin: 0; }\n}\n",
+    );
+
+    let gap = find_by_name(&items, "--gap");
+    assert_eq!(gap.kind, SymbolKind::Const);
+    assert!(gap.metadata.is_custom_property);
+
+    let brand = find_by_name(&items, "--brand-color");
+    assert_eq!(brand.kind, SymbolKind::Const);
+    assert!(brand.metadata.is_custom_property);
+
+    let layer = find_by_name(&items, "@layer base");
+    assert_eq!(layer.kind, SymbolKind::Module);
+    assert_eq!(layer.metadata.at_rule_name.as_deref(), Some("layer"));
+}
+
 // ── @container tests ───────────────────────────────────────────
 
 #[test]
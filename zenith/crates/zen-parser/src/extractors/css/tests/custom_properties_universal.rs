@@ -43,6 +43,15 @@ fn simple_custom_property() {
     assert!(cp.metadata.is_custom_property);
 }
 
+#[test]
+fn root_custom_property_value() {
+    let items = parse_and_extract(":root { --primary: #fff; }");
+    let cp = find_by_name(&items, "--primary");
+    assert_eq!(cp.kind, SymbolKind::Const);
+    assert!(cp.metadata.is_custom_property);
+    assert!(cp.signature.contains("#fff"));
+}
+
 // ── Universal selector tests ──────────────────────────────────
 
 #[test]
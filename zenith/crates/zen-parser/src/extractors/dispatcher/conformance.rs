@@ -1,6 +1,6 @@
 use ast_grep_language::{LanguageExt, SupportLang};
 
-use crate::types::SymbolKind;
+use crate::types::{ExtractOptions, SymbolKind};
 
 #[test]
 fn constructor_normalization_across_languages() {
@@ -194,7 +194,7 @@ fn assert_go_member_ownership() {
     let go_source =
         "package demo; type Card struct { id string }; func (c *Card) Set(v string) { c.id = v }";
     let go_root = SupportLang::Go.ast_grep(go_source);
-    let go_items = super::go::extract(&go_root).expect("go extraction");
+    let go_items = super::go::extract(&go_root, go_source, "main.go").expect("go extraction");
 
     let go_field = go_items
         .iter()
@@ -237,7 +237,8 @@ fn assert_ruby_member_ownership() {
 fn assert_json_member_ownership() {
     let json_source = "{\"app\":{\"name\":\"zenith\"},\"routes\":[{\"path\":\"/health\"}]}";
     let json_root = SupportLang::Json.ast_grep(json_source);
-    let json_items = super::json::extract(&json_root).expect("json extraction");
+    let json_items = super::json::extract(&json_root, "test.json", ExtractOptions::default())
+        .expect("json extraction");
 
     let app_name = json_items
         .iter()
@@ -257,7 +258,8 @@ fn assert_json_member_ownership() {
 fn assert_yaml_member_ownership() {
     let yaml_source = "app:\n  name: zenith\nroutes:\n  - path: /health\n";
     let yaml_root = SupportLang::Yaml.ast_grep(yaml_source);
-    let yaml_items = super::yaml::extract(&yaml_root).expect("yaml extraction");
+    let yaml_items =
+        super::yaml::extract(&yaml_root, ExtractOptions::default()).expect("yaml extraction");
 
     let app_name = yaml_items
         .iter()
@@ -65,15 +65,13 @@ pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
             | "interface_declaration"
             | "enum_declaration"
             | "delegate_declaration" => {
-                if let Some(item) = processors::process_type_declaration(&node) {
-                    items.push(item);
-                }
+                items.extend(processors::process_type_declaration(&node));
             }
             _ => items.extend(processors::process_member_declaration(&node)),
         }
     }
 
-    Ok(items)
+    Ok(processors::merge_partial_types(items))
 }
 
 #[cfg(test)]
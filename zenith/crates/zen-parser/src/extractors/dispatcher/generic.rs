@@ -0,0 +1,154 @@
+//! Generic kind-based extractor for languages without a rich extractor.
+//!
+//! Used for ast-grep built-in languages that don't have a dedicated rich
+//! extractor yet (Scala, Solidity) and for custom-lane languages with their
+//! own `ast_grep_core::Language` impl (Dart, via `tree-sitter-dart`, wired
+//! the same way as Svelte). Maps a per-language table of
+//! function/class/trait/contract-shaped node kinds to the normalized
+//! `SymbolKind` taxonomy, extracts names and (where the node shape allows)
+//! doc comments, and tags every item `generic:<lang>` so consumers know
+//! fidelity is lower than a rich extractor.
+
+use crate::types::{ParsedItem, SymbolKind};
+
+#[path = "../generic/helpers.rs"]
+mod generic_helpers;
+#[path = "../generic/processors.rs"]
+mod processors;
+
+use processors::KindMapping;
+
+const SCALA_MAPPINGS: &[KindMapping] = &[
+    KindMapping {
+        kind: "class_definition",
+        symbol_kind: SymbolKind::Class,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "trait_definition",
+        symbol_kind: SymbolKind::Trait,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "object_definition",
+        symbol_kind: SymbolKind::Class,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "function_definition",
+        symbol_kind: SymbolKind::Function,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "function_declaration",
+        symbol_kind: SymbolKind::Function,
+        name_via: None,
+    },
+];
+
+const SOLIDITY_MAPPINGS: &[KindMapping] = &[
+    KindMapping {
+        kind: "contract_declaration",
+        symbol_kind: SymbolKind::Class,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "interface_declaration",
+        symbol_kind: SymbolKind::Interface,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "library_declaration",
+        symbol_kind: SymbolKind::Module,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "function_definition",
+        symbol_kind: SymbolKind::Function,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "struct_declaration",
+        symbol_kind: SymbolKind::Struct,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "enum_declaration",
+        symbol_kind: SymbolKind::Enum,
+        name_via: None,
+    },
+];
+
+const DART_MAPPINGS: &[KindMapping] = &[
+    KindMapping {
+        kind: "class_declaration",
+        symbol_kind: SymbolKind::Class,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "mixin_declaration",
+        symbol_kind: SymbolKind::Trait,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "enum_declaration",
+        symbol_kind: SymbolKind::Enum,
+        name_via: None,
+    },
+    KindMapping {
+        kind: "function_declaration",
+        symbol_kind: SymbolKind::Function,
+        name_via: Some("signature"),
+    },
+    KindMapping {
+        kind: "method_declaration",
+        symbol_kind: SymbolKind::Method,
+        name_via: Some("signature"),
+    },
+];
+
+/// Extract Scala symbols via the generic kind-based extractor.
+///
+/// # Errors
+/// Returns `ParserError` if parsing fails.
+pub fn extract_scala<D: ast_grep_core::Doc>(
+    root: &ast_grep_core::AstGrep<D>,
+) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
+    Ok(processors::extract_mapped(
+        &root.root(),
+        "scala",
+        SCALA_MAPPINGS,
+    ))
+}
+
+/// Extract Solidity symbols via the generic kind-based extractor.
+///
+/// # Errors
+/// Returns `ParserError` if parsing fails.
+pub fn extract_solidity<D: ast_grep_core::Doc>(
+    root: &ast_grep_core::AstGrep<D>,
+) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
+    Ok(processors::extract_mapped(
+        &root.root(),
+        "solidity",
+        SOLIDITY_MAPPINGS,
+    ))
+}
+
+/// Extract Dart symbols via the generic kind-based extractor.
+///
+/// # Errors
+/// Returns `ParserError` if parsing fails.
+pub fn extract_dart<D: ast_grep_core::Doc>(
+    root: &ast_grep_core::AstGrep<D>,
+) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
+    Ok(processors::extract_mapped(
+        &root.root(),
+        "dart",
+        DART_MAPPINGS,
+    ))
+}
+
+#[cfg(test)]
+#[path = "../generic/tests/mod.rs"]
+mod tests;
@@ -27,12 +27,18 @@ const GO_TOP_KINDS: &[&str] = &[
 
 /// Extract all API symbols from a Go source file.
 ///
+/// `file_path` is used to detect `_test.go` files so `TestXxx` functions can
+/// be tagged with [`crate::types::SymbolKind::Test`].
+///
 /// # Errors
 /// Returns `ParserError` if parsing fails.
 pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
     root: &ast_grep_core::AstGrep<D>,
+    source: &str,
+    file_path: &str,
 ) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
     let mut items = Vec::new();
+    let is_test_file = file_path.ends_with("_test.go");
     let matchers: Vec<KindMatcher> = GO_TOP_KINDS
         .iter()
         .map(|k| KindMatcher::new(k, SupportLang::Go))
@@ -51,7 +57,7 @@ pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
                 items.extend(processors::process_import_declaration(&node));
             }
             "function_declaration" => {
-                if let Some(item) = processors::process_function(&node) {
+                if let Some(item) = processors::process_function(&node, is_test_file) {
                     items.push(item);
                 }
             }
@@ -72,6 +78,14 @@ pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
             _ => {}
         }
     }
+
+    if let Some(constraint) = go_helpers::detect_build_constraint(source) {
+        let tag = format!("go:build:{constraint}");
+        for item in &mut items {
+            item.metadata.attributes.push(tag.clone());
+        }
+    }
+
     Ok(items)
 }
 
@@ -23,6 +23,7 @@ const HASKELL_TOP_KINDS: &[&str] = &[
     "newtype",
     "type_family",
     "type_instance",
+    "instance",
     "foreign_import",
     "foreign_export",
 ];
@@ -67,6 +68,15 @@ pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
                 if let Some(item) = processors::process_type_decl(&node) {
                     items.push(item);
                 }
+                if node.kind().as_ref() == "data_type" {
+                    items.extend(processors::process_record_field_items(&node));
+                }
+            }
+            "instance" => {
+                if let Some(item) = processors::process_instance(&node) {
+                    items.push(item);
+                }
+                items.extend(processors::process_instance_members(&node));
             }
             _ => {}
         }
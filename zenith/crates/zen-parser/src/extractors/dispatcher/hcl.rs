@@ -0,0 +1,35 @@
+//! HCL/Terraform extractor via the custom lane — no `ast-grep` grammar is
+//! available for HCL, so this scans `.tf` source directly for
+//! `resource`/`module`/`variable`/`output`/`provider` block headers.
+
+use crate::types::ParsedItem;
+
+#[path = "../hcl/helpers.rs"]
+mod helpers;
+#[path = "../hcl/processors.rs"]
+mod processors;
+
+/// Extract `resource`, `module`, `variable`, `output`, and `provider` blocks
+/// from an HCL/Terraform document.
+///
+/// Nested block structure (e.g. a resource's `tags = { ... }`) is captured
+/// shallowly, as attribute names on the containing block, rather than
+/// recursed into.
+///
+/// # Errors
+/// Never returns an error; the signature matches the other custom-lane
+/// extractors for consistency.
+pub fn extract(source: &str) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut items = vec![processors::root_item(lines.len() as u32)];
+
+    for block in helpers::detect_blocks(source) {
+        items.push(processors::block_item(&lines, &block));
+    }
+
+    Ok(items)
+}
+
+#[cfg(test)]
+#[path = "../hcl/tests/mod.rs"]
+mod tests;
@@ -2,7 +2,7 @@
 
 use ast_grep_language::SupportLang;
 
-use crate::types::ParsedItem;
+use crate::types::{ExtractOptions, ParsedItem};
 
 #[path = "../json/helpers.rs"]
 mod json_helpers;
@@ -11,12 +11,23 @@ mod processors;
 
 /// Extract all significant JSON symbols from a document.
 ///
+/// `file_path` is used only to special-case well-known manifest files
+/// (`package.json`, `tsconfig.json`) down to their interesting top-level
+/// keys; pass `""` if it's not known. `options` bounds how deep/wide/long a
+/// single document is allowed to expand into items — see [`ExtractOptions`].
+///
 /// # Errors
 /// Returns `ParserError` if parsing fails.
 pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
     root: &ast_grep_core::AstGrep<D>,
+    file_path: &str,
+    options: ExtractOptions,
 ) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
-    Ok(processors::extract_document(&root.root()))
+    Ok(processors::extract_document(
+        &root.root(),
+        file_path,
+        options,
+    ))
 }
 
 #[cfg(test)]
@@ -32,16 +32,11 @@ fn set_owner(item: &mut ParsedItem, owner: &str) {
         .push(format!("md:owner_path:{owner}"));
 }
 
-/// Extract significant markdown symbols from a document.
-///
-/// # Errors
-/// Returns `ParserError` if parsing fails.
-pub fn extract<D: ast_grep_core::Doc>(
+fn collect_headings<D: ast_grep_core::Doc>(
     root: &ast_grep_core::AstGrep<D>,
-) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
-    let mut items = vec![processors::root_item(&root.root())];
-    let lang = root.root().lang().clone();
-
+    lang: &D::Lang,
+) -> (Vec<ParsedItem>, Vec<HeadingContext>) {
+    let mut items = Vec::new();
     let mut heading_nodes: Vec<_> = root
         .root()
         .find_all(KindMatcher::new("atx_heading", lang.clone()))
@@ -84,6 +79,16 @@ pub fn extract<D: ast_grep_core::Doc>(
         items.push(item);
     }
 
+    (items, heading_ctx)
+}
+
+fn collect_block_items<D: ast_grep_core::Doc>(
+    root: &ast_grep_core::AstGrep<D>,
+    lang: &D::Lang,
+    heading_ctx: &[HeadingContext],
+) -> Vec<ParsedItem> {
+    let mut items = Vec::new();
+
     for kind in [
         "fenced_code_block",
         "list",
@@ -94,23 +99,54 @@ pub fn extract<D: ast_grep_core::Doc>(
         "plus_metadata",
     ] {
         for node in root.root().find_all(KindMatcher::new(kind, lang.clone())) {
+            let line = node.start_pos().line() as u32 + 1;
             let mut item = match kind {
                 "fenced_code_block" => processors::code_fence_item(&node),
                 "list" => processors::list_item(&node),
-                "pipe_table" => processors::table_item(&node),
+                "pipe_table" => {
+                    let heading = heading_ctx
+                        .iter()
+                        .rev()
+                        .find(|h| h.start_line <= line)
+                        .map(|h| h.path.rsplit('/').next().unwrap_or(&h.path).to_string());
+                    processors::table_item(&node, heading.as_deref())
+                }
                 "link_reference_definition" => processors::link_reference_item(&node),
                 "thematic_break" => processors::thematic_break_item(&node),
                 "minus_metadata" => processors::frontmatter_item(&node, "yaml"),
                 "plus_metadata" => processors::frontmatter_item(&node, "toml"),
                 _ => unreachable!("unsupported markdown kind: {kind}"),
             };
-            let line = item.start_line;
-            let owner = owner_path_for_line(&heading_ctx, line);
+            let owner = owner_path_for_line(heading_ctx, item.start_line);
             set_owner(&mut item, &owner);
             items.push(item);
         }
     }
 
+    for mut item in processors::definition_list_items(&root.root()) {
+        let owner = owner_path_for_line(heading_ctx, item.start_line);
+        set_owner(&mut item, &owner);
+        items.push(item);
+    }
+
+    items
+}
+
+/// Extract significant markdown symbols from a document.
+///
+/// # Errors
+/// Returns `ParserError` if parsing fails.
+pub fn extract<D: ast_grep_core::Doc>(
+    root: &ast_grep_core::AstGrep<D>,
+) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
+    let mut items = vec![processors::root_item(&root.root())];
+    let lang = root.root().lang().clone();
+
+    let (heading_items, heading_ctx) = collect_headings(root, &lang);
+    items.extend(heading_items);
+
+    items.extend(collect_block_items(root, &lang, &heading_ctx));
+
     for node in root
         .root()
         .find_all(KindMatcher::new("paragraph", lang.clone()))
@@ -4,14 +4,17 @@ pub mod cpp;
 pub mod csharp;
 pub mod css;
 pub mod elixir;
+pub mod generic;
 pub mod go;
 pub mod haskell;
+pub mod hcl;
 pub mod html;
 pub mod java;
 pub mod javascript;
 pub mod json;
 pub mod lua;
 pub mod markdown;
+pub mod openapi;
 pub mod php;
 pub mod python;
 pub mod rst;
@@ -0,0 +1,45 @@
+//! OpenAPI/Swagger spec extractor.
+//!
+//! Detected by content shape — a top-level `openapi`/`swagger` version key
+//! alongside a `paths` key — so both `.json` and `.yaml`/`.yml` documents
+//! with that shape are routed here instead of the generic JSON/YAML
+//! extractors, regardless of file name.
+
+use ast_grep_language::SupportLang;
+
+use crate::types::{ExtractOptions, ParsedItem};
+
+#[path = "../openapi/helpers.rs"]
+mod helpers;
+#[path = "../openapi/json.rs"]
+mod json;
+#[path = "../openapi/yaml.rs"]
+mod yaml;
+
+pub use helpers::looks_like_openapi;
+
+/// Extract `OpenAPI` operations and component schemas from a JSON document.
+///
+/// # Errors
+/// Returns `ParserError` if parsing fails.
+pub fn extract_json<D: ast_grep_core::Doc<Lang = SupportLang>>(
+    root: &ast_grep_core::AstGrep<D>,
+    options: ExtractOptions,
+) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
+    Ok(json::extract(&root.root(), &options))
+}
+
+/// Extract `OpenAPI` operations and component schemas from a YAML document.
+///
+/// # Errors
+/// Returns `ParserError` if parsing fails.
+pub fn extract_yaml<D: ast_grep_core::Doc<Lang = SupportLang>>(
+    root: &ast_grep_core::AstGrep<D>,
+    options: ExtractOptions,
+) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
+    Ok(yaml::extract(&root.root(), &options))
+}
+
+#[cfg(test)]
+#[path = "../openapi/tests/mod.rs"]
+mod tests;
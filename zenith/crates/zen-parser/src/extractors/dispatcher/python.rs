@@ -46,6 +46,7 @@ pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
     // Module docstring (first expression_statement containing a string)
     if let Some(module_doc) = extract_module_docstring(&root.root()) {
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Module,
             name: "<module>".to_string(),
             signature: String::new(),
@@ -18,6 +18,7 @@ const RUBY_TOP_KINDS: &[&str] = &[
     "singleton_method",
     "assignment",
     "call",
+    "alias",
 ];
 
 /// Extract all API symbols from a Ruby source file.
@@ -52,6 +53,11 @@ pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
                 }
             }
             "call" => items.extend(processors::process_call(&node)),
+            "alias" => {
+                if let Some(item) = processors::process_alias(&node) {
+                    items.push(item);
+                }
+            }
             _ => {}
         }
     }
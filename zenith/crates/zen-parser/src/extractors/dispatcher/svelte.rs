@@ -2,7 +2,7 @@
 
 use ast_grep_core::matcher::KindMatcher;
 
-use crate::types::ParsedItem;
+use crate::types::{CommonMetadataExt, ParsedItem};
 
 #[path = "../svelte/processors.rs"]
 mod processors;
@@ -25,8 +25,22 @@ pub fn extract<D: ast_grep_core::Doc>(
     {
         let script = processors::script_item(&node);
         let script_name = script.name.clone();
+        let script_text = node.text().to_string();
+        let is_typescript = script
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == "svelte:embedded_parser:typescript");
+
         items.push(script);
         items.extend(processors::script_api_items(&node, &script_name));
+        items.extend(processors::rune_items(&node, &script_name));
+        if is_typescript {
+            items.extend(processors::script_ts_type_items(&script_text, &script_name));
+        }
+        for attr in processors::props_type_attributes(&script_text) {
+            items[0].metadata.push_attribute(attr);
+        }
     }
 
     for node in root
@@ -47,6 +47,16 @@ pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
 
     for node in root.root().find_all(&matcher) {
         let kind = node.kind();
+        // `export_statement` and `ambient_declaration` walk their own
+        // children explicitly, so skip a bare re-match of the same node
+        // here — otherwise every exported/declared item is extracted twice.
+        if is_container_handled_child(kind.as_ref())
+            && node
+                .parent()
+                .is_some_and(|p| matches!(p.kind().as_ref(), "export_statement" | "ambient_declaration"))
+        {
+            continue;
+        }
         match kind.as_ref() {
             "export_statement" => {
                 items.extend(processors::process_export_statement(&node));
@@ -102,7 +112,25 @@ pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
             _ => {}
         }
     }
-    Ok(items)
+    Ok(processors::merge_declarations(items))
+}
+
+/// Kinds that `export_statement`/`ambient_declaration` already dispatch to
+/// their own processors when found as a direct child.
+fn is_container_handled_child(kind: &str) -> bool {
+    matches!(
+        kind,
+        "function_declaration"
+            | "class_declaration"
+            | "abstract_class_declaration"
+            | "interface_declaration"
+            | "type_alias_declaration"
+            | "enum_declaration"
+            | "lexical_declaration"
+            | "variable_declaration"
+            | "internal_module"
+            | "function_signature"
+    )
 }
 
 #[cfg(test)]
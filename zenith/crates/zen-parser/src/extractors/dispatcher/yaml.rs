@@ -2,7 +2,7 @@
 
 use ast_grep_language::SupportLang;
 
-use crate::types::ParsedItem;
+use crate::types::{ExtractOptions, ParsedItem};
 
 #[path = "../yaml/processors.rs"]
 mod processors;
@@ -11,12 +11,16 @@ mod yaml_helpers;
 
 /// Extract significant YAML symbols from a document stream.
 ///
+/// `options` bounds how deep/wide/long a single document is allowed to
+/// expand into items — see [`ExtractOptions`].
+///
 /// # Errors
 /// Returns `ParserError` if parsing fails.
 pub fn extract<D: ast_grep_core::Doc<Lang = SupportLang>>(
     root: &ast_grep_core::AstGrep<D>,
+    options: ExtractOptions,
 ) -> Result<Vec<ParsedItem>, crate::error::ParserError> {
-    Ok(processors::extract_stream(&root.root()))
+    Ok(processors::extract_stream(&root.root(), options))
 }
 
 #[cfg(test)]
@@ -126,13 +126,22 @@ pub(super) fn extract_guard<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<Str
     None
 }
 
+/// Resolved `@doc`/`@moduledoc` attribute attached to a definition.
+#[derive(Debug, Clone, Default)]
+pub(super) struct ElixirDoc {
+    /// The doc text, or empty if there was no `@doc`/`@moduledoc` at all.
+    pub text: String,
+    /// Whether the attribute was explicitly `@doc false`/`@moduledoc false` —
+    /// an explicit "no docs, private API" marker rather than the mere absence
+    /// of documentation.
+    pub explicitly_hidden: bool,
+}
+
 /// Extract `@doc` content from the preceding sibling of a def/defmacro call.
 ///
 /// In Elixir's AST, `@doc "..."` is a `unary_operator` sibling with:
 /// `@` child + `call` child (identifier="doc") + `arguments` child (string or heredoc).
-///
-/// `@doc false` means "no doc" — we return empty string.
-pub(super) fn extract_elixir_doc<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
+pub(super) fn extract_elixir_doc<D: ast_grep_core::Doc>(node: &Node<D>) -> ElixirDoc {
     let mut current = node.prev();
     while let Some(sibling) = current {
         let k = sibling.kind();
@@ -155,13 +164,13 @@ pub(super) fn extract_elixir_doc<D: ast_grep_core::Doc>(node: &Node<D>) -> Strin
             _ => break,
         }
     }
-    String::new()
+    ElixirDoc::default()
 }
 
 /// Extract `@moduledoc` content from inside a defmodule's `do_block`.
-pub(super) fn extract_moduledoc<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
+pub(super) fn extract_moduledoc<D: ast_grep_core::Doc>(node: &Node<D>) -> ElixirDoc {
     let Some(do_block) = node.children().find(|c| c.kind().as_ref() == "do_block") else {
-        return String::new();
+        return ElixirDoc::default();
     };
 
     for child in do_block.children() {
@@ -171,18 +180,18 @@ pub(super) fn extract_moduledoc<D: ast_grep_core::Doc>(node: &Node<D>) -> String
             return doc;
         }
     }
-    String::new()
+    ElixirDoc::default()
 }
 
 /// Try to extract a doc string from a `unary_operator` node representing `@doc` or `@moduledoc`.
 ///
 /// Returns `None` if the node is not the expected attribute.
-/// Returns `Some("")` for `@doc false`.
-/// Returns `Some(content)` for `@doc "content"` or `@doc """content"""`.
+/// Returns `Some(ElixirDoc { explicitly_hidden: true, .. })` for `@doc false`.
+/// Returns `Some(ElixirDoc { text: content, .. })` for `@doc "content"` or `@doc """content"""`.
 pub(super) fn try_extract_doc_attr<D: ast_grep_core::Doc>(
     node: &Node<D>,
     attr_name: &str,
-) -> Option<String> {
+) -> Option<ElixirDoc> {
     // Structure: unary_operator → @ + call(identifier=attr_name, arguments(string|boolean))
     let call_node = node.children().find(|c| c.kind().as_ref() == "call")?;
 
@@ -202,11 +211,16 @@ pub(super) fn try_extract_doc_attr<D: ast_grep_core::Doc>(
         let k = child.kind();
         match k.as_ref() {
             "string" => {
-                return Some(extract_string_content(&child));
+                return Some(ElixirDoc {
+                    text: extract_string_content(&child),
+                    explicitly_hidden: false,
+                });
             }
-            "boolean" => {
-                // @doc false means no documentation
-                return Some(String::new());
+            "boolean" if child.text().as_ref() == "false" => {
+                return Some(ElixirDoc {
+                    text: String::new(),
+                    explicitly_hidden: true,
+                });
             }
             _ => {}
         }
@@ -285,10 +299,11 @@ pub(super) fn build_elixir_signature<D: ast_grep_core::Doc>(
         format!("({})", params.join(", "))
     };
 
-    guard.map_or_else(
+    let sig = guard.map_or_else(
         || format!("{keyword} {name}{param_str}"),
         |g| format!("{keyword} {name}{param_str} when {g}"),
-    )
+    );
+    crate::extractors::helpers::normalize_signature(&sig, "elixir")
 }
 
 /// Extract `@spec` from preceding siblings of a def call.
@@ -316,6 +331,77 @@ pub(super) fn extract_spec<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<Stri
     None
 }
 
+/// An `@spec` split into its parameter types and return type.
+pub(super) struct ParsedSpec {
+    pub parameters: Vec<String>,
+    pub return_type: String,
+}
+
+/// Split `@spec` text like `classify(x :: integer()) :: atom()` into its
+/// parameter list and return type, splitting on the top-level `::` (the one
+/// outside any parens/brackets/braces — not the `::` inside a named
+/// parameter's inline type annotation).
+///
+/// Returns `None` if the text doesn't look like a parenthesized call
+/// followed by `:: <return type>`.
+pub(super) fn parse_spec_text(spec_text: &str) -> Option<ParsedSpec> {
+    let split_at = find_top_level(spec_text, "::")?;
+    let head = spec_text[..split_at].trim();
+    let return_type = spec_text[split_at + 2..].trim().to_string();
+
+    let open = head.find('(')?;
+    let close = head.rfind(')')?;
+    let parameters = split_top_level(&head[open + 1..close], ',')
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    Some(ParsedSpec {
+        parameters,
+        return_type,
+    })
+}
+
+/// Find the byte offset of the first occurrence of `delim` outside any
+/// `()`/`[]`/`{}` nesting.
+fn find_top_level(text: &str, delim: &str) -> Option<usize> {
+    let mut depth = 0i32;
+    let bytes = text.as_bytes();
+    for i in 0..bytes.len() {
+        match bytes[i] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 && text[i..].starts_with(delim) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Split `text` on `delim`, ignoring occurrences nested inside
+/// `()`/`[]`/`{}` (so a tuple type like `{:ok, term()}` isn't split apart).
+fn split_top_level(text: &str, delim: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0usize;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            _ if c == delim && depth == 0 => {
+                parts.push(text[start..i].to_string());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(text[start..].to_string());
+    parts
+}
+
 /// Try to extract the full text of an `@attr ...` node.
 pub(super) fn try_extract_at_attr_text<D: ast_grep_core::Doc>(
     node: &Node<D>,
@@ -7,9 +7,35 @@ use super::super::elixir_helpers::{
     build_elixir_signature, extract_callbacks, extract_def_name, extract_def_params,
     extract_elixir_doc, extract_guard, extract_module_methods, extract_module_name,
     extract_moduledoc, extract_spec, extract_struct_fields_from_module, has_impl_attr,
-    module_has_keyword,
+    module_has_keyword, parse_spec_text,
 };
 
+/// Downgrade `Public` to `PublicCrate` when an explicit `@doc false`/
+/// `@moduledoc false` marks the item as hidden from documentation — an
+/// intentional "no docs, private API" signal, distinct from simply lacking
+/// a doc comment.
+fn apply_doc_hidden(visibility: Visibility, explicitly_hidden: bool) -> Visibility {
+    if explicitly_hidden && visibility == Visibility::Public {
+        Visibility::PublicCrate
+    } else {
+        visibility
+    }
+}
+
+/// Pair each bare parameter name with its `@spec` type, e.g. `x` +
+/// `integer()` becomes `x: integer()`. Falls back to the bare names if there
+/// is no spec or its arity doesn't match the def's parameter list.
+fn annotate_params_with_spec(params: Vec<String>, spec_types: Option<&[String]>) -> Vec<String> {
+    match spec_types {
+        Some(types) if types.len() == params.len() => params
+            .into_iter()
+            .zip(types)
+            .map(|(name, ty)| format!("{name}: {ty}"))
+            .collect(),
+        _ => params,
+    }
+}
+
 // ── defmodule ──────────────────────────────────────────────────────
 
 pub fn process_defmodule<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
@@ -21,14 +47,15 @@ pub fn process_defmodule<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<Parsed
     let has_defexception = module_has_keyword(node, "defexception");
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "elixir"),
         source: helpers::extract_source(node, 50),
-        doc_comment: doc,
+        doc_comment: doc.text,
         start_line: node.start_pos().line() as u32 + 1,
         end_line: node.end_pos().line() as u32 + 1,
-        visibility: Visibility::Public,
+        visibility: apply_doc_hidden(Visibility::Public, doc.explicitly_hidden),
         metadata: SymbolMetadata {
             methods,
             fields,
@@ -51,6 +78,7 @@ pub fn process_def<D: ast_grep_core::Doc>(
     let params = extract_def_params(node);
     let guard = extract_guard(node);
     let spec = extract_spec(node);
+    let parsed_spec = spec.as_deref().and_then(parse_spec_text);
     let is_callback_impl = has_impl_attr(node);
     let keyword = if visibility == Visibility::Public {
         "def"
@@ -59,24 +87,29 @@ pub fn process_def<D: ast_grep_core::Doc>(
     };
 
     let mut metadata = SymbolMetadata::default();
-    for param in params {
+    let spec_param_types = parsed_spec.as_ref().map(|s| s.parameters.as_slice());
+    for param in annotate_params_with_spec(params, spec_param_types) {
         metadata.push_parameter(param);
     }
-    metadata.set_spec(spec);
+    match parsed_spec {
+        Some(parsed) => metadata.set_spec(Some(parsed.return_type)),
+        None => metadata.set_spec(spec),
+    }
     metadata.set_guard(guard);
     if is_callback_impl {
         metadata.mark_callback_impl();
     }
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature: build_elixir_signature(node, keyword),
         source: helpers::extract_source(node, 50),
-        doc_comment: doc,
+        doc_comment: doc.text,
         start_line: node.start_pos().line() as u32 + 1,
         end_line: node.end_pos().line() as u32 + 1,
-        visibility,
+        visibility: apply_doc_hidden(visibility, doc.explicitly_hidden),
         metadata,
     })
 }
@@ -103,14 +136,15 @@ pub fn process_defmacro<D: ast_grep_core::Doc>(
     }
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name,
         signature: build_elixir_signature(node, keyword),
         source: helpers::extract_source(node, 50),
-        doc_comment: doc,
+        doc_comment: doc.text,
         start_line: node.start_pos().line() as u32 + 1,
         end_line: node.end_pos().line() as u32 + 1,
-        visibility,
+        visibility: apply_doc_hidden(visibility, doc.explicitly_hidden),
         metadata,
     })
 }
@@ -139,14 +173,15 @@ pub fn process_defguard<D: ast_grep_core::Doc>(
     metadata.set_guard(guard);
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name,
         signature: build_elixir_signature(node, keyword),
         source: helpers::extract_source(node, 50),
-        doc_comment: doc,
+        doc_comment: doc.text,
         start_line: node.start_pos().line() as u32 + 1,
         end_line: node.end_pos().line() as u32 + 1,
-        visibility,
+        visibility: apply_doc_hidden(visibility, doc.explicitly_hidden),
         metadata,
     })
 }
@@ -156,6 +191,7 @@ pub fn process_defguard<D: ast_grep_core::Doc>(
 /// Process a `defdelegate` call — extracted as a public `Function`.
 pub fn process_defdelegate<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
     let name = extract_def_name(node)?;
+    let doc = extract_elixir_doc(node);
     let params = extract_def_params(node);
     let delegate_target = extract_delegate_target(node);
 
@@ -166,14 +202,15 @@ pub fn process_defdelegate<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<Pars
     metadata.set_delegate_target(delegate_target);
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Function,
         name,
         signature: build_elixir_signature(node, "defdelegate"),
         source: helpers::extract_source(node, 50),
-        doc_comment: String::new(),
+        doc_comment: doc.text,
         start_line: node.start_pos().line() as u32 + 1,
         end_line: node.end_pos().line() as u32 + 1,
-        visibility: Visibility::Public,
+        visibility: apply_doc_hidden(Visibility::Public, doc.explicitly_hidden),
         metadata,
     })
 }
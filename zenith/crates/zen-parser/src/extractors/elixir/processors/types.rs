@@ -18,14 +18,19 @@ pub fn process_defprotocol<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<Pars
     let methods = extract_module_methods(node);
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Interface,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "elixir"),
         source: helpers::extract_source(node, 50),
-        doc_comment: doc,
+        doc_comment: doc.text,
         start_line: node.start_pos().line() as u32 + 1,
         end_line: node.end_pos().line() as u32 + 1,
-        visibility: Visibility::Public,
+        visibility: if doc.explicitly_hidden {
+            Visibility::PublicCrate
+        } else {
+            Visibility::Public
+        },
         metadata: SymbolMetadata {
             methods,
             ..Default::default()
@@ -41,9 +46,10 @@ pub fn process_defimpl<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedIt
     let methods = extract_module_methods(node);
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Trait,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "elixir"),
         source: helpers::extract_source(node, 50),
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
@@ -104,9 +110,10 @@ pub fn process_defstruct<D: ast_grep_core::Doc>(node: &Node<D>) -> ParsedItem {
     let fields = extract_defstruct_fields(node);
 
     ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Struct,
         name: "defstruct".to_string(),
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "elixir"),
         source: helpers::extract_source(node, 50),
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
@@ -131,9 +138,10 @@ pub fn process_defexception<D: ast_grep_core::Doc>(node: &Node<D>) -> ParsedItem
     metadata.mark_error_type();
 
     ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Struct,
         name: "defexception".to_string(),
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "elixir"),
         source: helpers::extract_source(node, 50),
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
@@ -201,9 +209,10 @@ pub fn try_extract_type_attr<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<Pa
     let spec_text = args.text().to_string();
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::TypeAlias,
         name,
-        signature: format!("@{attr_name} {spec_text}"),
+        signature: helpers::normalize_signature(&format!("@{attr_name} {spec_text}"), "elixir"),
         source: Some(node.text().to_string()),
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
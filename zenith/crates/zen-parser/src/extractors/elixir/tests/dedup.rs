@@ -24,3 +24,22 @@ fn multi_clause_keeps_first_doc() {
         f.doc_comment
     );
 }
+
+#[test]
+fn multi_clause_keeps_first_spec() {
+    // Two unrelated `transform`s exist in the fixture (a private helper in
+    // Sample.Processor, and the multi-clause spec'd one in Sample.Types) —
+    // disambiguate by the spec'd return type rather than name alone.
+    let source = include_str!("../../../../tests/fixtures/sample.ex");
+    let items = parse_and_extract(source);
+    let f = find_all_by_name(&items, "transform")
+        .into_iter()
+        .find(|i| i.metadata.return_type.is_some())
+        .expect("spec'd transform/1 should survive dedup");
+    assert_eq!(
+        f.metadata.return_type.as_deref(),
+        Some("String.t()"),
+        "spec return type: {:?}",
+        f.metadata.return_type
+    );
+}
@@ -45,6 +45,21 @@ fn defdelegate_signature_format() {
     );
 }
 
+#[test]
+fn defdelegate_doc_extracted() {
+    let source = include_str!("../../../../tests/fixtures/sample.ex");
+    let items = parse_and_extract(source);
+    let delegates: Vec<_> = items
+        .iter()
+        .filter(|i| i.metadata.for_type.as_deref() == Some("Sample.Processor"))
+        .collect();
+    assert!(!delegates.is_empty());
+    assert_eq!(
+        delegates[0].doc_comment,
+        "Delegates to Sample.Processor.process/1."
+    );
+}
+
 #[test]
 fn delegator_module_methods_include_delegates() {
     let source = include_str!("../../../../tests/fixtures/sample.ex");
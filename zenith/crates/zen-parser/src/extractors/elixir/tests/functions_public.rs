@@ -34,7 +34,9 @@ fn function_params_extracted() {
     let source = include_str!("../../../../tests/fixtures/sample.ex");
     let items = parse_and_extract(source);
     let f = find_by_name(&items, "process");
-    assert_eq!(f.metadata.parameters, vec!["items"]);
+    // `@spec process(list(String.t())) :: list(String.t())` annotates the
+    // bare parameter name with its spec'd type.
+    assert_eq!(f.metadata.parameters, vec!["items: list(String.t())"]);
 }
 
 #[test]
@@ -78,6 +80,27 @@ fn function_doc_false_is_empty() {
     assert_eq!(f.doc_comment, "");
 }
 
+#[test]
+fn function_doc_false_downgrades_visibility() {
+    let source = include_str!("../../../../tests/fixtures/sample.ex");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "internal_helper");
+    assert_eq!(
+        f.visibility,
+        Visibility::PublicCrate,
+        "`@doc false` should downgrade a public def from full Public visibility"
+    );
+}
+
+#[test]
+fn function_spec_split_into_return_type_and_parameters() {
+    let source = include_str!("../../../../tests/fixtures/sample.ex");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "process");
+    assert_eq!(f.metadata.return_type.as_deref(), Some("list(String.t())"));
+    assert_eq!(f.metadata.parameters, vec!["items: list(String.t())"]);
+}
+
 #[test]
 fn oneline_function_extracted() {
     let source = include_str!("../../../../tests/fixtures/sample.ex");
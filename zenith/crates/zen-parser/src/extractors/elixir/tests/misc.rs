@@ -42,6 +42,18 @@ fn moduledoc_false_is_empty() {
     assert_eq!(m.doc_comment, "");
 }
 
+#[test]
+fn moduledoc_false_downgrades_visibility() {
+    let source = include_str!("../../../../tests/fixtures/sample.ex");
+    let items = parse_and_extract(source);
+    let m = find_by_name(&items, "Sample.Internal");
+    assert_eq!(
+        m.visibility,
+        Visibility::PublicCrate,
+        "`@moduledoc false` should downgrade a public module from full Public visibility"
+    );
+}
+
 #[test]
 fn module_methods_listed() {
     let source = include_str!("../../../../tests/fixtures/sample.ex");
@@ -0,0 +1,74 @@
+//! Node-kind-agnostic name/doc-comment resolution for the generic
+//! kind-based extractor.
+
+use ast_grep_core::Node;
+
+/// Node kinds treated as comments when walking backward for a doc comment.
+/// Scala, Solidity, and Dart all use C-style `//` and `/** */` comments,
+/// just under slightly different kind names.
+const COMMENT_KINDS: &[&str] = &[
+    "comment",
+    "line_comment",
+    "block_comment",
+    "documentation_block_comment",
+];
+
+/// Resolve a mapped node's name: either the node's own `name` field, or (for
+/// shapes like Dart's `function_declaration`/`method_declaration`, which wrap
+/// a `signature` node) the `name` field reachable through the named child
+/// field given by `name_via`.
+///
+/// Dart's `method_declaration.signature` is a `method_signature` node that
+/// has no fields of its own — it wraps an unnamed `function_signature` child
+/// that actually owns `name` — so when the field itself has no `name`, this
+/// falls through to that child.
+pub(super) fn resolve_name<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    name_via: Option<&str>,
+) -> Option<String> {
+    let target = match name_via {
+        Some(field) => node.field(field)?,
+        None => node.clone(),
+    };
+    if let Some(name) = target.field("name") {
+        return Some(name.text().to_string());
+    }
+    target
+        .children()
+        .find(|child| child.kind().as_ref() == "function_signature")
+        .and_then(|signature| signature.field("name"))
+        .map(|name| name.text().to_string())
+}
+
+/// Walk backward through comment-kind siblings collecting a doc comment,
+/// stripping `//`, `/**`/`*/`, and leading `*` markers.
+///
+/// Best-effort: the generic lane has no per-language doc-comment convention
+/// to key off of, so this treats every immediately preceding comment as
+/// documentation, same as a rich extractor's fallback path would.
+pub(super) fn extract_doc_comment<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
+    let mut blocks = Vec::new();
+    let mut current = node.prev();
+    while let Some(sibling) = current {
+        if !COMMENT_KINDS.contains(&sibling.kind().as_ref()) {
+            break;
+        }
+        let text = sibling.text().to_string();
+        let cleaned = text
+            .trim_start_matches("///")
+            .trim_start_matches("//")
+            .trim_start_matches("/**")
+            .trim_end_matches("*/")
+            .lines()
+            .map(|line| line.trim().trim_start_matches('*').trim())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if !cleaned.is_empty() {
+            blocks.push(cleaned);
+        }
+        current = sibling.prev();
+    }
+    blocks.reverse();
+    blocks.join("\n")
+}
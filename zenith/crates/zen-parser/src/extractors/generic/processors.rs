@@ -0,0 +1,66 @@
+use ast_grep_core::Node;
+use ast_grep_core::matcher::KindMatcher;
+
+use crate::extractors::helpers::extract_signature;
+use crate::types::{CommonMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
+
+use super::generic_helpers;
+
+/// One row of a generic extractor's kind table: an ast-grep node `kind`,
+/// the `SymbolKind` it normalizes to, and — for shapes that nest their name
+/// under a child field rather than owning it directly (Dart's
+/// `function_declaration`/`method_declaration` wrap a `signature` node) —
+/// the name of that field.
+pub struct KindMapping {
+    pub kind: &'static str,
+    pub symbol_kind: SymbolKind,
+    pub name_via: Option<&'static str>,
+}
+
+/// Extract symbols from `root` by walking `mappings` in table order and
+/// tagging every item `generic:<lang>` so consumers know fidelity is lower
+/// than a rich extractor.
+///
+/// Nodes whose name can't be resolved (e.g. an anonymous or malformed
+/// declaration) are skipped rather than emitted with an empty name.
+pub(super) fn extract_mapped<D: ast_grep_core::Doc>(
+    root: &Node<D>,
+    lang: &str,
+    mappings: &[KindMapping],
+) -> Vec<ParsedItem> {
+    let ts_lang = root.lang().clone();
+    let mut items = Vec::new();
+    for mapping in mappings {
+        let matcher = KindMatcher::new(mapping.kind, ts_lang.clone());
+        for node in root.find_all(&matcher) {
+            let Some(name) = generic_helpers::resolve_name(&node, mapping.name_via) else {
+                continue;
+            };
+            items.push(build_item(&node, mapping.symbol_kind, name, lang));
+        }
+    }
+    items
+}
+
+fn build_item<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    kind: SymbolKind,
+    name: String,
+    lang: &str,
+) -> ParsedItem {
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute(format!("generic:{lang}"));
+
+    ParsedItem {
+        is_deprecated: false,
+        kind,
+        name,
+        signature: extract_signature(node, lang),
+        source: Some(node.text().to_string()),
+        doc_comment: generic_helpers::extract_doc_comment(node),
+        start_line: node.start_pos().line() as u32 + 1,
+        end_line: node.end_pos().line() as u32 + 1,
+        visibility: Visibility::Public,
+        metadata,
+    }
+}
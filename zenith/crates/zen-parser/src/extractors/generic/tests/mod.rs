@@ -0,0 +1,104 @@
+use ast_grep_core::tree_sitter::LanguageExt;
+use ast_grep_language::SupportLang;
+
+use super::{extract_dart, extract_scala, extract_solidity};
+use crate::parser::DartLang;
+use crate::types::{ParsedItem, SymbolKind};
+
+fn has_attr(item: &ParsedItem, attr: &str) -> bool {
+    item.metadata.attributes.iter().any(|a| a == attr)
+}
+
+fn find_by_name<'a>(items: &'a [ParsedItem], name: &str) -> &'a ParsedItem {
+    items
+        .iter()
+        .find(|item| item.name == name)
+        .unwrap_or_else(|| {
+            let names: Vec<_> = items.iter().map(|i| i.name.as_str()).collect();
+            panic!("missing item '{name}', available={names:?}")
+        })
+}
+
+#[test]
+fn scala_smoke_fixture_extracts_trait_class_object_and_methods() {
+    let source = include_str!("../../../../tests/fixtures/sample.scala");
+    let root = SupportLang::Scala.ast_grep(source);
+    let items = extract_scala(&root).expect("scala generic extraction should succeed");
+
+    assert!(
+        items.len() >= 5,
+        "expected at least 5 items, got {}: {:?}",
+        items.len(),
+        items.iter().map(|i| &i.name).collect::<Vec<_>>()
+    );
+
+    let shape = find_by_name(&items, "Shape");
+    assert_eq!(shape.kind, SymbolKind::Trait);
+    assert!(has_attr(shape, "generic:scala"));
+
+    let circle = find_by_name(&items, "Circle");
+    assert_eq!(circle.kind, SymbolKind::Class);
+
+    let utils = find_by_name(&items, "ShapeUtils");
+    assert_eq!(utils.kind, SymbolKind::Class);
+
+    let describe = find_by_name(&items, "describe");
+    assert_eq!(describe.kind, SymbolKind::Function);
+}
+
+#[test]
+fn solidity_smoke_fixture_extracts_contract_interface_struct_and_enum() {
+    let source = include_str!("../../../../tests/fixtures/sample.sol");
+    let root = SupportLang::Solidity.ast_grep(source);
+    let items = extract_solidity(&root).expect("solidity generic extraction should succeed");
+
+    assert!(
+        items.len() >= 5,
+        "expected at least 5 items, got {}: {:?}",
+        items.len(),
+        items.iter().map(|i| &i.name).collect::<Vec<_>>()
+    );
+
+    let token = find_by_name(&items, "Token");
+    assert_eq!(token.kind, SymbolKind::Class);
+    assert!(has_attr(token, "generic:solidity"));
+
+    let iface = find_by_name(&items, "ITokenLike");
+    assert_eq!(iface.kind, SymbolKind::Interface);
+
+    let account = find_by_name(&items, "Account");
+    assert_eq!(account.kind, SymbolKind::Struct);
+
+    let status = find_by_name(&items, "Status");
+    assert_eq!(status.kind, SymbolKind::Enum);
+}
+
+#[test]
+fn dart_smoke_fixture_extracts_class_mixin_enum_and_functions() {
+    let source = include_str!("../../../../tests/fixtures/sample.dart");
+    let root = DartLang.ast_grep(source);
+    let items = extract_dart(&root).expect("dart generic extraction should succeed");
+
+    assert!(
+        items.len() >= 5,
+        "expected at least 5 items, got {}: {:?}",
+        items.len(),
+        items.iter().map(|i| &i.name).collect::<Vec<_>>()
+    );
+
+    let shape = find_by_name(&items, "Shape");
+    assert_eq!(shape.kind, SymbolKind::Class);
+    assert!(has_attr(shape, "generic:dart"));
+
+    let describable = find_by_name(&items, "Describable");
+    assert_eq!(describable.kind, SymbolKind::Trait);
+
+    let status = find_by_name(&items, "Status");
+    assert_eq!(status.kind, SymbolKind::Enum);
+
+    let compute_area = find_by_name(&items, "computeArea");
+    assert_eq!(compute_area.kind, SymbolKind::Function);
+
+    let area_method = find_by_name(&items, "area");
+    assert_eq!(area_method.kind, SymbolKind::Method);
+}
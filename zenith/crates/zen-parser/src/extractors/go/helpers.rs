@@ -215,23 +215,40 @@ pub(super) fn extract_param_decls<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<
 /// - Embedded types: `field_declaration` with only `type_identifier` (e.g., `Config`)
 /// - Embedded pointer types: `field_declaration` with `*` + `type_identifier` (e.g., `*Logger`)
 pub(super) fn extract_struct_fields<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
+    extract_struct_fields_with_tags(node)
+        .into_iter()
+        .map(|(name, _tag)| name)
+        .collect()
+}
+
+/// Like [`extract_struct_fields`], but also captures the backtick-delimited
+/// struct tag on each field (e.g. `` `json:"name" gorm:"column:name"` ``),
+/// with the surrounding backticks stripped. Embedded fields never carry a
+/// tag of their own, so their entry is always `(name, None)`.
+pub(super) fn extract_struct_fields_with_tags<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+) -> Vec<(String, Option<String>)> {
     let mut fields = Vec::new();
     for child in node.children() {
         if child.kind().as_ref() == "field_declaration_list" {
             for field in child.children() {
                 if field.kind().as_ref() == "field_declaration" {
+                    let tag = field
+                        .field("tag")
+                        .map(|tag| tag.text().trim_matches('`').to_string());
+
                     if let Some(name) = field
                         .children()
                         .find(|c| c.kind().as_ref() == "field_identifier")
                     {
                         // Named field: `Port int`
-                        fields.push(name.text().to_string());
+                        fields.push((name.text().to_string(), tag));
                     } else if let Some(type_id) = field
                         .children()
                         .find(|c| c.kind().as_ref() == "type_identifier")
                     {
                         // Embedded type: `Config` or `*Logger` (type_identifier is the name)
-                        fields.push(type_id.text().to_string());
+                        fields.push((type_id.text().to_string(), tag));
                     }
                 }
             }
@@ -271,3 +288,27 @@ pub(super) fn extract_go_type_parameters<D: ast_grep_core::Doc>(node: &Node<D>)
         .find(|c| c.kind().as_ref() == "type_parameter_list")
         .map(|tp| tp.text().to_string())
 }
+
+// ── Build constraints ──────────────────────────────────────────────
+
+/// Detect a `//go:build <expr>` constraint from the leading comment block
+/// of a Go file.
+///
+/// Build-tagged files (e.g. `foo_windows.go` alongside a `//go:build
+/// windows` comment) extract identically to any other file, so callers
+/// tag every extracted item with the constraint to keep platform variants
+/// from colliding downstream. Only the modern `//go:build` directive is
+/// recognized; it must appear before the `package` clause, per the Go
+/// spec.
+pub(super) fn detect_build_constraint(source: &str) -> Option<String> {
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if let Some(expr) = trimmed.strip_prefix("//go:build ") {
+            return Some(expr.trim().to_string());
+        }
+        if trimmed.starts_with("package ") {
+            break;
+        }
+    }
+    None
+}
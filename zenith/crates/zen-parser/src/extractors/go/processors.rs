@@ -7,16 +7,17 @@ use super::go_helpers::{
     canonical_go_type_text, canonical_receiver, extract_go_doc, extract_go_method_parameters,
     extract_go_parameters, extract_go_receiver, extract_go_return_type, extract_go_type_parameters,
     extract_go_type_params_from_spec, extract_import_specs, extract_interface_methods,
-    extract_package_name, extract_struct_fields, go_visibility,
+    extract_package_name, extract_struct_fields, extract_struct_fields_with_tags, go_visibility,
 };
 
 pub(super) fn process_package_clause<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
     let name = extract_package_name(node)?;
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "go"),
         source: helpers::extract_source(node, 20),
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
@@ -37,13 +38,14 @@ pub(super) fn process_import_declaration<D: ast_grep_core::Doc>(node: &Node<D>)
             }
 
             ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Module,
                 name: if alias.is_empty() {
                     path
                 } else {
                     format!("{path} as {alias}")
                 },
-                signature: helpers::extract_signature(node),
+                signature: helpers::extract_signature(node, "go"),
                 source: helpers::extract_source(node, 20),
                 doc_comment: String::new(),
                 start_line: node.start_pos().line() as u32 + 1,
@@ -55,7 +57,10 @@ pub(super) fn process_import_declaration<D: ast_grep_core::Doc>(node: &Node<D>)
         .collect()
 }
 
-pub(super) fn process_function<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
+pub(super) fn process_function<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    is_test_file: bool,
+) -> Option<ParsedItem> {
     let name = node
         .children()
         .find(|c| c.kind().as_ref() == "identifier")
@@ -79,10 +84,17 @@ pub(super) fn process_function<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<
         metadata.attributes.push(format!("go:type_param:{tp}"));
     }
 
+    let kind = if is_test_file && name.starts_with("Test") {
+        SymbolKind::Test
+    } else {
+        SymbolKind::Function
+    };
+
     Some(ParsedItem {
-        kind: SymbolKind::Function,
+        is_deprecated: false,
+        kind,
         name: name.clone(),
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "go"),
         source: helpers::extract_source(node, 50),
         doc_comment: doc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -127,9 +139,10 @@ pub(super) fn process_method<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<Pa
     }
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Method,
         name: name.clone(),
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "go"),
         source: helpers::extract_source(node, 50),
         doc_comment: doc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -177,21 +190,29 @@ fn extract_type_member_items<D: ast_grep_core::Doc>(
     for child in node.children() {
         match child.kind().as_ref() {
             "struct_type" => {
-                for field in extract_struct_fields(&child) {
+                for (field, tag) in extract_struct_fields_with_tags(&child) {
+                    let mut attributes = if field.chars().next().is_some_and(char::is_uppercase) {
+                        vec!["go:embedded_field".to_string()]
+                    } else {
+                        Vec::new()
+                    };
+                    if let Some(tag) = &tag {
+                        attributes.push(format!("go:tag:{tag}"));
+                    }
                     let metadata = SymbolMetadata {
                         owner_name: Some(owner_name.to_string()),
                         owner_kind: Some(owner_kind),
-                        attributes: if field.chars().next().is_some_and(char::is_uppercase) {
-                            vec!["go:embedded_field".to_string()]
-                        } else {
-                            Vec::new()
-                        },
+                        attributes,
                         ..Default::default()
                     };
+                    let signature = tag
+                        .as_ref()
+                        .map_or_else(|| field.clone(), |tag| format!("{field} `{tag}`"));
                     items.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Field,
                         name: format!("{owner_name}::{field}"),
-                        signature: field.clone(),
+                        signature,
                         source: None,
                         doc_comment: String::new(),
                         start_line: child.start_pos().line() as u32 + 1,
@@ -211,6 +232,7 @@ fn extract_type_member_items<D: ast_grep_core::Doc>(
                         ..Default::default()
                     };
                     items.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Method,
                         name: format!("{owner_name}::{method}"),
                         signature: method.clone(),
@@ -243,9 +265,10 @@ pub(super) fn process_type_spec<D: ast_grep_core::Doc>(
     let (symbol_kind, metadata) = classify_type_spec(node, &name);
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: symbol_kind,
         name: name.clone(),
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "go"),
         source: helpers::extract_source(node, 50),
         doc_comment: doc.to_string(),
         start_line: node.start_pos().line() as u32 + 1,
@@ -306,9 +329,10 @@ pub(super) fn process_type_alias<D: ast_grep_core::Doc>(
         .map(|n| n.text().to_string())?;
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::TypeAlias,
         name: name.clone(),
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "go"),
         source: helpers::extract_source(node, 50),
         doc_comment: doc.to_string(),
         start_line: node.start_pos().line() as u32 + 1,
@@ -352,9 +376,10 @@ pub(super) fn process_const_spec<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Const,
         name: name.clone(),
-        signature: node.text().to_string(),
+        signature: helpers::normalize_signature(&node.text(), "go"),
         source: Some(node.text().to_string()),
         doc_comment: doc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -407,9 +432,10 @@ pub(super) fn process_var_spec<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Static,
         name: name.clone(),
-        signature: node.text().to_string(),
+        signature: helpers::normalize_signature(&node.text(), "go"),
         source: Some(node.text().to_string()),
         doc_comment: doc,
         start_line: node.start_pos().line() as u32 + 1,
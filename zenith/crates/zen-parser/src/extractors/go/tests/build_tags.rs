@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn go_build_constraint_tags_every_item() {
+    let source = include_str!("../../../../tests/fixtures/build_tags_windows.go");
+    let items = parse_and_extract(source);
+
+    assert!(!items.is_empty());
+    for item in &items {
+        assert!(
+            item.metadata
+                .attributes
+                .iter()
+                .any(|a| a == "go:build:windows"),
+            "item {:?} missing go:build:windows attribute, has {:?}",
+            item.name,
+            item.metadata.attributes
+        );
+    }
+}
+
+#[test]
+fn go_build_constraint_differs_per_platform_file() {
+    let windows_source = include_str!("../../../../tests/fixtures/build_tags_windows.go");
+    let linux_source = include_str!("../../../../tests/fixtures/build_tags_linux.go");
+
+    let windows_fn = find_by_name(&parse_and_extract(windows_source), "OpenFile").clone();
+    let linux_fn = find_by_name(&parse_and_extract(linux_source), "OpenFile").clone();
+
+    assert!(
+        windows_fn
+            .metadata
+            .attributes
+            .contains(&"go:build:windows".to_string())
+    );
+    assert!(
+        linux_fn
+            .metadata
+            .attributes
+            .contains(&"go:build:linux".to_string())
+    );
+}
+
+#[test]
+fn no_build_constraint_when_absent() {
+    let source = "package demo\nfunc x() {}";
+    let items = parse_and_extract(source);
+    for item in &items {
+        assert!(!item.metadata.attributes.iter().any(|a| a.starts_with("go:build:")));
+    }
+}
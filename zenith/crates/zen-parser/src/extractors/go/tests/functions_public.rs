@@ -8,3 +8,21 @@ fn constructor_function_extracted() {
     assert_eq!(f.kind, SymbolKind::Function);
     assert_eq!(f.visibility, Visibility::Public);
 }
+
+#[test]
+fn test_function_in_test_file_gets_test_kind() {
+    let source =
+        "package demo\n\nfunc TestAdd(t *testing.T) {\n\tif 1+1 != 2 {\n\t\tt.Fail()\n\t}\n}\n";
+    let items = parse_and_extract_with_path(source, "adder_test.go");
+    let f = find_by_name(&items, "TestAdd");
+    assert_eq!(f.kind, SymbolKind::Test);
+}
+
+#[test]
+fn test_prefixed_function_outside_test_file_stays_function() {
+    let source =
+        "package demo\n\nfunc TestAdd(t *testing.T) {\n\tif 1+1 != 2 {\n\t\tt.Fail()\n\t}\n}\n";
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "TestAdd");
+    assert_eq!(f.kind, SymbolKind::Function);
+}
@@ -3,6 +3,7 @@ use ast_grep_language::LanguageExt;
 use super::*;
 pub(super) use crate::types::{SymbolKind, Visibility};
 
+mod build_tags;
 mod constants_vars;
 mod dedupe_edge_cases;
 mod embedded_types_edge_cases;
@@ -24,7 +25,12 @@ mod variadics;
 
 fn parse_and_extract(source: &str) -> Vec<ParsedItem> {
     let root = SupportLang::Go.ast_grep(source);
-    extract(&root).expect("extraction should succeed")
+    extract(&root, source, "main.go").expect("extraction should succeed")
+}
+
+fn parse_and_extract_with_path(source: &str, file_path: &str) -> Vec<ParsedItem> {
+    let root = SupportLang::Go.ast_grep(source);
+    extract(&root, source, file_path).expect("extraction should succeed")
 }
 
 fn find_by_name<'a>(items: &'a [ParsedItem], name: &str) -> &'a ParsedItem {
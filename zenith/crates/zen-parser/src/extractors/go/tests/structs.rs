@@ -84,3 +84,43 @@ fn named_fields_still_work_with_embedded() {
         s.metadata.fields
     );
 }
+
+#[test]
+fn struct_tag_captured_in_field_item_signature() {
+    let source = include_str!("../../../../tests/fixtures/sample.go");
+    let items = parse_and_extract(source);
+    let host = find_by_name(&items, "Server::Host");
+    assert_eq!(host.signature, r#"Host `json:"host" gorm:"column:host"`"#);
+}
+
+#[test]
+fn struct_tag_captured_in_field_attributes() {
+    let source = include_str!("../../../../tests/fixtures/sample.go");
+    let items = parse_and_extract(source);
+    let host = find_by_name(&items, "Server::Host");
+    assert!(
+        host.metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == r#"go:tag:json:"host" gorm:"column:host""#),
+        "attributes: {:?}",
+        host.metadata.attributes
+    );
+}
+
+#[test]
+fn field_without_tag_has_no_tag_attribute() {
+    let source = include_str!("../../../../tests/fixtures/sample.go");
+    let items = parse_and_extract(source);
+    let port = find_by_name(&items, "Server::Port");
+    assert_eq!(port.signature, "Port");
+    assert!(
+        !port
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr.starts_with("go:tag:")),
+        "attributes: {:?}",
+        port.metadata.attributes
+    );
+}
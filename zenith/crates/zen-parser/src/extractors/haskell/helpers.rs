@@ -1,4 +1,7 @@
 use ast_grep_core::Node;
+use ast_grep_core::matcher::KindMatcher;
+use ast_grep_core::ops::Any;
+use ast_grep_language::SupportLang;
 
 use crate::types::SymbolKind;
 
@@ -57,6 +60,19 @@ pub(super) fn extract_data_constructors<D: ast_grep_core::Doc>(node: &Node<D>) -
 }
 
 pub(super) fn extract_record_fields<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
+    extract_record_field_entries(node)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Extract `(field_name, field_type)` pairs from a record-style data
+/// declaration, e.g. `data Widget = Widget { widgetId :: Int, widgetName ::
+/// T.Text }`. Fields sharing a type via `a, b :: T` are each given their own
+/// entry with that shared type.
+pub(super) fn extract_record_field_entries<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+) -> Vec<(String, String)> {
     let text = node.text().to_string();
     let Some(start) = text.find('{') else {
         return Vec::new();
@@ -69,21 +85,94 @@ pub(super) fn extract_record_fields<D: ast_grep_core::Doc>(node: &Node<D>) -> Ve
     }
 
     let body = &text[start + 1..end];
-    body.split(',')
-        .filter_map(|field| {
-            let candidate = field
-                .split("::")
-                .next()
-                .unwrap_or("")
-                .split_whitespace()
-                .next()
-                .unwrap_or("")
-                .trim();
-            if candidate.is_empty() {
-                None
-            } else {
-                Some(candidate.to_string())
+    let mut entries = Vec::new();
+    let mut pending_names: Vec<String> = Vec::new();
+
+    for segment in body.split(',') {
+        let segment = segment.trim();
+        if segment.is_empty() {
+            continue;
+        }
+
+        if let Some((names_part, type_part)) = segment.split_once("::") {
+            let field_type = type_part.trim().to_string();
+            for name in std::mem::take(&mut pending_names) {
+                entries.push((name, field_type.clone()));
+            }
+            for name in names_part.split_whitespace() {
+                entries.push((name.to_string(), field_type.clone()));
             }
+        } else if let Some(name) = segment.split_whitespace().next() {
+            pending_names.push(name.to_string());
+        }
+    }
+
+    entries
+}
+
+/// Extract `(class_name, type_text)` from an `instance` declaration, e.g.
+/// `("ToJSON", "Person")` from `instance ToJSON Person where ...`.
+pub(super) fn extract_instance_class_and_type<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+) -> Option<(String, String)> {
+    let class_name = node.field("name")?.text().trim().to_string();
+    let type_name = node.field("patterns")?.text().trim().to_string();
+    if class_name.is_empty() || type_name.is_empty() {
+        return None;
+    }
+    Some((class_name, type_name))
+}
+
+/// Find the method-like declarations (`function`, `bind`, `signature`)
+/// directly inside an `instance` declaration's `where` block.
+pub(super) fn instance_method_nodes<'r, D: ast_grep_core::Doc<Lang = SupportLang>>(
+    node: &Node<'r, D>,
+) -> Vec<Node<'r, D>> {
+    let Some(declarations) = node.field("declarations") else {
+        return Vec::new();
+    };
+    let matcher = Any::new(vec![
+        KindMatcher::new("function", SupportLang::Haskell),
+        KindMatcher::new("bind", SupportLang::Haskell),
+        KindMatcher::new("signature", SupportLang::Haskell),
+    ]);
+    declarations.find_all(&matcher).map(Node::from).collect()
+}
+
+/// Names of the method-like declarations inside an `instance` declaration.
+pub(super) fn instance_method_names<D: ast_grep_core::Doc<Lang = SupportLang>>(
+    node: &Node<'_, D>,
+) -> Vec<String> {
+    instance_method_nodes(node)
+        .iter()
+        .filter_map(extract_name)
+        .collect()
+}
+
+/// Extract the class names listed in `deriving (...)` clauses attached to a
+/// data/newtype declaration.
+pub(super) fn extract_deriving_clauses<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
+    node.field_children("deriving")
+        .flat_map(|deriving| {
+            let text = deriving.text().to_string();
+            let inner = text
+                .trim()
+                .trim_start_matches("deriving")
+                .trim()
+                .trim_start_matches("newtype")
+                .trim()
+                .trim_start_matches("stock")
+                .trim()
+                .trim_start_matches("anyclass")
+                .trim()
+                .trim_start_matches('(')
+                .trim_end_matches(')')
+                .to_string();
+            inner
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
         })
         .collect()
 }
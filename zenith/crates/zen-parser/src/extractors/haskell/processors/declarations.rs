@@ -1,6 +1,7 @@
 use ast_grep_core::Node;
+use ast_grep_language::SupportLang;
 
-use crate::types::{ParsedItem, SymbolKind, SymbolMetadata};
+use crate::types::{CommonMetadataExt, ParsedItem, SymbolKind, SymbolMetadata};
 
 use super::super::hs_helpers;
 use super::build_item;
@@ -64,9 +65,85 @@ pub(super) fn process_type_decl<D: ast_grep_core::Doc>(node: &Node<D>) -> Option
         _ => return None,
     };
 
+    for class in hs_helpers::extract_deriving_clauses(node) {
+        metadata.push_attribute(format!("haskell:deriving:{class}"));
+    }
+
     Some(build_item(node, kind, name, metadata))
 }
 
+/// Emit one `Field` item per named field of a record-style data declaration,
+/// so record accessors show up as searchable symbols in their own right.
+pub(super) fn process_record_field_items<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<ParsedItem> {
+    let Some(owner_name) = hs_helpers::extract_name(node) else {
+        return Vec::new();
+    };
+
+    hs_helpers::extract_record_field_entries(node)
+        .into_iter()
+        .map(|(field_name, field_type)| {
+            let metadata = SymbolMetadata {
+                owner_name: Some(owner_name.clone()),
+                owner_kind: Some(SymbolKind::Struct),
+                return_type: Some(field_type),
+                ..Default::default()
+            };
+            build_item(node, SymbolKind::Field, field_name, metadata)
+        })
+        .collect()
+}
+
+/// Process an `instance` declaration as a method-bearing item named
+/// `ClassName TypeName`, e.g. `ToJSON Person`.
+pub(super) fn process_instance<D: ast_grep_core::Doc<Lang = SupportLang>>(
+    node: &Node<D>,
+) -> Option<ParsedItem> {
+    let (class_name, type_name) = hs_helpers::extract_instance_class_and_type(node)?;
+    let mut metadata = SymbolMetadata {
+        trait_name: Some(class_name.clone()),
+        for_type: Some(type_name.clone()),
+        methods: hs_helpers::instance_method_names(node),
+        ..Default::default()
+    };
+    metadata.push_attribute("haskell:instance");
+
+    Some(build_item(
+        node,
+        SymbolKind::Trait,
+        format!("{class_name} {type_name}"),
+        metadata,
+    ))
+}
+
+/// Emit the method bindings inside an `instance` declaration as `Method`
+/// members owned by the instance item.
+pub(super) fn process_instance_members<D: ast_grep_core::Doc<Lang = SupportLang>>(
+    node: &Node<D>,
+) -> Vec<ParsedItem> {
+    let Some((class_name, type_name)) = hs_helpers::extract_instance_class_and_type(node) else {
+        return Vec::new();
+    };
+    let owner_name = format!("{class_name} {type_name}");
+
+    hs_helpers::instance_method_nodes(node)
+        .into_iter()
+        .filter_map(|method_node| {
+            let name = hs_helpers::extract_name(&method_node)?;
+            let metadata = SymbolMetadata {
+                owner_name: Some(owner_name.clone()),
+                owner_kind: Some(SymbolKind::Trait),
+                ..Default::default()
+            };
+            Some(build_item(
+                &method_node,
+                SymbolKind::Method,
+                format!("{owner_name}::{name}"),
+                metadata,
+            ))
+        })
+        .collect()
+}
+
 pub(super) fn dedupe_and_merge(items: Vec<ParsedItem>) -> Vec<ParsedItem> {
     let mut deduped: Vec<ParsedItem> = Vec::new();
 
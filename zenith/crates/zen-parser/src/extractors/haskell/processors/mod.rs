@@ -24,6 +24,24 @@ pub(super) fn process_type_decl<D: ast_grep_core::Doc>(node: &Node<D>) -> Option
     declarations::process_type_decl(node)
 }
 
+pub(super) fn process_record_field_items<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<ParsedItem> {
+    declarations::process_record_field_items(node)
+}
+
+pub(super) fn process_instance<D: ast_grep_core::Doc<Lang = ast_grep_language::SupportLang>>(
+    node: &Node<D>,
+) -> Option<ParsedItem> {
+    declarations::process_instance(node)
+}
+
+pub(super) fn process_instance_members<
+    D: ast_grep_core::Doc<Lang = ast_grep_language::SupportLang>,
+>(
+    node: &Node<D>,
+) -> Vec<ParsedItem> {
+    declarations::process_instance_members(node)
+}
+
 pub(super) fn dedupe_and_merge(items: Vec<ParsedItem>) -> Vec<ParsedItem> {
     declarations::dedupe_and_merge(items)
 }
@@ -35,9 +53,10 @@ pub(super) fn build_item<D: ast_grep_core::Doc>(
     metadata: SymbolMetadata,
 ) -> ParsedItem {
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
-        signature: crate::extractors::helpers::extract_signature(node),
+        signature: crate::extractors::helpers::extract_signature(node, "haskell"),
         source: crate::extractors::helpers::extract_source(node, 40),
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
@@ -0,0 +1,91 @@
+use super::*;
+
+#[test]
+fn instance_declarations_become_method_bearing_items() {
+    let items = aeson_fixture_items();
+
+    let to_json = find_by_name(&items, "ToJSON Person");
+    assert_eq!(to_json.kind, SymbolKind::Trait);
+    assert!(
+        to_json
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "haskell:instance")
+    );
+    assert!(to_json.metadata.methods.iter().any(|m| m == "toJSON"));
+
+    let from_json = find_by_name(&items, "FromJSON Person");
+    assert_eq!(from_json.kind, SymbolKind::Trait);
+    assert!(from_json.metadata.methods.iter().any(|m| m == "parseJSON"));
+}
+
+#[test]
+fn instance_methods_are_emitted_as_owned_members() {
+    let items = aeson_fixture_items();
+
+    let to_json_method = find_by_name(&items, "ToJSON Person::toJSON");
+    assert_eq!(to_json_method.kind, SymbolKind::Method);
+    assert_eq!(
+        to_json_method.metadata.owner_name.as_deref(),
+        Some("ToJSON Person")
+    );
+    assert_eq!(to_json_method.metadata.owner_kind, Some(SymbolKind::Trait));
+
+    let parse_json_method = find_by_name(&items, "FromJSON Person::parseJSON");
+    assert_eq!(parse_json_method.kind, SymbolKind::Method);
+    assert_eq!(
+        parse_json_method.metadata.owner_name.as_deref(),
+        Some("FromJSON Person")
+    );
+}
+
+#[test]
+fn record_fields_are_emitted_with_return_type() {
+    let items = aeson_fixture_items();
+
+    let name_field = find_by_name(&items, "personName");
+    assert_eq!(name_field.kind, SymbolKind::Field);
+    assert_eq!(name_field.metadata.owner_name.as_deref(), Some("Person"));
+    assert_eq!(name_field.metadata.owner_kind, Some(SymbolKind::Struct));
+    assert!(
+        name_field
+            .metadata
+            .return_type
+            .as_deref()
+            .unwrap_or("")
+            .contains("Text")
+    );
+
+    let age_field = find_by_name(&items, "personAge");
+    assert_eq!(age_field.kind, SymbolKind::Field);
+    assert_eq!(age_field.metadata.return_type.as_deref(), Some("Int"));
+}
+
+#[test]
+fn deriving_clauses_are_recorded_as_attributes() {
+    let items = aeson_fixture_items();
+
+    let person = find_by_name(&items, "Person");
+    assert!(
+        person
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "haskell:deriving:Eq")
+    );
+    assert!(
+        person
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "haskell:deriving:Show")
+    );
+    assert!(
+        person
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "haskell:deriving:Generic")
+    );
+}
@@ -5,6 +5,7 @@ pub(super) use crate::types::{ParsedItem, SymbolKind};
 
 mod foreign_symbols;
 mod functions_and_signatures;
+mod instances_and_fields;
 mod lines_and_signatures;
 mod modules_and_imports;
 mod types_and_classes;
@@ -29,3 +30,8 @@ fn fixture_items() -> Vec<ParsedItem> {
     let source = include_str!("../../../../tests/fixtures/sample.hs");
     parse_and_extract(source)
 }
+
+fn aeson_fixture_items() -> Vec<ParsedItem> {
+    let source = include_str!("../../../../tests/fixtures/aeson_like.hs");
+    parse_and_extract(source)
+}
@@ -0,0 +1,215 @@
+//! Block-header and shallow-body parsing heuristics for HCL/Terraform documents.
+
+/// The top-level HCL block kinds this extractor recognizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum HclBlockKind {
+    Resource,
+    Module,
+    Variable,
+    Output,
+    Provider,
+}
+
+/// A top-level HCL block detected by heuristic line scanning.
+#[derive(Debug, Clone)]
+pub(super) struct HclBlock {
+    pub kind: HclBlockKind,
+    /// The block's type label, e.g. `aws_instance` in `resource "aws_instance" "web"`.
+    /// Only `resource` blocks carry a second label; the rest are `None`.
+    pub block_type: Option<String>,
+    /// The block's name label, e.g. `web` in `resource "aws_instance" "web"`.
+    pub name: String,
+    /// Zero-based line index of the line holding the opening `{`.
+    pub start_line: usize,
+    /// Zero-based line index of the line holding the matching closing `}`.
+    pub end_line: usize,
+}
+
+/// Scan `source` for top-level `resource`/`module`/`variable`/`output`/`provider`
+/// blocks, using brace counting to find each block's extent.
+///
+/// This is a heuristic line scan, not a real HCL parser — it doesn't handle
+/// braces embedded in string literals or heredocs. Good enough for the
+/// well-formatted `.tf` files this extractor targets.
+pub(super) fn detect_blocks(source: &str) -> Vec<HclBlock> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if let Some((kind, block_type, name)) = parse_block_header(lines[i])
+            && let Some(end_line) = find_block_end(&lines, i)
+        {
+            blocks.push(HclBlock {
+                kind,
+                block_type,
+                name,
+                start_line: i,
+                end_line,
+            });
+            i = end_line + 1;
+            continue;
+        }
+        i += 1;
+    }
+    blocks
+}
+
+/// Parse a single line as a recognized block header, e.g.
+/// `resource "aws_instance" "web" {` or `module "vpc" {`.
+fn parse_block_header(line: &str) -> Option<(HclBlockKind, Option<String>, String)> {
+    let trimmed = line.trim();
+    let body = trimmed.strip_suffix('{')?.trim();
+    let (keyword, rest) = body.split_once(char::is_whitespace)?;
+    let kind = match keyword {
+        "resource" => HclBlockKind::Resource,
+        "module" => HclBlockKind::Module,
+        "variable" => HclBlockKind::Variable,
+        "output" => HclBlockKind::Output,
+        "provider" => HclBlockKind::Provider,
+        _ => return None,
+    };
+
+    let mut labels = extract_quoted_labels(rest).into_iter();
+    if kind == HclBlockKind::Resource {
+        let block_type = labels.next()?;
+        let name = labels.next()?;
+        Some((kind, Some(block_type), name))
+    } else {
+        let name = labels.next()?;
+        Some((kind, None, name))
+    }
+}
+
+/// Extract the contents of each double-quoted `"..."` label on a line.
+fn extract_quoted_labels(s: &str) -> Vec<String> {
+    let mut labels = Vec::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '"' {
+            labels.push(chars.by_ref().take_while(|&c| c != '"').collect());
+        }
+    }
+    labels
+}
+
+/// Find the line index of the `}` that closes the block opened on `lines[start]`,
+/// by counting braces across subsequent lines.
+fn find_block_end(lines: &[&str], start: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    for (offset, line) in lines[start..].iter().enumerate() {
+        for c in line.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+        if depth == 0 {
+            return Some(start + offset);
+        }
+    }
+    None
+}
+
+/// Collect the names of `key = value` attributes directly inside a block body
+/// (depth 1 relative to the block), skipping over anything nested inside a
+/// child block. Used to populate `SymbolMetadata::fields` with a shallow
+/// summary of the block's contents without recursing into nested blocks.
+pub(super) fn shallow_attribute_names(lines: &[&str], block: &HclBlock) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut depth = 0i32;
+    for line in &lines[block.start_line + 1..block.end_line] {
+        let trimmed = line.trim();
+        if depth == 0
+            && let Some((key, _)) = trimmed.split_once('=')
+            && is_attribute_name(key.trim())
+        {
+            names.push(key.trim().to_string());
+        }
+        for c in trimmed.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+    names
+}
+
+/// Find the value assigned to a top-level `default = ...` attribute directly
+/// inside a `variable` block body, if present.
+pub(super) fn find_default_value(lines: &[&str], block: &HclBlock) -> Option<String> {
+    let mut depth = 0i32;
+    for line in &lines[block.start_line + 1..block.end_line] {
+        let trimmed = line.trim();
+        if depth == 0
+            && let Some((key, value)) = trimmed.split_once('=')
+            && key.trim() == "default"
+        {
+            return Some(value.trim().to_string());
+        }
+        for c in trimmed.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// Whether `s` looks like a bare HCL attribute identifier (not a nested
+/// block's quoted label or a `key = value` line that isn't really one).
+fn is_attribute_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        && s.chars().next().is_some_and(|c| !c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_resource_block() {
+        let source = "resource \"aws_instance\" \"web\" {\n  ami = \"abc\"\n}\n";
+        let blocks = detect_blocks(source);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].kind, HclBlockKind::Resource);
+        assert_eq!(blocks[0].block_type.as_deref(), Some("aws_instance"));
+        assert_eq!(blocks[0].name, "web");
+    }
+
+    #[test]
+    fn detects_module_block() {
+        let source = "module \"vpc\" {\n  source = \"./modules/vpc\"\n}\n";
+        let blocks = detect_blocks(source);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0].kind, HclBlockKind::Module);
+        assert_eq!(blocks[0].block_type, None);
+        assert_eq!(blocks[0].name, "vpc");
+    }
+
+    #[test]
+    fn shallow_attributes_skip_nested_block() {
+        let source = "resource \"aws_instance\" \"web\" {\n  ami = \"abc\"\n  tags = {\n    Name = \"web\"\n  }\n}\n";
+        let lines: Vec<&str> = source.lines().collect();
+        let blocks = detect_blocks(source);
+        let names = shallow_attribute_names(&lines, &blocks[0]);
+        assert_eq!(names, vec!["ami".to_string(), "tags".to_string()]);
+    }
+
+    #[test]
+    fn finds_default_value() {
+        let source = "variable \"region\" {\n  type    = string\n  default = \"us-east-1\"\n}\n";
+        let lines: Vec<&str> = source.lines().collect();
+        let blocks = detect_blocks(source);
+        assert_eq!(
+            find_default_value(&lines, &blocks[0]),
+            Some("\"us-east-1\"".to_string())
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Build `ParsedItem`s from heuristically-detected HCL blocks.
+
+use std::fmt::Write as _;
+
+use crate::types::{CommonMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
+
+use super::helpers::{HclBlock, HclBlockKind, find_default_value, shallow_attribute_names};
+
+/// Build a `ParsedItem` for the document root.
+pub(super) fn root_item(total_lines: u32) -> ParsedItem {
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute("hcl:kind:document");
+    ParsedItem {
+        is_deprecated: false,
+        kind: SymbolKind::Module,
+        name: "$".to_string(),
+        signature: "document".to_string(),
+        source: None,
+        doc_comment: String::new(),
+        start_line: 1,
+        end_line: total_lines.max(1),
+        visibility: Visibility::Public,
+        metadata,
+    }
+}
+
+/// Build a `ParsedItem` for a single detected HCL block.
+pub(super) fn block_item(lines: &[&str], block: &HclBlock) -> ParsedItem {
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute(format!("hcl:block:{}", block_keyword(block.kind)));
+    metadata.set_fields(shallow_attribute_names(lines, block));
+
+    let (kind, name, signature) = match block.kind {
+        HclBlockKind::Resource => {
+            let block_type = block.block_type.as_deref().unwrap_or_default();
+            metadata.push_attribute(format!("hcl:type:{block_type}"));
+            (
+                SymbolKind::Component,
+                format!("{block_type}.{}", block.name),
+                format!("resource \"{block_type}\" \"{}\"", block.name),
+            )
+        }
+        HclBlockKind::Module => (
+            SymbolKind::Module,
+            block.name.clone(),
+            format!("module \"{}\"", block.name),
+        ),
+        HclBlockKind::Variable => {
+            let mut signature = format!("variable \"{}\"", block.name);
+            if let Some(default) = find_default_value(lines, block) {
+                metadata.push_attribute(format!("hcl:default:{default}"));
+                let _ = write!(signature, " (default = {default})");
+            }
+            (SymbolKind::Static, block.name.clone(), signature)
+        }
+        HclBlockKind::Output => (
+            SymbolKind::Property,
+            block.name.clone(),
+            format!("output \"{}\"", block.name),
+        ),
+        HclBlockKind::Provider => (
+            SymbolKind::Interface,
+            block.name.clone(),
+            format!("provider \"{}\"", block.name),
+        ),
+    };
+
+    ParsedItem {
+        is_deprecated: false,
+        kind,
+        name,
+        signature,
+        source: Some(lines[block.start_line..=block.end_line].join("\n")),
+        doc_comment: String::new(),
+        start_line: block.start_line as u32 + 1,
+        end_line: block.end_line as u32 + 1,
+        visibility: Visibility::Public,
+        metadata,
+    }
+}
+
+const fn block_keyword(kind: HclBlockKind) -> &'static str {
+    match kind {
+        HclBlockKind::Resource => "resource",
+        HclBlockKind::Module => "module",
+        HclBlockKind::Variable => "variable",
+        HclBlockKind::Output => "output",
+        HclBlockKind::Provider => "provider",
+    }
+}
@@ -0,0 +1,44 @@
+use crate::types::SymbolKind;
+
+use super::*;
+
+#[test]
+fn resource_block_is_extracted_as_component() {
+    let source = "resource \"aws_instance\" \"web\" {\n  ami           = \"ami-123\"\n  instance_type = \"t3.micro\"\n}\n";
+    let items = extract(source).unwrap();
+    let resource = items
+        .iter()
+        .find(|i| i.kind == SymbolKind::Component)
+        .expect("resource block should be extracted");
+    assert_eq!(resource.name, "aws_instance.web");
+    assert_eq!(resource.signature, "resource \"aws_instance\" \"web\"");
+}
+
+#[test]
+fn variable_with_default_is_extracted_as_static() {
+    let source = "variable \"region\" {\n  type    = string\n  default = \"us-east-1\"\n}\n";
+    let items = extract(source).unwrap();
+    let variable = items
+        .iter()
+        .find(|i| i.kind == SymbolKind::Static)
+        .expect("variable block should be extracted");
+    assert_eq!(variable.name, "region");
+    assert!(
+        variable
+            .metadata
+            .attributes
+            .contains(&"hcl:default:\"us-east-1\"".to_string()),
+        "default value should be captured in metadata"
+    );
+}
+
+#[test]
+fn module_reference_is_extracted_as_module() {
+    let source = "module \"vpc\" {\n  source = \"./modules/vpc\"\n  cidr   = \"10.0.0.0/16\"\n}\n";
+    let items = extract(source).unwrap();
+    let module = items
+        .iter()
+        .find(|i| i.kind == SymbolKind::Module && i.name == "vpc")
+        .expect("module block should be extracted");
+    assert_eq!(module.signature, "module \"vpc\"");
+}
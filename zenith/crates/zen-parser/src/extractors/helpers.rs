@@ -11,7 +11,7 @@ use crate::types::Visibility;
 ///
 /// Spike 0.21 finding: normalize whitespace (collapse newlines/runs to single space)
 /// for deterministic signatures regardless of source formatting.
-pub fn extract_signature<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
+pub fn extract_signature<D: ast_grep_core::Doc>(node: &Node<D>, lang: &str) -> String {
     let text = node.text().to_string();
     let brace = text.find('{');
     let semi = text.find(';');
@@ -21,11 +21,45 @@ pub fn extract_signature<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
         (None, Some(s)) => s,
         (None, None) => text.len(),
     };
-    let sig = text[..end].trim();
-    sig.replace('\n', " ")
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join(" ")
+    normalize_signature(&text[..end], lang)
+}
+
+/// Maximum length, in characters, of a normalized signature before it is
+/// truncated with an ellipsis. Chosen to keep heavily-attributed items
+/// (e.g. a Rust function whose generic bounds spill across several lines)
+/// from bloating the lake.
+const MAX_SIGNATURE_LEN: usize = 512;
+
+/// Normalize a raw signature string into the shape every
+/// `ParsedItem::signature` should have: no trailing `{`/`;`/`:`, whitespace
+/// collapsed to single spaces, and capped at [`MAX_SIGNATURE_LEN`]
+/// characters with an ellipsis.
+///
+/// Attributes/decorators are not stripped here — extractors are expected to
+/// pull those into `SymbolMetadata::attributes` via `extract_attributes` (or
+/// the language's equivalent) and keep them out of the raw signature text in
+/// the first place, since attribute nodes are separate AST siblings rather
+/// than part of an item's own text.
+pub fn normalize_signature(raw: &str, lang: &str) -> String {
+    let mut sig = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    while sig.ends_with(['{', ';', ':']) {
+        sig.pop();
+        while sig.ends_with(char::is_whitespace) {
+            sig.pop();
+        }
+    }
+
+    if sig.chars().count() <= MAX_SIGNATURE_LEN {
+        return sig;
+    }
+
+    tracing::trace!(
+        lang,
+        original_len = sig.chars().count(),
+        "signature exceeded {MAX_SIGNATURE_LEN} chars, truncating"
+    );
+    let truncated: String = sig.chars().take(MAX_SIGNATURE_LEN).collect();
+    format!("{truncated}...")
 }
 
 /// Extract Python signature: definition line(s) before the body.
@@ -63,7 +97,7 @@ pub fn extract_signature_python<D: ast_grep_core::Doc>(node: &Node<D>) -> String
     if let Some(rt) = return_type {
         sig.push_str(&rt);
     }
-    sig
+    normalize_signature(&sig, "python")
 }
 
 /// Extract full source up to `max_lines` lines.
@@ -278,6 +312,51 @@ pub fn is_pyo3(attrs: &[String]) -> bool {
     })
 }
 
+/// Check if an item is marked `#[deprecated]` or `#[deprecated(...)]`.
+pub fn is_deprecated_attribute(attrs: &[String]) -> bool {
+    attrs
+        .iter()
+        .any(|a| a == "deprecated" || a.starts_with("deprecated("))
+}
+
+/// Extract the idents listed in a `#[derive(...)]` attribute, if present.
+pub fn extract_derives(attrs: &[String]) -> Vec<String> {
+    attrs
+        .iter()
+        .find_map(|a| a.strip_prefix("derive(")?.strip_suffix(')'))
+        .map(|inner| {
+            inner
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The macro name a `#[proc_macro]` or `#[proc_macro_derive(...)]` function
+/// exports, if any. `#[proc_macro]` (and `#[proc_macro_attribute]`) export
+/// under the function's own name; `#[proc_macro_derive(Name, ...)]` exports
+/// under `Name`.
+pub fn proc_macro_export_name(attrs: &[String], fn_name: &str) -> Option<String> {
+    for attr in attrs {
+        if attr == "proc_macro" || attr == "proc_macro_attribute" {
+            return Some(fn_name.to_string());
+        }
+        if let Some(rest) = attr
+            .strip_prefix("proc_macro_derive(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            let name = rest.split(',').next().unwrap_or(rest).trim();
+            if !name.is_empty() {
+                return Some(name.to_string());
+            }
+        }
+    }
+    None
+}
+
 /// Analyze attributes for common semantic flags.
 ///
 /// Returns `(is_cfg, is_deprecated, is_must_use, is_doc_hidden)`.
@@ -446,7 +525,7 @@ mod tests {
                 SupportLang::Rust,
             ))
             .expect("should find function");
-        let sig = extract_signature(&func);
+        let sig = extract_signature(&func, "rust");
         assert!(!sig.contains('{'));
         assert!(sig.contains("fn hello"));
     }
@@ -462,7 +541,7 @@ mod tests {
                 SupportLang::Rust,
             ))
             .expect("should find function");
-        let sig = extract_signature(&func);
+        let sig = extract_signature(&func, "rust");
         assert!(!sig.contains('\n'));
     }
 
@@ -67,6 +67,7 @@ fn process_element<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<Parsed
     metadata.set_self_closing(is_self_closing);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: symbol_kind,
         name,
         signature,
@@ -97,6 +98,7 @@ fn process_script_element<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec
     metadata.set_html_attributes(attrs);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
         signature,
@@ -118,6 +120,7 @@ fn process_style_element<D: ast_grep_core::Doc>(node: &Node<D>, items: &mut Vec<
     metadata.set_html_attributes(attrs);
 
     items.push(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name: "inline-style".to_string(),
         signature,
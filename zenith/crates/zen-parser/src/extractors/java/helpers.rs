@@ -1,6 +1,6 @@
 use ast_grep_core::Node;
 
-use crate::types::{SymbolKind, Visibility};
+use crate::types::{DocSections, SymbolKind, Visibility};
 
 pub(super) struct ModuleDirectiveParts {
     pub directive: String,
@@ -48,6 +48,38 @@ pub(super) fn extract_javadoc_before<D: ast_grep_core::Doc>(anchor: &Node<D>) ->
     docs.join("\n")
 }
 
+/// Parse Javadoc tags (`@param`, `@return`, `@throws`) out of a raw
+/// [`extract_javadoc_before`] comment into a normalized [`DocSections`].
+pub(super) fn parse_javadoc_sections(doc: &str) -> DocSections {
+    let mut sections = DocSections::default();
+    for line in doc.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("@param ") {
+            let rest = rest.trim();
+            if let Some((name, desc)) = rest.split_once(char::is_whitespace) {
+                sections
+                    .args
+                    .insert(name.to_string(), desc.trim().to_string());
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("@return ") {
+            sections.returns = Some(rest.trim().to_string());
+        } else if let Some(rest) = trimmed.strip_prefix("@throws ") {
+            let rest = rest.trim();
+            let (exc, desc) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            sections
+                .raises
+                .insert(exc.to_string(), desc.trim().to_string());
+        }
+    }
+    sections
+}
+
+/// Whether a parsed Javadoc comment carries an `@deprecated` tag.
+pub(super) fn has_deprecated_tag(doc: &str) -> bool {
+    doc.lines()
+        .any(|line| line.trim().starts_with("@deprecated"))
+}
+
 pub(super) fn extract_modifiers<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
     const JAVA_MODIFIERS: &[&str] = &[
         "public",
@@ -110,10 +142,94 @@ pub(super) fn extract_annotations<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<
             let kind = child.kind();
             kind.as_ref() == "annotation" || kind.as_ref() == "marker_annotation"
         })
-        .map(|child| child.text().to_string())
+        .map(|child| normalize_whitespace(child.text().as_ref()))
         .collect()
 }
 
+/// Collapse runs of whitespace (including the newlines and indentation of an
+/// annotation wrapped across multiple lines, e.g. `@RequestMapping(\n    path
+/// = "/x",\n    method = GET\n)`) into single spaces, so a symbol's
+/// attributes are stable regardless of how the source formatted them.
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Well-known Spring Framework annotations. Codebases built on Spring
+/// (Boot, MVC, Data) mark component roles and request mappings this way,
+/// so surfacing them lets downstream tooling identify a class's role
+/// without re-parsing the raw source.
+const SPRING_ANNOTATIONS: &[&str] = &[
+    "RestController",
+    "Controller",
+    "Service",
+    "Repository",
+    "Component",
+    "Configuration",
+    "Bean",
+    "Autowired",
+    "Qualifier",
+    "Value",
+    "RequestMapping",
+    "GetMapping",
+    "PostMapping",
+    "PutMapping",
+    "DeleteMapping",
+    "PatchMapping",
+    "RequestParam",
+    "RequestBody",
+    "ResponseBody",
+    "PathVariable",
+    "ExceptionHandler",
+    "Transactional",
+    "Scheduled",
+    "Async",
+    "Profile",
+    "SpringBootApplication",
+];
+
+/// Map raw annotation text (e.g. `@GetMapping("/x")`) to `spring:<name>`
+/// tags for any annotation matching [`SPRING_ANNOTATIONS`].
+pub(super) fn spring_annotation_tags(annotations: &[String]) -> Vec<String> {
+    annotations
+        .iter()
+        .filter_map(|raw| {
+            let name = raw
+                .trim_start_matches('@')
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .next()?;
+            SPRING_ANNOTATIONS
+                .contains(&name)
+                .then(|| format!("spring:{name}"))
+        })
+        .collect()
+}
+
+/// Whether `annotations` includes a JUnit/TestNG `@Test` marker (with or
+/// without arguments, and regardless of import — `@Test` and
+/// `@org.junit.Test` both match on the simple name).
+pub(super) fn is_test_annotated(annotations: &[String]) -> bool {
+    annotations.iter().any(|raw| {
+        raw.trim_start_matches('@')
+            .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '.')
+            .next()
+            .and_then(|path| path.rsplit('.').next())
+            == Some("Test")
+    })
+}
+
+/// Whether `annotations` includes `@Deprecated` (with or without a
+/// package qualifier — `@Deprecated` and `@java.lang.Deprecated` both
+/// match on the simple name).
+pub(super) fn is_deprecated_annotated(annotations: &[String]) -> bool {
+    annotations.iter().any(|raw| {
+        raw.trim_start_matches('@')
+            .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '.')
+            .next()
+            .and_then(|path| path.rsplit('.').next())
+            == Some("Deprecated")
+    })
+}
+
 pub(super) fn extract_throws<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<String> {
     node.children()
         .find(|child| child.kind().as_ref() == "throws")
@@ -208,6 +324,27 @@ pub(super) fn extract_base_types<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<S
     out
 }
 
+/// The `permits` clause of a `sealed` class/interface (`permits Circle,
+/// Square`), as the list of permitted subtype names. Empty for
+/// non-`sealed` declarations, which have no `permits` field.
+pub(super) fn extract_permits<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
+    let Some(permits) = node.field("permits") else {
+        return Vec::new();
+    };
+    let Some(type_list) = permits
+        .children()
+        .find(|child| child.kind().as_ref() == "type_list")
+    else {
+        return Vec::new();
+    };
+
+    type_list
+        .children()
+        .filter(|child| child.kind().as_ref() != ",")
+        .map(|child| child.text().to_string())
+        .collect()
+}
+
 pub(super) fn extract_enum_variants<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
     node.children()
         .find(|child| child.kind().as_ref() == "enum_body")
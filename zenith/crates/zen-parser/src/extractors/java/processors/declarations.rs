@@ -6,7 +6,7 @@ use super::super::java_helpers;
 use super::build_item;
 
 pub(super) fn process_module_like<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
-    let signature = crate::extractors::helpers::extract_signature(node);
+    let signature = crate::extractors::helpers::extract_signature(node, "java");
     let name = match node.kind().as_ref() {
         "package_declaration" => signature
             .trim_start_matches("package")
@@ -36,6 +36,7 @@ pub(super) fn process_module_like<D: ast_grep_core::Doc>(node: &Node<D>) -> Opti
         Visibility::Public,
         SymbolMetadata::default(),
         java_helpers::extract_javadoc_before(node),
+        false,
     ))
 }
 
@@ -54,13 +55,21 @@ pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(node: &Node<D>) ->
     let modifiers = java_helpers::extract_modifiers(node);
     let visibility = java_helpers::visibility_from_modifiers(&modifiers);
 
+    let annotations = java_helpers::extract_annotations(node);
+    let is_deprecated = java_helpers::is_deprecated_annotated(&annotations);
+
     let mut metadata = SymbolMetadata {
         type_parameters: node
             .field("type_parameters")
             .map(|params| params.text().to_string()),
         base_classes: java_helpers::extract_base_types(node),
+        implements: java_helpers::extract_permits(node),
+        attributes: annotations,
         ..Default::default()
     };
+    metadata
+        .attributes
+        .extend(java_helpers::spring_annotation_tags(&metadata.attributes));
 
     if kind == SymbolKind::Enum {
         metadata.variants = java_helpers::extract_enum_variants(node);
@@ -80,6 +89,7 @@ pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(node: &Node<D>) ->
         visibility,
         metadata,
         java_helpers::extract_javadoc_before(node),
+        is_deprecated,
     )];
 
     if kind == SymbolKind::Struct {
@@ -94,6 +104,7 @@ pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(node: &Node<D>) ->
                         ..Default::default()
                     };
                     ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Field,
                         name: field_name.clone(),
                         signature: field_name,
@@ -119,7 +130,7 @@ pub(super) fn process_module_directive<D: ast_grep_core::Doc>(
         return None;
     }
 
-    let signature = crate::extractors::helpers::extract_signature(node);
+    let signature = crate::extractors::helpers::extract_signature(node, "java");
     if signature.is_empty() {
         return None;
     }
@@ -146,5 +157,6 @@ pub(super) fn process_module_directive<D: ast_grep_core::Doc>(
         Visibility::Public,
         metadata,
         java_helpers::extract_javadoc_before(node),
+        false,
     ))
 }
@@ -27,8 +27,13 @@ fn single_member<D: ast_grep_core::Doc>(
     let visibility = java_helpers::visibility_from_modifiers(&modifiers);
     let owner = java_helpers::owner_from_ancestors(node);
 
+    let annotations = java_helpers::extract_annotations(node);
+    let is_deprecated = java_helpers::is_deprecated_annotated(&annotations);
+
     let kind = if fallback_kind == SymbolKind::Method && owner.is_none() {
         SymbolKind::Function
+    } else if fallback_kind == SymbolKind::Method && java_helpers::is_test_annotated(&annotations) {
+        SymbolKind::Test
     } else {
         fallback_kind
     };
@@ -46,7 +51,7 @@ fn single_member<D: ast_grep_core::Doc>(
         type_parameters: node
             .field("type_parameters")
             .map(|type_params| type_params.text().to_string()),
-        attributes: java_helpers::extract_annotations(node),
+        attributes: annotations,
         ..Default::default()
     };
 
@@ -54,6 +59,10 @@ fn single_member<D: ast_grep_core::Doc>(
         metadata.attributes.push(throws);
     }
 
+    metadata
+        .attributes
+        .extend(java_helpers::spring_annotation_tags(&metadata.attributes));
+
     if node.kind().as_ref() == "annotation_type_element_declaration"
         && let Some(default_value) = node.field("value")
     {
@@ -75,6 +84,7 @@ fn single_member<D: ast_grep_core::Doc>(
         visibility,
         metadata,
         java_helpers::extract_javadoc_before(node),
+        is_deprecated,
     )]
 }
 
@@ -90,19 +100,25 @@ fn field_like_members<D: ast_grep_core::Doc>(node: &Node<D>, force_const: bool)
         SymbolKind::Field
     };
 
+    let annotations = java_helpers::extract_annotations(node);
+    let is_deprecated = java_helpers::is_deprecated_annotated(&annotations);
+
     names
         .into_iter()
         .map(|name| {
-            let metadata = SymbolMetadata {
+            let mut metadata = SymbolMetadata {
                 owner_name: owner.as_ref().map(|(owner_name, _)| owner_name.clone()),
                 owner_kind: owner.as_ref().map(|(_, owner_kind)| *owner_kind),
                 is_static_member: java_helpers::is_static_member(&modifiers),
                 return_type: node
                     .field("type")
                     .map(|field_type| field_type.text().to_string()),
-                attributes: java_helpers::extract_annotations(node),
+                attributes: annotations.clone(),
                 ..Default::default()
             };
+            metadata
+                .attributes
+                .extend(java_helpers::spring_annotation_tags(&metadata.attributes));
 
             build_item(
                 node,
@@ -111,6 +127,7 @@ fn field_like_members<D: ast_grep_core::Doc>(node: &Node<D>, force_const: bool)
                 visibility.clone(),
                 metadata,
                 java_helpers::extract_javadoc_before(node),
+                is_deprecated,
             )
         })
         .collect()
@@ -3,7 +3,9 @@ mod members;
 
 use ast_grep_core::Node;
 
-use crate::types::{ParsedItem, SymbolKind, SymbolMetadata, Visibility};
+use crate::types::{CommonMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
+
+use super::java_helpers;
 
 pub(super) fn process_module_like<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
     declarations::process_module_like(node)
@@ -50,13 +52,17 @@ pub(super) fn build_item<D: ast_grep_core::Doc>(
     kind: SymbolKind,
     name: String,
     visibility: Visibility,
-    metadata: SymbolMetadata,
+    mut metadata: SymbolMetadata,
     doc_comment: String,
+    is_deprecated: bool,
 ) -> ParsedItem {
+    metadata.set_doc_sections(java_helpers::parse_javadoc_sections(&doc_comment));
+
     ParsedItem {
+        is_deprecated: is_deprecated || java_helpers::has_deprecated_tag(&doc_comment),
         kind,
         name,
-        signature: crate::extractors::helpers::extract_signature(node),
+        signature: crate::extractors::helpers::extract_signature(node, "java"),
         source: crate::extractors::helpers::extract_source(node, 40),
         doc_comment,
         start_line: node.start_pos().line() as u32 + 1,
@@ -17,3 +17,45 @@ public class Docs {
     assert!(compute.start_line >= 3);
     assert!(compute.end_line >= compute.start_line);
 }
+
+#[test]
+fn javadoc_tags_populate_doc_sections_and_deprecated() {
+    let source = r"
+public class Docs {
+    /**
+     * Adds two numbers.
+     * @param x the first operand
+     * @param y the second operand
+     * @return the sum
+     * @throws IllegalArgumentException if either operand is negative
+     * @deprecated use {@link #computeSum} instead
+     */
+    public int add(int x, int y) { return x + y; }
+}
+";
+
+    let items = parse_and_extract(source);
+    let add = find_by_name(&items, "add");
+
+    assert_eq!(
+        add.metadata.doc_sections.args.get("x").map(String::as_str),
+        Some("the first operand")
+    );
+    assert_eq!(
+        add.metadata.doc_sections.args.get("y").map(String::as_str),
+        Some("the second operand")
+    );
+    assert_eq!(
+        add.metadata.doc_sections.returns.as_deref(),
+        Some("the sum")
+    );
+    assert_eq!(
+        add.metadata
+            .doc_sections
+            .raises
+            .get("IllegalArgumentException")
+            .map(String::as_str),
+        Some("if either operand is negative")
+    );
+    assert!(add.is_deprecated);
+}
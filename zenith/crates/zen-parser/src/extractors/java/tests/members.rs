@@ -83,6 +83,10 @@ class Meta {
             .iter()
             .any(|attr| attr == "@Deprecated")
     );
+    assert!(
+        ctor.is_deprecated,
+        "constructor should be marked deprecated"
+    );
     assert!(
         ctor.metadata
             .attributes
@@ -92,6 +96,7 @@ class Meta {
 
     let method = find_by_name(&items, "id");
     assert_eq!(method.metadata.type_parameters.as_deref(), Some("<T>"));
+    assert!(!method.is_deprecated, "id should not be marked deprecated");
     assert!(
         method
             .metadata
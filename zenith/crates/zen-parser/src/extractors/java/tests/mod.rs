@@ -5,6 +5,7 @@ pub(super) use crate::types::{ParsedItem, SymbolKind, Visibility};
 
 mod docs_signatures_lines;
 mod members;
+mod spring_annotations;
 mod types_and_modules;
 
 fn parse_and_extract(source: &str) -> Vec<ParsedItem> {
@@ -0,0 +1,128 @@
+use super::*;
+
+#[test]
+fn rest_controller_class_gets_spring_tag() {
+    let source = r#"
+@RestController
+public class UserController {
+    @Autowired
+    private UserService userService;
+
+    @GetMapping("/users")
+    public List<User> list() {
+        return userService.findAll();
+    }
+}
+"#;
+
+    let items = parse_and_extract(source);
+
+    let controller = find_by_name(&items, "UserController");
+    assert!(
+        controller
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == "spring:RestController"),
+        "attributes: {:?}",
+        controller.metadata.attributes
+    );
+
+    let field = find_by_name(&items, "userService");
+    assert!(
+        field
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == "spring:Autowired")
+    );
+
+    let list = find_by_name(&items, "list");
+    assert!(
+        list.metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == "spring:GetMapping")
+    );
+}
+
+#[test]
+fn junit_test_annotated_method_gets_test_kind() {
+    let source = "
+class MathTest {
+    @Test
+    public void addsNumbers() {}
+}
+";
+
+    let items = parse_and_extract(source);
+    let method = find_by_name(&items, "addsNumbers");
+    assert_eq!(method.kind, SymbolKind::Test);
+}
+
+#[test]
+fn multiline_annotation_arguments_round_trip_with_normalized_whitespace() {
+    let source = "
+@RestController
+public class OrderController {
+    @RequestMapping(
+        path = \"/orders\",
+        method = GET
+    )
+    public List<Order> list() {
+        return null;
+    }
+}
+";
+
+    let items = parse_and_extract(source);
+
+    let list = find_by_name(&items, "list");
+    assert!(
+        list.metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == "@RequestMapping( path = \"/orders\", method = GET )"),
+        "attributes: {:?}",
+        list.metadata.attributes
+    );
+}
+
+#[test]
+fn sealed_interface_captures_permits_list_as_implements() {
+    let source = "
+sealed interface Shape permits Circle, Square {}
+
+final class Circle implements Shape {}
+
+final class Square implements Shape {}
+";
+
+    let items = parse_and_extract(source);
+
+    let shape = find_by_name(&items, "Shape");
+    assert_eq!(
+        shape.metadata.implements,
+        vec!["Circle".to_string(), "Square".to_string()]
+    );
+}
+
+#[test]
+fn non_spring_annotations_are_not_tagged() {
+    let source = "
+class Meta {
+    @Deprecated
+    public void run() {}
+}
+";
+
+    let items = parse_and_extract(source);
+    let run = find_by_name(&items, "run");
+    assert!(
+        !run.metadata
+            .attributes
+            .iter()
+            .any(|a| a.starts_with("spring:"))
+    );
+    assert!(run.metadata.attributes.iter().any(|a| a == "@Deprecated"));
+}
@@ -162,3 +162,29 @@ fn extracts_record_components_as_fields() {
     assert_eq!(y.metadata.owner_name.as_deref(), Some("Point"));
     assert_eq!(y.metadata.owner_kind, Some(SymbolKind::Struct));
 }
+
+#[test]
+fn deprecated_class_annotation_detected() {
+    let source = r"
+@Deprecated
+public class LegacyWidget {
+}
+
+public class ModernWidget {
+}
+";
+
+    let items = parse_and_extract(source);
+
+    let legacy = find_by_name(&items, "LegacyWidget");
+    assert!(
+        legacy.is_deprecated,
+        "LegacyWidget should be marked deprecated"
+    );
+
+    let modern = find_by_name(&items, "ModernWidget");
+    assert!(
+        !modern.is_deprecated,
+        "ModernWidget should not be marked deprecated"
+    );
+}
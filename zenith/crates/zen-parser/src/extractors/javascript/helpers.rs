@@ -69,6 +69,12 @@ pub(super) fn parse_jsdoc_sections(doc: &str) -> DocSections {
     sections
 }
 
+/// Whether a parsed `JSDoc` comment carries an `@deprecated` tag.
+pub(super) fn has_deprecated_tag(doc: &str) -> bool {
+    doc.lines()
+        .any(|line| line.trim().starts_with("@deprecated"))
+}
+
 // ── JS-specific helpers ────────────────────────────────────────────
 
 pub(super) fn extract_js_parameters<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
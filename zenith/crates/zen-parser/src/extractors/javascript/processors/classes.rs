@@ -4,7 +4,9 @@ use std::collections::HashSet;
 use crate::extractors::helpers;
 use crate::types::{JavaScriptMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
 
-use super::super::js_helpers::{extract_js_parameters, extract_jsdoc_before, parse_jsdoc_sections};
+use super::super::js_helpers::{
+    extract_js_parameters, extract_jsdoc_before, has_deprecated_tag, parse_jsdoc_sections,
+};
 
 // ── class_declaration ──────────────────────────────────────────────
 
@@ -45,9 +47,10 @@ pub fn process_class<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Class,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "javascript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -122,9 +125,10 @@ pub fn process_class_members<D: ast_grep_core::Doc>(
                     };
 
                     members.push(ParsedItem {
+                        is_deprecated: false,
                         kind,
                         name,
-                        signature: helpers::extract_signature(&child),
+                        signature: helpers::extract_signature(&child, "javascript"),
                         source: helpers::extract_source(&child, 30),
                         doc_comment: String::new(),
                         start_line: child.start_pos().line() as u32 + 1,
@@ -149,9 +153,10 @@ pub fn process_class_members<D: ast_grep_core::Doc>(
                     };
 
                     members.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Field,
                         name,
-                        signature: helpers::extract_signature(&child),
+                        signature: helpers::extract_signature(&child, "javascript"),
                         source: helpers::extract_source(&child, 20),
                         doc_comment: String::new(),
                         start_line: child.start_pos().line() as u32 + 1,
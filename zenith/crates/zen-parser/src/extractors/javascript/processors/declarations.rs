@@ -3,7 +3,9 @@ use ast_grep_core::Node;
 use crate::extractors::helpers;
 use crate::types::{JavaScriptMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
 
-use super::super::js_helpers::{extract_js_parameters, extract_jsdoc_before, parse_jsdoc_sections};
+use super::super::js_helpers::{
+    extract_js_parameters, extract_jsdoc_before, has_deprecated_tag, parse_jsdoc_sections,
+};
 
 // ── lexical_declaration (const/let with arrow functions or values) ─
 
@@ -79,9 +81,10 @@ fn process_variable_declarator<D: ast_grep_core::Doc>(
         metadata.set_doc_sections(doc_sections);
 
         Some(ParsedItem {
+            is_deprecated: has_deprecated_tag(&jsdoc),
             kind: SymbolKind::Function,
             name,
-            signature: helpers::extract_signature(declaration),
+            signature: helpers::extract_signature(declaration, "javascript"),
             source: helpers::extract_source(declaration, 50),
             doc_comment: jsdoc,
             start_line: declaration.start_pos().line() as u32 + 1,
@@ -98,9 +101,10 @@ fn process_variable_declarator<D: ast_grep_core::Doc>(
         metadata.set_doc_sections(doc_sections);
 
         Some(ParsedItem {
+            is_deprecated: has_deprecated_tag(&jsdoc),
             kind: value_kind,
             name,
-            signature: helpers::extract_signature(declaration),
+            signature: helpers::extract_signature(declaration, "javascript"),
             source: helpers::extract_source(declaration, 50),
             doc_comment: jsdoc,
             start_line: declaration.start_pos().line() as u32 + 1,
@@ -3,7 +3,9 @@ use ast_grep_core::Node;
 use crate::extractors::helpers;
 use crate::types::{JavaScriptMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
 
-use super::super::js_helpers::{extract_js_parameters, extract_jsdoc_before, parse_jsdoc_sections};
+use super::super::js_helpers::{
+    extract_js_parameters, extract_jsdoc_before, has_deprecated_tag, parse_jsdoc_sections,
+};
 
 // ── function_declaration ───────────────────────────────────────────
 
@@ -38,9 +40,10 @@ pub fn process_function<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Function,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "javascript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -80,9 +83,10 @@ pub fn process_generator_function<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Function,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "javascript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -56,6 +56,24 @@ fn non_documented_function_extracted() {
     assert!(f.doc_comment.is_empty());
 }
 
+#[test]
+fn jsdoc_deprecated_tag_marks_function_deprecated() {
+    let source = r"
+/**
+ * @deprecated use newLookup instead
+ */
+function legacyLookup() {}
+
+/**
+ * Still current.
+ */
+function currentLookup() {}
+";
+    let items = parse_and_extract(source);
+    assert!(find_by_name(&items, "legacyLookup").is_deprecated);
+    assert!(!find_by_name(&items, "currentLookup").is_deprecated);
+}
+
 #[test]
 fn function_parameters_extracted() {
     let source = include_str!("../../../../tests/fixtures/sample.js");
@@ -52,3 +52,15 @@ fn is_simple_path_segment(segment: &str) -> bool {
 fn escape_path_segment(segment: &str) -> String {
     segment.replace('\\', "\\\\").replace('"', "\\\"")
 }
+
+/// Truncate `text` to at most `max_len` characters, appending a marker if it
+/// was cut. Guards against a single huge value (a giant inlined string,
+/// minified array, ...) blowing up an item's stored `source` snippet even
+/// though it's only one line.
+pub(super) fn truncate_value(text: &str, max_len: usize) -> String {
+    if text.chars().count() <= max_len {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(max_len).collect();
+    format!("{truncated}... (truncated)")
+}
@@ -1,11 +1,66 @@
 use ast_grep_core::Node;
 use std::collections::{BTreeSet, HashSet};
 
-use crate::types::{CommonMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
+use crate::types::{
+    CommonMetadataExt, ExtractOptions, ParsedItem, SymbolKind, SymbolMetadata, Visibility,
+};
 
 use super::json_helpers;
 
-pub(super) fn extract_document<D: ast_grep_core::Doc>(root: &Node<D>) -> Vec<ParsedItem> {
+/// Top-level keys worth indexing for well-known manifest files that are
+/// mostly noise at scale: `package.json`'s `dependencies`/`devDependencies`
+/// are useful, but its lockfile-adjacent metadata isn't, and `tsconfig.json`
+/// is almost entirely `compilerOptions`. `Cargo.toml` doesn't need this —
+/// it's a TOML file handled by a separate extractor and stays small in
+/// practice.
+fn well_known_top_level_keys(file_name: &str) -> Option<&'static [&'static str]> {
+    match file_name {
+        "package.json" => Some(&[
+            "name",
+            "version",
+            "description",
+            "main",
+            "module",
+            "types",
+            "bin",
+            "exports",
+            "scripts",
+            "dependencies",
+            "devDependencies",
+            "peerDependencies",
+            "engines",
+            "workspaces",
+        ]),
+        "tsconfig.json" => Some(&[
+            "compilerOptions",
+            "include",
+            "exclude",
+            "references",
+            "extends",
+        ]),
+        _ => None,
+    }
+}
+
+struct JsonContext {
+    nonstandard_comments: bool,
+    options: ExtractOptions,
+    item_count: usize,
+    hit_max_depth: bool,
+    hit_max_items: bool,
+}
+
+impl JsonContext {
+    const fn truncated(&self) -> bool {
+        self.hit_max_depth || self.hit_max_items
+    }
+}
+
+pub(super) fn extract_document<D: ast_grep_core::Doc>(
+    root: &Node<D>,
+    file_path: &str,
+    options: ExtractOptions,
+) -> Vec<ParsedItem> {
     let mut items = Vec::new();
     let Some(value) = root.children().next() else {
         return items;
@@ -26,21 +81,51 @@ pub(super) fn extract_document<D: ast_grep_core::Doc>(root: &Node<D>) -> Vec<Par
         "$".to_string(),
         root_metadata,
         "$",
+        &options,
     ));
 
-    collect_value(&value, "", nonstandard_comments, &mut items);
+    let file_name = std::path::Path::new(file_path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    let allowlist = well_known_top_level_keys(file_name);
+
+    let mut ctx = JsonContext {
+        nonstandard_comments,
+        options,
+        item_count: 0,
+        hit_max_depth: false,
+        hit_max_items: false,
+    };
+
+    collect_value(&value, "", 0, allowlist, &mut ctx, &mut items);
+
+    if ctx.truncated() {
+        items.push(build_truncation_summary(&ctx));
+    }
+
     items
 }
 
 fn collect_value<D: ast_grep_core::Doc>(
     node: &Node<D>,
     path: &str,
-    nonstandard_comments: bool,
+    depth: usize,
+    allowlist: Option<&'static [&'static str]>,
+    ctx: &mut JsonContext,
     out: &mut Vec<ParsedItem>,
 ) {
+    if ctx.hit_max_items {
+        return;
+    }
+    if depth > ctx.options.max_depth {
+        ctx.hit_max_depth = true;
+        return;
+    }
+
     match node.kind().as_ref() {
-        "object" => collect_object(node, path, nonstandard_comments, out),
-        "array" => collect_array(node, path, nonstandard_comments, out),
+        "object" => collect_object(node, path, depth, allowlist, ctx, out),
+        "array" => collect_array(node, path, depth, ctx, out),
         _ => {}
     }
 }
@@ -48,36 +133,53 @@ fn collect_value<D: ast_grep_core::Doc>(
 fn collect_object<D: ast_grep_core::Doc>(
     node: &Node<D>,
     path: &str,
-    nonstandard_comments: bool,
+    depth: usize,
+    allowlist: Option<&'static [&'static str]>,
+    ctx: &mut JsonContext,
     out: &mut Vec<ParsedItem>,
 ) {
     let mut seen_keys = HashSet::new();
     for child in node.children() {
+        if ctx.hit_max_items {
+            return;
+        }
         if child.kind().as_ref() != "pair" {
             continue;
         }
 
-        let duplicate_key = child.field("key").and_then(|key| {
-            let key_name = json_helpers::unquote_json_string(&key.text());
-            if seen_keys.insert(key_name.clone()) {
-                None
-            } else {
-                Some(key_name)
-            }
-        });
+        let Some(key_node) = child.field("key") else {
+            continue;
+        };
+        let key_name = json_helpers::unquote_json_string(&key_node.text());
+        if let Some(keys) = allowlist
+            && !keys.contains(&key_name.as_str())
+        {
+            continue;
+        }
+
+        let duplicate_key = if seen_keys.insert(key_name.clone()) {
+            None
+        } else {
+            Some(key_name)
+        };
 
-        collect_pair(&child, path, nonstandard_comments, duplicate_key, out);
+        collect_pair(&child, path, depth, duplicate_key, ctx, out);
     }
 }
 
 fn collect_array<D: ast_grep_core::Doc>(
     node: &Node<D>,
     path: &str,
-    nonstandard_comments: bool,
+    depth: usize,
+    ctx: &mut JsonContext,
     out: &mut Vec<ParsedItem>,
 ) {
     let mut idx = 0usize;
     for child in node.children() {
+        if ctx.hit_max_items {
+            return;
+        }
+
         let kind = child.kind();
         let kr = kind.as_ref();
         if !matches!(
@@ -94,15 +196,17 @@ fn collect_array<D: ast_grep_core::Doc>(
         };
 
         if matches!(kr, "string" | "number" | "true" | "false" | "null") {
-            out.push(build_array_primitive_item(
+            let item = build_array_primitive_item(
                 &child,
                 &next_path,
                 path,
-                nonstandard_comments,
-            ));
+                ctx.nonstandard_comments,
+                &ctx.options,
+            );
+            push_item(out, ctx, item);
         }
 
-        collect_value(&child, &next_path, nonstandard_comments, out);
+        collect_value(&child, &next_path, depth + 1, None, ctx, out);
         idx += 1;
     }
 }
@@ -110,8 +214,9 @@ fn collect_array<D: ast_grep_core::Doc>(
 fn collect_pair<D: ast_grep_core::Doc>(
     pair: &Node<D>,
     parent_path: &str,
-    nonstandard_comments: bool,
+    depth: usize,
     duplicate_key: Option<String>,
+    ctx: &mut JsonContext,
     out: &mut Vec<ParsedItem>,
 ) {
     let Some(key_node) = pair.field("key") else {
@@ -134,7 +239,7 @@ fn collect_pair<D: ast_grep_core::Doc>(
     metadata.set_owner_kind(Some(SymbolKind::Module));
     metadata.set_return_type(Some(json_helpers::value_type_name(&value_node)));
     metadata.push_attribute(format!("json:key:{key_name}"));
-    if nonstandard_comments {
+    if ctx.nonstandard_comments {
         metadata.push_attribute("json:nonstandard");
         metadata.push_attribute("json:nonstandard:comments");
     }
@@ -143,15 +248,57 @@ fn collect_pair<D: ast_grep_core::Doc>(
     }
     enrich_value_shape_metadata(&value_node, &mut metadata);
 
-    out.push(build_item(
+    let item = build_item(
         pair,
         SymbolKind::Property,
         full_path.clone(),
         metadata,
         &key_name,
-    ));
+        &ctx.options,
+    );
+    push_item(out, ctx, item);
+
+    collect_value(&value_node, &full_path, depth + 1, None, ctx, out);
+}
+
+/// Push `item` unless the item budget is already spent.
+fn push_item(out: &mut Vec<ParsedItem>, ctx: &mut JsonContext, item: ParsedItem) {
+    if ctx.item_count >= ctx.options.max_items {
+        ctx.hit_max_items = true;
+        return;
+    }
+    ctx.item_count += 1;
+    out.push(item);
+}
 
-    collect_value(&value_node, &full_path, nonstandard_comments, out);
+fn build_truncation_summary(ctx: &JsonContext) -> ParsedItem {
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute("json:truncated");
+    if ctx.hit_max_items {
+        metadata.push_attribute(format!(
+            "json:truncated:max_items:{}",
+            ctx.options.max_items
+        ));
+    }
+    if ctx.hit_max_depth {
+        metadata.push_attribute(format!(
+            "json:truncated:max_depth:{}",
+            ctx.options.max_depth
+        ));
+    }
+
+    ParsedItem {
+        is_deprecated: false,
+        kind: SymbolKind::Module,
+        name: "$:truncated".to_string(),
+        signature: "$:truncated".to_string(),
+        source: None,
+        doc_comment: String::new(),
+        start_line: 1,
+        end_line: 1,
+        visibility: Visibility::Public,
+        metadata,
+    }
 }
 
 fn enrich_value_shape_metadata<D: ast_grep_core::Doc>(
@@ -219,6 +366,7 @@ fn build_array_primitive_item<D: ast_grep_core::Doc>(
     path: &str,
     owner_path: &str,
     nonstandard_comments: bool,
+    options: &ExtractOptions,
 ) -> ParsedItem {
     let mut metadata = SymbolMetadata::default();
     metadata.set_owner_name(Some(if owner_path.is_empty() {
@@ -234,7 +382,14 @@ fn build_array_primitive_item<D: ast_grep_core::Doc>(
         metadata.push_attribute("json:nonstandard:comments");
     }
 
-    build_item(node, SymbolKind::Property, path.to_string(), metadata, path)
+    build_item(
+        node,
+        SymbolKind::Property,
+        path.to_string(),
+        metadata,
+        path,
+        options,
+    )
 }
 
 fn contains_comment<D: ast_grep_core::Doc>(node: &Node<D>) -> bool {
@@ -250,12 +405,17 @@ fn build_item<D: ast_grep_core::Doc>(
     name: String,
     metadata: SymbolMetadata,
     signature_name: &str,
+    options: &ExtractOptions,
 ) -> ParsedItem {
+    let source = crate::extractors::helpers::extract_source(node, 40)
+        .map(|text| json_helpers::truncate_value(&text, options.max_value_len));
+
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature: signature_name.to_string(),
-        source: crate::extractors::helpers::extract_source(node, 40),
+        source,
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
         end_line: node.end_pos().line() as u32 + 1,
@@ -0,0 +1,101 @@
+use super::*;
+
+fn deeply_nested_json(depth: usize) -> String {
+    let mut source = String::from("{\"a\":");
+    for _ in 0..depth {
+        source.push_str("{\"a\":");
+    }
+    source.push('1');
+    source.push_str(&"}".repeat(depth + 1));
+    source
+}
+
+fn wide_json(count: usize) -> String {
+    let pairs: Vec<String> = (0..count).map(|i| format!("\"k{i}\":{i}")).collect();
+    format!("{{{}}}", pairs.join(","))
+}
+
+#[test]
+fn deep_document_stops_at_max_depth_and_reports_truncated() {
+    let source = deeply_nested_json(50);
+    let options = ExtractOptions {
+        max_depth: 5,
+        ..ExtractOptions::default()
+    };
+    let items = parse_and_extract_with(&source, "deep.json", options);
+
+    let summary = find_by_name(&items, "$:truncated");
+    assert!(
+        summary
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == "json:truncated"),
+        "attrs: {:?}",
+        summary.metadata.attributes
+    );
+    assert!(
+        summary
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr.starts_with("json:truncated:max_depth:")),
+        "attrs: {:?}",
+        summary.metadata.attributes
+    );
+}
+
+#[test]
+fn shallow_document_is_not_truncated() {
+    let source = deeply_nested_json(2);
+    let options = ExtractOptions {
+        max_depth: 5,
+        ..ExtractOptions::default()
+    };
+    let items = parse_and_extract_with(&source, "shallow.json", options);
+    assert!(items.iter().all(|item| item.name != "$:truncated"));
+}
+
+#[test]
+fn huge_document_stops_at_max_items_and_reports_truncated() {
+    let source = wide_json(200);
+    let options = ExtractOptions {
+        max_items: 10,
+        ..ExtractOptions::default()
+    };
+    let items = parse_and_extract_with(&source, "huge.json", options);
+
+    // The root item plus at most max_items keys plus the summary item.
+    assert!(
+        items.len() <= 1 + options.max_items + 1,
+        "items: {}",
+        items.len()
+    );
+
+    let summary = find_by_name(&items, "$:truncated");
+    assert!(
+        summary
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr.starts_with("json:truncated:max_items:")),
+        "attrs: {:?}",
+        summary.metadata.attributes
+    );
+}
+
+#[test]
+fn long_scalar_value_is_truncated_in_source() {
+    let long_value = "x".repeat(2_000);
+    let source = format!("{{\"big\":\"{long_value}\"}}");
+    let options = ExtractOptions {
+        max_value_len: 100,
+        ..ExtractOptions::default()
+    };
+    let items = parse_and_extract_with(&source, "big.json", options);
+
+    let big = find_by_name(&items, "big");
+    let text = big.source.as_deref().unwrap_or_default();
+    assert!(text.len() < long_value.len());
+    assert!(text.ends_with("... (truncated)"), "source: {text:?}");
+}
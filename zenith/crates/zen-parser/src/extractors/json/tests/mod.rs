@@ -1,18 +1,28 @@
 use ast_grep_language::LanguageExt;
 
 use super::*;
-pub(super) use crate::types::{ParsedItem, SymbolKind};
+pub(super) use crate::types::{ExtractOptions, ParsedItem, SymbolKind};
 
 mod duplicate_and_empty;
+mod limits;
 mod metadata;
 mod nested_paths;
 mod path_edge_cases;
 mod structure;
 mod top_level_variants;
+mod well_known_files;
 
 fn parse_and_extract(source: &str) -> Vec<ParsedItem> {
+    parse_and_extract_with(source, "test.json", ExtractOptions::default())
+}
+
+fn parse_and_extract_with(
+    source: &str,
+    file_path: &str,
+    options: ExtractOptions,
+) -> Vec<ParsedItem> {
     let root = SupportLang::Json.ast_grep(source);
-    extract(&root).expect("extraction should succeed")
+    extract(&root, file_path, options).expect("extraction should succeed")
 }
 
 fn fixture_items() -> Vec<ParsedItem> {
@@ -0,0 +1,51 @@
+use super::*;
+
+const PACKAGE_JSON: &str = r#"{
+  "name": "zenith",
+  "version": "1.0.0",
+  "gitHead": "deadbeef",
+  "_resolved": "https://example.com/zenith-1.0.0.tgz",
+  "dependencies": { "left-pad": "^1.0.0" },
+  "devDependencies": { "vitest": "^2.0.0" }
+}"#;
+
+const TSCONFIG_JSON: &str = r#"{
+  "compilerOptions": { "strict": true },
+  "include": ["src"],
+  "$schema": "https://json.schemastore.org/tsconfig"
+}"#;
+
+#[test]
+fn package_json_keeps_interesting_top_level_keys() {
+    let items = parse_and_extract_with(PACKAGE_JSON, "package.json", ExtractOptions::default());
+    assert!(find_all_by_name(&items, "name").len() == 1);
+    assert!(find_all_by_name(&items, "version").len() == 1);
+    assert!(find_all_by_name(&items, "dependencies").len() == 1);
+    assert!(find_all_by_name(&items, "devDependencies").len() == 1);
+}
+
+#[test]
+fn package_json_drops_uninteresting_top_level_keys() {
+    let items = parse_and_extract_with(PACKAGE_JSON, "package.json", ExtractOptions::default());
+    assert!(items.iter().all(|item| item.name != "gitHead"));
+    assert!(items.iter().all(|item| item.name != "_resolved"));
+}
+
+#[test]
+fn tsconfig_json_keeps_compiler_options_drops_schema() {
+    let items = parse_and_extract_with(TSCONFIG_JSON, "tsconfig.json", ExtractOptions::default());
+    assert!(find_all_by_name(&items, "compilerOptions").len() == 1);
+    assert!(find_all_by_name(&items, "include").len() == 1);
+    assert!(items.iter().all(|item| item.name != "$schema"));
+}
+
+#[test]
+fn unrecognized_file_name_keeps_all_top_level_keys() {
+    let items = parse_and_extract_with(
+        PACKAGE_JSON,
+        "some-other-file.json",
+        ExtractOptions::default(),
+    );
+    assert!(find_all_by_name(&items, "gitHead").len() == 1);
+    assert!(find_all_by_name(&items, "_resolved").len() == 1);
+}
@@ -60,9 +60,10 @@ fn build_item<D: ast_grep_core::Doc>(
     doc_comment: String,
 ) -> ParsedItem {
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
-        signature: crate::extractors::helpers::extract_signature(node),
+        signature: crate::extractors::helpers::extract_signature(node, "lua"),
         source: crate::extractors::helpers::extract_source(node, 40),
         doc_comment,
         start_line: node.start_pos().line() as u32 + 1,
@@ -67,6 +67,17 @@ pub(super) fn link_reference_label(raw: &str) -> String {
         .to_string()
 }
 
+pub(super) fn link_reference_target(raw: &str) -> String {
+    raw.split_once("]:")
+        .map(|(_, rest)| rest)
+        .unwrap_or_default()
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_matches(|c| c == '<' || c == '>')
+        .to_string()
+}
+
 pub(super) fn extract_inline_links(line: &str) -> Vec<(String, String)> {
     let mut out = Vec::new();
     let bytes = line.as_bytes();
@@ -283,3 +294,49 @@ pub(super) fn extract_bare_urls(line: &str) -> Vec<String> {
 
     out
 }
+
+/// Find Pandoc-style definition lists (a term line immediately followed by
+/// one or more `: definition` lines). Not part of the `tree-sitter-md`
+/// grammar, so this scans raw lines the same way [`list_item_count`] does.
+///
+/// Returns `(start_line, end_line, entries)` per block, 1-based and
+/// inclusive, with each entry formatted as `"term: definition"`.
+pub(super) fn definition_list_blocks(source: &str) -> Vec<(u32, u32, Vec<String>)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut blocks = Vec::new();
+    let mut i = 0usize;
+
+    while i < lines.len() {
+        let is_term = |line: &str| {
+            let t = line.trim();
+            !t.is_empty() && !t.starts_with(['#', '-', '*', '>', '|', ':', '`'])
+        };
+        let is_def = |line: &str| line.trim_start().starts_with(": ");
+
+        if !is_term(lines[i]) || !lines.get(i + 1).is_some_and(|l| is_def(l)) {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let mut entries = Vec::new();
+        loop {
+            let term = lines[i].trim().to_string();
+            i += 1;
+            while lines.get(i).is_some_and(|l| is_def(l)) {
+                let def_text = lines[i].trim_start()[1..].trim();
+                entries.push(format!("{term}: {def_text}"));
+                i += 1;
+            }
+            if lines.get(i).is_some_and(|l| is_term(l))
+                && lines.get(i + 1).is_some_and(|l| is_def(l))
+            {
+                continue;
+            }
+            break;
+        }
+        blocks.push((start as u32 + 1, i as u32, entries));
+    }
+
+    blocks
+}
@@ -14,6 +14,7 @@ fn build_item<D: ast_grep_core::Doc>(
     metadata: SymbolMetadata,
 ) -> ParsedItem {
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature,
@@ -95,38 +96,90 @@ pub(super) fn list_item<D: ast_grep_core::Doc>(node: &Node<D>) -> ParsedItem {
     )
 }
 
-pub(super) fn table_item<D: ast_grep_core::Doc>(node: &Node<D>) -> ParsedItem {
-    let rows = node.text().lines().count();
+pub(super) fn table_item<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    heading: Option<&str>,
+) -> ParsedItem {
+    let rows: Vec<String> = node
+        .children()
+        .filter(|c| matches!(c.kind().as_ref(), "pipe_table_header" | "pipe_table_row"))
+        .map(|row| {
+            row.children()
+                .filter(|c| c.kind().as_ref() == "pipe_table_cell")
+                .map(|c| c.text().trim().to_string())
+                .collect::<Vec<_>>()
+                .join(" | ")
+        })
+        .collect();
 
     let mut metadata = SymbolMetadata::default();
     metadata.push_attribute("md:kind:table");
-    metadata.push_attribute(format!("md:table_rows:{rows}"));
+    metadata.push_attribute(format!("md:table_rows:{}", rows.len()));
+    metadata.set_fields(rows.clone());
 
     let line = node.start_pos().line() + 1;
-    build_item(
-        node,
-        SymbolKind::Property,
-        format!("table-{line}"),
-        "table".to_string(),
-        metadata,
-    )
+    let name = heading.map_or_else(
+        || format!("table-{line}"),
+        |title| format!("{title}-table-{line}"),
+    );
+    let signature = rows.first().cloned().unwrap_or_else(|| "table".to_string());
+    build_item(node, SymbolKind::Property, name, signature, metadata)
 }
 
 pub(super) fn link_reference_item<D: ast_grep_core::Doc>(node: &Node<D>) -> ParsedItem {
     let raw = node.text().to_string();
     let label = markdown_helpers::link_reference_label(&raw);
+    let target = markdown_helpers::link_reference_target(&raw);
     let line = node.start_pos().line() + 1;
 
     let mut metadata = SymbolMetadata::default();
     metadata.push_attribute("md:kind:link_ref");
+    if !target.is_empty() {
+        metadata.push_attribute(format!("md:target:{target}"));
+    }
 
     let name = if label.is_empty() {
         format!("link-ref-{line}")
     } else {
         label.clone()
     };
+    let signature = if target.is_empty() {
+        label
+    } else {
+        format!("{label}: {target}")
+    };
 
-    build_item(node, SymbolKind::Property, name, label, metadata)
+    build_item(node, SymbolKind::Property, name, signature, metadata)
+}
+
+pub(super) fn definition_list_items<D: ast_grep_core::Doc>(root: &Node<D>) -> Vec<ParsedItem> {
+    let source = root.text().to_string();
+
+    markdown_helpers::definition_list_blocks(&source)
+        .into_iter()
+        .map(|(start_line, end_line, entries)| {
+            let mut metadata = SymbolMetadata::default();
+            metadata.push_attribute("md:kind:definition_list");
+            metadata.push_attribute(format!("md:definitions:{}", entries.len()));
+            metadata.set_fields(entries.clone());
+
+            ParsedItem {
+                is_deprecated: false,
+                kind: SymbolKind::Property,
+                name: format!("definition-list-{start_line}"),
+                signature: entries
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| "definition-list".to_string()),
+                source: Some(entries.join("\n")),
+                doc_comment: String::new(),
+                start_line,
+                end_line,
+                visibility: Visibility::Public,
+                metadata,
+            }
+        })
+        .collect()
 }
 
 pub(super) fn thematic_break_item<D: ast_grep_core::Doc>(node: &Node<D>) -> ParsedItem {
@@ -182,6 +235,7 @@ pub(super) fn inline_items_from_node<D: ast_grep_core::Doc>(node: &Node<D>) -> V
                 alt.clone()
             };
             out.push(ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Property,
                 name,
                 signature: format!("![{alt}]({src})"),
@@ -208,6 +262,7 @@ pub(super) fn inline_items_from_node<D: ast_grep_core::Doc>(node: &Node<D>) -> V
                 label.clone()
             };
             out.push(ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Property,
                 name,
                 signature: format!("[{label}]({url})"),
@@ -239,6 +294,7 @@ pub(super) fn inline_items_from_node<D: ast_grep_core::Doc>(node: &Node<D>) -> V
             };
 
             out.push(ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Property,
                 name,
                 signature: format!("[{label}][{reference}]"),
@@ -260,6 +316,7 @@ pub(super) fn inline_items_from_node<D: ast_grep_core::Doc>(node: &Node<D>) -> V
             metadata.push_attribute(format!("md:url:{target}"));
 
             out.push(ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Property,
                 name: format!("autolink-{line_no}-{}", auto_idx + 1),
                 signature: format!("<{target}>"),
@@ -286,6 +343,7 @@ pub(super) fn inline_items_from_node<D: ast_grep_core::Doc>(node: &Node<D>) -> V
             metadata.push_attribute(format!("md:url:{target}"));
 
             out.push(ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Property,
                 name: format!("bare-url-{line_no}-{}", bare_idx + 1),
                 signature: target,
@@ -306,6 +364,7 @@ pub(super) fn inline_items_from_node<D: ast_grep_core::Doc>(node: &Node<D>) -> V
             metadata.push_attribute("md:kind:inline_code");
 
             out.push(ParsedItem {
+                is_deprecated: false,
                 kind: SymbolKind::Property,
                 name: format!("inline-code-{line_no}-{}", code_idx + 1),
                 signature: code,
@@ -0,0 +1,80 @@
+use super::common::{Expected, assert_items_contain_snapshot, extract_md};
+use crate::types::SymbolKind;
+
+#[test]
+fn multiple_fenced_languages_captured_distinctly() {
+    let src = "# Examples\n\n```python\nprint(\"hi\")\n```\n\n```bash\necho hi\n```\n";
+    let items = extract_md(src);
+
+    let expected_subset = vec![
+        Expected {
+            name: "code-fence-3",
+            signature: "```python",
+            kind: SymbolKind::Property,
+            start_line: 3,
+            end_line: 6,
+            attrs: &["md:kind:code_fence", "md:code_lang:python"],
+        },
+        Expected {
+            name: "code-fence-7",
+            signature: "```bash",
+            kind: SymbolKind::Property,
+            start_line: 7,
+            end_line: 10,
+            attrs: &["md:kind:code_fence", "md:code_lang:bash"],
+        },
+    ];
+
+    assert_items_contain_snapshot(&items, &expected_subset);
+}
+
+#[test]
+fn rust_and_python_fences_are_extracted_with_language_attrs() {
+    let src = "# Snippets\n\n```rust\nfn main() {}\n```\n\n```python\ndef main(): pass\n```\n";
+    let items = extract_md(src);
+
+    let fences: Vec<_> = items
+        .iter()
+        .filter(|item| {
+            item.metadata
+                .attributes
+                .iter()
+                .any(|attr| attr == "md:kind:code_fence")
+        })
+        .collect();
+    assert_eq!(fences.len(), 2, "expected two code fence items");
+
+    let rust_fence = fences
+        .iter()
+        .find(|item| {
+            item.metadata
+                .attributes
+                .iter()
+                .any(|attr| attr == "md:code_lang:rust")
+        })
+        .expect("should find rust code fence");
+    assert!(
+        rust_fence
+            .source
+            .as_deref()
+            .unwrap_or_default()
+            .contains("fn main()")
+    );
+
+    let python_fence = fences
+        .iter()
+        .find(|item| {
+            item.metadata
+                .attributes
+                .iter()
+                .any(|attr| attr == "md:code_lang:python")
+        })
+        .expect("should find python code fence");
+    assert!(
+        python_fence
+            .source
+            .as_deref()
+            .unwrap_or_default()
+            .contains("def main()")
+    );
+}
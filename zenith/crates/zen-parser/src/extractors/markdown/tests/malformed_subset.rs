@@ -35,11 +35,11 @@ fn malformed_md_resilience_subset_snapshot() {
         },
         Expected {
             name: "ok",
-            signature: "ok",
+            signature: "ok: https://example.com",
             kind: SymbolKind::Property,
             start_line: 8,
             end_line: 9,
-            attrs: &["md:kind:link_ref"],
+            attrs: &["md:kind:link_ref", "md:target:https://example.com"],
         },
         Expected {
             name: "hr-11",
@@ -1,5 +1,7 @@
+mod code_fence_languages;
 mod common;
 mod inline_and_owner;
 mod malformed_subset;
 mod snapshot_sample;
 mod snapshot_weird;
+mod tables_and_definitions;
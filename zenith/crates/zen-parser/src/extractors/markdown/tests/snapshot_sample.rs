@@ -48,20 +48,20 @@ fn sample_md_snapshot() {
             attrs: &["md:kind:list", "md:list_items:2"],
         },
         Expected {
-            name: "table-18",
-            signature: "table",
+            name: "Install-table-18",
+            signature: "col",
             kind: SymbolKind::Property,
             start_line: 18,
             end_line: 21,
-            attrs: &["md:kind:table", "md:table_rows:3"],
+            attrs: &["md:kind:table", "md:table_rows:2"],
         },
         Expected {
             name: "ref",
-            signature: "ref",
+            signature: "ref: https://example.com",
             kind: SymbolKind::Property,
             start_line: 22,
             end_line: 23,
-            attrs: &["md:kind:link_ref"],
+            attrs: &["md:kind:link_ref", "md:target:https://example.com"],
         },
         Expected {
             name: "frontmatter-yaml-1",
@@ -49,19 +49,19 @@ fn weird_md_snapshot() {
         },
         Expected {
             name: "dup",
-            signature: "dup",
+            signature: "dup: /a",
             kind: SymbolKind::Property,
             start_line: 13,
             end_line: 14,
-            attrs: &["md:kind:link_ref"],
+            attrs: &["md:kind:link_ref", "md:target:/a"],
         },
         Expected {
             name: "dup2",
-            signature: "dup2",
+            signature: "dup2: /b",
             kind: SymbolKind::Property,
             start_line: 14,
             end_line: 15,
-            attrs: &["md:kind:link_ref"],
+            attrs: &["md:kind:link_ref", "md:target:/b"],
         },
         Expected {
             name: "hr-16",
@@ -0,0 +1,135 @@
+use super::common::extract_md;
+use crate::types::SymbolKind;
+
+const CLI_OPTIONS_MD: &str = "\
+# CLI Reference
+
+## Options
+
+| Flag | Description |
+| --- | --- |
+| --verbose | Enable verbose logging |
+| --output | Set the output path |
+";
+
+#[test]
+fn table_name_comes_from_nearest_heading() {
+    let items = extract_md(CLI_OPTIONS_MD);
+    let table = items
+        .iter()
+        .find(|i| i.metadata.attributes.iter().any(|a| a == "md:kind:table"))
+        .expect("should extract table");
+    assert!(
+        table.name.starts_with("Options-table-"),
+        "name: {}",
+        table.name
+    );
+    assert_eq!(table.kind, SymbolKind::Property);
+}
+
+#[test]
+fn table_rows_serialized_into_metadata_fields() {
+    let items = extract_md(CLI_OPTIONS_MD);
+    let table = items
+        .iter()
+        .find(|i| i.metadata.attributes.iter().any(|a| a == "md:kind:table"))
+        .expect("should extract table");
+
+    assert!(
+        table
+            .metadata
+            .fields
+            .contains(&"Flag | Description".to_string()),
+        "fields: {:?}",
+        table.metadata.fields
+    );
+    assert!(
+        table
+            .metadata
+            .fields
+            .contains(&"--verbose | Enable verbose logging".to_string()),
+        "fields: {:?}",
+        table.metadata.fields
+    );
+    assert!(
+        table
+            .metadata
+            .fields
+            .contains(&"--output | Set the output path".to_string()),
+        "fields: {:?}",
+        table.metadata.fields
+    );
+    assert!(
+        table
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "md:table_rows:3")
+    );
+}
+
+#[test]
+fn link_reference_definition_target_is_resolvable() {
+    let src = "See [foo] for details.\n\n[foo]: https://example.com/docs \"Docs\"\n";
+    let items = extract_md(src);
+    let link_ref = items
+        .iter()
+        .find(|i| {
+            i.metadata
+                .attributes
+                .iter()
+                .any(|a| a == "md:kind:link_ref")
+        })
+        .expect("should extract link reference definition");
+
+    assert_eq!(link_ref.name, "foo");
+    assert_eq!(link_ref.signature, "foo: https://example.com/docs");
+    assert!(
+        link_ref
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "md:target:https://example.com/docs")
+    );
+}
+
+#[test]
+fn definition_list_extracted_as_single_item_with_row_fields() {
+    let src = "\
+# Glossary
+
+Term One
+: The first definition.
+Term Two
+: The second definition.
+";
+    let items = extract_md(src);
+    let def_list = items
+        .iter()
+        .find(|i| {
+            i.metadata
+                .attributes
+                .iter()
+                .any(|a| a == "md:kind:definition_list")
+        })
+        .expect("should extract definition list");
+
+    assert_eq!(def_list.kind, SymbolKind::Property);
+    assert!(
+        def_list
+            .metadata
+            .fields
+            .contains(&"Term One: The first definition.".to_string()),
+        "fields: {:?}",
+        def_list.metadata.fields
+    );
+    assert!(
+        def_list
+            .metadata
+            .fields
+            .contains(&"Term Two: The second definition.".to_string()),
+        "fields: {:?}",
+        def_list.metadata.fields
+    );
+    assert_eq!(def_list.metadata.owner_name.as_deref(), Some("Glossary"));
+}
@@ -8,14 +8,17 @@ pub use dispatcher::cpp;
 pub use dispatcher::csharp;
 pub use dispatcher::css;
 pub use dispatcher::elixir;
+pub use dispatcher::generic;
 pub use dispatcher::go;
 pub use dispatcher::haskell;
+pub use dispatcher::hcl;
 pub use dispatcher::html;
 pub use dispatcher::java;
 pub use dispatcher::javascript;
 pub use dispatcher::json;
 pub use dispatcher::lua;
 pub use dispatcher::markdown;
+pub use dispatcher::openapi;
 pub use dispatcher::php;
 pub use dispatcher::python;
 pub use dispatcher::rst;
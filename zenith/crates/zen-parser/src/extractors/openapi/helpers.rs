@@ -0,0 +1,44 @@
+/// HTTP methods recognized as `OpenAPI` path-item operations.
+pub(super) const HTTP_METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Whether `source` looks like an `OpenAPI`/Swagger document.
+///
+/// Checked directly against the source text, not the parsed tree, so both
+/// JSON and YAML documents are recognized by their content shape (a version
+/// key alongside a `paths` key) regardless of what the file is named.
+#[must_use]
+pub fn looks_like_openapi(source: &str) -> bool {
+    (has_key(source, "openapi") || has_key(source, "swagger")) && has_key(source, "paths")
+}
+
+fn has_key(source: &str, key: &str) -> bool {
+    source.contains(&format!("\"{key}\":")) || contains_top_level_yaml_key(source, key)
+}
+
+fn contains_top_level_yaml_key(source: &str, key: &str) -> bool {
+    let prefix = format!("{key}:");
+    source.lines().any(|line| line.starts_with(&prefix))
+}
+
+pub(super) fn unquote(raw: &str) -> String {
+    let trimmed = raw.trim();
+    if (trimmed.starts_with('"') && trimmed.ends_with('"'))
+        || (trimmed.starts_with('\'') && trimmed.ends_with('\''))
+    {
+        trimmed[1..trimmed.len() - 1].to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Truncate `text` to at most `max_len` characters, appending a marker if it
+/// was cut. Mirrors the json/yaml extractors' own `truncate_value`.
+pub(super) fn truncate_value(text: &str, max_len: usize) -> String {
+    if text.chars().count() <= max_len {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(max_len).collect();
+    format!("{truncated}... (truncated)")
+}
@@ -0,0 +1,174 @@
+use ast_grep_core::Node;
+
+use crate::types::{
+    CommonMetadataExt, ExtractOptions, ParsedItem, SymbolKind, SymbolMetadata, Visibility,
+};
+
+use super::helpers;
+
+pub(super) fn extract<D: ast_grep_core::Doc>(
+    root: &Node<D>,
+    options: &ExtractOptions,
+) -> Vec<ParsedItem> {
+    let mut items = Vec::new();
+    let Some(document) = root.children().next() else {
+        return items;
+    };
+    if document.kind().as_ref() != "object" {
+        return items;
+    }
+
+    if let Some(paths) = object_value(&document, "paths") {
+        collect_paths(&paths, options, &mut items);
+    }
+    if let Some(components) = object_value(&document, "components")
+        && let Some(schemas) = object_value(&components, "schemas")
+    {
+        collect_schemas(&schemas, options, &mut items);
+    }
+
+    items
+}
+
+fn object_value<'a, D: ast_grep_core::Doc>(object: &Node<'a, D>, key: &str) -> Option<Node<'a, D>> {
+    if object.kind().as_ref() != "object" {
+        return None;
+    }
+    object.children().find_map(|pair| {
+        if pair.kind().as_ref() != "pair" {
+            return None;
+        }
+        let key_node = pair.field("key")?;
+        if helpers::unquote(&key_node.text()) == key {
+            pair.field("value")
+        } else {
+            None
+        }
+    })
+}
+
+fn object_pairs<'a, D: ast_grep_core::Doc>(object: &Node<'a, D>) -> Vec<(String, Node<'a, D>)> {
+    if object.kind().as_ref() != "object" {
+        return Vec::new();
+    }
+    object
+        .children()
+        .filter(|child| child.kind().as_ref() == "pair")
+        .filter_map(|pair| {
+            let key_node = pair.field("key")?;
+            let value_node = pair.field("value")?;
+            Some((helpers::unquote(&key_node.text()), value_node))
+        })
+        .collect()
+}
+
+fn collect_paths<D: ast_grep_core::Doc>(
+    paths: &Node<D>,
+    options: &ExtractOptions,
+    items: &mut Vec<ParsedItem>,
+) {
+    for (path, path_item) in object_pairs(paths) {
+        for (method, operation) in object_pairs(&path_item) {
+            let method = method.to_ascii_lowercase();
+            if !helpers::HTTP_METHODS.contains(&method.as_str()) {
+                continue;
+            }
+            items.push(build_operation_item(&operation, &method, &path, options));
+        }
+    }
+}
+
+fn build_operation_item<D: ast_grep_core::Doc>(
+    operation: &Node<D>,
+    method: &str,
+    path: &str,
+    options: &ExtractOptions,
+) -> ParsedItem {
+    let name = format!("{} {path}", method.to_ascii_uppercase());
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute(format!("openapi:path:{path}"));
+    metadata.push_attribute(format!("openapi:method:{method}"));
+
+    if let Some(operation_id) = object_value(operation, "operationId") {
+        metadata.set_return_type(Some(helpers::unquote(&operation_id.text())));
+    }
+    if let Some(parameters) = object_value(operation, "parameters") {
+        for param in parameters
+            .children()
+            .filter(|child| child.kind().as_ref() == "object")
+        {
+            if let Some(param_name) = object_value(&param, "name") {
+                metadata.push_parameter(helpers::unquote(&param_name.text()));
+            }
+        }
+    }
+    if let Some(responses) = object_value(operation, "responses") {
+        for (status, _) in object_pairs(&responses) {
+            metadata.push_attribute(format!("openapi:response:{status}"));
+        }
+    }
+
+    build_item(
+        operation,
+        SymbolKind::Function,
+        name.clone(),
+        metadata,
+        &name,
+        options,
+    )
+}
+
+fn collect_schemas<D: ast_grep_core::Doc>(
+    schemas: &Node<D>,
+    options: &ExtractOptions,
+    items: &mut Vec<ParsedItem>,
+) {
+    for (name, schema) in object_pairs(schemas) {
+        let mut metadata = SymbolMetadata::default();
+        metadata.push_attribute(format!("openapi:schema:{name}"));
+        if let Some(schema_type) = object_value(&schema, "type") {
+            metadata.set_return_type(Some(helpers::unquote(&schema_type.text())));
+        }
+        if let Some(properties) = object_value(&schema, "properties") {
+            let fields = object_pairs(&properties)
+                .into_iter()
+                .map(|(field_name, _)| field_name)
+                .collect();
+            metadata.set_fields(fields);
+        }
+
+        items.push(build_item(
+            &schema,
+            SymbolKind::Struct,
+            name.clone(),
+            metadata,
+            &name,
+            options,
+        ));
+    }
+}
+
+fn build_item<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    kind: SymbolKind,
+    name: String,
+    metadata: SymbolMetadata,
+    signature_name: &str,
+    options: &ExtractOptions,
+) -> ParsedItem {
+    let source = crate::extractors::helpers::extract_source(node, 40)
+        .map(|text| helpers::truncate_value(&text, options.max_value_len));
+
+    ParsedItem {
+        is_deprecated: false,
+        kind,
+        name,
+        signature: signature_name.to_string(),
+        source,
+        doc_comment: String::new(),
+        start_line: node.start_pos().line() as u32 + 1,
+        end_line: node.end_pos().line() as u32 + 1,
+        visibility: Visibility::Public,
+        metadata,
+    }
+}
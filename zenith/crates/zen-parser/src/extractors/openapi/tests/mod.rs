@@ -0,0 +1,140 @@
+use ast_grep_language::LanguageExt;
+
+use super::*;
+use crate::types::SymbolKind;
+
+const JSON_SPEC: &str = r#"{
+  "openapi": "3.0.0",
+  "paths": {
+    "/users": {
+      "get": {
+        "operationId": "listUsers",
+        "parameters": [{ "name": "limit", "in": "query" }],
+        "responses": { "200": { "description": "ok" } }
+      }
+    },
+    "/users/{id}": {
+      "get": {
+        "operationId": "getUser",
+        "parameters": [{ "name": "id", "in": "path" }],
+        "responses": { "200": { "description": "ok" }, "404": { "description": "not found" } }
+      }
+    }
+  },
+  "components": {
+    "schemas": {
+      "User": {
+        "type": "object",
+        "properties": { "id": { "type": "string" }, "name": { "type": "string" } }
+      }
+    }
+  }
+}"#;
+
+const YAML_SPEC: &str = "
+openapi: 3.0.0
+paths:
+  /users:
+    get:
+      operationId: listUsers
+      parameters:
+        - name: limit
+          in: query
+      responses:
+        '200':
+          description: ok
+  /users/{id}:
+    get:
+      operationId: getUser
+      parameters:
+        - name: id
+          in: path
+      responses:
+        '200':
+          description: ok
+        '404':
+          description: not found
+components:
+  schemas:
+    User:
+      type: object
+      properties:
+        id:
+          type: string
+        name:
+          type: string
+";
+
+fn find_by_name<'a>(items: &'a [ParsedItem], name: &str) -> &'a ParsedItem {
+    items
+        .iter()
+        .find(|item| item.name == name)
+        .unwrap_or_else(|| {
+            let names: Vec<_> = items.iter().map(|item| item.name.as_str()).collect();
+            panic!("should find item named '{name}', available: {names:?}")
+        })
+}
+
+#[test]
+fn detects_openapi_documents_by_content_shape() {
+    assert!(looks_like_openapi(JSON_SPEC));
+    assert!(looks_like_openapi(YAML_SPEC));
+    assert!(!looks_like_openapi(r#"{"paths": {}}"#));
+    assert!(!looks_like_openapi(r#"{"openapi": "3.0.0"}"#));
+}
+
+#[test]
+fn json_spec_extracts_endpoints_and_schema_with_correct_names() {
+    let root = SupportLang::Json.ast_grep(JSON_SPEC);
+    let items = extract_json(&root, ExtractOptions::default()).expect("extraction should succeed");
+
+    let list_users = find_by_name(&items, "GET /users");
+    assert_eq!(list_users.kind, SymbolKind::Function);
+    assert!(
+        list_users
+            .metadata
+            .attributes
+            .contains(&"openapi:response:200".to_string())
+    );
+    assert_eq!(list_users.metadata.parameters, vec!["limit".to_string()]);
+
+    let get_user = find_by_name(&items, "GET /users/{id}");
+    assert!(
+        get_user
+            .metadata
+            .attributes
+            .contains(&"openapi:response:404".to_string())
+    );
+
+    let user_schema = find_by_name(&items, "User");
+    assert_eq!(user_schema.kind, SymbolKind::Struct);
+    assert_eq!(
+        user_schema.metadata.fields,
+        vec!["id".to_string(), "name".to_string()]
+    );
+}
+
+#[test]
+fn yaml_spec_extracts_endpoints_and_schema_with_correct_names() {
+    let root = SupportLang::Yaml.ast_grep(YAML_SPEC);
+    let items = extract_yaml(&root, ExtractOptions::default()).expect("extraction should succeed");
+
+    let list_users = find_by_name(&items, "GET /users");
+    assert_eq!(list_users.kind, SymbolKind::Function);
+    assert_eq!(list_users.metadata.parameters, vec!["limit".to_string()]);
+
+    let get_user = find_by_name(&items, "GET /users/{id}");
+    assert!(
+        get_user
+            .metadata
+            .attributes
+            .contains(&"openapi:response:404".to_string())
+    );
+
+    let user_schema = find_by_name(&items, "User");
+    assert_eq!(user_schema.kind, SymbolKind::Struct);
+    assert_eq!(
+        user_schema.metadata.fields,
+        vec!["id".to_string(), "name".to_string()]
+    );
+}
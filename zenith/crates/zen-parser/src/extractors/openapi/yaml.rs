@@ -0,0 +1,245 @@
+use ast_grep_core::Node;
+
+use crate::types::{
+    CommonMetadataExt, ExtractOptions, ParsedItem, SymbolKind, SymbolMetadata, Visibility,
+};
+
+use super::helpers;
+
+pub(super) fn extract<D: ast_grep_core::Doc>(
+    root: &Node<D>,
+    options: &ExtractOptions,
+) -> Vec<ParsedItem> {
+    let mut items = Vec::new();
+
+    let Some(document) = root
+        .children()
+        .find(|child| child.kind().as_ref() == "document")
+    else {
+        return items;
+    };
+    let Some(value) = document
+        .children()
+        .find(|child| matches!(child.kind().as_ref(), "block_node" | "flow_node"))
+    else {
+        return items;
+    };
+    let root_mapping = unwrap(&value);
+    if !is_mapping(&root_mapping) {
+        return items;
+    }
+
+    if let Some(paths) = mapping_value(&root_mapping, "paths") {
+        collect_paths(&paths, options, &mut items);
+    }
+    if let Some(components) = mapping_value(&root_mapping, "components")
+        && let Some(schemas) = mapping_value(&components, "schemas")
+    {
+        collect_schemas(&schemas, options, &mut items);
+    }
+
+    items
+}
+
+fn is_mapping<D: ast_grep_core::Doc>(node: &Node<D>) -> bool {
+    matches!(node.kind().as_ref(), "block_mapping" | "flow_mapping")
+}
+
+fn is_mapping_pair<D: ast_grep_core::Doc>(node: &Node<D>) -> bool {
+    matches!(node.kind().as_ref(), "block_mapping_pair" | "flow_pair")
+}
+
+fn is_sequence<D: ast_grep_core::Doc>(node: &Node<D>) -> bool {
+    matches!(node.kind().as_ref(), "block_sequence" | "flow_sequence")
+}
+
+/// Peel `anchor`/`tag` wrapper nodes off a `block_node`/`flow_node` to reach
+/// its concrete mapping/sequence/scalar value. Aliases aren't resolved —
+/// following them isn't needed to pull out paths/schemas by name.
+fn unwrap<'a, D: ast_grep_core::Doc>(node: &Node<'a, D>) -> Node<'a, D> {
+    let mut current = node.clone();
+    loop {
+        let kind = current.kind();
+        if kind.as_ref() != "block_node" && kind.as_ref() != "flow_node" {
+            return current;
+        }
+        let Some(next) = current
+            .children()
+            .find(|child| !matches!(child.kind().as_ref(), "anchor" | "tag"))
+        else {
+            return current;
+        };
+        current = next;
+    }
+}
+
+fn key_text<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
+    helpers::unquote(&unwrap(node).text())
+}
+
+fn mapping_value<'a, D: ast_grep_core::Doc>(
+    mapping: &Node<'a, D>,
+    key: &str,
+) -> Option<Node<'a, D>> {
+    if !is_mapping(mapping) {
+        return None;
+    }
+    mapping.children().find_map(|pair| {
+        if !is_mapping_pair(&pair) {
+            return None;
+        }
+        let key_node = pair.field("key")?;
+        if key_text(&key_node) == key {
+            pair.field("value").map(|value| unwrap(&value))
+        } else {
+            None
+        }
+    })
+}
+
+fn mapping_pairs<'a, D: ast_grep_core::Doc>(mapping: &Node<'a, D>) -> Vec<(String, Node<'a, D>)> {
+    if !is_mapping(mapping) {
+        return Vec::new();
+    }
+    mapping
+        .children()
+        .filter(|child| is_mapping_pair(child))
+        .filter_map(|pair| {
+            let key_node = pair.field("key")?;
+            let value_node = pair.field("value")?;
+            Some((key_text(&key_node), unwrap(&value_node)))
+        })
+        .collect()
+}
+
+fn sequence_items<'a, D: ast_grep_core::Doc>(sequence: &Node<'a, D>) -> Vec<Node<'a, D>> {
+    if !is_sequence(sequence) {
+        return Vec::new();
+    }
+    sequence
+        .children()
+        .filter_map(|child| {
+            if child.kind().as_ref() == "block_sequence_item" {
+                child
+                    .children()
+                    .find(|node| matches!(node.kind().as_ref(), "block_node" | "flow_node"))
+            } else if child.kind().as_ref() == "flow_node" {
+                Some(child)
+            } else {
+                None
+            }
+        })
+        .map(|value| unwrap(&value))
+        .collect()
+}
+
+fn collect_paths<D: ast_grep_core::Doc>(
+    paths: &Node<D>,
+    options: &ExtractOptions,
+    items: &mut Vec<ParsedItem>,
+) {
+    for (path, path_item) in mapping_pairs(paths) {
+        for (method, operation) in mapping_pairs(&path_item) {
+            let method = method.to_ascii_lowercase();
+            if !helpers::HTTP_METHODS.contains(&method.as_str()) {
+                continue;
+            }
+            items.push(build_operation_item(&operation, &method, &path, options));
+        }
+    }
+}
+
+fn build_operation_item<D: ast_grep_core::Doc>(
+    operation: &Node<D>,
+    method: &str,
+    path: &str,
+    options: &ExtractOptions,
+) -> ParsedItem {
+    let name = format!("{} {path}", method.to_ascii_uppercase());
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute(format!("openapi:path:{path}"));
+    metadata.push_attribute(format!("openapi:method:{method}"));
+
+    if let Some(operation_id) = mapping_value(operation, "operationId") {
+        metadata.set_return_type(Some(helpers::unquote(&operation_id.text())));
+    }
+    if let Some(parameters) = mapping_value(operation, "parameters") {
+        for param in sequence_items(&parameters)
+            .iter()
+            .filter(|item| is_mapping(item))
+        {
+            if let Some(param_name) = mapping_value(param, "name") {
+                metadata.push_parameter(helpers::unquote(&param_name.text()));
+            }
+        }
+    }
+    if let Some(responses) = mapping_value(operation, "responses") {
+        for (status, _) in mapping_pairs(&responses) {
+            metadata.push_attribute(format!("openapi:response:{status}"));
+        }
+    }
+
+    build_item(
+        operation,
+        SymbolKind::Function,
+        name.clone(),
+        metadata,
+        &name,
+        options,
+    )
+}
+
+fn collect_schemas<D: ast_grep_core::Doc>(
+    schemas: &Node<D>,
+    options: &ExtractOptions,
+    items: &mut Vec<ParsedItem>,
+) {
+    for (name, schema) in mapping_pairs(schemas) {
+        let mut metadata = SymbolMetadata::default();
+        metadata.push_attribute(format!("openapi:schema:{name}"));
+        if let Some(schema_type) = mapping_value(&schema, "type") {
+            metadata.set_return_type(Some(helpers::unquote(&schema_type.text())));
+        }
+        if let Some(properties) = mapping_value(&schema, "properties") {
+            let fields = mapping_pairs(&properties)
+                .into_iter()
+                .map(|(field_name, _)| field_name)
+                .collect();
+            metadata.set_fields(fields);
+        }
+
+        items.push(build_item(
+            &schema,
+            SymbolKind::Struct,
+            name.clone(),
+            metadata,
+            &name,
+            options,
+        ));
+    }
+}
+
+fn build_item<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    kind: SymbolKind,
+    name: String,
+    metadata: SymbolMetadata,
+    signature_name: &str,
+    options: &ExtractOptions,
+) -> ParsedItem {
+    let source = crate::extractors::helpers::extract_source(node, 40)
+        .map(|text| helpers::truncate_value(&text, options.max_value_len));
+
+    ParsedItem {
+        is_deprecated: false,
+        kind,
+        name,
+        signature: signature_name.to_string(),
+        source,
+        doc_comment: String::new(),
+        start_line: node.start_pos().line() as u32 + 1,
+        end_line: node.end_pos().line() as u32 + 1,
+        visibility: Visibility::Public,
+        metadata,
+    }
+}
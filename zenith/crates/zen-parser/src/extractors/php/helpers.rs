@@ -225,12 +225,31 @@ pub(super) fn collect_enum_variants<D: ast_grep_core::Doc>(node: &Node<D>) -> Ve
         .map(|body| {
             body.children()
                 .filter(|c| c.kind().as_ref() == "enum_case")
-                .filter_map(|case| case.field("name").map(|n| n.text().to_string()))
+                .filter_map(|case| {
+                    let name = case.field("name")?.text().to_string();
+                    Some(match case.field("value") {
+                        Some(value) => format!("{name}={}", value.text()),
+                        None => name,
+                    })
+                })
                 .collect()
         })
         .unwrap_or_default()
 }
 
+/// The scalar type backing an `enum ...: string|int { ... }` declaration, if
+/// any, e.g. `Some("string")` for `enum Status: string { ... }`.
+pub(super) fn extract_enum_backing_type<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<String> {
+    node.children()
+        .find(|c| c.kind().as_ref() == "primitive_type")
+        .map(|ty| ty.text().to_string())
+}
+
+pub(super) fn is_readonly<D: ast_grep_core::Doc>(node: &Node<D>) -> bool {
+    node.children()
+        .any(|child| child.kind().as_ref() == "readonly_modifier")
+}
+
 pub(super) fn apply_phpdoc_metadata(doc: &str, metadata: &mut SymbolMetadata) {
     let parsed = phpdoc::parse_phpdoc(doc);
 
@@ -73,6 +73,14 @@ pub(super) fn process_type_declaration<D: ast_grep_core::Doc>(
     php_helpers::apply_phpdoc_metadata(&doc, &mut metadata);
     if kind == SymbolKind::Enum {
         metadata.variants = php_helpers::collect_enum_variants(node);
+        if let Some(backing_type) = php_helpers::extract_enum_backing_type(node) {
+            metadata
+                .attributes
+                .push(format!("php:enum:backing:{backing_type}"));
+        }
+    }
+    if kind == SymbolKind::Class && php_helpers::is_readonly(node) {
+        metadata.attributes.push("php:readonly".to_string());
     }
 
     Some(build_item(
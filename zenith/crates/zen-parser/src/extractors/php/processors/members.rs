@@ -59,7 +59,10 @@ fn process_property_declaration<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<Pa
     let owner = php_helpers::owner_from_ancestors(node);
     let visibility = php_helpers::extract_visibility(node);
     let is_static = php_helpers::is_static(node);
-    let attrs = php_helpers::extract_attributes(node);
+    let mut attrs = php_helpers::extract_attributes(node);
+    if php_helpers::is_readonly(node) {
+        attrs.push("php:readonly".to_string());
+    }
     let ty = types::normalize_type_node(node.field("type"));
 
     let mut items: Vec<ParsedItem> = node
@@ -207,12 +210,17 @@ fn process_promoted_property<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<Parse
         return Vec::new();
     }
 
+    let mut attrs = php_helpers::extract_attributes(node);
+    if node.field("readonly").is_some() {
+        attrs.push("php:readonly".to_string());
+    }
+
     let metadata = SymbolMetadata {
         owner_name: Some(owner_name),
         owner_kind: Some(owner_kind),
         is_static_member: false,
         return_type: types::normalize_type_node(node.field("type")),
-        attributes: php_helpers::extract_attributes(node),
+        attributes: attrs,
         ..Default::default()
     };
 
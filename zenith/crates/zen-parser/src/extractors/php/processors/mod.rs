@@ -7,7 +7,7 @@ pub mod types;
 
 use ast_grep_core::Node;
 
-use crate::types::{ParsedItem, SymbolKind, SymbolMetadata, Visibility};
+use crate::types::{CommonMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
 
 pub(super) fn process_module_like<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
     declarations::process_module_like(node)
@@ -65,13 +65,16 @@ fn build_item<D: ast_grep_core::Doc>(
     kind: SymbolKind,
     name: String,
     visibility: Visibility,
-    metadata: SymbolMetadata,
+    mut metadata: SymbolMetadata,
     doc_comment: String,
 ) -> ParsedItem {
+    metadata.set_doc_sections(phpdoc::parse_doc_sections(&doc_comment));
+
     ParsedItem {
+        is_deprecated: phpdoc::has_deprecated_tag(&doc_comment),
         kind,
         name,
-        signature: crate::extractors::helpers::extract_signature(node),
+        signature: crate::extractors::helpers::extract_signature(node, "php"),
         source: crate::extractors::helpers::extract_source(node, 40),
         doc_comment,
         start_line: node.start_pos().line() as u32 + 1,
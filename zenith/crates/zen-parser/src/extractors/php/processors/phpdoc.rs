@@ -1,3 +1,5 @@
+use crate::types::DocSections;
+
 #[derive(Default)]
 pub struct PhpDocData {
     pub return_type: Option<String>,
@@ -115,3 +117,51 @@ pub fn parse_phpdoc(doc: &str) -> PhpDocData {
 
     out
 }
+
+/// Parse `@param`/`@return`/`@throws` descriptions out of a raw `PHPDoc`
+/// comment into a normalized [`DocSections`]. Complements [`parse_phpdoc`],
+/// which extracts *types* for merging into `SymbolMetadata` fields.
+pub fn parse_doc_sections(doc: &str) -> DocSections {
+    let mut sections = DocSections::default();
+
+    for line in doc.lines() {
+        let trimmed = line.trim();
+
+        if let Some(rest) = trimmed.strip_prefix("@param ") {
+            // `@param Type $name description` or untyped `@param $name description`.
+            let (first, remainder) = split_type_prefixed_tag(rest);
+            let (name, desc) = if first.starts_with('$') {
+                (first, remainder)
+            } else {
+                split_type_prefixed_tag(remainder)
+            };
+            let name = name.trim_start_matches('$');
+            if !name.is_empty() {
+                sections.args.insert(name.to_string(), desc.to_string());
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("@return ") {
+            sections.returns = Some(rest.trim().to_string());
+        } else if let Some(rest) = trimmed.strip_prefix("@throws ") {
+            let (exc, desc) = split_type_prefixed_tag(rest);
+            if !exc.is_empty() {
+                sections.raises.insert(exc.to_string(), desc.to_string());
+            }
+        }
+    }
+
+    sections
+}
+
+/// Split a `Type $name description` (or `Type description`) tag body into
+/// its leading token and the remaining description text.
+fn split_type_prefixed_tag(rest: &str) -> (&str, &str) {
+    let rest = rest.trim();
+    let (head, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    (head, tail.trim())
+}
+
+/// Whether a raw `PHPDoc` comment carries an `@deprecated` tag.
+pub fn has_deprecated_tag(doc: &str) -> bool {
+    doc.lines()
+        .any(|line| line.trim().starts_with("@deprecated"))
+}
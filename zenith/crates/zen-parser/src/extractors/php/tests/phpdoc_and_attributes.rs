@@ -69,6 +69,49 @@ class UserRepo {}
     );
 }
 
+#[test]
+fn phpdoc_tags_populate_doc_sections_and_deprecated() {
+    let source = r"
+<?php
+/**
+ * Adds two numbers.
+ * @param int $x the first operand
+ * @param int $y the second operand
+ * @return int the sum
+ * @throws InvalidArgumentException if either operand is negative
+ * @deprecated use computeSum() instead
+ */
+function add($x, $y) {
+    return $x + $y;
+}
+";
+
+    let items = parse_and_extract(source);
+    let add = find_by_name(&items, "add");
+
+    assert_eq!(
+        add.metadata.doc_sections.args.get("x").map(String::as_str),
+        Some("the first operand")
+    );
+    assert_eq!(
+        add.metadata.doc_sections.args.get("y").map(String::as_str),
+        Some("the second operand")
+    );
+    assert_eq!(
+        add.metadata.doc_sections.returns.as_deref(),
+        Some("int the sum")
+    );
+    assert_eq!(
+        add.metadata
+            .doc_sections
+            .raises
+            .get("InvalidArgumentException")
+            .map(String::as_str),
+        Some("if either operand is negative")
+    );
+    assert!(add.is_deprecated);
+}
+
 #[test]
 fn parses_php_attributes() {
     let source = r"
@@ -94,3 +137,80 @@ function endpoint(): void {}
             .any(|a| a == "attr:args:('/ok')")
     );
 }
+
+#[test]
+fn symfony_controller_attributes_and_readonly_are_captured() {
+    let items = fixture_items();
+
+    let controller = find_by_name(&items, "UserController");
+    assert_eq!(controller.kind, SymbolKind::Class);
+    assert!(
+        controller
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "attr:name:Route")
+    );
+    assert!(
+        controller
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "php:readonly")
+    );
+
+    let repository = find_by_name(&items, "repository");
+    assert_eq!(repository.kind, SymbolKind::Field);
+    assert_eq!(
+        repository.metadata.owner_name.as_deref(),
+        Some("UserController")
+    );
+    assert!(
+        repository
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "attr:name:Autowire")
+    );
+    assert!(
+        repository
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "php:readonly")
+    );
+
+    let show = items
+        .iter()
+        .find(|i| i.name == "show" && i.metadata.owner_name.as_deref() == Some("UserController"))
+        .expect("expected UserController::show");
+    assert!(
+        show.metadata
+            .attributes
+            .iter()
+            .any(|a| a == "attr:name:Route")
+    );
+    assert!(
+        show.metadata
+            .attributes
+            .iter()
+            .any(|a| a == "attr:args:('/users/{id}', methods: ['GET'])")
+    );
+}
+
+#[test]
+fn backed_enum_case_values_and_backing_type_are_recorded() {
+    let items = fixture_items();
+
+    let priority = find_by_name(&items, "Priority");
+    assert_eq!(priority.kind, SymbolKind::Enum);
+    assert!(
+        priority
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "php:enum:backing:int")
+    );
+    assert!(priority.metadata.variants.iter().any(|v| v == "Low=1"));
+    assert!(priority.metadata.variants.iter().any(|v| v == "High=2"));
+}
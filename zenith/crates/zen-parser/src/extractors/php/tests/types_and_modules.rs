@@ -21,6 +21,19 @@ fn extracts_namespaces_imports_and_types() {
     assert_eq!(find_by_name(&items, "Status").kind, SymbolKind::Enum);
 
     let status = find_by_name(&items, "Status");
-    assert!(status.metadata.variants.iter().any(|v| v == "Ready"));
-    assert!(status.metadata.variants.iter().any(|v| v == "Done"));
+    assert!(
+        status
+            .metadata
+            .variants
+            .iter()
+            .any(|v| v == "Ready='ready'")
+    );
+    assert!(status.metadata.variants.iter().any(|v| v == "Done='done'"));
+    assert!(
+        status
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "php:enum:backing:string")
+    );
 }
@@ -7,7 +7,9 @@ use crate::extractors::helpers;
 use crate::types::{ParsedItem, PythonMetadataExt, SymbolKind, SymbolMetadata, Visibility};
 
 use super::super::doc::{extract_docstring, parse_python_doc_sections};
-use super::super::pyhelpers::{decorator_matches_any, is_exception_subclass, python_visibility};
+use super::super::pyhelpers::{
+    decorator_matches, decorator_matches_any, is_exception_subclass, python_visibility,
+};
 
 pub fn process_class<D: ast_grep_core::Doc>(
     node: &Node<D>,
@@ -32,6 +34,7 @@ pub fn process_class<D: ast_grep_core::Doc>(
     let is_generic = base_classes
         .iter()
         .any(|b| b.starts_with("Generic[") || b == "Generic");
+    let is_deprecated = decorator_matches(decorators, "deprecated");
 
     let (methods, fields) = extract_class_members(node);
 
@@ -86,6 +89,7 @@ pub fn process_class<D: ast_grep_core::Doc>(
     }
 
     Some(ParsedItem {
+        is_deprecated,
         kind: symbol_kind,
         name,
         signature: helpers::extract_signature_python(node),
@@ -274,11 +278,14 @@ fn build_function_member_item<D: ast_grep_core::Doc>(
         &["property", "cached_property"],
     ) {
         SymbolKind::Property
+    } else if owner_name.starts_with("Test") || name.starts_with("test_") {
+        SymbolKind::Test
     } else {
         SymbolKind::Method
     };
 
     Some(ParsedItem {
+        is_deprecated: decorator_matches(decorators, "deprecated"),
         kind,
         name: format!("{owner_name}::{name}"),
         signature: helpers::extract_signature_python(node),
@@ -318,9 +325,10 @@ fn build_field_member_item<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Field,
         name: format!("{owner_name}::{raw_name}"),
-        signature: text.clone(),
+        signature: crate::extractors::helpers::normalize_signature(&text, "python"),
         source: Some(text),
         doc_comment: String::new(),
         start_line: expr_stmt.start_pos().line() as u32 + 1,
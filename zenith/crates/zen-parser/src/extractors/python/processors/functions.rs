@@ -32,6 +32,7 @@ pub fn process_function<D: ast_grep_core::Doc>(
     let is_context_manager = decorator_matches(decorators, "contextmanager")
         || decorator_matches(decorators, "asynccontextmanager");
     let is_abstract = decorator_matches(decorators, "abstractmethod");
+    let is_deprecated = decorator_matches(decorators, "deprecated");
 
     let visibility = python_visibility(&name);
     let returns_result = helpers::returns_result(return_type.as_deref());
@@ -78,11 +79,14 @@ pub fn process_function<D: ast_grep_core::Doc>(
         SymbolKind::Constructor
     } else if is_property {
         SymbolKind::Property
+    } else if name.starts_with("test_") {
+        SymbolKind::Test
     } else {
         SymbolKind::Function
     };
 
     Some(ParsedItem {
+        is_deprecated,
         kind,
         name,
         signature: helpers::extract_signature_python(node),
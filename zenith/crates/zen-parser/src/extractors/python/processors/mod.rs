@@ -104,9 +104,10 @@ pub(super) fn process_module_assignment<D: ast_grep_core::Doc>(
     let visibility = python_visibility(&name);
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: symbol_kind,
         name,
-        signature: assignment.text().to_string(),
+        signature: crate::extractors::helpers::normalize_signature(&assignment.text(), "python"),
         source: Some(assignment.text().to_string()),
         doc_comment: String::new(),
         start_line: expr_stmt.start_pos().line() as u32 + 1,
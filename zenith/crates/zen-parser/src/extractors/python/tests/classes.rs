@@ -456,3 +456,20 @@ fn visibility_example_class() {
 }
 
 // ── Unit tests for python_visibility ────────────────────────────
+
+#[test]
+fn method_in_test_class_gets_test_kind() {
+    let source = include_str!("../../../../tests/fixtures/sample.py");
+    let items = parse_and_extract(source);
+    let m = find_by_name(&items, "TestWidget::test_creates_widget");
+    assert_eq!(m.kind, SymbolKind::Test);
+}
+
+#[test]
+fn non_test_named_method_in_test_class_still_test_kind() {
+    // Any method inside a Test* class is treated as test scaffolding.
+    let source = include_str!("../../../../tests/fixtures/sample.py");
+    let items = parse_and_extract(source);
+    let m = find_by_name(&items, "TestWidget::setUp");
+    assert_eq!(m.kind, SymbolKind::Test);
+}
@@ -66,4 +66,36 @@ fn decorator_matches_exact() {
     assert!(super::decorator_matches(&decorators, "staticmethod"));
 }
 
+// ── Deprecation tests ──────────────────────────────────────────
+
+#[test]
+fn deprecated_function_detected() {
+    let source = include_str!("../../../../tests/fixtures/sample.py");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "old_processor");
+    assert!(f.is_deprecated, "old_processor should be marked deprecated");
+}
+
+#[test]
+fn deprecated_method_detected() {
+    let source = include_str!("../../../../tests/fixtures/sample.py");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "LegacyClient::fetch");
+    assert!(
+        f.is_deprecated,
+        "LegacyClient::fetch should be marked deprecated"
+    );
+}
+
+#[test]
+fn non_deprecated_function_not_flagged() {
+    let source = include_str!("../../../../tests/fixtures/sample.py");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "transform");
+    assert!(
+        !f.is_deprecated,
+        "transform should not be marked deprecated"
+    );
+}
+
 // ── Property tests ─────────────────────────────────────────────
@@ -172,3 +172,11 @@ fn async_function_signature_prefix() {
         fetch.signature
     );
 }
+
+#[test]
+fn module_level_test_function_gets_test_kind() {
+    let source = include_str!("../../../../tests/fixtures/sample.py");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "test_module_level_case");
+    assert_eq!(f.kind, SymbolKind::Test);
+}
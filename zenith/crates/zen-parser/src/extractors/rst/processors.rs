@@ -13,6 +13,7 @@ fn build_item<D: ast_grep_core::Doc>(
     metadata: SymbolMetadata,
 ) -> ParsedItem {
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature,
@@ -300,6 +301,7 @@ pub(super) fn virtual_table_item(
     metadata.set_owner_kind(Some(SymbolKind::Module));
 
     ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Property,
         name: format!("{table_kind}-{start_line}"),
         signature: table_kind.to_string(),
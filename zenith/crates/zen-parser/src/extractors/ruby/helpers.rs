@@ -1,12 +1,10 @@
 use ast_grep_core::Node;
 
-use crate::types::Visibility;
+use crate::types::{DocSections, Visibility};
 
 pub(super) fn extract_ruby_signature<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
-    node.text()
-        .lines()
-        .next()
-        .map_or_else(String::new, |line| line.trim().to_string())
+    let first_line = node.text().lines().next().unwrap_or_default().to_string();
+    crate::extractors::helpers::normalize_signature(&first_line, "ruby")
 }
 
 pub(super) fn extract_ruby_doc<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
@@ -73,6 +71,50 @@ fn extract_ruby_doc_by_line<D: ast_grep_core::Doc>(node: &Node<D>) -> String {
     docs.join("\n")
 }
 
+/// Parse YARD tags (`@param`, `@return`, `@raise`) out of a raw doc comment.
+///
+/// `doc` is left untouched elsewhere — this only extracts structured
+/// sections for [`crate::types::CommonMetadataExt::set_doc_sections`].
+pub(super) fn parse_yard_sections(doc: &str) -> DocSections {
+    let mut sections = DocSections::default();
+
+    for line in doc.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("@param ") {
+            let rest = rest.trim();
+            let (name, description) = match rest.split_once(char::is_whitespace) {
+                Some((name, description)) => (name, strip_yard_type(description.trim_start())),
+                None => (rest, ""),
+            };
+            if !name.is_empty() {
+                sections
+                    .args
+                    .insert(name.to_string(), description.trim().to_string());
+            }
+        } else if let Some(rest) = trimmed.strip_prefix("@return ") {
+            sections.returns = Some(strip_yard_type(rest.trim()).trim().to_string());
+        } else if let Some(rest) = trimmed.strip_prefix("@raise ") {
+            let rest = rest.trim();
+            if let Some(inner) = rest.strip_prefix('[')
+                && let Some((exception, description)) = inner.split_once(']')
+            {
+                sections
+                    .raises
+                    .insert(exception.trim().to_string(), description.trim().to_string());
+            }
+        }
+    }
+
+    sections
+}
+
+/// Strip a leading YARD `[Type]` annotation, returning the remaining text.
+fn strip_yard_type(text: &str) -> &str {
+    text.strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .map_or(text, |(_, rest)| rest.trim_start())
+}
+
 pub(super) fn normalize_const_path(path: &str) -> String {
     path.trim().trim_start_matches("::").to_string()
 }
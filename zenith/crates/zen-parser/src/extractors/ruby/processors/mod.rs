@@ -56,6 +56,9 @@ pub(super) fn process_method_declaration<D: ast_grep_core::Doc>(
     let visibility = resolve_method_visibility(node, &method_name, static_member);
     let mut metadata = SymbolMetadata::default();
     metadata.set_parameters(ruby_helpers::extract_method_parameters(node));
+    metadata.set_doc_sections(ruby_helpers::parse_yard_sections(
+        &ruby_helpers::extract_ruby_doc(node),
+    ));
 
     if let Some(owner) = owner {
         metadata.set_owner_name(Some(owner.name));
@@ -214,6 +217,11 @@ pub(super) fn process_call<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<ParsedI
                 ));
             }
         }
+        "alias_method" => {
+            if let [new_name, old_name] = symbol_args.as_slice() {
+                items.push(build_alias_item(node, &owner, new_name.clone(), old_name));
+            }
+        }
         "delegate" => {
             for delegated in symbol_args {
                 let mut metadata = member_metadata(&owner, false);
@@ -259,6 +267,78 @@ pub(super) fn process_call<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<ParsedI
     items
 }
 
+/// Handle the `alias new_name old_name` keyword form (as opposed to the
+/// `alias_method :new_name, :old_name` call form handled in `process_call`).
+pub(super) fn process_alias<D: ast_grep_core::Doc>(node: &Node<D>) -> Option<ParsedItem> {
+    let new_name = node.field("name").map(|name| name.text().to_string())?;
+    let old_name = node.field("alias").map(|name| name.text().to_string())?;
+    let owner = owner_context(node)?;
+
+    Some(build_alias_item(node, &owner, new_name, &old_name))
+}
+
+/// Build a `Method` item for an alias, mirroring the visibility and static-ness
+/// of `old_name` when it is defined in the same class body, and tagging it
+/// with `ruby:alias_of:<old_name>` so the alias is findable from its target.
+fn build_alias_item<D: ast_grep_core::Doc>(
+    node: &Node<D>,
+    owner: &OwnerContext,
+    new_name: String,
+    old_name: &str,
+) -> ParsedItem {
+    let (visibility, static_member) =
+        find_owner_method(node, old_name).map_or((Visibility::Public, false), |target| {
+            let static_member = is_static_method(&target);
+            (
+                resolve_method_visibility(&target, old_name, static_member),
+                static_member,
+            )
+        });
+
+    let mut metadata = member_metadata(owner, static_member);
+    metadata.push_attribute(format!("ruby:alias_of:{old_name}"));
+
+    build_item(node, SymbolKind::Method, new_name, visibility, metadata)
+}
+
+/// Find a `method`/`singleton_method` named `name` in the nearest enclosing
+/// class or module body, without descending into any nested class/module.
+fn find_owner_method<'r, D: ast_grep_core::Doc>(
+    node: &Node<'r, D>,
+    name: &str,
+) -> Option<Node<'r, D>> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if matches!(parent.kind().as_ref(), "class" | "module") {
+            return find_method_in_body(&parent, name);
+        }
+        current = parent.parent();
+    }
+    None
+}
+
+fn find_method_in_body<'r, D: ast_grep_core::Doc>(
+    scope: &Node<'r, D>,
+    name: &str,
+) -> Option<Node<'r, D>> {
+    for child in scope.children() {
+        match child.kind().as_ref() {
+            "method" | "singleton_method" => {
+                if ruby_helpers::extract_method_name(&child).as_deref() == Some(name) {
+                    return Some(child);
+                }
+            }
+            "class" | "module" => {}
+            _ => {
+                if let Some(found) = find_method_in_body(&child, name) {
+                    return Some(found);
+                }
+            }
+        }
+    }
+    None
+}
+
 pub(super) fn dedupe(items: Vec<ParsedItem>) -> Vec<ParsedItem> {
     let mut seen = HashSet::new();
     let mut out = Vec::new();
@@ -588,6 +668,7 @@ fn build_item<D: ast_grep_core::Doc>(
     metadata: SymbolMetadata,
 ) -> ParsedItem {
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature: ruby_helpers::extract_ruby_signature(node),
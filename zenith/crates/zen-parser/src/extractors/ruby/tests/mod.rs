@@ -10,6 +10,7 @@ mod dsl_coverage_edge_cases;
 mod members_and_visibility;
 mod rails_dsl;
 mod types_and_modules;
+mod yard_and_aliases;
 
 fn parse_and_extract(source: &str) -> Vec<ParsedItem> {
     let root = SupportLang::Ruby.ast_grep(source);
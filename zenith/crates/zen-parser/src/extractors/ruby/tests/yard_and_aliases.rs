@@ -0,0 +1,101 @@
+use super::*;
+
+#[test]
+fn yard_param_and_return_tags_are_parsed() {
+    let source = r"
+class Order
+  # Applies a discount to the order total.
+  #
+  # @param rate [Float] the discount rate, between 0 and 1
+  # @return [Float] the discounted total
+  # @raise [ArgumentError] if rate is out of range
+  def apply_discount(rate)
+    total * (1 - rate)
+  end
+end
+";
+    let items = parse_and_extract(source);
+    let method = find_by_name(&items, "apply_discount");
+    assert!(
+        method.doc_comment.contains("@param rate"),
+        "raw doc_comment should keep the YARD tags: {:?}",
+        method.doc_comment
+    );
+
+    let sections = &method.metadata.doc_sections;
+    assert_eq!(
+        sections.args.get("rate").map(String::as_str),
+        Some("the discount rate, between 0 and 1")
+    );
+    assert_eq!(sections.returns.as_deref(), Some("the discounted total"));
+    assert_eq!(
+        sections.raises.get("ArgumentError").map(String::as_str),
+        Some("if rate is out of range")
+    );
+}
+
+#[test]
+fn alias_method_call_mirrors_target_visibility() {
+    let source = r"
+class Account
+  def balance
+    0
+  end
+  alias_method :funds, :balance
+
+  private
+
+  def secret
+    42
+  end
+  alias_method :hidden, :secret
+end
+";
+    let items = parse_and_extract(source);
+
+    let funds = find_by_name(&items, "funds");
+    assert_eq!(funds.kind, SymbolKind::Method);
+    assert_eq!(funds.visibility, Visibility::Public);
+    assert_eq!(funds.metadata.owner_name.as_deref(), Some("Account"));
+    assert!(
+        funds
+            .metadata
+            .attributes
+            .iter()
+            .any(|attribute| attribute == "ruby:alias_of:balance")
+    );
+
+    let hidden = find_by_name(&items, "hidden");
+    assert_eq!(hidden.visibility, Visibility::Private);
+    assert!(
+        hidden
+            .metadata
+            .attributes
+            .iter()
+            .any(|attribute| attribute == "ruby:alias_of:secret")
+    );
+}
+
+#[test]
+fn alias_keyword_form_mirrors_target_visibility() {
+    let source = r"
+class Account
+  def balance
+    0
+  end
+  alias funds balance
+end
+";
+    let items = parse_and_extract(source);
+    let funds = find_by_name(&items, "funds");
+    assert_eq!(funds.kind, SymbolKind::Method);
+    assert_eq!(funds.visibility, Visibility::Public);
+    assert_eq!(funds.metadata.owner_name.as_deref(), Some("Account"));
+    assert!(
+        funds
+            .metadata
+            .attributes
+            .iter()
+            .any(|attribute| attribute == "ruby:alias_of:balance")
+    );
+}
@@ -50,11 +50,13 @@ pub(super) fn process_rust_node<D: ast_grep_core::Doc>(
 
     let name = extract_name(node)?;
     let (symbol_kind, metadata) = build_metadata(node, k, source, &name);
+    let is_deprecated = helpers::is_deprecated_attribute(&helpers::extract_attributes(node));
 
     Some(ParsedItem {
+        is_deprecated,
         kind: symbol_kind,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "rust"),
         source: helpers::extract_source(node, 50),
         doc_comment: helpers::extract_doc_comments_rust(node, source),
         start_line: node.start_pos().line() as u32 + 1,
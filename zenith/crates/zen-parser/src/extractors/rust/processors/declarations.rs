@@ -39,9 +39,10 @@ pub(super) fn process_foreign_mod<D: ast_grep_core::Doc>(
                     .map(|n| n.text().to_string());
                 if let Some(name) = name {
                     items.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Function,
                         name,
-                        signature: helpers::extract_signature(&child),
+                        signature: helpers::extract_signature(&child, "rust"),
                         source: helpers::extract_source(&child, 10),
                         doc_comment: helpers::extract_doc_comments_rust(&child, source),
                         start_line: child.start_pos().line() as u32 + 1,
@@ -64,9 +65,10 @@ pub(super) fn process_foreign_mod<D: ast_grep_core::Doc>(
                     .map(|n| n.text().to_string());
                 if let Some(name) = name {
                     items.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Static,
                         name,
-                        signature: helpers::extract_signature(&child),
+                        signature: helpers::extract_signature(&child, "rust"),
                         source: helpers::extract_source(&child, 10),
                         doc_comment: helpers::extract_doc_comments_rust(&child, source),
                         start_line: child.start_pos().line() as u32 + 1,
@@ -119,9 +121,10 @@ pub(super) fn process_use_declaration<D: ast_grep_core::Doc>(
     let is_reexport = vis == Visibility::Public || vis == Visibility::PublicCrate;
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "rust"),
         source: None,
         doc_comment: helpers::extract_doc_comments_rust(node, source),
         start_line: node.start_pos().line() as u32 + 1,
@@ -151,9 +154,10 @@ pub(super) fn process_extern_crate<D: ast_grep_core::Doc>(
         .filter(|n| !n.is_empty())?;
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "rust"),
         source: None,
         doc_comment: helpers::extract_doc_comments_rust(node, source),
         start_line: node.start_pos().line() as u32 + 1,
@@ -182,9 +186,10 @@ pub(super) fn process_macro_invocation<D: ast_grep_core::Doc>(
         .filter(|n| !n.is_empty())?;
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Macro,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "rust"),
         source: helpers::extract_source(node, 10),
         doc_comment: helpers::extract_doc_comments_rust(node, source),
         start_line: node.start_pos().line() as u32 + 1,
@@ -44,8 +44,26 @@ pub(super) fn build_function_metadata<D: ast_grep_core::Doc>(
     if helpers::is_pyo3(&attrs) {
         metadata.mark_pyo3();
     }
+    if let Some(exported) = helpers::proc_macro_export_name(&attrs, name) {
+        metadata.attributes.push(format!("proc_macro:{exported}"));
+    }
+
+    let kind = if is_test_attribute(&attrs) {
+        SymbolKind::Test
+    } else {
+        SymbolKind::Function
+    };
 
-    (SymbolKind::Function, metadata)
+    (kind, metadata)
+}
+
+/// Whether `attrs` marks a function as a test (`#[test]` or `#[tokio::test]`,
+/// with or without arguments like `#[tokio::test(flavor = "multi_thread")]`).
+fn is_test_attribute(attrs: &[String]) -> bool {
+    attrs.iter().any(|attr| {
+        let name = attr.split('(').next().unwrap_or(attr);
+        name == "test" || name == "tokio::test"
+    })
 }
 
 pub(super) fn build_macro_metadata<D: ast_grep_core::Doc>(
@@ -64,9 +82,26 @@ pub(super) fn build_macro_metadata<D: ast_grep_core::Doc>(
         SymbolKind::Macro,
         SymbolMetadata {
             attributes: final_attrs,
+            parameters: extract_macro_rule_patterns(node),
             is_exported,
             doc_sections,
             ..Default::default()
         },
     )
 }
+
+/// Summarize each `macro_rules!` rule's matcher (left-hand side) pattern as
+/// a single normalized-whitespace string, one entry per rule.
+fn extract_macro_rule_patterns<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec<String> {
+    node.children()
+        .filter(|child| child.kind().as_ref() == "macro_rule")
+        .filter_map(|rule| rule.field("left"))
+        .map(|pattern| {
+            pattern
+                .text()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect()
+}
@@ -59,9 +59,10 @@ pub(super) fn process_impl_item<D: ast_grep_core::Doc>(
         && let (Some(trait_n), Some(for_t)) = (&trait_name, &for_type)
     {
         items.push(ParsedItem {
+            is_deprecated: false,
             kind: SymbolKind::Trait,
             name: format!("!{trait_n}"),
-            signature: helpers::extract_signature(node),
+            signature: helpers::extract_signature(node, "rust"),
             source: helpers::extract_source(node, 10),
             doc_comment: String::new(),
             start_line: node.start_pos().line() as u32 + 1,
@@ -173,9 +174,10 @@ fn process_impl_method<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: helpers::is_deprecated_attribute(&attrs),
         kind,
         name,
-        signature: helpers::extract_signature(child),
+        signature: helpers::extract_signature(child, "rust"),
         source: helpers::extract_source(child, 50),
         doc_comment: doc,
         start_line: child.start_pos().line() as u32 + 1,
@@ -216,9 +218,10 @@ fn process_impl_assoc_const<D: ast_grep_core::Doc>(
         helpers::extract_return_type(node).or_else(|| helpers::extract_type_annotation(node));
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Const,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "rust"),
         source: helpers::extract_source(node, 10),
         doc_comment: helpers::extract_doc_comments_rust(node, source),
         start_line: node.start_pos().line() as u32 + 1,
@@ -249,9 +252,10 @@ fn process_impl_assoc_type<D: ast_grep_core::Doc>(
         .filter(|n| !n.is_empty())?;
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::TypeAlias,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "rust"),
         source: helpers::extract_source(node, 10),
         doc_comment: helpers::extract_doc_comments_rust(node, source),
         start_line: node.start_pos().line() as u32 + 1,
@@ -9,6 +9,7 @@ pub(super) fn build_struct_metadata<D: ast_grep_core::Doc>(
     name: &str,
 ) -> (SymbolKind, SymbolMetadata) {
     let attrs = helpers::extract_attributes(node);
+    let derives = helpers::extract_derives(&attrs);
     let generics = helpers::extract_generics(node);
     let fields = extract_struct_fields(node);
     let is_error =
@@ -27,6 +28,7 @@ pub(super) fn build_struct_metadata<D: ast_grep_core::Doc>(
         SymbolMetadata {
             generics: generics.clone(),
             attributes: attrs,
+            derives,
             lifetimes: helpers::extract_lifetimes(generics.as_deref()),
             where_clause: helpers::extract_where_clause(node),
             fields,
@@ -43,6 +45,7 @@ pub(super) fn build_enum_metadata<D: ast_grep_core::Doc>(
     name: &str,
 ) -> (SymbolKind, SymbolMetadata) {
     let attrs = helpers::extract_attributes(node);
+    let derives = helpers::extract_derives(&attrs);
     let generics = helpers::extract_generics(node);
     let variants = extract_enum_variants(node);
     let is_error =
@@ -55,6 +58,7 @@ pub(super) fn build_enum_metadata<D: ast_grep_core::Doc>(
         SymbolMetadata {
             generics: generics.clone(),
             attributes: attrs,
+            derives,
             lifetimes: helpers::extract_lifetimes(generics.as_deref()),
             variants,
             is_error_type: is_error,
@@ -161,6 +161,28 @@ fn static_item_has_type() {
     );
 }
 
+#[test]
+fn struct_derives_extracted() {
+    let source = include_str!("../../../../tests/fixtures/sample.rs");
+    let items = parse_and_extract(source);
+    let heavy = find_by_name(&items, "DeriveHeavy");
+    assert_eq!(heavy.kind, SymbolKind::Struct);
+    assert_eq!(
+        heavy.metadata.derives,
+        vec![
+            "Debug",
+            "Clone",
+            "PartialEq",
+            "Eq",
+            "Hash",
+            "Serialize",
+            "Deserialize"
+        ],
+        "derives: {:?}",
+        heavy.metadata.derives
+    );
+}
+
 #[test]
 fn receiver_struct_detected() {
     let source = include_str!("../../../../tests/fixtures/sample.rs");
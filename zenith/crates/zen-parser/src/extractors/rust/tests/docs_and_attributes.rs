@@ -108,6 +108,22 @@ fn doc_hidden_attribute_preserved() {
     );
 }
 
+#[test]
+fn sync_test_function_gets_test_kind() {
+    let source = include_str!("../../../../tests/fixtures/sample.rs");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "sync_test_case");
+    assert_eq!(f.kind, SymbolKind::Test);
+}
+
+#[test]
+fn tokio_test_function_gets_test_kind() {
+    let source = include_str!("../../../../tests/fixtures/sample.rs");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "async_test_case");
+    assert_eq!(f.kind, SymbolKind::Test);
+}
+
 #[test]
 fn block_doc_comment_extracted() {
     let source = include_str!("../../../../tests/fixtures/sample.rs");
@@ -119,3 +135,34 @@ fn block_doc_comment_extracted() {
         f.doc_comment
     );
 }
+
+#[test]
+fn deprecated_attribute_detected() {
+    let source = include_str!("../../../../tests/fixtures/sample.rs");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "old_api");
+    assert!(f.is_deprecated, "old_api should be marked deprecated");
+}
+
+#[test]
+fn proc_macro_derive_export_name_detected() {
+    let source = include_str!("../../../../tests/fixtures/sample.rs");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "derive_my_derive");
+    assert!(
+        f.metadata
+            .attributes
+            .iter()
+            .any(|a| a == "proc_macro:MyDerive"),
+        "attributes: {:?}",
+        f.metadata.attributes
+    );
+}
+
+#[test]
+fn non_deprecated_function_not_flagged() {
+    let source = include_str!("../../../../tests/fixtures/sample.rs");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "process");
+    assert!(!f.is_deprecated, "process should not be marked deprecated");
+}
@@ -64,6 +64,29 @@ fn macro_invocation_extracted() {
     );
 }
 
+#[test]
+fn macro_rule_patterns_extracted() {
+    let source = include_str!("../../../../tests/fixtures/sample.rs");
+    let items = parse_and_extract(source);
+    let three_rules = find_by_name(&items, "three_rules");
+    assert_eq!(three_rules.kind, SymbolKind::Macro);
+    assert_eq!(
+        three_rules.metadata.parameters.len(),
+        3,
+        "parameters: {:?}",
+        three_rules.metadata.parameters
+    );
+    assert!(
+        three_rules
+            .metadata
+            .parameters
+            .iter()
+            .any(|p| p.contains("$first") && p.contains("$second")),
+        "parameters: {:?}",
+        three_rules.metadata.parameters
+    );
+}
+
 #[test]
 fn macro_export_detected() {
     let source = include_str!("../../../../tests/fixtures/sample.rs");
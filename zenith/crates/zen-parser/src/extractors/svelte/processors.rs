@@ -17,6 +17,7 @@ fn build_item<D: ast_grep_core::Doc>(
 ) -> ParsedItem {
     metadata.push_attribute("svelte:extractor");
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature,
@@ -283,6 +284,196 @@ pub(super) fn tag_item<D: ast_grep_core::Doc>(node: &Node<D>, name: &str) -> Par
     )
 }
 
+/// Svelte 5 rune identifiers that mark a `let`/`const` declaration as
+/// reactive state, as opposed to a plain local variable.
+const RUNES: &[&str] = &["$state", "$derived"];
+
+/// Emit `let`/`const` declarations initialized with a rune (`$state`,
+/// `$derived`, `$derived.by`) as `Property` items tagged
+/// `svelte:rune:<name>`, so reactive state shows up in the symbol graph the
+/// same way `export let` props already do.
+pub(super) fn rune_items<D: ast_grep_core::Doc>(
+    script_node: &Node<D>,
+    owner: &str,
+) -> Vec<ParsedItem> {
+    let text = script_node.text().to_string();
+    let mut out = Vec::new();
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        let Some(rest) = trimmed
+            .strip_prefix("let ")
+            .or_else(|| trimmed.strip_prefix("const "))
+        else {
+            continue;
+        };
+        let Some((name_part, value_part)) = rest.split_once('=') else {
+            continue;
+        };
+        let value_part = value_part.trim_start();
+        let Some(rune) = RUNES.iter().find(|rune| {
+            value_part.starts_with(&format!("{rune}("))
+                || value_part.starts_with(&format!("{rune}."))
+        }) else {
+            continue;
+        };
+
+        let name = name_part
+            .split(|c: char| c == ':' || c.is_whitespace())
+            .next()
+            .unwrap_or("")
+            .trim();
+        if name.is_empty() {
+            continue;
+        }
+
+        let mut metadata = SymbolMetadata::default();
+        metadata.push_attribute("svelte:kind:rune_state");
+        metadata.push_attribute(format!("svelte:rune:{rune}"));
+        metadata.set_owner_name(Some(owner.to_string()));
+        metadata.set_owner_kind(Some(SymbolKind::Module));
+        out.push(build_item(
+            script_node,
+            SymbolKind::Property,
+            format!("rune:{name}"),
+            trimmed.to_string(),
+            metadata,
+        ));
+    }
+
+    out
+}
+
+/// Route a `<script lang="ts">` block through the full TypeScript extractor
+/// and keep only interfaces/type aliases — the declarations the line-based
+/// `script_api_items` scan can't see, since it never builds a real AST for
+/// the script body.
+pub(super) fn script_ts_type_items(script_text: &str, owner: &str) -> Vec<ParsedItem> {
+    use ast_grep_language::SupportLang;
+
+    let ts_tree = crate::parser::parse_source(script_text, SupportLang::TypeScript);
+    let Ok(ts_items) = crate::extractors::typescript::extract(&ts_tree, SupportLang::TypeScript)
+    else {
+        return Vec::new();
+    };
+
+    ts_items
+        .into_iter()
+        .filter(|item| matches!(item.kind, SymbolKind::Interface | SymbolKind::TypeAlias))
+        .map(|mut item| {
+            item.metadata.push_attribute("svelte:embedded_ts_type");
+            item.metadata.set_owner_name(Some(owner.to_string()));
+            item.metadata.set_owner_kind(Some(SymbolKind::Module));
+            item
+        })
+        .collect()
+}
+
+/// Collect `svelte:props_type:<name>:<type>` attributes for a script block,
+/// covering both `export let name: Type = default` props and Svelte 5
+/// `let { name }: { name: Type } = $props()` destructuring.
+pub(super) fn props_type_attributes(script_text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+
+    for line in script_text.lines() {
+        let trimmed = line.trim();
+        let Some(rest) = trimmed.strip_prefix("export let ") else {
+            continue;
+        };
+        let decl = rest.trim_end_matches(';').trim();
+        let name_and_type = decl.split_once('=').map_or(decl, |(name, _)| name.trim());
+        let (name, ty) = name_and_type.split_once(':').map_or_else(
+            || (name_and_type.trim(), None),
+            |(n, t)| (n.trim(), Some(t.trim().to_string())),
+        );
+        if !name.is_empty() {
+            out.push(format!(
+                "svelte:props_type:{name}:{}",
+                ty.unwrap_or_else(|| "any".to_string())
+            ));
+        }
+    }
+
+    for line in script_text.lines() {
+        let trimmed = line.trim();
+        let Some(rest) = trimmed
+            .strip_prefix("let ")
+            .or_else(|| trimmed.strip_prefix("const "))
+        else {
+            continue;
+        };
+        let Some((pattern, value_part)) = rest.split_once('=') else {
+            continue;
+        };
+        if !value_part.trim_start().starts_with("$props(") {
+            continue;
+        }
+        let Some(names_start) = pattern.find('{') else {
+            continue;
+        };
+        let Some(names_end) = pattern[names_start..].find('}').map(|i| names_start + i) else {
+            continue;
+        };
+        let names_block = &pattern[names_start + 1..names_end];
+        let type_block = pattern[names_end + 1..]
+            .trim()
+            .trim_start_matches(':')
+            .trim();
+        let types = parse_type_annotation_block(type_block);
+
+        for raw in names_block.split(',') {
+            let name = raw
+                .trim()
+                .trim_start_matches("...")
+                .split(['=', ':'])
+                .next()
+                .unwrap_or("")
+                .trim();
+            if name.is_empty() {
+                continue;
+            }
+            let ty = types
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| "any".to_string());
+            out.push(format!("svelte:props_type:{name}:{ty}"));
+        }
+    }
+
+    out
+}
+
+/// Extract the type annotation from an `export let name: Type = default`
+/// declaration's tail (everything after `export let `), if present.
+fn prop_type_annotation(rest: &str) -> Option<String> {
+    let decl = rest.trim_end_matches(';').trim();
+    let name_and_type = decl.split_once('=').map_or(decl, |(name, _)| name.trim());
+    name_and_type
+        .split_once(':')
+        .map(|(_, ty)| ty.trim().to_string())
+}
+
+/// Parse a `{ name: Type; other?: Type }`-shaped TS object type annotation
+/// into a `name -> type` map, used to resolve types for `$props()`
+/// destructured names.
+fn parse_type_annotation_block(block: &str) -> HashMap<String, String> {
+    let inner = block.trim().trim_start_matches('{').trim_end_matches('}');
+    let mut map = HashMap::new();
+    for entry in inner.split([';', ',']) {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        if let Some((name, ty)) = entry.split_once(':') {
+            map.insert(
+                name.trim().trim_end_matches('?').to_string(),
+                ty.trim().to_string(),
+            );
+        }
+    }
+    map
+}
+
 pub(super) fn script_api_items<D: ast_grep_core::Doc>(
     script_node: &Node<D>,
     owner: &str,
@@ -311,6 +502,10 @@ pub(super) fn script_api_items<D: ast_grep_core::Doc>(
                 metadata.push_attribute(format!("svelte:script_api_kind:{kind}"));
                 metadata.set_owner_name(Some(owner.to_string()));
                 metadata.set_owner_kind(Some(SymbolKind::Module));
+                if kind == "prop" {
+                    metadata.push_attribute("svelte:prop");
+                    metadata.return_type = prop_type_annotation(rest);
+                }
                 out.push(build_item(
                     script_node,
                     SymbolKind::Property,
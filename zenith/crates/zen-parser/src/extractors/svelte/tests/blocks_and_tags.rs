@@ -46,6 +46,26 @@ fn extracts_special_tags() {
     );
 }
 
+#[test]
+fn extracts_export_let_props_with_svelte_prop_attribute() {
+    let items = fixture_items();
+
+    let count = find_by_name(&items, "script_api:count");
+    assert_eq!(count.kind, SymbolKind::Property);
+    assert!(has_attr(count, "svelte:prop"));
+    assert_eq!(count.metadata.return_type, None);
+
+    let label = find_by_name(&items, "script_api:label");
+    assert_eq!(label.kind, SymbolKind::Property);
+    assert!(has_attr(label, "svelte:prop"));
+    assert_eq!(label.metadata.return_type.as_deref(), Some("string"));
+
+    let disabled = find_by_name(&items, "script_api:disabled");
+    assert_eq!(disabled.kind, SymbolKind::Property);
+    assert!(has_attr(disabled, "svelte:prop"));
+    assert_eq!(disabled.metadata.return_type, None);
+}
+
 #[test]
 fn extracts_script_api_and_events_and_directives() {
     let items = fixture_items();
@@ -4,6 +4,7 @@ use super::*;
 use crate::types::{ParsedItem, SymbolKind};
 
 mod blocks_and_tags;
+mod runes_and_props;
 mod structure;
 
 fn parse_and_extract(source: &str) -> Vec<ParsedItem> {
@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn extracts_rune_declared_state() {
+    let items = fixture_items();
+
+    let clicks = find_by_name(&items, "rune:clicks");
+    assert_eq!(clicks.kind, SymbolKind::Property);
+    assert!(has_attr(clicks, "svelte:kind:rune_state"));
+    assert!(has_attr(clicks, "svelte:rune:$state"));
+
+    let doubled = find_by_name(&items, "rune:doubled");
+    assert!(has_attr(doubled, "svelte:rune:$derived"));
+
+    let tripled = find_by_name(&items, "rune:tripled");
+    assert!(has_attr(tripled, "svelte:rune:$derived"));
+}
+
+#[test]
+fn routes_typescript_script_through_typescript_extractor() {
+    let items = fixture_items();
+
+    let config = find_by_name(&items, "DemoConfig");
+    assert_eq!(config.kind, SymbolKind::Interface);
+    assert!(has_attr(config, "svelte:embedded_ts_type"));
+}
+
+#[test]
+fn captures_props_type_metadata_on_root_item() {
+    let items = fixture_items();
+
+    let root = find_by_name(&items, "$");
+    assert!(has_attr(root, "svelte:props_type:count:any"));
+    assert!(has_attr(root, "svelte:props_type:title:string"));
+}
+
+#[test]
+fn plain_js_script_is_not_routed_through_typescript_extractor() {
+    let source = r"
+<script>
+  interface NotReal { x: number; }
+</script>
+";
+    let items = parse_and_extract(source);
+    assert!(items.iter().all(|item| item.kind != SymbolKind::Interface));
+}
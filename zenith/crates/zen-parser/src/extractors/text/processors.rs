@@ -9,6 +9,7 @@ pub(super) fn root_item(total_lines: u32) -> ParsedItem {
     let mut metadata = SymbolMetadata::default();
     metadata.push_attribute("txt:kind:document");
     ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name: "$".to_string(),
         signature: "document".to_string(),
@@ -28,6 +29,7 @@ pub(super) fn heading_item(heading: &PlainTextHeading, end_line: u32) -> ParsedI
     metadata.push_attribute(format!("txt:level:{}", heading.level));
 
     ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Module,
         name: heading.title.clone(),
         signature: heading.title.clone(),
@@ -47,6 +49,7 @@ pub(super) fn paragraph_item(start_line: u32, end_line: u32, first_line_text: &s
 
     let name = truncate_name(first_line_text, 60);
     ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Property,
         name,
         signature: String::new(),
@@ -185,18 +185,17 @@ pub(super) fn dependency_from_path(full_path: &str) -> Option<(String, String)>
         ));
     }
 
-    if let Some((idx, _)) = parts
-        .iter()
-        .enumerate()
-        .find(|(_, p)| **p == "dependencies")
+    if parts[0] == "target"
+        && let Some((idx, scope)) = parts.iter().enumerate().find_map(|(i, p)| match *p {
+            "dependencies" => Some((i, "cargo:target-dependencies")),
+            "dev-dependencies" => Some((i, "cargo:target-dev-dependencies")),
+            "build-dependencies" => Some((i, "cargo:target-build-dependencies")),
+            _ => None,
+        })
         && idx > 0
-        && parts[0] == "target"
         && parts.len() > idx + 1
     {
-        return Some((
-            "cargo:target-dependencies".to_string(),
-            parts[idx + 1].to_string(),
-        ));
+        return Some((scope.to_string(), parts[idx + 1].to_string()));
     }
 
     if parts.len() >= 4 && parts[0] == "tool" && parts[1] == "poetry" && parts[2] == "dependencies"
@@ -222,6 +221,25 @@ pub(super) fn dependency_from_path(full_path: &str) -> Option<(String, String)>
     None
 }
 
+/// Extract the target cfg expression from a target-specific dependency path,
+/// e.g. `"cfg(windows)"` from `target.cfg(windows).dependencies.winapi`.
+pub(super) fn target_cfg_from_path(full_path: &str) -> Option<String> {
+    let parts: Vec<&str> = full_path.split('.').collect();
+    if parts.first().copied() != Some("target") {
+        return None;
+    }
+    let idx = parts.iter().position(|p| {
+        matches!(
+            *p,
+            "dependencies" | "dev-dependencies" | "build-dependencies"
+        )
+    })?;
+    if idx <= 1 {
+        return None;
+    }
+    Some(parts[1..idx].join("."))
+}
+
 pub(super) fn pep508_req_from_string(raw: &str) -> Option<(String, String)> {
     let trimmed = raw.trim().trim_matches('"').trim_matches('\'');
     if trimmed.is_empty() {
@@ -242,6 +242,7 @@ fn collect_pair<D: ast_grep_core::Doc>(
     }
     enrich_dependency_metadata(&full_path, &value_node, &mut metadata);
     enrich_shape_metadata(&value_node, &mut metadata);
+    enrich_feature_metadata(&full_path, &value_node, &mut metadata);
 
     let mut item = build_item(
         pair,
@@ -384,6 +385,11 @@ fn enrich_dependency_metadata<D: ast_grep_core::Doc>(
     metadata.push_attribute("toml:dependency");
     metadata.push_attribute(format!("toml:dep_scope:{scope}"));
     metadata.push_attribute(format!("toml:dep_name:{dep_name}"));
+    if let Some(cfg) = toml_helpers::target_cfg_from_path(full_path) {
+        metadata.push_attribute(format!("toml:target_cfg:{cfg}"));
+    }
+
+    let mut workspace_inherited = false;
 
     match value.kind().as_ref() {
         "string" => {
@@ -415,6 +421,7 @@ fn enrich_dependency_metadata<D: ast_grep_core::Doc>(
                 }
                 if pair_text.trim_start().starts_with("workspace") {
                     metadata.push_attribute("toml:dep_source:workspace");
+                    workspace_inherited = pair_text.contains("= true");
                 }
                 if pair_text.trim_start().starts_with("registry") {
                     metadata.push_attribute("toml:dep_source:registry");
@@ -437,6 +444,39 @@ fn enrich_dependency_metadata<D: ast_grep_core::Doc>(
         }
         _ => {}
     }
+
+    metadata.push_attribute(if workspace_inherited {
+        "toml:dep_kind:workspace_inherited".to_string()
+    } else {
+        "toml:dep_kind:direct".to_string()
+    });
+}
+
+/// For a `[features]` entry (`features.<name> = [...]`), record the enabled
+/// dependency/feature references in `metadata.fields`.
+fn enrich_feature_metadata<D: ast_grep_core::Doc>(
+    full_path: &str,
+    value: &Node<D>,
+    metadata: &mut SymbolMetadata,
+) {
+    let Some(feature_name) = full_path.strip_prefix("features.") else {
+        return;
+    };
+    if feature_name.is_empty() || feature_name.contains('.') {
+        return;
+    }
+    if value.kind().as_ref() != "array" {
+        return;
+    }
+
+    metadata.push_attribute("toml:feature");
+    for child in value.children() {
+        if child.kind().as_ref() == "string"
+            && let Some(dep) = toml_helpers::normalized_scalar("string", &child.text())
+        {
+            metadata.fields.push(dep);
+        }
+    }
 }
 
 fn enrich_array_dependency_metadata<D: ast_grep_core::Doc>(
@@ -633,6 +673,7 @@ fn build_item<D: ast_grep_core::Doc>(
     };
 
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature,
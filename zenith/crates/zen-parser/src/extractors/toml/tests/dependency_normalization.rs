@@ -58,6 +58,74 @@ fn poetry_and_pep621_dependencies_are_detected() {
     assert!(has_attr(dev0, "toml:dep_name:pytest"));
 }
 
+#[test]
+fn workspace_dependencies_table_is_detected() {
+    let items = dependency_fixture_items();
+
+    let anyhow = find_by_name(&items, "workspace.dependencies.anyhow");
+    assert!(has_attr(
+        anyhow,
+        "toml:dep_scope:cargo:workspace-dependencies"
+    ));
+    assert!(has_attr(anyhow, "toml:dep_name:anyhow"));
+    assert!(has_attr(anyhow, "toml:dep_kind:direct"));
+
+    let zen_schema = find_by_name(&items, "workspace.dependencies.zen-schema");
+    assert!(has_attr(
+        zen_schema,
+        "toml:dep_scope:cargo:workspace-dependencies"
+    ));
+    assert!(has_attr(zen_schema, "toml:dep_source:path"));
+    assert!(has_attr(zen_schema, "toml:dep_kind:direct"));
+}
+
+#[test]
+fn workspace_true_reference_is_marked_inherited() {
+    let items = dependency_fixture_items();
+
+    let zen_core = find_by_name(&items, "dependencies.zen-core");
+    assert!(has_attr(zen_core, "toml:dep_scope:cargo:dependencies"));
+    assert!(has_attr(zen_core, "toml:dep_source:workspace"));
+    assert!(has_attr(zen_core, "toml:dep_kind:workspace_inherited"));
+
+    let serde = find_by_name(&items, "dependencies.serde");
+    assert!(has_attr(serde, "toml:dep_kind:direct"));
+}
+
+#[test]
+fn target_specific_dependency_tables_are_detected() {
+    let items = dependency_fixture_items();
+
+    let winapi = find_by_name(&items, "target.cfg(windows).dependencies.winapi");
+    assert!(has_attr(winapi, "toml:dep_scope:cargo:target-dependencies"));
+    assert!(has_attr(winapi, "toml:dep_name:winapi"));
+    assert!(has_attr(winapi, "toml:target_cfg:cfg(windows)"));
+    assert!(has_attr(winapi, "toml:dep_kind:direct"));
+
+    let nix = find_by_name(&items, "target.cfg(unix).dev-dependencies.nix");
+    assert!(has_attr(
+        nix,
+        "toml:dep_scope:cargo:target-dev-dependencies"
+    ));
+    assert!(has_attr(nix, "toml:target_cfg:cfg(unix)"));
+}
+
+#[test]
+fn feature_entries_list_enabled_deps_in_fields() {
+    let items = dependency_fixture_items();
+
+    let default_feature = find_by_name(&items, "features.default");
+    assert!(has_attr(default_feature, "toml:feature"));
+    assert_eq!(default_feature.metadata.fields, vec!["std".to_string()]);
+
+    let std_feature = find_by_name(&items, "features.std");
+    assert!(has_attr(std_feature, "toml:feature"));
+    assert_eq!(
+        std_feature.metadata.fields,
+        vec!["dep:serde".to_string(), "tokio/rt".to_string()]
+    );
+}
+
 #[test]
 fn scalar_values_are_normalized_for_package_fields() {
     let items = dependency_fixture_items();
@@ -66,6 +66,46 @@ pub(super) fn extract_props_type_from_params<D: ast_grep_core::Doc>(
     None
 }
 
+/// Extract destructured prop names from function parameters.
+///
+/// Matches `({ title, onClick }: PropsType)` in `formal_parameters` and
+/// returns the individual prop identifiers (`title`, `onClick`).
+pub(super) fn extract_prop_names_from_params<D: ast_grep_core::Doc>(func: &Node<D>) -> Vec<String> {
+    let Some(params) = func.field("parameters") else {
+        return Vec::new();
+    };
+    for child in params.children() {
+        if child.kind().as_ref() == "required_parameter" {
+            let object_pattern = child
+                .children()
+                .find(|c| c.kind().as_ref() == "object_pattern");
+            if let Some(pattern) = object_pattern {
+                return collect_object_pattern_names(&pattern);
+            }
+        }
+    }
+    Vec::new()
+}
+
+fn collect_object_pattern_names<D: ast_grep_core::Doc>(pattern: &Node<D>) -> Vec<String> {
+    pattern
+        .children()
+        .filter_map(|child| match child.kind().as_ref() {
+            "shorthand_property_identifier_pattern" => Some(child.text().to_string()),
+            "pair_pattern" => child.field("key").map(|k| k.text().to_string()),
+            "object_assignment_pattern" => child
+                .field("left")
+                .filter(|left| left.kind().as_ref() == "shorthand_property_identifier_pattern")
+                .map(|left| left.text().to_string()),
+            "rest_pattern" => child
+                .children()
+                .find(|c| c.kind().as_ref() == "identifier")
+                .map(|n| n.text().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Extract props type from a type annotation like `React.FC<UserCardProps>`.
 pub(super) fn extract_props_from_type_annotation(annotation: Option<&str>) -> Option<String> {
     let ann = annotation?;
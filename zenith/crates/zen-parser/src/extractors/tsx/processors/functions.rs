@@ -3,9 +3,10 @@ use ast_grep_core::Node;
 use crate::types::{ParsedItem, SymbolKind, TsxMetadataExt};
 
 use super::super::tsx_helpers::{
-    collect_hooks_recursive, collect_jsx_tags_recursive, extract_props_from_arrow_params,
-    extract_props_from_type_annotation, extract_props_type_from_params, has_jsx_recursive,
-    is_component_name, is_component_return_type, is_hoc_name, is_hook_name,
+    collect_hooks_recursive, collect_jsx_tags_recursive, extract_prop_names_from_params,
+    extract_props_from_arrow_params, extract_props_from_type_annotation,
+    extract_props_type_from_params, has_jsx_recursive, is_component_name, is_component_return_type,
+    is_hoc_name, is_hook_name,
 };
 use super::FnBody;
 
@@ -55,6 +56,9 @@ pub fn enrich_fn_item(item: &mut ParsedItem, bodies: &[FnBody]) {
             item.metadata.set_jsx_elements(b.jsx_elements.clone());
         }
         item.metadata.set_props_type_if_none(b.props_type.clone());
+        if !b.prop_names.is_empty() {
+            item.metadata.set_prop_names(b.prop_names.clone());
+        }
     }
 }
 
@@ -130,6 +134,7 @@ fn analyze_function<D: ast_grep_core::Doc>(node: &Node<D>, anchor: &Node<D>) ->
     jsx_elems.dedup();
 
     let props_type = extract_props_type_from_params(node);
+    let prop_names = extract_prop_names_from_params(node);
 
     Some(FnBody {
         start_line: anchor.start_pos().line() as u32 + 1,
@@ -141,6 +146,7 @@ fn analyze_function<D: ast_grep_core::Doc>(node: &Node<D>, anchor: &Node<D>) ->
         is_memo: false,
         is_lazy: false,
         props_type,
+        prop_names,
         type_annotation: None,
     })
 }
@@ -171,6 +177,7 @@ fn analyze_variable_declarator<D: ast_grep_core::Doc>(
         let (has_jsx, hooks, jsx_elems) = analyze_node_content(&value);
         let props_type = extract_props_from_type_annotation(type_annotation.as_deref())
             .or_else(|| extract_props_from_arrow_params(&value));
+        let prop_names = extract_prop_names_from_params(&value);
 
         Some(FnBody {
             start_line: anchor.start_pos().line() as u32 + 1,
@@ -182,6 +189,7 @@ fn analyze_variable_declarator<D: ast_grep_core::Doc>(
             is_memo: false,
             is_lazy: false,
             props_type,
+            prop_names,
             type_annotation,
         })
     } else if vk == "call_expression" {
@@ -211,6 +219,7 @@ fn analyze_variable_declarator<D: ast_grep_core::Doc>(
             is_memo,
             is_lazy,
             props_type,
+            prop_names: Vec::new(),
             type_annotation,
         })
     } else {
@@ -20,6 +20,7 @@ pub(super) struct FnBody {
     pub is_memo: bool,
     pub is_lazy: bool,
     pub props_type: Option<String>,
+    pub prop_names: Vec<String>,
     pub type_annotation: Option<String>,
 }
 
@@ -28,6 +28,17 @@ fn button_has_props_type() {
     assert_eq!(btn.metadata.props_type.as_deref(), Some("ButtonProps"));
 }
 
+#[test]
+fn button_has_prop_names() {
+    let source = include_str!("../../../../tests/fixtures/sample.tsx");
+    let items = parse_and_extract(source);
+    let btn = find_by_name(&items, "Button");
+    assert_eq!(
+        btn.metadata.prop_names,
+        vec!["label", "onClick", "disabled", "variant", "children"]
+    );
+}
+
 #[test]
 fn button_has_jsdoc() {
     let source = include_str!("../../../../tests/fixtures/sample.tsx");
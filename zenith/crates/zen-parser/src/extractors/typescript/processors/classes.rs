@@ -4,7 +4,7 @@ use std::collections::HashSet;
 use crate::extractors::helpers;
 use crate::types::{ParsedItem, SymbolKind, SymbolMetadata, TypeScriptMetadataExt, Visibility};
 
-use super::super::ts_helpers::{extract_jsdoc_before, parse_jsdoc_sections};
+use super::super::ts_helpers::{extract_jsdoc_before, has_deprecated_tag, parse_jsdoc_sections};
 
 // ── class_declaration / abstract_class_declaration ─────────────────
 
@@ -57,9 +57,10 @@ pub fn process_class<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Class,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "typescript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -157,9 +158,10 @@ fn build_ts_class_callable_member<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind,
         name,
-        signature: helpers::extract_signature(child),
+        signature: helpers::extract_signature(child, "typescript"),
         source: helpers::extract_source(child, 30),
         doc_comment: String::new(),
         start_line: child.start_pos().line() as u32 + 1,
@@ -196,9 +198,10 @@ fn build_ts_class_field_member<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind,
         name,
-        signature: helpers::extract_signature(child),
+        signature: helpers::extract_signature(child, "typescript"),
         source: helpers::extract_source(child, 20),
         doc_comment: String::new(),
         start_line: child.start_pos().line() as u32 + 1,
@@ -226,9 +229,10 @@ fn build_ts_class_indexer_member<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Indexer,
         name: format!("{owner_name}[]"),
-        signature: helpers::extract_signature(child),
+        signature: helpers::extract_signature(child, "typescript"),
         source: helpers::extract_source(child, 10),
         doc_comment: String::new(),
         start_line: child.start_pos().line() as u32 + 1,
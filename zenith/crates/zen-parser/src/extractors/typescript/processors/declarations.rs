@@ -4,7 +4,8 @@ use crate::extractors::helpers;
 use crate::types::{ParsedItem, SymbolKind, SymbolMetadata, TypeScriptMetadataExt, Visibility};
 
 use super::super::ts_helpers::{
-    extract_jsdoc_before, extract_ts_parameters, extract_ts_return_type, parse_jsdoc_sections,
+    extract_jsdoc_before, extract_ts_parameters, extract_ts_return_type, has_deprecated_tag,
+    parse_jsdoc_sections,
 };
 use super::classes::process_class;
 use super::functions::process_function_signature;
@@ -33,6 +34,7 @@ pub fn process_ambient_declaration<D: ast_grep_core::Doc>(node: &Node<D>) -> Vec
                 if let Some(name_node) = child.field("name") {
                     let name = name_node.text().to_string();
                     items.push(ParsedItem {
+                        is_deprecated: false,
                         kind: SymbolKind::Module,
                         name: name.trim_matches('"').to_string(),
                         signature: format!("declare module {name}"),
@@ -119,9 +121,10 @@ fn process_variable_declarator<D: ast_grep_core::Doc>(
             .map(|tp| tp.text().to_string());
 
         Some(ParsedItem {
+            is_deprecated: has_deprecated_tag(&jsdoc),
             kind: SymbolKind::Function,
             name,
-            signature: helpers::extract_signature(declaration),
+            signature: helpers::extract_signature(declaration, "typescript"),
             source: helpers::extract_source(declaration, 50),
             doc_comment: jsdoc,
             start_line: declaration.start_pos().line() as u32 + 1,
@@ -156,9 +159,10 @@ fn process_variable_declarator<D: ast_grep_core::Doc>(
             });
 
         Some(ParsedItem {
+            is_deprecated: has_deprecated_tag(&jsdoc),
             kind: value_kind,
             name,
-            signature: helpers::extract_signature(declaration),
+            signature: helpers::extract_signature(declaration, "typescript"),
             source: helpers::extract_source(declaration, 50),
             doc_comment: jsdoc,
             start_line: declaration.start_pos().line() as u32 + 1,
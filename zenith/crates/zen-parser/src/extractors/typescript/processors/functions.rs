@@ -4,7 +4,8 @@ use crate::extractors::helpers;
 use crate::types::{ParsedItem, SymbolKind, SymbolMetadata, TypeScriptMetadataExt, Visibility};
 
 use super::super::ts_helpers::{
-    extract_jsdoc_before, extract_ts_parameters, extract_ts_return_type, parse_jsdoc_sections,
+    extract_jsdoc_before, extract_ts_parameters, extract_ts_return_type, has_deprecated_tag,
+    parse_jsdoc_sections,
 };
 
 // ── function_declaration ───────────────────────────────────────────
@@ -46,9 +47,10 @@ pub fn process_function<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Function,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "typescript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -73,9 +75,10 @@ pub fn process_function_signature<D: ast_grep_core::Doc>(
         .map(|tp| tp.text().to_string());
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Function,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "typescript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
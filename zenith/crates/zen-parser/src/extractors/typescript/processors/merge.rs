@@ -0,0 +1,125 @@
+//! Post-extraction merging: interface declaration merging and overload
+//! signature collapsing.
+//!
+//! `.d.ts` files commonly declare the same interface name more than once
+//! (declaration merging) and split a single function into several
+//! signature-only overloads followed by one implementing declaration.
+//! Both forms extract as separate same-name items by default, which
+//! duplicates symbols downstream. This pass folds them back together
+//! after the per-node extraction has run.
+
+use std::collections::HashSet;
+
+use crate::types::{ParsedItem, SymbolKind};
+
+/// Merge same-name top-level interfaces and collapse function overload
+/// groups into a single implementing item.
+pub fn merge_declarations(items: Vec<ParsedItem>) -> Vec<ParsedItem> {
+    let items = merge_interfaces(items);
+    collapse_overloads(items)
+}
+
+fn merge_interfaces(items: Vec<ParsedItem>) -> Vec<ParsedItem> {
+    let mut merged: Vec<ParsedItem> = Vec::with_capacity(items.len());
+    let mut seen_members: HashSet<String> = HashSet::new();
+
+    for item in items {
+        // Member items (e.g. "Owner::member") are deduped globally so a
+        // repeated interface declaration doesn't repeat its members.
+        if item.name.contains("::") || item.name.ends_with("[]") {
+            let dedupe_key = format!("{}:{}", item.kind, item.name);
+            if !seen_members.insert(dedupe_key) {
+                continue;
+            }
+            merged.push(item);
+            continue;
+        }
+
+        if item.kind != SymbolKind::Interface {
+            merged.push(item);
+            continue;
+        }
+
+        if let Some(existing) = merged
+            .iter_mut()
+            .find(|m| m.kind == SymbolKind::Interface && m.name == item.name)
+        {
+            let merge_count = existing
+                .metadata
+                .attributes
+                .iter()
+                .find_map(|a| a.strip_prefix("merged:"))
+                .and_then(|n| n.parse::<u32>().ok())
+                .unwrap_or(1)
+                + 1;
+            existing
+                .metadata
+                .attributes
+                .retain(|a| !a.starts_with("merged:"));
+            existing.metadata.attributes.push(format!("merged:{merge_count}"));
+
+            for method in item.metadata.methods {
+                if !existing.metadata.methods.contains(&method) {
+                    existing.metadata.methods.push(method);
+                }
+            }
+            existing.end_line = existing.end_line.max(item.end_line);
+            existing.start_line = existing.start_line.min(item.start_line);
+        } else {
+            merged.push(item);
+        }
+    }
+
+    merged
+}
+
+fn collapse_overloads(items: Vec<ParsedItem>) -> Vec<ParsedItem> {
+    let mut result: Vec<ParsedItem> = Vec::with_capacity(items.len());
+
+    for item in items {
+        if item.kind != SymbolKind::Function || item.name.contains("::") {
+            result.push(item);
+            continue;
+        }
+
+        if let Some(existing) = result
+            .iter_mut()
+            .find(|m| m.kind == SymbolKind::Function && m.name == item.name)
+        {
+            // The implementing declaration spans the most lines; overload
+            // signatures are single-line and should fold into it.
+            let existing_span = existing.end_line - existing.start_line;
+            let item_span = item.end_line - item.start_line;
+
+            if existing_span >= item_span {
+                existing
+                    .metadata
+                    .attributes
+                    .push(format!("overload:{}", item.signature.trim()));
+                existing.start_line = existing.start_line.min(item.start_line);
+                if existing.doc_comment.is_empty() {
+                    existing.doc_comment = item.doc_comment;
+                }
+            } else {
+                let mut implementation = item;
+                implementation
+                    .metadata
+                    .attributes
+                    .append(&mut existing.metadata.attributes);
+                implementation
+                    .metadata
+                    .attributes
+                    .push(format!("overload:{}", existing.signature.trim()));
+                implementation.start_line = implementation.start_line.min(existing.start_line);
+                if implementation.doc_comment.is_empty() {
+                    implementation.doc_comment.clone_from(&existing.doc_comment);
+                }
+                *existing = implementation;
+            }
+        } else {
+            result.push(item);
+        }
+    }
+
+    result
+}
@@ -4,17 +4,19 @@
 mod classes;
 mod declarations;
 mod functions;
+mod merge;
 mod types;
 
 use ast_grep_core::Node;
 
-use crate::types::ParsedItem;
+use crate::types::{ParsedItem, SymbolKind, SymbolMetadata, Visibility};
 
 pub(super) use classes::{process_class, process_class_members};
 pub(super) use declarations::{
     process_ambient_declaration, process_lexical_declaration, process_variable_declaration,
 };
 pub(super) use functions::{process_function, process_function_signature};
+pub(super) use merge::merge_declarations;
 pub(super) use types::{
     process_enum, process_interface, process_interface_members, process_namespace,
     process_type_alias,
@@ -25,6 +27,16 @@ pub(super) use types::{
 pub(super) fn process_export_statement<D: ast_grep_core::Doc>(
     export_node: &Node<D>,
 ) -> Vec<ParsedItem> {
+    // `export = expr;` (CommonJS-style export assignment, common in older
+    // `.d.ts` packages) and `export as namespace Name;` (UMD global name)
+    // have no `declaration` field — detect them from their literal tokens.
+    if let Some(item) = process_export_assignment(export_node) {
+        return vec![item];
+    }
+    if let Some(item) = process_namespace_export(export_node) {
+        return vec![item];
+    }
+
     let is_default = export_node
         .children()
         .any(|c| c.kind().as_ref() == "default");
@@ -38,6 +50,11 @@ pub(super) fn process_export_statement<D: ast_grep_core::Doc>(
                     items.push(item);
                 }
             }
+            "function_signature" => {
+                if let Some(item) = process_function_signature(&child, export_node) {
+                    items.push(item);
+                }
+            }
             "class_declaration" | "abstract_class_declaration" => {
                 if let Some(item) = process_class(&child, export_node, true, is_default) {
                     items.push(item);
@@ -73,3 +90,56 @@ pub(super) fn process_export_statement<D: ast_grep_core::Doc>(
     }
     items
 }
+
+// ── export = expr / export as namespace Name (`.d.ts` UMD forms) ──
+
+fn process_export_assignment<D: ast_grep_core::Doc>(export_node: &Node<D>) -> Option<ParsedItem> {
+    if !export_node.children().any(|c| c.kind().as_ref() == "=") {
+        return None;
+    }
+    let target = export_node
+        .children()
+        .find(|c| c.kind().as_ref() != "export" && c.kind().as_ref() != "=")?;
+
+    Some(ParsedItem {
+        is_deprecated: false,
+        kind: SymbolKind::Const,
+        name: target.text().to_string(),
+        signature: export_node.text().to_string(),
+        source: None,
+        doc_comment: String::new(),
+        start_line: export_node.start_pos().line() as u32 + 1,
+        end_line: export_node.end_pos().line() as u32 + 1,
+        visibility: Visibility::Export,
+        metadata: SymbolMetadata {
+            attributes: vec!["cjs:export-equals".to_string()],
+            ..Default::default()
+        },
+    })
+}
+
+fn process_namespace_export<D: ast_grep_core::Doc>(export_node: &Node<D>) -> Option<ParsedItem> {
+    if !export_node.children().any(|c| c.kind().as_ref() == "namespace") {
+        return None;
+    }
+    let name = export_node
+        .children()
+        .find(|c| c.kind().as_ref() == "identifier")
+        .map(|n| n.text().to_string())?;
+
+    Some(ParsedItem {
+        is_deprecated: false,
+        kind: SymbolKind::Module,
+        name,
+        signature: export_node.text().to_string(),
+        source: None,
+        doc_comment: String::new(),
+        start_line: export_node.start_pos().line() as u32 + 1,
+        end_line: export_node.end_pos().line() as u32 + 1,
+        visibility: Visibility::Export,
+        metadata: SymbolMetadata {
+            attributes: vec!["cjs:export-as-namespace".to_string()],
+            ..Default::default()
+        },
+    })
+}
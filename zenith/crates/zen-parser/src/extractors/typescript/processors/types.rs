@@ -5,7 +5,8 @@ use crate::extractors::helpers;
 use crate::types::{ParsedItem, SymbolKind, SymbolMetadata, TypeScriptMetadataExt, Visibility};
 
 use super::super::ts_helpers::{
-    extract_jsdoc_before, extract_ts_parameters, extract_ts_return_type, parse_jsdoc_sections,
+    extract_jsdoc_before, extract_ts_parameters, extract_ts_return_type, has_deprecated_tag,
+    parse_jsdoc_sections,
 };
 
 // ── interface_declaration ──────────────────────────────────────────
@@ -41,9 +42,10 @@ pub fn process_interface<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Interface,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "typescript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -129,9 +131,10 @@ fn build_interface_method_member<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Method,
         name: format!("{owner_name}::{member_name}"),
-        signature: helpers::extract_signature(member),
+        signature: helpers::extract_signature(member, "typescript"),
         source: helpers::extract_source(member, 10),
         doc_comment: String::new(),
         start_line: member.start_pos().line() as u32 + 1,
@@ -175,9 +178,10 @@ fn build_interface_property_member<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind,
         name: format!("{owner_name}::{member_name}"),
-        signature: helpers::extract_signature(member),
+        signature: helpers::extract_signature(member, "typescript"),
         source: helpers::extract_source(member, 10),
         doc_comment: String::new(),
         start_line: member.start_pos().line() as u32 + 1,
@@ -205,9 +209,10 @@ fn build_interface_indexer_member<D: ast_grep_core::Doc>(
     };
 
     Some(ParsedItem {
+        is_deprecated: false,
         kind: SymbolKind::Indexer,
         name: format!("{owner_name}[]"),
-        signature: helpers::extract_signature(member),
+        signature: helpers::extract_signature(member, "typescript"),
         source: helpers::extract_source(member, 10),
         doc_comment: String::new(),
         start_line: member.start_pos().line() as u32 + 1,
@@ -287,9 +292,10 @@ pub fn process_type_alias<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::TypeAlias,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "typescript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -325,9 +331,10 @@ pub fn process_enum<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Enum,
         name,
-        signature: helpers::extract_signature(node),
+        signature: helpers::extract_signature(node, "typescript"),
         source: helpers::extract_source(node, 50),
         doc_comment: jsdoc,
         start_line: node.start_pos().line() as u32 + 1,
@@ -382,6 +389,7 @@ pub fn process_namespace<D: ast_grep_core::Doc>(
     metadata.set_doc_sections(doc_sections);
 
     Some(ParsedItem {
+        is_deprecated: has_deprecated_tag(&jsdoc),
         kind: SymbolKind::Module,
         name,
         signature: format!(
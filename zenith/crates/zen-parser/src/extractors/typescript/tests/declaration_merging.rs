@@ -0,0 +1,81 @@
+use super::*;
+
+#[test]
+fn merged_interfaces_union_members_and_record_merge_count() {
+    let source = include_str!("../../../../tests/fixtures/express_like.d.ts");
+    let items = parse_and_extract(source);
+
+    let requests: Vec<&ParsedItem> = items
+        .iter()
+        .filter(|i| i.kind == SymbolKind::Interface && i.name == "Request")
+        .collect();
+    assert_eq!(requests.len(), 1, "merged interfaces should collapse to one item");
+
+    let request = requests[0];
+    assert!(request.metadata.methods.contains(&"get".to_string()));
+    assert!(
+        request
+            .metadata
+            .attributes
+            .iter()
+            .any(|a| a == "merged:2"),
+        "attributes: {:?}",
+        request.metadata.attributes
+    );
+}
+
+#[test]
+fn merged_interface_members_are_not_duplicated() {
+    let source = include_str!("../../../../tests/fixtures/express_like.d.ts");
+    let items = parse_and_extract(source);
+
+    let gets = items.iter().filter(|i| i.name == "Request::get").count();
+    assert_eq!(gets, 1);
+}
+
+#[test]
+fn overload_group_collapses_into_implementing_function() {
+    let source = include_str!("../../../../tests/fixtures/express_like.d.ts");
+    let items = parse_and_extract(source);
+
+    let routers: Vec<&ParsedItem> = items.iter().filter(|i| i.name == "Router").collect();
+    assert_eq!(routers.len(), 1);
+    let overload_attrs = routers[0]
+        .metadata
+        .attributes
+        .iter()
+        .filter(|a| a.starts_with("overload:"))
+        .count();
+    assert_eq!(overload_attrs, 2, "both signature-only overloads should be recorded");
+}
+
+#[test]
+fn export_equals_extracted() {
+    let source = include_str!("../../../../tests/fixtures/express_like.d.ts");
+    let items = parse_and_extract(source);
+
+    let export_eq = items
+        .iter()
+        .find(|i| i.kind == SymbolKind::Const && i.name == "createApplication")
+        .expect("export assignment target should be extracted");
+    assert!(
+        export_eq
+            .metadata
+            .attributes
+            .contains(&"cjs:export-equals".to_string())
+    );
+}
+
+#[test]
+fn export_as_namespace_extracted() {
+    let source = "export as namespace MyLib;\n";
+    let items = parse_and_extract(source);
+
+    let ns = find_by_name(&items, "MyLib");
+    assert_eq!(ns.kind, SymbolKind::Module);
+    assert!(
+        ns.metadata
+            .attributes
+            .contains(&"cjs:export-as-namespace".to_string())
+    );
+}
@@ -51,3 +51,22 @@ fn jsdoc_throws_parsed() {
         f.metadata.doc_sections.raises
     );
 }
+
+#[test]
+fn jsdoc_deprecated_tag_detected() {
+    let source = include_str!("../../../../tests/fixtures/sample.ts");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "legacyLookup");
+    assert!(f.is_deprecated, "legacyLookup should be marked deprecated");
+}
+
+#[test]
+fn jsdoc_without_deprecated_tag_not_flagged() {
+    let source = include_str!("../../../../tests/fixtures/sample.ts");
+    let items = parse_and_extract(source);
+    let f = find_by_name(&items, "processItems");
+    assert!(
+        !f.is_deprecated,
+        "processItems should not be marked deprecated"
+    );
+}
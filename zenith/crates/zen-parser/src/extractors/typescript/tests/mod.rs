@@ -7,6 +7,7 @@ mod ambient_declarations;
 mod arrow_functions;
 mod classes;
 mod constants_vars;
+mod declaration_merging;
 mod enums;
 mod functions;
 mod interfaces;
@@ -1,15 +1,24 @@
 use super::*;
 
 #[test]
-fn function_overload_signatures_extracted() {
+fn function_overload_signatures_collapse_into_implementation() {
     let source = include_str!("../../../../tests/fixtures/sample.ts");
     let items = parse_and_extract(source);
     let greets: Vec<&ParsedItem> = items.iter().filter(|i| i.name == "greet").collect();
-    assert!(
-        greets.len() >= 3,
-        "should find at least 3 greet items (2 overloads + 1 impl), found {}",
+    assert_eq!(
+        greets.len(),
+        1,
+        "overload signatures should collapse into the implementing function, found {}",
         greets.len()
     );
+
+    let overload_attrs = greets[0]
+        .metadata
+        .attributes
+        .iter()
+        .filter(|a| a.starts_with("overload:"))
+        .count();
+    assert_eq!(overload_attrs, 2, "both overload signatures should be recorded");
 }
 
 #[test]
@@ -108,3 +108,15 @@ fn is_simple_segment(segment: &str) -> bool {
     }
     chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
 }
+
+/// Truncate `text` to at most `max_len` characters, appending a marker if it
+/// was cut. Guards against a single huge value (a giant block scalar, a long
+/// inlined flow sequence, ...) blowing up an item's stored `source` snippet
+/// even though it's only one line.
+pub(super) fn truncate_value(text: &str, max_len: usize) -> String {
+    if text.chars().count() <= max_len {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(max_len).collect();
+    format!("{truncated}... (truncated)")
+}
@@ -1,7 +1,9 @@
 use ast_grep_core::Node;
 use std::collections::{BTreeSet, HashMap, HashSet};
 
-use crate::types::{CommonMetadataExt, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
+use crate::types::{
+    CommonMetadataExt, ExtractOptions, ParsedItem, SymbolKind, SymbolMetadata, Visibility,
+};
 
 use super::yaml_helpers;
 
@@ -9,9 +11,22 @@ struct YamlContext {
     nonstandard_comments: bool,
     doc_count: usize,
     anchors: HashMap<String, String>,
+    options: ExtractOptions,
+    item_count: usize,
+    hit_max_depth: bool,
+    hit_max_items: bool,
 }
 
-pub(super) fn extract_stream<D: ast_grep_core::Doc>(root: &Node<D>) -> Vec<ParsedItem> {
+impl YamlContext {
+    const fn truncated(&self) -> bool {
+        self.hit_max_depth || self.hit_max_items
+    }
+}
+
+pub(super) fn extract_stream<D: ast_grep_core::Doc>(
+    root: &Node<D>,
+    options: ExtractOptions,
+) -> Vec<ParsedItem> {
     let mut items = Vec::new();
     let doc_count = root
         .children()
@@ -23,6 +38,10 @@ pub(super) fn extract_stream<D: ast_grep_core::Doc>(root: &Node<D>) -> Vec<Parse
         nonstandard_comments,
         doc_count,
         anchors: HashMap::new(),
+        options,
+        item_count: 0,
+        hit_max_depth: false,
+        hit_max_items: false,
     };
 
     let mut root_metadata = SymbolMetadata::default();
@@ -38,23 +57,62 @@ pub(super) fn extract_stream<D: ast_grep_core::Doc>(root: &Node<D>) -> Vec<Parse
         "$".to_string(),
         root_metadata,
         "$",
+        &ctx.options,
     ));
 
     let mut doc_index = 0usize;
     for doc in root.children() {
+        if ctx.hit_max_items {
+            break;
+        }
         if doc.kind().as_ref() != "document" {
             continue;
         }
-        collect_document(&doc, doc_index, &mut ctx, &mut items);
+        collect_document(&doc, doc_index, 0, &mut ctx, &mut items);
         doc_index += 1;
     }
 
+    if ctx.truncated() {
+        items.push(build_truncation_summary(&ctx));
+    }
+
     items
 }
 
+fn build_truncation_summary(ctx: &YamlContext) -> ParsedItem {
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute("yaml:truncated");
+    if ctx.hit_max_items {
+        metadata.push_attribute(format!(
+            "yaml:truncated:max_items:{}",
+            ctx.options.max_items
+        ));
+    }
+    if ctx.hit_max_depth {
+        metadata.push_attribute(format!(
+            "yaml:truncated:max_depth:{}",
+            ctx.options.max_depth
+        ));
+    }
+
+    ParsedItem {
+        is_deprecated: false,
+        kind: SymbolKind::Module,
+        name: "$:truncated".to_string(),
+        signature: "$:truncated".to_string(),
+        source: None,
+        doc_comment: String::new(),
+        start_line: 1,
+        end_line: 1,
+        visibility: Visibility::Public,
+        metadata,
+    }
+}
+
 fn collect_document<D: ast_grep_core::Doc>(
     document: &Node<D>,
     index: usize,
+    depth: usize,
     ctx: &mut YamlContext,
     out: &mut Vec<ParsedItem>,
 ) {
@@ -65,6 +123,10 @@ fn collect_document<D: ast_grep_core::Doc>(
     };
 
     for child in document.children() {
+        if ctx.hit_max_items {
+            return;
+        }
+
         let kind = child.kind();
         let kr = kind.as_ref();
 
@@ -79,30 +141,52 @@ fn collect_document<D: ast_grep_core::Doc>(
             } else {
                 format!("{doc_prefix}.{kr}")
             };
-            out.push(build_item(&child, SymbolKind::Module, name, metadata, kr));
+            let item = build_item(&child, SymbolKind::Module, name, metadata, kr, &ctx.options);
+            push_item(out, ctx, item);
             continue;
         }
 
         if kr == "block_node" || kr == "flow_node" {
-            collect_value(&child, &doc_prefix, ctx, out);
+            collect_value(&child, &doc_prefix, depth, ctx, out);
         }
     }
 }
 
+/// Push `item` unless the item budget is already spent.
+fn push_item(out: &mut Vec<ParsedItem>, ctx: &mut YamlContext, item: ParsedItem) {
+    if ctx.item_count >= ctx.options.max_items {
+        ctx.hit_max_items = true;
+        return;
+    }
+    ctx.item_count += 1;
+    out.push(item);
+}
+
 fn collect_value<D: ast_grep_core::Doc>(
     node: &Node<D>,
     path: &str,
+    depth: usize,
     ctx: &mut YamlContext,
     out: &mut Vec<ParsedItem>,
 ) {
+    if ctx.hit_max_items {
+        return;
+    }
+    if depth > ctx.options.max_depth {
+        ctx.hit_max_depth = true;
+        return;
+    }
+
     let wrapped = unwrap_yaml_value(node);
     if wrapped.alias_name.is_some() {
         return;
     }
 
     match wrapped.value.kind().as_ref() {
-        "block_mapping" | "flow_mapping" => collect_mapping(&wrapped.value, path, ctx, out),
-        "block_sequence" | "flow_sequence" => collect_sequence(&wrapped.value, path, ctx, out),
+        "block_mapping" | "flow_mapping" => collect_mapping(&wrapped.value, path, depth, ctx, out),
+        "block_sequence" | "flow_sequence" => {
+            collect_sequence(&wrapped.value, path, depth, ctx, out);
+        }
         _ => {}
     }
 }
@@ -110,6 +194,7 @@ fn collect_value<D: ast_grep_core::Doc>(
 fn collect_mapping<D: ast_grep_core::Doc>(
     mapping: &Node<D>,
     path: &str,
+    depth: usize,
     ctx: &mut YamlContext,
     out: &mut Vec<ParsedItem>,
 ) {
@@ -117,6 +202,9 @@ fn collect_mapping<D: ast_grep_core::Doc>(
     let mut pair_count = 0usize;
 
     for child in mapping.children() {
+        if ctx.hit_max_items {
+            break;
+        }
         if !is_mapping_pair(&child) {
             continue;
         }
@@ -131,7 +219,7 @@ fn collect_mapping<D: ast_grep_core::Doc>(
             }
         });
 
-        collect_pair(&child, path, duplicate, ctx, out);
+        collect_pair(&child, path, depth, duplicate, ctx, out);
     }
 
     if !path.is_empty()
@@ -146,6 +234,7 @@ fn collect_mapping<D: ast_grep_core::Doc>(
 fn collect_pair<D: ast_grep_core::Doc>(
     pair: &Node<D>,
     parent_path: &str,
+    depth: usize,
     duplicate_key: Option<String>,
     ctx: &mut YamlContext,
     out: &mut Vec<ParsedItem>,
@@ -184,14 +273,14 @@ fn collect_pair<D: ast_grep_core::Doc>(
         metadata.push_attribute("yaml:merge_key");
     }
 
-    for anchor in &wrapped.anchors {
+    for (anchor, _) in &wrapped.anchors {
         metadata.push_attribute(format!("yaml:anchor:{anchor}"));
         ctx.anchors.insert(anchor.clone(), full_path.clone());
     }
     for tag in &wrapped.tags {
         metadata.push_attribute(format!("yaml:tag:{}", yaml_helpers::normalize_tag(tag)));
     }
-    if let Some(alias) = wrapped.alias_name {
+    if let Some(alias) = wrapped.alias_name.clone() {
         metadata.push_attribute(format!("yaml:alias:{alias}"));
         if key_name == "<<" {
             metadata.push_attribute(format!("yaml:merge_alias:{alias}"));
@@ -204,20 +293,30 @@ fn collect_pair<D: ast_grep_core::Doc>(
     enrich_shape(&wrapped.value, &mut metadata);
     enrich_block_scalar_style(&wrapped.value, &mut metadata);
 
-    out.push(build_item(
+    let item = build_item(
         pair,
         SymbolKind::Property,
         full_path.clone(),
         metadata,
         &key_name,
-    ));
+        &ctx.options,
+    );
+    push_item(out, ctx, item);
+
+    for (anchor, anchor_node) in &wrapped.anchors {
+        push_anchor_item(anchor, anchor_node, ctx, out);
+    }
+    if let (Some(alias), Some(alias_node)) = (&wrapped.alias_name, &wrapped.alias_node) {
+        push_alias_item(alias, alias_node, ctx, out);
+    }
 
-    collect_value(&value_node, &full_path, ctx, out);
+    collect_value(&value_node, &full_path, depth + 1, ctx, out);
 }
 
 fn collect_sequence<D: ast_grep_core::Doc>(
     sequence: &Node<D>,
     path: &str,
+    depth: usize,
     ctx: &mut YamlContext,
     out: &mut Vec<ParsedItem>,
 ) {
@@ -225,6 +324,9 @@ fn collect_sequence<D: ast_grep_core::Doc>(
     let mut kinds = BTreeSet::new();
 
     for child in sequence.children() {
+        if ctx.hit_max_items {
+            break;
+        }
         if child.kind().as_ref() != "block_sequence_item" && child.kind().as_ref() != "flow_node" {
             continue;
         }
@@ -263,31 +365,40 @@ fn collect_sequence<D: ast_grep_core::Doc>(
             if ctx.nonstandard_comments {
                 metadata.push_attribute("yaml:nonstandard:comments");
             }
-            if let Some(alias) = wrapped.alias_name {
+            if let Some(alias) = wrapped.alias_name.clone() {
                 metadata.push_attribute(format!("yaml:alias:{alias}"));
                 if let Some(target) = ctx.anchors.get(&alias) {
                     metadata.push_attribute(format!("yaml:alias_target:{target}"));
                 }
             }
-            for anchor in wrapped.anchors {
+            for (anchor, _) in &wrapped.anchors {
                 metadata.push_attribute(format!("yaml:anchor:{anchor}"));
-                ctx.anchors.insert(anchor, item_path.clone());
+                ctx.anchors.insert(anchor.clone(), item_path.clone());
             }
-            for tag in wrapped.tags {
-                metadata.push_attribute(format!("yaml:tag:{}", yaml_helpers::normalize_tag(&tag)));
+            for tag in &wrapped.tags {
+                metadata.push_attribute(format!("yaml:tag:{}", yaml_helpers::normalize_tag(tag)));
             }
             enrich_block_scalar_style(&wrapped.value, &mut metadata);
 
-            out.push(build_item(
+            let item = build_item(
                 &value_node,
                 SymbolKind::Property,
                 item_path.clone(),
                 metadata,
                 &item_path,
-            ));
+                &ctx.options,
+            );
+            push_item(out, ctx, item);
+
+            for (anchor, anchor_node) in &wrapped.anchors {
+                push_anchor_item(anchor, anchor_node, ctx, out);
+            }
+            if let (Some(alias), Some(alias_node)) = (&wrapped.alias_name, &wrapped.alias_node) {
+                push_alias_item(alias, alias_node, ctx, out);
+            }
         }
 
-        collect_value(&value_node, &item_path, ctx, out);
+        collect_value(&value_node, &item_path, depth + 1, ctx, out);
         idx += 1;
     }
 
@@ -381,9 +492,10 @@ fn is_mapping_pair<D: ast_grep_core::Doc>(node: &Node<D>) -> bool {
 
 struct WrappedValue<'a, D: ast_grep_core::Doc> {
     value: Node<'a, D>,
-    anchors: Vec<String>,
+    anchors: Vec<(String, Node<'a, D>)>,
     tags: Vec<String>,
     alias_name: Option<String>,
+    alias_node: Option<Node<'a, D>>,
 }
 
 fn unwrap_yaml_value<'a, D: ast_grep_core::Doc>(node: &Node<'a, D>) -> WrappedValue<'a, D> {
@@ -391,6 +503,7 @@ fn unwrap_yaml_value<'a, D: ast_grep_core::Doc>(node: &Node<'a, D>) -> WrappedVa
     let mut anchors = Vec::new();
     let mut tags = Vec::new();
     let mut alias_name = None;
+    let mut alias_node = None;
 
     loop {
         let kind = current.kind();
@@ -399,6 +512,7 @@ fn unwrap_yaml_value<'a, D: ast_grep_core::Doc>(node: &Node<'a, D>) -> WrappedVa
         if kr != "block_node" && kr != "flow_node" {
             if kr == "alias" {
                 alias_name = yaml_helpers::alias_name(&current);
+                alias_node = Some(current.clone());
             }
             break;
         }
@@ -408,13 +522,14 @@ fn unwrap_yaml_value<'a, D: ast_grep_core::Doc>(node: &Node<'a, D>) -> WrappedVa
             match child.kind().as_ref() {
                 "anchor" => {
                     if let Some(name) = yaml_helpers::anchor_name(&child) {
-                        anchors.push(name);
+                        anchors.push((name, child.clone()));
                     }
                 }
                 "tag" => tags.push(child.text().to_string()),
                 other => {
                     if other == "alias" {
                         alias_name = yaml_helpers::alias_name(&child);
+                        alias_node = Some(child.clone());
                     }
                     if next.is_none() {
                         next = Some(child);
@@ -438,21 +553,71 @@ fn unwrap_yaml_value<'a, D: ast_grep_core::Doc>(node: &Node<'a, D>) -> WrappedVa
         anchors,
         tags,
         alias_name,
+        alias_node,
     }
 }
 
+/// Emit a standalone `SymbolKind::Const` item for an `anchor` node, tagged
+/// `yaml:anchor`, alongside the `yaml:anchor:<name>` attribute already
+/// recorded on the anchored key/element.
+fn push_anchor_item<D: ast_grep_core::Doc>(
+    name: &str,
+    node: &Node<D>,
+    ctx: &mut YamlContext,
+    out: &mut Vec<ParsedItem>,
+) {
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute("yaml:anchor");
+    let item = build_item(
+        node,
+        SymbolKind::Const,
+        format!("&{name}"),
+        metadata,
+        name,
+        &ctx.options,
+    );
+    push_item(out, ctx, item);
+}
+
+/// Emit a standalone `SymbolKind::Property` item for an `alias` node,
+/// tagged `yaml:alias:<target_name>`, alongside the `yaml:alias:<name>`
+/// attribute already recorded on the aliasing key/element.
+fn push_alias_item<D: ast_grep_core::Doc>(
+    name: &str,
+    node: &Node<D>,
+    ctx: &mut YamlContext,
+    out: &mut Vec<ParsedItem>,
+) {
+    let mut metadata = SymbolMetadata::default();
+    metadata.push_attribute(format!("yaml:alias:{name}"));
+    let item = build_item(
+        node,
+        SymbolKind::Property,
+        format!("*{name}"),
+        metadata,
+        name,
+        &ctx.options,
+    );
+    push_item(out, ctx, item);
+}
+
 fn build_item<D: ast_grep_core::Doc>(
     node: &Node<D>,
     kind: SymbolKind,
     name: String,
     metadata: SymbolMetadata,
     signature_name: &str,
+    options: &ExtractOptions,
 ) -> ParsedItem {
+    let source = crate::extractors::helpers::extract_source(node, 40)
+        .map(|text| yaml_helpers::truncate_value(&text, options.max_value_len));
+
     ParsedItem {
+        is_deprecated: false,
         kind,
         name,
         signature: signature_name.to_string(),
-        source: crate::extractors::helpers::extract_source(node, 40),
+        source,
         doc_comment: String::new(),
         start_line: node.start_pos().line() as u32 + 1,
         end_line: node.end_pos().line() as u32 + 1,
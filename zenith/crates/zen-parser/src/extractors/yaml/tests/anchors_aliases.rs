@@ -53,3 +53,39 @@ service:
             .any(|attr| attr == "yaml:alias_target:service.local")
     );
 }
+
+#[test]
+fn anchor_and_its_aliases_are_emitted_as_standalone_items() {
+    let source = r"
+defaults: &defaults
+  retries: 3
+service:
+  a: *defaults
+  b: *defaults
+  c: *defaults
+";
+    let items = parse_and_extract(source);
+
+    let anchor = find_by_name(&items, "&defaults");
+    assert_eq!(anchor.kind, SymbolKind::Const);
+    assert!(
+        anchor
+            .metadata
+            .attributes
+            .iter()
+            .any(|attr| attr == "yaml:anchor")
+    );
+
+    let aliases = find_all_by_name(&items, "*defaults");
+    assert_eq!(aliases.len(), 3);
+    for alias in aliases {
+        assert_eq!(alias.kind, SymbolKind::Property);
+        assert!(
+            alias
+                .metadata
+                .attributes
+                .iter()
+                .any(|attr| attr == "yaml:alias:defaults")
+        );
+    }
+}
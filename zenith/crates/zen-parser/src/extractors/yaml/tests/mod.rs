@@ -1,11 +1,12 @@
 use ast_grep_language::LanguageExt;
 
 use super::*;
-pub(super) use crate::types::{ParsedItem, SymbolKind};
+pub(super) use crate::types::{ExtractOptions, ParsedItem, SymbolKind};
 
 mod anchors_aliases;
 mod directives_multidoc;
 mod duplicate_keys;
+mod limits;
 mod metadata;
 mod nested_paths;
 mod path_edge_cases;
@@ -14,8 +15,12 @@ mod tags_block_scalars;
 mod top_level_variants;
 
 fn parse_and_extract(source: &str) -> Vec<ParsedItem> {
+    parse_and_extract_with(source, ExtractOptions::default())
+}
+
+fn parse_and_extract_with(source: &str, options: ExtractOptions) -> Vec<ParsedItem> {
     let root = SupportLang::Yaml.ast_grep(source);
-    extract(&root).expect("extraction should succeed")
+    extract(&root, options).expect("extraction should succeed")
 }
 
 fn fixture_items() -> Vec<ParsedItem> {
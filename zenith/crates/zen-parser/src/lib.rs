@@ -6,10 +6,15 @@
 //! Supports all 26 ast-grep built-in languages with tiered extraction:
 //! - **Rich extractors** (Rust, Python, TypeScript/TSX/JS, Go, Elixir, C#, Haskell, Java, Lua, PHP, Ruby, JSON, YAML):
 //!   full `ParsedItem` metadata with language-specific features
-//! - **Generic extractor** (all other built-in languages):
-//!   kind-based extraction capturing function/class/type definitions
-//! - **Custom language lane** (Markdown via `tree-sitter-md`, TOML via `tree-sitter-toml-ng`, RST via `tree-sitter-rst`, Svelte via `tree-sitter-svelte-next`):
+//! - **Generic extractor** (built-in languages without a rich extractor —
+//!   Scala, Solidity — and custom-lane languages without one — Dart):
+//!   kind-based extraction capturing function/class/trait/contract-shaped
+//!   definitions, tagged `generic:<lang>` since fidelity is lower than a
+//!   rich extractor
+//! - **Custom language lane** (Markdown via `tree-sitter-md`, TOML via `tree-sitter-toml-ng`, RST via `tree-sitter-rst`, Svelte via `tree-sitter-svelte-next`, Dart via `tree-sitter-dart`):
 //!   parser-backed extraction using a custom ast-grep `Language`
+//! - **Regex-based custom lane** (plain text, HCL/Terraform): no `ast-grep`
+//!   grammar available, so extraction scans source directly with heuristics
 //!
 //! Symbol taxonomy is normalized across extractors:
 //! - top-level callables use `Function`
@@ -25,25 +30,30 @@
 pub mod doc_chunker;
 pub mod error;
 pub mod extractors;
+pub mod incremental;
 pub mod parser;
 pub mod test_files;
 pub mod types;
 
 pub use error::ParserError;
+pub use incremental::{ExtractionOutcome, Extractor, HashStore};
 pub use parser::{
-    DetectedLanguage, MarkdownLang, RstLang, SvelteLang, TomlLang, detect_language,
-    detect_language_ext, parse_markdown_source, parse_rst_source, parse_source,
-    parse_svelte_source, parse_toml_source,
+    DartLang, DetectedLanguage, LanguageOverrides, MarkdownLang, RstLang, SvelteLang, TomlLang,
+    detect_language, detect_language_ext, detect_language_ext_with_overrides, parse_dart_source,
+    parse_markdown_source, parse_rst_source, parse_source, parse_svelte_source, parse_toml_source,
 };
-pub use test_files::{is_test_dir, is_test_file};
-pub use types::{DocSections, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
+pub use test_files::{
+    TestClassification, TestFileMatcher, classify_dir, classify_file, classify_path, is_test_dir,
+    is_test_file,
+};
+pub use types::{DocSections, ExtractOptions, ParsedItem, SymbolKind, SymbolMetadata, Visibility};
 
 use ast_grep_language::SupportLang;
 
 /// Extract API symbols from source code for any supported language.
 ///
 /// Detects the language from `file_path`, parses with ast-grep (or a custom
-/// parser for Markdown/TOML/RST/Svelte/Text), and extracts symbols.
+/// parser for Markdown/TOML/RST/Svelte/Text/HCL), and extracts symbols.
 ///
 /// If ast-grep extraction yields zero items, logs a warning and returns an
 /// empty `Vec`. Regex fallback is deferred to a future PR.
@@ -62,11 +72,37 @@ use ast_grep_language::SupportLang;
 /// assert!(!items.is_empty());
 /// ```
 pub fn extract_api(source: &str, file_path: &str) -> Result<Vec<ParsedItem>, ParserError> {
-    let lang = detect_language_ext(file_path)
+    extract_api_with_lang(source, file_path, None)
+}
+
+/// Extract API symbols like [`extract_api`], but let the caller force the
+/// language instead of inferring it from `file_path`.
+///
+/// Pass `forced: Some(lang)` to steer nonstandard extensions (e.g. `.rs.in`
+/// templates, or a `.tsx` file that should be treated as plain TypeScript)
+/// that `detect_language_ext` would otherwise get wrong or reject. Pass
+/// `None` to fall back to normal extension-based detection.
+///
+/// # Errors
+///
+/// Returns [`ParserError::UnsupportedLanguage`] if `forced` is `None` and
+/// the file extension is not recognized by any extractor.
+pub fn extract_api_with_lang(
+    source: &str,
+    file_path: &str,
+    forced: Option<DetectedLanguage>,
+) -> Result<Vec<ParsedItem>, ParserError> {
+    let lang = forced
+        .or_else(|| detect_language_ext(file_path))
         .ok_or_else(|| ParserError::UnsupportedLanguage(file_path.to_string()))?;
 
+    let (source, had_bom) = parser::strip_bom(source);
+    if had_bom {
+        tracing::debug!(file = file_path, "stripped UTF-8 BOM before parsing");
+    }
+
     let items = match lang {
-        DetectedLanguage::Builtin(builtin) => extract_builtin(source, builtin)?,
+        DetectedLanguage::Builtin(builtin) => extract_builtin(source, file_path, builtin)?,
         DetectedLanguage::Markdown => {
             let root = parse_markdown_source(source);
             extractors::markdown::extract(&root)?
@@ -83,7 +119,12 @@ pub fn extract_api(source: &str, file_path: &str) -> Result<Vec<ParsedItem>, Par
             let root = parse_svelte_source(source);
             extractors::svelte::extract(&root)?
         }
+        DetectedLanguage::Dart => {
+            let root = parse_dart_source(source);
+            extractors::generic::extract_dart(&root)?
+        }
         DetectedLanguage::Text => extractors::text::extract(source)?,
+        DetectedLanguage::Hcl => extractors::hcl::extract(source)?,
     };
 
     if items.is_empty() {
@@ -96,13 +137,83 @@ pub fn extract_api(source: &str, file_path: &str) -> Result<Vec<ParsedItem>, Par
     Ok(items)
 }
 
+/// Extract API symbols like [`extract_api`], but return an empty result for
+/// test files instead of parsing them.
+///
+/// Consults [`is_test_file`] against the file name and [`is_test_dir`]
+/// against every path component of `file_path`, so both naming conventions
+/// (`*_test.go`) and directory conventions (`tests/foo.rs`) are honored.
+/// This mirrors the filtering `zen-search::walk::build_walker` applies at
+/// the walker level, for callers that invoke extraction directly on a file
+/// rather than going through a filtered directory walk.
+///
+/// # Errors
+///
+/// Returns [`ParserError::UnsupportedLanguage`] if `skip_tests` is `false`
+/// (or `file_path` isn't a test file) and the file extension is not
+/// recognized by any extractor.
+pub fn extract_api_skip_tests(
+    source: &str,
+    file_path: &str,
+    skip_tests: bool,
+) -> Result<Vec<ParsedItem>, ParserError> {
+    if skip_tests && is_test_path(file_path) {
+        return Ok(Vec::new());
+    }
+    extract_api(source, file_path)
+}
+
+/// Extract API symbols like [`extract_api`], but drop `source` and
+/// `doc_comment` from every item for fast API-surface listing.
+///
+/// Callers that only need kind/name/signature/visibility (e.g. an index
+/// listing) don't need the source-truncation work `extract_source` does per
+/// item, nor the doc comment text. This runs full extraction and then
+/// clears both fields, rather than threading a "lightweight" flag through
+/// every one of the 26 language extractors.
+///
+/// # Errors
+///
+/// Returns [`ParserError::UnsupportedLanguage`] if the file extension is not
+/// recognized by any extractor.
+pub fn extract_api_signatures_only(
+    source: &str,
+    file_path: &str,
+) -> Result<Vec<ParsedItem>, ParserError> {
+    let mut items = extract_api(source, file_path)?;
+    for item in &mut items {
+        item.source = None;
+        item.doc_comment = String::new();
+    }
+    Ok(items)
+}
+
+/// Whether `file_path` names a test file, or lives under a test directory.
+fn is_test_path(file_path: &str) -> bool {
+    let path = std::path::Path::new(file_path);
+    if path
+        .file_name()
+        .is_some_and(|name| is_test_file(&name.to_string_lossy()))
+    {
+        return true;
+    }
+    path.components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .any(is_test_dir)
+}
+
 /// Dispatch to the correct builtin language extractor.
 ///
-/// Handles the three dispatcher signature families:
+/// Handles the four dispatcher signature families:
 /// - `extract(root)` — most languages
 /// - `extract(root, source)` — bash, c, cpp, rust
+/// - `extract(root, source, file_path)` — go
 /// - `extract(root, lang)` — typescript, tsx
-fn extract_builtin(source: &str, lang: SupportLang) -> Result<Vec<ParsedItem>, ParserError> {
+fn extract_builtin(
+    source: &str,
+    file_path: &str,
+    lang: SupportLang,
+) -> Result<Vec<ParsedItem>, ParserError> {
     let root = parse_source(source, lang);
     match lang {
         SupportLang::Rust => extractors::rust::extract(&root, source),
@@ -110,7 +221,7 @@ fn extract_builtin(source: &str, lang: SupportLang) -> Result<Vec<ParsedItem>, P
         SupportLang::TypeScript => extractors::typescript::extract(&root, lang),
         SupportLang::Tsx => extractors::tsx::extract(&root, lang),
         SupportLang::JavaScript => extractors::javascript::extract(&root),
-        SupportLang::Go => extractors::go::extract(&root),
+        SupportLang::Go => extractors::go::extract(&root, source, file_path),
         SupportLang::Elixir => extractors::elixir::extract(&root),
         SupportLang::C => extractors::c::extract(&root, source),
         SupportLang::Cpp => extractors::cpp::extract(&root, source),
@@ -119,12 +230,20 @@ fn extract_builtin(source: &str, lang: SupportLang) -> Result<Vec<ParsedItem>, P
         SupportLang::Haskell => extractors::haskell::extract(&root),
         SupportLang::Html => extractors::html::extract(&root),
         SupportLang::Java => extractors::java::extract(&root),
-        SupportLang::Json => extractors::json::extract(&root),
+        SupportLang::Json if extractors::openapi::looks_like_openapi(source) => {
+            extractors::openapi::extract_json(&root, ExtractOptions::default())
+        }
+        SupportLang::Json => extractors::json::extract(&root, file_path, ExtractOptions::default()),
         SupportLang::Lua => extractors::lua::extract(&root),
         SupportLang::Php => extractors::php::extract(&root),
         SupportLang::Ruby => extractors::ruby::extract(&root),
         SupportLang::Bash => extractors::bash::extract(&root, source),
-        SupportLang::Yaml => extractors::yaml::extract(&root),
+        SupportLang::Yaml if extractors::openapi::looks_like_openapi(source) => {
+            extractors::openapi::extract_yaml(&root, ExtractOptions::default())
+        }
+        SupportLang::Yaml => extractors::yaml::extract(&root, ExtractOptions::default()),
+        SupportLang::Scala => extractors::generic::extract_scala(&root),
+        SupportLang::Solidity => extractors::generic::extract_solidity(&root),
         // Catch-all for any future SupportLang variants
         _ => Err(ParserError::UnsupportedLanguage(format!("{lang:?}"))),
     }
@@ -187,4 +306,81 @@ mod extract_api_tests {
         // Plain text with no headings — root item + paragraph items
         assert!(!items.is_empty());
     }
+
+    #[test]
+    fn forced_language_overrides_inferred_extension() {
+        // "config.tsx" would normally infer Tsx, but we force plain TypeScript.
+        let source = "function hello() {}\n";
+        let items = extract_api_with_lang(
+            source,
+            "config.tsx",
+            Some(DetectedLanguage::Builtin(
+                ast_grep_language::SupportLang::TypeScript,
+            )),
+        )
+        .unwrap();
+        assert!(items.iter().any(|i| i.name == "hello"));
+    }
+
+    #[test]
+    fn forced_language_extracts_unknown_extension() {
+        let source = "fn hello() {}\n";
+        let items = extract_api_with_lang(
+            source,
+            "template.rs.in",
+            Some(DetectedLanguage::Builtin(
+                ast_grep_language::SupportLang::Rust,
+            )),
+        )
+        .unwrap();
+        assert!(items.iter().any(|i| i.name == "hello"));
+        // Without forcing, the extension is unrecognized.
+        assert!(extract_api(source, "template.rs.in").is_err());
+    }
+
+    #[test]
+    fn skip_tests_false_extracts_test_dir_file() {
+        let source = "fn hello() {}\n";
+        let items = extract_api_skip_tests(source, "tests/support.rs", false).unwrap();
+        assert!(items.iter().any(|i| i.name == "hello"));
+    }
+
+    #[test]
+    fn skip_tests_true_skips_test_dir_file() {
+        let source = "fn hello() {}\n";
+        let items = extract_api_skip_tests(source, "tests/support.rs", true).unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn skip_tests_true_skips_test_named_file() {
+        let source = "func Handler() {}\n";
+        let items = extract_api_skip_tests(source, "src/handler_test.go", true).unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn skip_tests_true_still_extracts_non_test_file() {
+        let source = "fn hello() {}\n";
+        let items = extract_api_skip_tests(source, "src/main.rs", true).unwrap();
+        assert!(items.iter().any(|i| i.name == "hello"));
+    }
+
+    #[test]
+    fn signatures_only_matches_full_extraction_minus_source_and_doc() {
+        let source = "/// Says hello.\nfn hello() {}\nstruct Foo;\n";
+        let full = extract_api(source, "src/main.rs").unwrap();
+        let signatures_only = extract_api_signatures_only(source, "src/main.rs").unwrap();
+
+        assert_eq!(full.len(), signatures_only.len());
+        assert!(full.iter().any(|item| !item.doc_comment.is_empty()));
+        for (full_item, lite_item) in full.iter().zip(&signatures_only) {
+            assert_eq!(full_item.kind, lite_item.kind);
+            assert_eq!(full_item.name, lite_item.name);
+            assert_eq!(full_item.signature, lite_item.signature);
+            assert_eq!(full_item.visibility, lite_item.visibility);
+            assert_eq!(lite_item.doc_comment, "");
+            assert_eq!(lite_item.source, None);
+        }
+    }
 }
@@ -3,6 +3,8 @@
 use ast_grep_core::tree_sitter::StrDoc;
 use ast_grep_language::SupportLang;
 
+mod dart_lang;
+pub use dart_lang::DartLang;
 mod markdown_lang;
 pub use markdown_lang::MarkdownLang;
 mod rst_lang;
@@ -27,6 +29,9 @@ pub type RstAstTree = ast_grep_core::AstGrep<StrDoc<RstLang>>;
 /// The concrete AST type returned by `parse_svelte_source`.
 pub type SvelteAstTree = ast_grep_core::AstGrep<StrDoc<SvelteLang>>;
 
+/// The concrete AST type returned by `parse_dart_source`.
+pub type DartAstTree = ast_grep_core::AstGrep<StrDoc<DartLang>>;
+
 /// Extended language detection that includes custom languages.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DetectedLanguage {
@@ -35,8 +40,15 @@ pub enum DetectedLanguage {
     Rst,
     Svelte,
     Toml,
+    /// Dart (`.dart`) — not an ast-grep built-in `SupportLang`, so it's
+    /// wired as a custom language like Markdown/TOML/RST/Svelte, routed
+    /// through the generic kind-based extractor rather than a rich one.
+    Dart,
     /// Plain text (`.txt`, `llms.txt`, etc.) — uses smart format routing.
     Text,
+    /// HCL/Terraform (`.tf`, `.tfvars`) — no `ast-grep` grammar available,
+    /// so extraction scans source directly for block headers.
+    Hcl,
 }
 
 /// Detect the programming language from a file path extension.
@@ -66,6 +78,8 @@ pub fn detect_language(file_path: &str) -> Option<SupportLang> {
         "rb" => Some(SupportLang::Ruby),
         "sh" | "bash" | "zsh" => Some(SupportLang::Bash),
         "yaml" | "yml" => Some(SupportLang::Yaml),
+        "scala" | "sc" => Some(SupportLang::Scala),
+        "sol" => Some(SupportLang::Solidity),
         _ => None,
     }
 }
@@ -78,16 +92,74 @@ pub fn detect_language_ext(file_path: &str) -> Option<DetectedLanguage> {
         "md" | "markdown" | "mdx" => Some(DetectedLanguage::Markdown),
         "rst" | "rest" => Some(DetectedLanguage::Rst),
         "svelte" => Some(DetectedLanguage::Svelte),
+        "dart" => Some(DetectedLanguage::Dart),
         "toml" => Some(DetectedLanguage::Toml),
         "txt" => Some(DetectedLanguage::Text),
+        "tf" | "tfvars" => Some(DetectedLanguage::Hcl),
         _ => detect_language(file_path).map(DetectedLanguage::Builtin),
     }
 }
 
+/// User-supplied extension → language overrides, consulted before the
+/// built-in table in [`detect_language_ext_with_overrides`].
+///
+/// Lets a project config steer detection for nonstandard extensions (e.g.
+/// `.rs.in` templates, or `.mjs` files that should be treated as plain
+/// TypeScript) that the built-in table would otherwise get wrong or
+/// reject outright.
+#[derive(Debug, Clone, Default)]
+pub struct LanguageOverrides(std::collections::HashMap<String, DetectedLanguage>);
+
+impl LanguageOverrides {
+    /// Create an empty override map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an override for `ext` (without the leading dot), returning
+    /// `self` for chaining.
+    #[must_use]
+    pub fn with(mut self, ext: impl Into<String>, lang: DetectedLanguage) -> Self {
+        self.0.insert(ext.into(), lang);
+        self
+    }
+}
+
+/// Detect language, consulting `overrides` before the built-in extension
+/// table used by [`detect_language_ext`].
+#[must_use]
+pub fn detect_language_ext_with_overrides(
+    file_path: &str,
+    overrides: &LanguageOverrides,
+) -> Option<DetectedLanguage> {
+    let ext = file_path.rsplit('.').next()?;
+    overrides
+        .0
+        .get(ext)
+        .copied()
+        .or_else(|| detect_language_ext(file_path))
+}
+
+/// Strip a leading UTF-8 BOM (`\u{FEFF}`), if present.
+///
+/// Windows-authored source files occasionally carry a BOM; left in place, it
+/// becomes part of the first token tree-sitter sees and shifts every
+/// subsequent column/line calculation that assumes the file starts at byte
+/// 0. Returns `(rest, true)` if a BOM was stripped, `(source, false)`
+/// otherwise.
+#[must_use]
+pub fn strip_bom(source: &str) -> (&str, bool) {
+    source
+        .strip_prefix('\u{feff}')
+        .map_or((source, false), |rest| (rest, true))
+}
+
 /// Parse source code into an ast-grep tree for the given language.
 #[must_use]
 pub fn parse_source(source: &str, lang: SupportLang) -> AstTree {
     use ast_grep_language::LanguageExt;
+    let (source, _had_bom) = strip_bom(source);
     lang.ast_grep(source)
 }
 
@@ -95,6 +167,7 @@ pub fn parse_source(source: &str, lang: SupportLang) -> AstTree {
 #[must_use]
 pub fn parse_markdown_source(source: &str) -> MarkdownAstTree {
     use ast_grep_core::tree_sitter::LanguageExt;
+    let (source, _had_bom) = strip_bom(source);
     MarkdownLang.ast_grep(source)
 }
 
@@ -102,6 +175,7 @@ pub fn parse_markdown_source(source: &str) -> MarkdownAstTree {
 #[must_use]
 pub fn parse_toml_source(source: &str) -> TomlAstTree {
     use ast_grep_core::tree_sitter::LanguageExt;
+    let (source, _had_bom) = strip_bom(source);
     TomlLang.ast_grep(source)
 }
 
@@ -109,6 +183,7 @@ pub fn parse_toml_source(source: &str) -> TomlAstTree {
 #[must_use]
 pub fn parse_rst_source(source: &str) -> RstAstTree {
     use ast_grep_core::tree_sitter::LanguageExt;
+    let (source, _had_bom) = strip_bom(source);
     RstLang.ast_grep(source)
 }
 
@@ -116,9 +191,18 @@ pub fn parse_rst_source(source: &str) -> RstAstTree {
 #[must_use]
 pub fn parse_svelte_source(source: &str) -> SvelteAstTree {
     use ast_grep_core::tree_sitter::LanguageExt;
+    let (source, _had_bom) = strip_bom(source);
     SvelteLang.ast_grep(source)
 }
 
+/// Parse Dart source using the custom `tree-sitter-dart` language.
+#[must_use]
+pub fn parse_dart_source(source: &str) -> DartAstTree {
+    use ast_grep_core::tree_sitter::LanguageExt;
+    let (source, _had_bom) = strip_bom(source);
+    DartLang.ast_grep(source)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -247,6 +331,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn detect_dart_extended() {
+        assert_eq!(
+            detect_language_ext("lib/main.dart"),
+            Some(DetectedLanguage::Dart)
+        );
+    }
+
+    #[test]
+    fn detect_scala_and_solidity() {
+        assert_eq!(detect_language("Main.scala"), Some(SupportLang::Scala));
+        assert_eq!(detect_language("Token.sol"), Some(SupportLang::Solidity));
+    }
+
+    #[test]
+    fn detect_hcl_extended() {
+        assert_eq!(detect_language_ext("main.tf"), Some(DetectedLanguage::Hcl));
+        assert_eq!(
+            detect_language_ext("terraform.tfvars"),
+            Some(DetectedLanguage::Hcl)
+        );
+    }
+
     #[test]
     fn detect_builtin_via_extended() {
         assert_eq!(
@@ -292,4 +399,46 @@ mod tests {
         let tree = parse_svelte_source("<script>let n = 1;</script><h1>{n}</h1>");
         assert_eq!(tree.root().kind().as_ref(), "document");
     }
+
+    #[test]
+    fn parse_dart_source_produces_valid_tree() {
+        let tree = parse_dart_source("void main() {}\n");
+        assert_eq!(tree.root().kind().as_ref(), "source_file");
+    }
+
+    #[test]
+    fn overrides_take_precedence_over_builtin_table() {
+        let overrides = LanguageOverrides::new()
+            .with("tsx", DetectedLanguage::Builtin(SupportLang::TypeScript));
+        assert_eq!(
+            detect_language_ext_with_overrides("component.tsx", &overrides),
+            Some(DetectedLanguage::Builtin(SupportLang::TypeScript))
+        );
+        // Without the override, .tsx still detects as Tsx.
+        assert_eq!(
+            detect_language_ext("component.tsx"),
+            Some(DetectedLanguage::Builtin(SupportLang::Tsx))
+        );
+    }
+
+    #[test]
+    fn overrides_enable_unknown_extensions() {
+        let overrides =
+            LanguageOverrides::new().with("in", DetectedLanguage::Builtin(SupportLang::Rust));
+        assert_eq!(detect_language_ext("template.rs.in"), None);
+        assert_eq!(
+            detect_language_ext_with_overrides("template.rs.in", &overrides),
+            Some(DetectedLanguage::Builtin(SupportLang::Rust))
+        );
+    }
+
+    #[test]
+    fn overrides_fall_back_to_builtin_table_when_unmatched() {
+        let overrides =
+            LanguageOverrides::new().with("in", DetectedLanguage::Builtin(SupportLang::Rust));
+        assert_eq!(
+            detect_language_ext_with_overrides("src/main.rs", &overrides),
+            Some(DetectedLanguage::Builtin(SupportLang::Rust))
+        );
+    }
 }
@@ -1,36 +1,101 @@
-//! Test file and directory detection for filtering during indexing.
+//! Test file and directory classification for filtering during indexing.
 //!
 //! Used by the walker factory (`zen-search/src/walk.rs`) to skip test
 //! files and directories during package indexing. Patterns cover Go, Rust,
 //! JavaScript/TypeScript, Python, Elixir, and common framework conventions.
 
-/// Directory names conventionally used for tests, benchmarks, examples, and fixtures.
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+
+/// How a path was classified relative to the project's test suite.
+///
+/// Distinct from a plain bool so callers can choose to skip fixtures while
+/// still indexing benches, or vice versa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestClassification {
+    /// A test file or a file under a test directory (`tests/`, `*_test.go`, ...).
+    Test,
+    /// A benchmark file or a file under a benchmark directory (`benches/`, ...).
+    Bench,
+    /// Test data that isn't itself a test: fixtures, snapshots, mocks, examples.
+    Fixture,
+    /// Everything else.
+    Source,
+}
+
+impl std::fmt::Display for TestClassification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Test => "test",
+            Self::Bench => "bench",
+            Self::Fixture => "fixture",
+            Self::Source => "source",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Directory names conventionally used for tests.
 const TEST_DIRS: &[&str] = &[
     "test",
     "tests",
     "spec",
     "specs",
     "__tests__",
+    "e2e",
+    "integration_tests",
+    "unit_tests",
+];
+
+/// Directory names conventionally used for benchmarks.
+const BENCH_DIRS: &[&str] = &["benches", "benchmarks"];
+
+/// Directory names conventionally used for fixtures, mocks, and generated data.
+const FIXTURE_DIRS: &[&str] = &[
     "__mocks__",
     "__snapshots__",
     "testdata",
     "test_data",
     "fixtures",
-    "e2e",
-    "integration_tests",
-    "unit_tests",
-    "benches",
-    "benchmarks",
     "examples",
 ];
 
-/// Returns `true` if `dir_name` matches a known test/fixture directory convention.
+/// Classify a single directory name by test/bench/fixture convention.
 ///
 /// Comparison is case-sensitive (directory names are almost always lowercase).
 ///
 /// # Examples
 ///
 /// ```
+/// use zen_parser::{classify_dir, TestClassification};
+/// assert_eq!(classify_dir("tests"), TestClassification::Test);
+/// assert_eq!(classify_dir("benches"), TestClassification::Bench);
+/// assert_eq!(classify_dir("fixtures"), TestClassification::Fixture);
+/// assert_eq!(classify_dir("src"), TestClassification::Source);
+/// ```
+#[must_use]
+pub fn classify_dir(dir_name: &str) -> TestClassification {
+    if TEST_DIRS.contains(&dir_name) {
+        TestClassification::Test
+    } else if BENCH_DIRS.contains(&dir_name) {
+        TestClassification::Bench
+    } else if FIXTURE_DIRS.contains(&dir_name) {
+        TestClassification::Fixture
+    } else {
+        TestClassification::Source
+    }
+}
+
+/// Returns `true` if `dir_name` matches any known test/bench/fixture directory
+/// convention.
+///
+/// Kept for callers that only need a yes/no filter; use [`classify_dir`] to
+/// distinguish tests from benches and fixtures.
+///
+/// # Examples
+///
+/// ```
 /// use zen_parser::is_test_dir;
 /// assert!(is_test_dir("tests"));
 /// assert!(is_test_dir("__tests__"));
@@ -38,24 +103,73 @@ const TEST_DIRS: &[&str] = &[
 /// ```
 #[must_use]
 pub fn is_test_dir(dir_name: &str) -> bool {
-    TEST_DIRS.contains(&dir_name)
+    classify_dir(dir_name) != TestClassification::Source
 }
 
-/// Returns `true` if `file_name` matches a known test file naming convention.
+/// Classify a single file name by test/bench/fixture naming convention.
 ///
 /// Supports conventions for:
-/// - **Go**: `*_test.go`
-/// - **Rust**: `*_test.rs`
-/// - **JavaScript/TypeScript**: `*.test.{js,ts,tsx,jsx}`, `*.spec.{js,ts,tsx,jsx}`
-/// - **Python**: `test_*.py`, `*_test.py`, `conftest.py`
-/// - **Elixir**: `*_test.exs`
-/// - **Go setup**: `setup_test.go`
+/// - **Go**: `*_test.go` (test), `*_bench_test.go` is still `*_test.go` so it
+///   classifies as `Test` -- Go has no separate benchmark file suffix.
+/// - **Rust**: `*_test.rs` (test)
+/// - **JavaScript/TypeScript**: `*.test.{js,ts,tsx,jsx,mjs,cjs}`,
+///   `*.spec.{js,ts,tsx,jsx,mjs,cjs}` (test)
+/// - **Python**: `test_*.py`, `*_test.py`, `conftest.py` (test)
+/// - **Elixir**: `*_test.exs` (test)
 ///
 /// Comparison is case-insensitive for the file name.
 ///
 /// # Examples
 ///
 /// ```
+/// use zen_parser::{classify_file, TestClassification};
+/// assert_eq!(classify_file("widget_test.go"), TestClassification::Test);
+/// assert_eq!(classify_file("App.test.tsx"), TestClassification::Test);
+/// assert_eq!(classify_file("test_utils.py"), TestClassification::Test);
+/// assert_eq!(classify_file("main.rs"), TestClassification::Source);
+/// ```
+#[must_use]
+pub fn classify_file(file_name: &str) -> TestClassification {
+    let name = file_name.to_lowercase();
+
+    let is_test = name.ends_with("_test.go")
+        || name.ends_with("_test.rs")
+        || name.ends_with(".test.js")
+        || name.ends_with(".test.ts")
+        || name.ends_with(".test.tsx")
+        || name.ends_with(".test.jsx")
+        || name.ends_with(".test.mjs")
+        || name.ends_with(".test.cjs")
+        || name.ends_with(".spec.js")
+        || name.ends_with(".spec.ts")
+        || name.ends_with(".spec.tsx")
+        || name.ends_with(".spec.jsx")
+        || name.ends_with(".spec.mjs")
+        || name.ends_with(".spec.cjs")
+        || (name.starts_with("test_")
+            && std::path::Path::new(&name)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("py")))
+        || name.ends_with("_test.py")
+        || name.ends_with("_test.exs")
+        || name == "conftest.py"
+        || name == "setup_test.go";
+
+    if is_test {
+        TestClassification::Test
+    } else {
+        TestClassification::Source
+    }
+}
+
+/// Returns `true` if `file_name` matches a known test file naming convention.
+///
+/// Kept for callers that only need a yes/no filter; use [`classify_file`] to
+/// distinguish tests from benches and fixtures.
+///
+/// # Examples
+///
+/// ```
 /// use zen_parser::is_test_file;
 /// assert!(is_test_file("widget_test.go"));
 /// assert!(is_test_file("App.test.tsx"));
@@ -64,47 +178,86 @@ pub fn is_test_dir(dir_name: &str) -> bool {
 /// ```
 #[must_use]
 pub fn is_test_file(file_name: &str) -> bool {
-    let name = file_name.to_lowercase();
+    classify_file(file_name) != TestClassification::Source
+}
 
-    // Go
-    name.ends_with("_test.go")
-    // Rust
-    || name.ends_with("_test.rs")
-    // JavaScript / TypeScript (.test.*)
-    || name.ends_with(".test.js")
-    || name.ends_with(".test.ts")
-    || name.ends_with(".test.tsx")
-    || name.ends_with(".test.jsx")
-    || name.ends_with(".test.mjs")
-    || name.ends_with(".test.cjs")
-    // JavaScript / TypeScript (.spec.*)
-    || name.ends_with(".spec.js")
-    || name.ends_with(".spec.ts")
-    || name.ends_with(".spec.tsx")
-    || name.ends_with(".spec.jsx")
-    || name.ends_with(".spec.mjs")
-    || name.ends_with(".spec.cjs")
-    // Python
-    || name.starts_with("test_") && std::path::Path::new(&name)
-        .extension()
-        .is_some_and(|ext| ext.eq_ignore_ascii_case("py"))
-    || name.ends_with("_test.py")
-    // Elixir
-    || name.ends_with("_test.exs")
-    // Special files
-    || name == "conftest.py"
-    || name == "setup_test.go"
+/// Classify a path (any number of directory components, plus a file name)
+/// against the built-in test/bench/fixture conventions.
+///
+/// Checks every path component for a directory-level match before falling
+/// back to the file name convention, so `src/testdata/config.rs` is a
+/// `Fixture` even though `config.rs` alone isn't a test file.
+#[must_use]
+pub fn classify_path(file_path: &str) -> TestClassification {
+    let path = std::path::Path::new(file_path);
+
+    for component in path.components().filter_map(|c| c.as_os_str().to_str()) {
+        let classification = classify_dir(component);
+        if classification != TestClassification::Source {
+            return classification;
+        }
+    }
+
+    path.file_name().map_or(TestClassification::Source, |name| {
+        classify_file(&name.to_string_lossy())
+    })
+}
+
+/// Classifies paths using the built-in conventions plus user-supplied globs.
+///
+/// Extra globs (typically sourced from `ZenConfig::general.test_globs`) are
+/// checked first and always classify a match as [`TestClassification::Test`],
+/// so a project can flag ecosystem-specific paths (e.g. `**/*.feature`) that
+/// the built-in rules don't cover.
+pub struct TestFileMatcher {
+    extra: GlobSet,
+}
+
+impl TestFileMatcher {
+    /// Build a matcher from user-supplied extra glob patterns.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`globset::Error`] if any pattern in `extra_globs` is not a
+    /// valid glob.
+    pub fn new(extra_globs: &[String]) -> Result<Self, globset::Error> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in extra_globs {
+            builder.add(Glob::new(pattern)?);
+        }
+        Ok(Self {
+            extra: builder.build()?,
+        })
+    }
+
+    /// Classify `file_path`, consulting extra globs before the built-in rules.
+    #[must_use]
+    pub fn classify(&self, file_path: &str) -> TestClassification {
+        if self.extra.is_match(file_path) {
+            return TestClassification::Test;
+        }
+        classify_path(file_path)
+    }
+}
+
+impl Default for TestFileMatcher {
+    /// A matcher with no extra globs, equivalent to [`classify_path`] alone.
+    fn default() -> Self {
+        Self {
+            extra: GlobSetBuilder::new().build().expect("empty globset builds"),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    // ── is_test_dir ──────────────────────────────────────────
+    // ── is_test_dir / classify_dir ───────────────────────────
 
     #[test]
     fn test_dir_matches_common_names() {
-        for dir in TEST_DIRS {
+        for dir in TEST_DIRS.iter().chain(BENCH_DIRS).chain(FIXTURE_DIRS) {
             assert!(is_test_dir(dir), "expected is_test_dir({dir:?}) == true");
         }
     }
@@ -121,13 +274,22 @@ mod tests {
 
     #[test]
     fn test_dir_is_case_sensitive() {
-        // TEST_DIRS are lowercase; uppercase should not match
         assert!(!is_test_dir("Tests"));
         assert!(!is_test_dir("TESTS"));
         assert!(!is_test_dir("__Tests__"));
     }
 
-    // ── is_test_file — Go ────────────────────────────────────
+    #[test]
+    fn classify_dir_distinguishes_test_bench_fixture() {
+        assert_eq!(classify_dir("tests"), TestClassification::Test);
+        assert_eq!(classify_dir("benches"), TestClassification::Bench);
+        assert_eq!(classify_dir("benchmarks"), TestClassification::Bench);
+        assert_eq!(classify_dir("fixtures"), TestClassification::Fixture);
+        assert_eq!(classify_dir("testdata"), TestClassification::Fixture);
+        assert_eq!(classify_dir("src"), TestClassification::Source);
+    }
+
+    // ── is_test_file / classify_file ─────────────────────────
 
     #[test]
     fn test_file_go() {
@@ -136,8 +298,6 @@ mod tests {
         assert!(!is_test_file("handler.go"));
     }
 
-    // ── is_test_file — Rust ──────────────────────────────────
-
     #[test]
     fn test_file_rust() {
         assert!(is_test_file("parser_test.rs"));
@@ -145,8 +305,6 @@ mod tests {
         assert!(!is_test_file("mod.rs"));
     }
 
-    // ── is_test_file — JavaScript / TypeScript ───────────────
-
     #[test]
     fn test_file_js_ts_test() {
         assert!(is_test_file("App.test.js"));
@@ -174,8 +332,6 @@ mod tests {
         assert!(!is_test_file("utils.js"));
     }
 
-    // ── is_test_file — Python ────────────────────────────────
-
     #[test]
     fn test_file_python() {
         assert!(is_test_file("test_utils.py"));
@@ -185,16 +341,12 @@ mod tests {
         assert!(!is_test_file("main.py"));
     }
 
-    // ── is_test_file — Elixir ────────────────────────────────
-
     #[test]
     fn test_file_elixir() {
         assert!(is_test_file("router_test.exs"));
         assert!(!is_test_file("router.ex"));
     }
 
-    // ── is_test_file — case insensitivity ────────────────────
-
     #[test]
     fn test_file_case_insensitive() {
         assert!(is_test_file("Handler_Test.go"));
@@ -202,8 +354,6 @@ mod tests {
         assert!(is_test_file("TEST_UTILS.PY"));
     }
 
-    // ── is_test_file — edge cases ────────────────────────────
-
     #[test]
     fn test_file_empty_and_dots() {
         assert!(!is_test_file(""));
@@ -211,4 +361,101 @@ mod tests {
         assert!(!is_test_file(".."));
         assert!(!is_test_file(".test"));
     }
+
+    // ── classify_path — table-driven across languages/dirs ───
+
+    #[test]
+    fn classify_path_table() {
+        let cases: &[(&str, TestClassification)] = &[
+            // Go
+            ("pkg/handler_test.go", TestClassification::Test),
+            ("pkg/handler.go", TestClassification::Source),
+            ("internal/setup_test.go", TestClassification::Test),
+            // Rust
+            ("crates/foo/src/lib.rs", TestClassification::Source),
+            ("crates/foo/tests/integration.rs", TestClassification::Test),
+            (
+                "crates/foo/benches/throughput.rs",
+                TestClassification::Bench,
+            ),
+            ("crates/foo/src/parser_test.rs", TestClassification::Test),
+            // Elixir
+            ("test/router_test.exs", TestClassification::Test),
+            ("lib/router.ex", TestClassification::Source),
+            // Python
+            ("app/test_utils.py", TestClassification::Test),
+            ("app/utils.py", TestClassification::Source),
+            ("app/conftest.py", TestClassification::Test),
+            ("tests/conftest.py", TestClassification::Test),
+            // JavaScript / TypeScript
+            ("src/App.tsx", TestClassification::Source),
+            ("src/App.test.tsx", TestClassification::Test),
+            ("src/App.spec.ts", TestClassification::Test),
+            ("__tests__/App.tsx", TestClassification::Test),
+            ("src/__mocks__/fs.js", TestClassification::Fixture),
+            ("src/__snapshots__/App.snap", TestClassification::Fixture),
+            // Generic fixture/test-data directories
+            (
+                "crates/foo/testdata/input.json",
+                TestClassification::Fixture,
+            ),
+            (
+                "crates/foo/test_data/input.json",
+                TestClassification::Fixture,
+            ),
+            (
+                "crates/foo/fixtures/sample.json",
+                TestClassification::Fixture,
+            ),
+            ("crates/foo/examples/basic.rs", TestClassification::Fixture),
+            // Spec directories (RSpec/Jasmine convention)
+            ("spec/models/user_spec.rb", TestClassification::Test),
+            ("specs/api_spec.rb", TestClassification::Test),
+            // e2e / integration / unit directories
+            ("e2e/login.spec.ts", TestClassification::Test),
+            ("integration_tests/api.rs", TestClassification::Test),
+            ("unit_tests/math.rs", TestClassification::Test),
+            // Nested: dir classification wins even for a plain source name
+            ("tests/support/helpers.rs", TestClassification::Test),
+            ("benches/support/setup.rs", TestClassification::Bench),
+            // Plain source, no test signal anywhere
+            ("src/main.rs", TestClassification::Source),
+            ("README.md", TestClassification::Source),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(
+                classify_path(path),
+                *expected,
+                "classify_path({path:?}) should be {expected:?}"
+            );
+        }
+    }
+
+    // ── TestFileMatcher ───────────────────────────────────────
+
+    #[test]
+    fn matcher_with_no_extra_globs_matches_built_in_rules() {
+        let matcher = TestFileMatcher::default();
+        assert_eq!(
+            matcher.classify("crates/foo/tests/integration.rs"),
+            TestClassification::Test
+        );
+        assert_eq!(matcher.classify("src/main.rs"), TestClassification::Source);
+    }
+
+    #[test]
+    fn matcher_extra_globs_classify_as_test() {
+        let matcher = TestFileMatcher::new(&["**/*.feature".to_string()]).unwrap();
+        assert_eq!(
+            matcher.classify("features/login.feature"),
+            TestClassification::Test
+        );
+        assert_eq!(matcher.classify("src/main.rs"), TestClassification::Source);
+    }
+
+    #[test]
+    fn matcher_invalid_glob_is_an_error() {
+        assert!(TestFileMatcher::new(&["[".to_string()]).is_err());
+    }
 }
@@ -0,0 +1,45 @@
+//! Limits controlling how much of a large or deeply-nested document gets extracted.
+
+/// Limits applied by the JSON and YAML extractors to keep indexing bounded on
+/// pathological inputs, e.g. a multi-hundred-thousand-line `package-lock.json`.
+///
+/// When a limit is hit, the extractor stops descending/emitting further items
+/// and adds a single summary item (name `$:truncated`) carrying a
+/// `json:truncated`/`yaml:truncated` attribute so callers can tell the result
+/// is incomplete rather than assuming the document was small.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtractOptions {
+    /// Maximum nesting depth to descend into. Values past this depth are not
+    /// expanded into child items (the pair/element that reaches the limit is
+    /// still emitted).
+    pub max_depth: usize,
+    /// Maximum number of items (not counting the root and summary items) to
+    /// emit before extraction stops early.
+    pub max_items: usize,
+    /// Maximum length, in characters, of the `source` snippet stored on a
+    /// single-line item. Longer values are truncated with a marker.
+    pub max_value_len: usize,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 32,
+            max_items: 5_000,
+            max_value_len: 500,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_sane() {
+        let options = ExtractOptions::default();
+        assert_eq!(options.max_depth, 32);
+        assert_eq!(options.max_items, 5_000);
+        assert_eq!(options.max_value_len, 500);
+    }
+}
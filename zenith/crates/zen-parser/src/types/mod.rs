@@ -1,6 +1,7 @@
 //! Core data types for parsed symbols extracted from source code.
 
 mod doc_sections;
+mod extract_options;
 mod parsed_item;
 mod symbol_kind;
 mod visibility;
@@ -8,6 +9,7 @@ mod visibility;
 mod symbol_metadata;
 
 pub use doc_sections::DocSections;
+pub use extract_options::ExtractOptions;
 pub use parsed_item::ParsedItem;
 pub use symbol_kind::SymbolKind;
 pub use symbol_metadata::SymbolMetadata;
@@ -14,6 +14,10 @@ pub struct ParsedItem {
     pub end_line: u32,
     pub visibility: Visibility,
     pub metadata: SymbolMetadata,
+    /// Whether the symbol is marked deprecated by the source language's
+    /// own convention (e.g. `#[deprecated]`, `@Deprecated`, a `@deprecated`
+    /// `JSDoc` tag, or a `.. deprecated::` docstring directive).
+    pub is_deprecated: bool,
 }
 
 #[cfg(test)]
@@ -46,6 +50,7 @@ mod tests {
     fn parsed_item_serializes_kind_in_snake_case() {
         for kind in KINDS {
             let item = ParsedItem {
+                is_deprecated: false,
                 kind: *kind,
                 name: "sample".to_string(),
                 signature: "sample()".to_string(),
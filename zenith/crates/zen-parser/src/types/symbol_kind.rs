@@ -23,6 +23,7 @@ pub enum SymbolKind {
     Module,
     Union,
     Component,
+    Test,
 }
 
 impl std::fmt::Display for SymbolKind {
@@ -47,6 +48,7 @@ impl std::fmt::Display for SymbolKind {
             Self::Module => "module",
             Self::Union => "union",
             Self::Component => "component",
+            Self::Test => "test",
         };
         write!(f, "{s}")
     }
@@ -76,6 +78,7 @@ mod tests {
         (SymbolKind::Module, "module"),
         (SymbolKind::Union, "union"),
         (SymbolKind::Component, "component"),
+        (SymbolKind::Test, "test"),
     ];
 
     #[test]
@@ -16,6 +16,7 @@ pub trait CommonMetadataExt {
     fn set_parameters(&mut self, parameters: Vec<String>);
     fn set_owner_name(&mut self, owner_name: Option<String>);
     fn set_owner_kind(&mut self, owner_kind: Option<SymbolKind>);
+    fn set_owner_local_name(&mut self, owner_local_name: Option<String>);
     fn mark_static_member(&mut self);
     fn set_doc_sections(&mut self, doc_sections: DocSections);
     fn set_where_clause(&mut self, where_clause: Option<String>);
@@ -81,6 +82,10 @@ impl CommonMetadataExt for SymbolMetadata {
         self.owner_kind = owner_kind;
     }
 
+    fn set_owner_local_name(&mut self, owner_local_name: Option<String>) {
+        self.owner_local_name = owner_local_name;
+    }
+
     fn mark_static_member(&mut self) {
         self.is_static_member = true;
     }
@@ -27,10 +27,14 @@ pub struct SymbolMetadata {
     pub generics: Option<String>,
     pub attributes: Vec<String>,
     pub parameters: Vec<String>,
-    /// Parent symbol name for member-level items (for example `MyType`).
+    /// Parent symbol name for member-level items, fully qualified through
+    /// enclosing namespaces/types (for example `Outer::Inner`).
     pub owner_name: Option<String>,
     /// Parent symbol kind for member-level items (for example `Class`).
     pub owner_kind: Option<SymbolKind>,
+    /// Unqualified name of the immediate parent, for display (for example
+    /// `Inner` when `owner_name` is `Outer::Inner`).
+    pub owner_local_name: Option<String>,
     /// Whether the member declaration is static in its owner context.
     pub is_static_member: bool,
 
@@ -42,6 +46,8 @@ pub struct SymbolMetadata {
     pub associated_types: Vec<String>,
     pub abi: Option<String>,
     pub is_pyo3: bool,
+    /// Idents listed in a `#[derive(...)]` attribute.
+    pub derives: Vec<String>,
 
     // Enum/Struct members
     pub variants: Vec<String>,
@@ -64,6 +70,8 @@ pub struct SymbolMetadata {
     pub is_exported: bool,
     pub is_default_export: bool,
     pub type_parameters: Option<String>,
+    /// Interfaces implemented (TypeScript `implements`) or, for a Java
+    /// `sealed` type, the subtypes named in its `permits` clause.
     pub implements: Vec<String>,
 
     // Documentation
@@ -99,6 +107,7 @@ pub struct SymbolMetadata {
     pub is_error_boundary: bool,
     pub component_directive: Option<String>,
     pub props_type: Option<String>,
+    pub prop_names: Vec<String>,
     pub hooks_used: Vec<String>,
     pub jsx_elements: Vec<String>,
 }
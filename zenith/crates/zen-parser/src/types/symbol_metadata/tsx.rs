@@ -13,6 +13,7 @@ pub trait TsxMetadataExt {
     fn set_hooks_used(&mut self, hooks: Vec<String>);
     fn set_jsx_elements(&mut self, elements: Vec<String>);
     fn set_props_type_if_none(&mut self, props_type: Option<String>);
+    fn set_prop_names(&mut self, prop_names: Vec<String>);
 }
 
 impl TsxMetadataExt for SymbolMetadata {
@@ -65,4 +66,8 @@ impl TsxMetadataExt for SymbolMetadata {
             self.props_type = props_type;
         }
     }
+
+    fn set_prop_names(&mut self, prop_names: Vec<String>) {
+        self.prop_names = prop_names;
+    }
 }
@@ -57,7 +57,11 @@ impl Handler for Config {
 
 impl Config {
     pub fn new(name: String) -> Self {
-        Self { name, count: 0, enabled: true }
+        Self {
+            name,
+            count: 0,
+            enabled: true,
+        }
     }
 }
 
@@ -145,7 +149,9 @@ pub trait Configurable {
 
 // 7. GATs (Generic Associated Types)
 pub trait Lending {
-    type Item<'a> where Self: 'a;
+    type Item<'a>
+    where
+        Self: 'a;
     fn lend(&self) -> Self::Item<'_>;
 }
 
@@ -297,3 +303,40 @@ impl std::fmt::Display for &RawValue {
         write!(f, "RawValue")
     }
 }
+
+// 33. Test functions
+#[test]
+fn sync_test_case() {
+    assert_eq!(1 + 1, 2);
+}
+
+#[tokio::test]
+async fn async_test_case() {
+    assert!(true);
+}
+
+// 34. Derive-heavy struct
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DeriveHeavy {
+    pub id: u64,
+    pub label: String,
+}
+
+// 35. Proc-macro export
+#[proc_macro_derive(MyDerive, attributes(my_attr))]
+pub fn derive_my_derive(input: TokenStream) -> TokenStream {
+    input
+}
+
+// 36. Macro with three rules
+macro_rules! three_rules {
+    () => {
+        0
+    };
+    ($val:expr) => {
+        $val
+    };
+    ($first:expr, $second:expr) => {
+        $first + $second
+    };
+}
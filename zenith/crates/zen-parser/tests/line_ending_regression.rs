@@ -0,0 +1,66 @@
+//! Cross-language regression test: CRLF line endings and a leading UTF-8 BOM
+//! must not shift `ParsedItem::name`/`start_line`/`end_line` relative to the
+//! plain-LF fixture, run against every "code" fixture under `tests/fixtures/`.
+
+/// (fixture path, forced file name used for language detection).
+const FIXTURES: &[&str] = &[
+    "sample.rs",
+    "sample.c",
+    "sample.cpp",
+    "sample.cs",
+    "sample.go",
+    "sample.java",
+    "sample.js",
+    "sample.ts",
+    "sample.tsx",
+    "sample.py",
+    "sample.rb",
+    "sample.ex",
+    "sample.hs",
+    "sample.lua",
+    "sample.php",
+];
+
+/// Convert LF line endings to CRLF and prepend a UTF-8 BOM.
+fn to_crlf_with_bom(source: &str) -> String {
+    let crlf = source.replace('\n', "\r\n");
+    format!("\u{feff}{crlf}")
+}
+
+#[test]
+fn crlf_and_bom_do_not_shift_item_names_or_line_numbers() {
+    for fixture in FIXTURES {
+        let path = format!("{}/tests/fixtures/{fixture}", env!("CARGO_MANIFEST_DIR"));
+        let lf_source = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read fixture {path}: {e}"));
+        let crlf_source = to_crlf_with_bom(&lf_source);
+
+        let lf_items = zen_parser::extract_api(&lf_source, fixture)
+            .unwrap_or_else(|e| panic!("failed to extract LF {fixture}: {e}"));
+        let crlf_items = zen_parser::extract_api(&crlf_source, fixture)
+            .unwrap_or_else(|e| panic!("failed to extract CRLF+BOM {fixture}: {e}"));
+
+        assert_eq!(
+            lf_items.len(),
+            crlf_items.len(),
+            "{fixture}: CRLF+BOM produced a different item count"
+        );
+
+        for (lf_item, crlf_item) in lf_items.iter().zip(crlf_items.iter()) {
+            assert_eq!(
+                lf_item.name, crlf_item.name,
+                "{fixture}: item name drifted under CRLF+BOM"
+            );
+            assert_eq!(
+                lf_item.start_line, crlf_item.start_line,
+                "{fixture}: {} start_line drifted under CRLF+BOM",
+                lf_item.name
+            );
+            assert_eq!(
+                lf_item.end_line, crlf_item.end_line,
+                "{fixture}: {} end_line drifted under CRLF+BOM",
+                lf_item.name
+            );
+        }
+    }
+}
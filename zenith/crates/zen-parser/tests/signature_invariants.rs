@@ -0,0 +1,66 @@
+//! Cross-language invariant checks for `ParsedItem::signature`, run against
+//! every "code" fixture under `tests/fixtures/`.
+//!
+//! `normalize_signature` (`extractors::helpers`) is the shared choke point
+//! every rich extractor's signature routes through; this test proves the
+//! invariants it promises actually hold end to end, not just for the raw
+//! strings unit tests feed it directly.
+
+const MAX_SIGNATURE_LEN: usize = 512 + "...".len();
+
+/// (fixture path, forced file name used for language detection).
+const FIXTURES: &[&str] = &[
+    "sample.rs",
+    "sample.c",
+    "sample.cpp",
+    "sample.cs",
+    "sample.go",
+    "sample.java",
+    "sample.js",
+    "sample.ts",
+    "sample.tsx",
+    "sample.py",
+    "sample.rb",
+    "sample.ex",
+    "sample.hs",
+    "sample.lua",
+    "sample.php",
+];
+
+#[test]
+fn signatures_satisfy_normalization_invariants_across_languages() {
+    for fixture in FIXTURES {
+        let path = format!("{}/tests/fixtures/{fixture}", env!("CARGO_MANIFEST_DIR"));
+        let source = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("failed to read fixture {path}: {e}"));
+
+        let items = zen_parser::extract_api(&source, fixture)
+            .unwrap_or_else(|e| panic!("failed to extract {fixture}: {e}"));
+        assert!(!items.is_empty(), "{fixture} produced no items");
+
+        for item in &items {
+            let sig = &item.signature;
+            assert!(
+                !sig.ends_with(['{', ';', ':']),
+                "{fixture}: {} signature ends with a brace/semicolon/colon: {sig:?}",
+                item.name
+            );
+            assert!(
+                !sig.contains('\n') && !sig.contains('\t'),
+                "{fixture}: {} signature has unnormalized whitespace: {sig:?}",
+                item.name
+            );
+            assert!(
+                !sig.contains("  "),
+                "{fixture}: {} signature has collapsed-whitespace runs: {sig:?}",
+                item.name
+            );
+            assert!(
+                sig.chars().count() <= MAX_SIGNATURE_LEN,
+                "{fixture}: {} signature exceeds the {MAX_SIGNATURE_LEN}-char cap: {} chars",
+                item.name,
+                sig.chars().count()
+            );
+        }
+    }
+}
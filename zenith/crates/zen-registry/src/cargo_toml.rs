@@ -0,0 +1,129 @@
+//! Bulk dependency resolution from a `Cargo.toml` manifest.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use toml::Value as TomlValue;
+
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
+
+/// Sections of a `Cargo.toml` that declare crates.io dependencies.
+const DEPENDENCY_SECTIONS: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Maximum number of concurrent crates.io lookups.
+const MAX_CONCURRENT_LOOKUPS: usize = 8;
+
+impl RegistryClient {
+    /// Resolve every dependency declared in a `Cargo.toml`'s `[dependencies]`,
+    /// `[dev-dependencies]`, and `[build-dependencies]` sections against
+    /// crates.io.
+    ///
+    /// Lookups run concurrently, capped at 8 in flight at a time. Results are
+    /// returned in the order the dependencies appear in the file, regardless
+    /// of which lookup finishes first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::Parse`] if `path` can't be read or parsed as
+    /// TOML, or the underlying [`RegistryError`] from the first dependency
+    /// lookup that fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal lookup semaphore is closed, which never
+    /// happens since it is never explicitly closed and is dropped only
+    /// after every acquire has completed.
+    pub async fn resolve_cargo_toml(&self, path: &Path) -> Result<Vec<PackageInfo>, RegistryError> {
+        let raw = std::fs::read_to_string(path).map_err(|error| {
+            RegistryError::Parse(format!("failed to read {}: {error}", path.display()))
+        })?;
+        let document: TomlValue = toml::from_str(&raw).map_err(|error| {
+            RegistryError::Parse(format!("failed to parse {}: {error}", path.display()))
+        })?;
+
+        let names = dependency_names(&document);
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT_LOOKUPS));
+
+        let tasks: Vec<_> = names
+            .into_iter()
+            .map(|name| {
+                let semaphore = Arc::clone(&semaphore);
+                let client = self.clone();
+                tokio::spawn(async move {
+                    let _permit = semaphore
+                        .acquire_owned()
+                        .await
+                        .expect("semaphore is never closed");
+                    client.search_crates_io(&name, 1).await
+                })
+            })
+            .collect();
+
+        let mut results = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            let matches = task
+                .await
+                .map_err(|error| RegistryError::Parse(error.to_string()))??;
+            if let Some(package) = matches.into_iter().next() {
+                results.push(package);
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Collect dependency names from a parsed `Cargo.toml`, in file order.
+fn dependency_names(document: &TomlValue) -> Vec<String> {
+    let mut names = Vec::new();
+    for section in DEPENDENCY_SECTIONS {
+        if let Some(table) = document.get(section).and_then(TomlValue::as_table) {
+            names.extend(table.keys().cloned());
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_MANIFEST: &str = r#"
+[package]
+name = "sample"
+version = "0.1.0"
+
+[dependencies]
+tokio = { version = "1", features = ["full"] }
+serde = "1"
+
+[dev-dependencies]
+pretty_assertions = "1"
+
+[build-dependencies]
+cc = "1"
+"#;
+
+    #[test]
+    fn collects_dependency_names_in_file_order() {
+        let document: TomlValue = toml::from_str(SAMPLE_MANIFEST).unwrap();
+        let names = dependency_names(&document);
+        assert_eq!(names, vec!["tokio", "serde", "pretty_assertions", "cc"]);
+    }
+
+    #[test]
+    fn ignores_sections_that_are_absent() {
+        let document: TomlValue = toml::from_str(
+            r#"
+[package]
+name = "sample"
+
+[dependencies]
+serde = "1"
+"#,
+        )
+        .unwrap();
+        let names = dependency_names(&document);
+        assert_eq!(names, vec!["serde"]);
+    }
+}
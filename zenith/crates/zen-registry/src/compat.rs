@@ -0,0 +1,153 @@
+//! Version-requirement compatibility analysis over two packages' dependency
+//! lists.
+
+use std::collections::HashMap;
+
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+
+use crate::Dependency;
+
+/// A shared dependency whose version requirements can't both be satisfied by
+/// the same version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conflict {
+    /// Name of the shared dependency.
+    pub dependency: String,
+    /// Requirement declared by the first package.
+    pub package_a_requirement: String,
+    /// Requirement declared by the second package.
+    pub package_b_requirement: String,
+}
+
+/// Outcome of comparing two packages' dependency lists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompatResult {
+    /// `true` if no conflicting shared dependency was found.
+    pub compatible: bool,
+    /// Shared dependencies with conflicting version requirements.
+    pub conflicts: Vec<Conflict>,
+}
+
+/// Compare two packages' dependency lists and report shared dependencies with
+/// conflicting version requirements.
+///
+/// This compares each package's *declared* dependencies (as returned by
+/// [`crate::RegistryClient::get_dependencies`]), not a fully resolved
+/// transitive closure.
+#[must_use]
+pub fn compare_dependency_trees(deps_a: &[Dependency], deps_b: &[Dependency]) -> CompatResult {
+    let by_name_b: HashMap<&str, &Dependency> =
+        deps_b.iter().map(|d| (d.name.as_str(), d)).collect();
+
+    let mut conflicts = Vec::new();
+    for dep_a in deps_a {
+        let Some(shared) = by_name_b.get(dep_a.name.as_str()) else {
+            continue;
+        };
+        if dep_a.version_req == shared.version_req {
+            continue;
+        }
+        if requirements_conflict(&dep_a.version_req, &shared.version_req) {
+            conflicts.push(Conflict {
+                dependency: dep_a.name.clone(),
+                package_a_requirement: dep_a.version_req.clone(),
+                package_b_requirement: shared.version_req.clone(),
+            });
+        }
+    }
+
+    CompatResult {
+        compatible: conflicts.is_empty(),
+        conflicts,
+    }
+}
+
+/// Whether two semver requirement strings can never both be satisfied by the
+/// same concrete version.
+///
+/// Builds a candidate version from each requirement's own lower bound and
+/// checks whether it satisfies the *other* requirement. This isn't a full
+/// range intersection, but it catches the common case — two requirements
+/// pinned to incompatible major/minor lines (e.g. `^1.0` vs `^2.0`).
+/// Unparseable requirements are treated as non-conflicting, since we can't
+/// reason about them.
+fn requirements_conflict(req_a: &str, req_b: &str) -> bool {
+    let (Ok(parsed_a), Ok(parsed_b)) = (VersionReq::parse(req_a), VersionReq::parse(req_b)) else {
+        return false;
+    };
+
+    let (Some(candidate_a), Some(candidate_b)) = (
+        lower_bound_candidate(&parsed_a),
+        lower_bound_candidate(&parsed_b),
+    ) else {
+        return false;
+    };
+
+    !parsed_b.matches(&candidate_a) && !parsed_a.matches(&candidate_b)
+}
+
+/// Build a concrete version from a requirement's first comparator, defaulting
+/// missing minor/patch components to zero.
+fn lower_bound_candidate(req: &VersionReq) -> Option<Version> {
+    let comparator = req.comparators.first()?;
+    Some(Version::new(
+        comparator.major,
+        comparator.minor.unwrap_or(0),
+        comparator.patch.unwrap_or(0),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dep(name: &str, version_req: &str) -> Dependency {
+        Dependency {
+            name: name.to_string(),
+            version_req: version_req.to_string(),
+        }
+    }
+
+    #[test]
+    fn reports_no_conflicts_for_disjoint_dependency_sets() {
+        let deps_a = vec![dep("tokio", "^1.0")];
+        let deps_b = vec![dep("axum", "^0.8")];
+
+        let result = compare_dependency_trees(&deps_a, &deps_b);
+        assert!(result.compatible);
+        assert!(result.conflicts.is_empty());
+    }
+
+    #[test]
+    fn reports_no_conflict_for_shared_dependency_with_compatible_requirements() {
+        let deps_a = vec![dep("serde", "^1.0")];
+        let deps_b = vec![dep("serde", "^1.2")];
+
+        let result = compare_dependency_trees(&deps_a, &deps_b);
+        assert!(result.compatible);
+        assert!(result.conflicts.is_empty());
+    }
+
+    #[test]
+    fn reports_conflict_for_shared_dependency_with_incompatible_major_versions() {
+        let deps_a = vec![dep("http", "^1.0")];
+        let deps_b = vec![dep("http", "^0.2")];
+
+        let result = compare_dependency_trees(&deps_a, &deps_b);
+        assert!(!result.compatible);
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(result.conflicts[0].dependency, "http");
+        assert_eq!(result.conflicts[0].package_a_requirement, "^1.0");
+        assert_eq!(result.conflicts[0].package_b_requirement, "^0.2");
+    }
+
+    #[test]
+    fn ignores_unparseable_requirements() {
+        let deps_a = vec![dep("weird", "not-a-semver-req")];
+        let deps_b = vec![dep("weird", "also-not-one")];
+
+        let result = compare_dependency_trees(&deps_a, &deps_b);
+        assert!(result.compatible);
+    }
+}
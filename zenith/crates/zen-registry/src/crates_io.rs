@@ -1,6 +1,6 @@
 //! crates.io registry client.
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{Dependency, PackageInfo, RegistryClient, error::RegistryError};
 
 #[derive(serde::Deserialize)]
 struct CratesResponse {
@@ -16,6 +16,34 @@ struct CrateInfo {
     license: Option<String>,
     repository: Option<String>,
     homepage: Option<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
+    #[serde(default)]
+    rust_version: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct DependenciesResponse {
+    dependencies: Vec<DependencyInfo>,
+}
+
+#[derive(serde::Deserialize)]
+struct DependencyInfo {
+    crate_id: String,
+    req: String,
+    kind: String,
+    optional: bool,
+}
+
+#[derive(serde::Deserialize)]
+struct CrateVersionResponse {
+    version: CrateVersionInfo,
+}
+
+#[derive(serde::Deserialize)]
+struct CrateVersionInfo {
+    #[serde(default)]
+    rust_version: Option<String>,
 }
 
 impl RegistryClient {
@@ -35,7 +63,7 @@ impl RegistryClient {
             "https://crates.io/api/v1/crates?q={}&per_page={limit}",
             urlencoding::encode(query)
         );
-        let resp = check_response(self.http.get(&url).send().await?).await?;
+        let resp = self.get(&url).await?;
 
         let data: CratesResponse = resp.json().await?;
         Ok(data
@@ -50,6 +78,64 @@ impl RegistryClient {
                 license: c.license,
                 repository: c.repository,
                 homepage: c.homepage,
+                keywords: c.keywords,
+                msrv: c.rust_version,
+            })
+            .collect())
+    }
+
+    /// Fetch the Minimum Supported Rust Version of `name`@`version` from crates.io.
+    ///
+    /// Returns `Ok(None)` when the registry doesn't record a `rust_version`
+    /// for that release (common for older publishes).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] if the HTTP request fails, the registry
+    /// returns a non-success status, or the response cannot be parsed.
+    pub async fn get_msrv(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> Result<Option<String>, RegistryError> {
+        let url = format!(
+            "https://crates.io/api/v1/crates/{}/{}",
+            urlencoding::encode(name),
+            urlencoding::encode(version)
+        );
+        let resp = self.get(&url).await?;
+
+        let data: CrateVersionResponse = resp.json().await?;
+        Ok(data.version.rust_version)
+    }
+
+    /// Fetch the normal (non-dev, non-optional) dependencies of `name`@`version`
+    /// from crates.io.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] if the HTTP request fails, the registry
+    /// returns a non-success status, or the response cannot be parsed.
+    pub async fn dependencies_crates_io(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> Result<Vec<Dependency>, RegistryError> {
+        let url = format!(
+            "https://crates.io/api/v1/crates/{}/{}/dependencies",
+            urlencoding::encode(name),
+            urlencoding::encode(version)
+        );
+        let resp = self.get(&url).await?;
+
+        let data: DependenciesResponse = resp.json().await?;
+        Ok(data
+            .dependencies
+            .into_iter()
+            .filter(|d| d.kind == "normal" && !d.optional)
+            .map(|d| Dependency {
+                name: d.crate_id,
+                version_req: d.req,
             })
             .collect())
     }
@@ -68,7 +154,9 @@ mod tests {
                 "downloads": 200000000,
                 "license": "MIT",
                 "repository": "https://github.com/tokio-rs/tokio",
-                "homepage": "https://tokio.rs"
+                "homepage": "https://tokio.rs",
+                "keywords": ["async", "io", "networking"],
+                "rust_version": "1.70"
             },
             {
                 "name": "tokio-util",
@@ -113,6 +201,8 @@ mod tests {
                 license: c.license,
                 repository: c.repository,
                 homepage: c.homepage,
+                keywords: c.keywords,
+                msrv: c.rust_version,
             })
             .collect();
 
@@ -120,5 +210,72 @@ mod tests {
         assert_eq!(packages[0].ecosystem, "rust");
         assert_eq!(packages[0].name, "tokio");
         assert!(packages[1].homepage.is_none());
+        assert!(!packages[0].keywords.is_empty());
+        assert_eq!(packages[0].keywords, vec!["async", "io", "networking"]);
+        assert!(packages[1].keywords.is_empty());
+        assert_eq!(packages[0].msrv.as_deref(), Some("1.70"));
+        assert!(packages[1].msrv.is_none());
+    }
+
+    const VERSION_FIXTURE: &str = r#"{
+        "version": {
+            "num": "1.49.0",
+            "rust_version": "1.70"
+        }
+    }"#;
+
+    #[test]
+    fn parses_msrv_from_version_response() {
+        let data: CrateVersionResponse = serde_json::from_str(VERSION_FIXTURE).unwrap();
+        assert_eq!(data.version.rust_version.as_deref(), Some("1.70"));
+    }
+
+    #[test]
+    fn missing_rust_version_parses_as_none() {
+        let data: CrateVersionResponse =
+            serde_json::from_str(r#"{"version": {"num": "0.1.0"}}"#).unwrap();
+        assert!(data.version.rust_version.is_none());
+    }
+
+    #[tokio::test]
+    #[ignore] // requires network
+    async fn get_msrv_returns_non_none_for_tokio_1_49_0() {
+        let client = RegistryClient::new();
+        let msrv = client.get_msrv("tokio", "1.49.0").await.unwrap();
+        assert!(msrv.is_some());
+    }
+
+    const DEPENDENCIES_FIXTURE: &str = r#"{
+        "dependencies": [
+            {"crate_id": "mio", "req": "^1.0", "kind": "normal", "optional": false},
+            {"crate_id": "criterion", "req": "^0.5", "kind": "dev", "optional": false},
+            {"crate_id": "tracing", "req": "^0.1", "kind": "normal", "optional": true}
+        ]
+    }"#;
+
+    #[test]
+    fn parse_dependencies_response() {
+        let data: DependenciesResponse = serde_json::from_str(DEPENDENCIES_FIXTURE).unwrap();
+        assert_eq!(data.dependencies.len(), 3);
+        assert_eq!(data.dependencies[0].crate_id, "mio");
+        assert_eq!(data.dependencies[0].req, "^1.0");
+    }
+
+    #[test]
+    fn filters_out_dev_and_optional_dependencies() {
+        let data: DependenciesResponse = serde_json::from_str(DEPENDENCIES_FIXTURE).unwrap();
+        let deps: Vec<Dependency> = data
+            .dependencies
+            .into_iter()
+            .filter(|d| d.kind == "normal" && !d.optional)
+            .map(|d| Dependency {
+                name: d.crate_id,
+                version_req: d.req,
+            })
+            .collect();
+
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].name, "mio");
+        assert_eq!(deps[0].version_req, "^1.0");
     }
 }
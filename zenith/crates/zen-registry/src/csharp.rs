@@ -1,6 +1,6 @@
 //! `NuGet` (C#/.NET) registry client.
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 #[derive(serde::Deserialize)]
 struct NuGetSearchResponse {
@@ -57,7 +57,7 @@ impl RegistryClient {
             "https://azuresearch-usnc.nuget.org/query?q={}&take={limit}&semVerLevel=2.0.0",
             urlencoding::encode(query)
         );
-        let resp = check_response(self.http.get(&url).send().await?).await?;
+        let resp = self.get(&url).await?;
 
         let data: NuGetSearchResponse = resp.json().await?;
         Ok(data
@@ -75,6 +75,8 @@ impl RegistryClient {
                     license,
                     repository,
                     homepage,
+                    keywords: Vec::new(),
+                    msrv: None,
                 }
             })
             .collect())
@@ -187,6 +189,8 @@ mod tests {
                     license,
                     repository,
                     homepage,
+                    keywords: Vec::new(),
+                    msrv: None,
                 }
             })
             .collect();
@@ -5,7 +5,7 @@
 //! paths). Keyword searches return empty results. Download counts are not
 //! available — results use `downloads: 0`.
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 /// Encode a Go module path per the module proxy protocol.
 ///
@@ -60,12 +60,12 @@ impl RegistryClient {
     async fn lookup_go_module(&self, module_path: &str) -> Result<Vec<PackageInfo>, RegistryError> {
         let encoded = encode_go_module_path(module_path);
         let url = format!("https://proxy.golang.org/{encoded}/@latest");
-        let resp = self.http.get(&url).send().await?;
-
-        if resp.status() == 404 || resp.status() == 410 {
-            return Ok(Vec::new());
-        }
-        let resp = check_response(resp).await?;
+        let resp = match self.get(&url).await {
+            Err(RegistryError::Api {
+                status: 404 | 410, ..
+            }) => return Ok(Vec::new()),
+            other => other?,
+        };
 
         let info: GoProxyInfo = resp.json().await?;
         Ok(vec![PackageInfo {
@@ -77,6 +77,8 @@ impl RegistryClient {
             license: None,
             repository: None,
             homepage: Some(format!("https://pkg.go.dev/{module_path}")),
+            keywords: Vec::new(),
+            msrv: None,
         }])
     }
 }
@@ -108,6 +110,8 @@ mod tests {
             license: None,
             repository: None,
             homepage: Some("https://pkg.go.dev/github.com/gin-gonic/gin".to_string()),
+            keywords: Vec::new(),
+            msrv: None,
         };
 
         assert_eq!(pkg.ecosystem, "go");
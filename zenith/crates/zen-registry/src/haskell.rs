@@ -5,7 +5,7 @@
 //! then fetching metadata for the latest version. Download counts are not
 //! available — results use `downloads: 0`.
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 /// Preferred versions response — lists normal and deprecated versions.
 #[derive(serde::Deserialize)]
@@ -47,12 +47,10 @@ impl RegistryClient {
 
         // Step 1: get version list
         let pref_url = format!("https://hackage.haskell.org/package/{encoded}/preferred.json");
-        let resp = self.http.get(&pref_url).send().await?;
-
-        if resp.status() == 404 {
-            return Ok(Vec::new());
-        }
-        let resp = check_response(resp).await?;
+        let resp = match self.get(&pref_url).await {
+            Err(RegistryError::Api { status: 404, .. }) => return Ok(Vec::new()),
+            other => other?,
+        };
 
         let preferred: HackagePreferred = resp.json().await.map_err(|e| {
             RegistryError::Parse(format!("hackage preferred.json parse error: {e}"))
@@ -104,6 +102,8 @@ impl RegistryClient {
             license,
             repository,
             homepage,
+            keywords: Vec::new(),
+            msrv: None,
         }])
     }
 }
@@ -174,6 +174,8 @@ mod tests {
             license: meta.license,
             repository,
             homepage,
+            keywords: Vec::new(),
+            msrv: None,
         };
 
         assert_eq!(pkg.ecosystem, "haskell");
@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 #[derive(serde::Deserialize)]
 struct HexPackage {
@@ -41,7 +41,7 @@ impl RegistryClient {
             "https://hex.pm/api/packages?search={}&sort=downloads&page=1&per_page={limit}",
             urlencoding::encode(query)
         );
-        let resp = check_response(self.http.get(&url).send().await?).await?;
+        let resp = self.get(&url).await?;
 
         let data: Vec<HexPackage> = resp.json().await?;
         Ok(data
@@ -63,6 +63,8 @@ impl RegistryClient {
                     }),
                     homepage: links
                         .and_then(|l| l.get("Homepage").or_else(|| l.get("homepage")).cloned()),
+                    keywords: Vec::new(),
+                    msrv: None,
                 }
             })
             .collect())
@@ -127,6 +129,8 @@ mod tests {
                     license: p.meta.licenses.as_ref().and_then(|l| l.first().cloned()),
                     repository: links.and_then(|l| l.get("GitHub").cloned()),
                     homepage: links.and_then(|l| l.get("Homepage").cloned()),
+                    keywords: Vec::new(),
+                    msrv: None,
                 }
             })
             .collect();
@@ -1,11 +1,88 @@
 //! Shared HTTP response helpers for registry clients.
 //!
 //! Centralizes status-code checks (429 rate limiting with `Retry-After`
-//! parsing, non-success → [`RegistryError::Api`]) so individual registry
-//! modules stay focused on request construction and response mapping.
+//! parsing, non-success → [`RegistryError::Api`]) and retry-with-backoff
+//! ([`get_with_retry`]) so individual registry modules stay focused on
+//! request construction and response mapping.
+
+use std::time::Duration;
 
 use crate::error::RegistryError;
 
+/// Retry/backoff policy for [`get_with_retry`], set via
+/// [`RegistryClientBuilder::retry_config`](crate::RegistryClientBuilder::retry_config).
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Retry attempts after the first failed request. `0` disables retries.
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries (doubled per
+    /// attempt). Ignored for 429 responses that carry a `Retry-After`
+    /// header — that value is honored instead.
+    pub base_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+/// GET `url`, retrying on transient failures with exponential backoff.
+///
+/// Retryable: HTTP 429 (waits `Retry-After` if present, else the backoff
+/// delay) and 5xx, plus network-level errors (timeouts, connection
+/// failures). Fatal (returned immediately, no retry): 404 and other 4xx.
+///
+/// # Errors
+///
+/// Returns the last encountered [`RegistryError`] once `retry.max_retries`
+/// attempts have been exhausted.
+pub async fn get_with_retry(
+    http: &reqwest::Client,
+    url: &str,
+    retry: RetryConfig,
+) -> Result<reqwest::Response, RegistryError> {
+    let mut attempt = 0;
+    loop {
+        let outcome = match http.get(url).send().await {
+            Ok(resp) => check_response(resp).await,
+            Err(error) => Err(RegistryError::from(error)),
+        };
+
+        match outcome {
+            Ok(resp) => return Ok(resp),
+            Err(error) if attempt < retry.max_retries && is_retryable(&error) => {
+                tokio::time::sleep(retry_delay(&error, retry.base_delay, attempt)).await;
+                attempt += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// Whether `error` represents a transient condition worth retrying.
+fn is_retryable(error: &RegistryError) -> bool {
+    match error {
+        RegistryError::RateLimited { .. } => true,
+        RegistryError::Api { status, .. } => *status >= 500,
+        RegistryError::Http(e) => e.is_timeout() || e.is_connect(),
+        RegistryError::Parse(_) | RegistryError::UnsupportedEcosystem(_) => false,
+    }
+}
+
+/// Delay before the next retry attempt: the `Retry-After` value for 429s,
+/// otherwise exponential backoff from `base_delay`.
+fn retry_delay(error: &RegistryError, base_delay: Duration, attempt: u32) -> Duration {
+    if let RegistryError::RateLimited { retry_after_secs } = error {
+        Duration::from_secs(*retry_after_secs)
+    } else {
+        base_delay * 2u32.pow(attempt)
+    }
+}
+
 /// Check an HTTP response for common error conditions.
 ///
 /// Returns the response unchanged on success. Handles:
@@ -111,4 +188,41 @@ mod tests {
         let resp = mock_response(200);
         assert!(check_response(resp).await.is_ok());
     }
+
+    #[tokio::test]
+    async fn get_with_retry_recovers_from_a_transient_503() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/pkg"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/pkg"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let http = reqwest::Client::new();
+        let url = format!("{}/pkg", server.uri());
+        let resp = get_with_retry(
+            &http,
+            &url,
+            RetryConfig {
+                max_retries: 1,
+                base_delay: Duration::from_millis(1),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resp.text().await.unwrap(), "ok");
+    }
 }
@@ -3,7 +3,7 @@
 //! Download counts are not available via the Maven Central search API —
 //! results use `downloads: 0`.
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 #[derive(serde::Deserialize)]
 struct MavenSearchResponse {
@@ -47,7 +47,7 @@ impl RegistryClient {
             "https://search.maven.org/solrsearch/select?q={}&rows={limit}&wt=json",
             urlencoding::encode(query)
         );
-        let resp = check_response(self.http.get(&url).send().await?).await?;
+        let resp = self.get(&url).await?;
 
         let data: MavenSearchResponse = resp.json().await?;
         Ok(data
@@ -69,6 +69,8 @@ impl RegistryClient {
                     license: None,
                     repository: None,
                     homepage,
+                    keywords: Vec::new(),
+                    msrv: None,
                 }
             })
             .collect())
@@ -133,6 +135,8 @@ mod tests {
                     license: None,
                     repository: None,
                     homepage,
+                    keywords: Vec::new(),
+                    msrv: None,
                 }
             })
             .collect();
@@ -16,6 +16,8 @@
 //! - hackage.haskell.org (Haskell)
 //! - luarocks.org (Lua/Neovim)
 
+pub mod cargo_toml;
+pub mod compat;
 pub mod crates_io;
 pub mod csharp;
 pub mod go;
@@ -31,7 +33,9 @@ pub mod ruby;
 mod error;
 mod http;
 
+pub use compat::{CompatResult, Conflict, compare_dependency_trees};
 pub use error::RegistryError;
+pub use http::RetryConfig;
 
 use serde::{Deserialize, Serialize};
 
@@ -56,13 +60,42 @@ pub struct PackageInfo {
     pub repository: Option<String>,
     /// Project homepage URL.
     pub homepage: Option<String>,
+    /// Registry-declared keywords/tags (e.g. crates.io `keywords`, npm
+    /// `keywords`, `PyPI` `info.keywords`). Empty when the ecosystem doesn't
+    /// expose keywords or none are set.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Minimum Supported Rust Version, when the registry records one (only
+    /// crates.io does, via the per-version `rust_version` field). `None` for
+    /// every other ecosystem and for crates.io releases published before
+    /// `rust_version` existed.
+    #[serde(default)]
+    pub msrv: Option<String>,
+}
+
+/// A single declared dependency: `name` required at `version_req` (a
+/// registry-native semver requirement string, e.g. `^1.0` or `~2.3.0`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dependency {
+    /// Dependency package name.
+    pub name: String,
+    /// Version requirement as declared by the depending package's manifest.
+    pub version_req: String,
 }
 
 // ── Client ─────────────────────────────────────────────────────────
 
+/// Default `User-Agent` sent with every registry request.
+const DEFAULT_USER_AGENT: &str = "zenith/0.1";
+
+/// Default per-request timeout.
+const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
 /// HTTP client for querying package registries across ecosystems.
+#[derive(Clone)]
 pub struct RegistryClient {
     http: reqwest::Client,
+    retry: RetryConfig,
 }
 
 impl Default for RegistryClient {
@@ -79,13 +112,28 @@ impl RegistryClient {
     /// Panics if the underlying `reqwest::Client` fails to build.
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            http: reqwest::Client::builder()
-                .user_agent("zenith/0.1")
-                .timeout(std::time::Duration::from_secs(10))
-                .build()
-                .expect("reqwest client should build"),
-        }
+        RegistryClientBuilder::new()
+            .build()
+            .expect("reqwest client should build")
+    }
+
+    /// Start building a client with a custom `User-Agent`, timeout, or
+    /// proxy.
+    #[must_use]
+    pub fn builder() -> RegistryClientBuilder {
+        RegistryClientBuilder::new()
+    }
+
+    /// GET `url`, retrying transient failures per the client's configured
+    /// [`RetryConfig`]. Used by every ecosystem client instead of calling
+    /// `self.http` directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] if the request ultimately fails or the
+    /// registry returns a non-success status after retries are exhausted.
+    pub(crate) async fn get(&self, url: &str) -> Result<reqwest::Response, RegistryError> {
+        http::get_with_retry(&self.http, url, self.retry).await
     }
 
     /// Search all registries concurrently. Returns merged results sorted by
@@ -160,6 +208,132 @@ impl RegistryClient {
             _ => Err(RegistryError::UnsupportedEcosystem(ecosystem.to_string())),
         }
     }
+
+    /// Search a specific ecosystem for packages tagged with `keyword`, using
+    /// each registry's keyword-qualified search syntax (e.g. `keywords:tokio`
+    /// on crates.io) rather than a plain full-text query.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::UnsupportedEcosystem`] for ecosystems without
+    /// keyword search support, or the usual HTTP/parse errors.
+    pub async fn search_by_keyword(
+        &self,
+        ecosystem: &str,
+        keyword: &str,
+        limit: usize,
+    ) -> Result<Vec<PackageInfo>, RegistryError> {
+        match ecosystem {
+            "rust" | "cargo" => {
+                self.search_crates_io(&format!("keywords:{keyword}"), limit)
+                    .await
+            }
+            "npm" | "javascript" | "typescript" => {
+                self.search_npm(&format!("keywords:{keyword}"), limit).await
+            }
+            _ => Err(RegistryError::UnsupportedEcosystem(ecosystem.to_string())),
+        }
+    }
+
+    /// Fetch the direct (non-dev, non-optional) dependencies declared by
+    /// `name`@`version` in the given ecosystem.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::UnsupportedEcosystem`] for ecosystems without
+    /// dependency-listing support yet, or the usual HTTP/parse errors.
+    pub async fn get_dependencies(
+        &self,
+        ecosystem: &str,
+        name: &str,
+        version: &str,
+    ) -> Result<Vec<Dependency>, RegistryError> {
+        match ecosystem {
+            "rust" | "cargo" => self.dependencies_crates_io(name, version).await,
+            _ => Err(RegistryError::UnsupportedEcosystem(ecosystem.to_string())),
+        }
+    }
+}
+
+/// Builder for [`RegistryClient`], for setting a custom `User-Agent`,
+/// timeout, or outbound proxy (e.g. for corporate networks).
+pub struct RegistryClientBuilder {
+    user_agent: String,
+    timeout: std::time::Duration,
+    proxy: Option<String>,
+    retry: RetryConfig,
+}
+
+impl Default for RegistryClientBuilder {
+    fn default() -> Self {
+        Self {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            timeout: DEFAULT_TIMEOUT,
+            proxy: None,
+            retry: RetryConfig::default(),
+        }
+    }
+}
+
+impl RegistryClientBuilder {
+    /// Start a new builder with the same defaults as [`RegistryClient::new`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the `User-Agent` header sent with every request.
+    ///
+    /// crates.io requires a UA that identifies the client and provides a
+    /// contact (see <https://crates.io/policies>); the default value does
+    /// not satisfy that on its own.
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Override the per-request timeout.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Route all requests through the given proxy (e.g.
+    /// `http://proxy.example.com:8080`).
+    #[must_use]
+    pub fn proxy(mut self, proxy_url: impl Into<String>) -> Self {
+        self.proxy = Some(proxy_url.into());
+        self
+    }
+
+    /// Override the retry/backoff policy applied to every request (default:
+    /// 3 retries, 200ms base delay).
+    #[must_use]
+    pub const fn retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Build the [`RegistryClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::Http`] if the proxy URL is invalid or the
+    /// underlying `reqwest::Client` fails to build.
+    pub fn build(self) -> Result<RegistryClient, RegistryError> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(self.user_agent)
+            .timeout(self.timeout);
+        if let Some(proxy_url) = self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy_url)?);
+        }
+        Ok(RegistryClient {
+            http: builder.build()?,
+            retry: self.retry,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -177,6 +351,8 @@ mod tests {
             license: Some("MIT".to_string()),
             repository: Some("https://github.com/test/test".to_string()),
             homepage: None,
+            keywords: vec!["async".to_string(), "testing".to_string()],
+            msrv: Some("1.70".to_string()),
         };
 
         let json = serde_json::to_string(&pkg).unwrap();
@@ -184,6 +360,8 @@ mod tests {
         assert_eq!(deserialized.name, "test-pkg");
         assert_eq!(deserialized.downloads, 42);
         assert!(deserialized.homepage.is_none());
+        assert_eq!(deserialized.keywords, vec!["async", "testing"]);
+        assert_eq!(deserialized.msrv.as_deref(), Some("1.70"));
     }
 
     #[test]
@@ -191,6 +369,16 @@ mod tests {
         let _client = RegistryClient::default();
     }
 
+    #[test]
+    fn builder_with_custom_user_agent_and_proxy() {
+        let client = RegistryClient::builder()
+            .user_agent("zenith-test/1.0 (contact@example.com)")
+            .timeout(std::time::Duration::from_secs(5))
+            .proxy("http://127.0.0.1:9999")
+            .build();
+        assert!(client.is_ok());
+    }
+
     #[tokio::test]
     async fn search_unsupported_ecosystem() {
         let client = RegistryClient::new();
@@ -132,18 +132,10 @@ impl RegistryClient {
             "https://api.github.com/search/repositories?q={}&sort=stars&per_page={limit}",
             urlencoding::encode(search_query)
         );
-        let Ok(resp) = self.http.get(&url).send().await else {
+        let Ok(resp) = self.get(&url).await else {
             tracing::warn!(query = search_query, "github repo search request failed");
             return Vec::new();
         };
-        if !resp.status().is_success() {
-            tracing::warn!(
-                query = search_query,
-                status = resp.status().as_u16(),
-                "github repo search returned non-success status"
-            );
-            return Vec::new();
-        }
         let Ok(data) = resp.json::<GitHubSearchResponse>().await else {
             tracing::warn!(
                 query = search_query,
@@ -162,6 +154,8 @@ impl RegistryClient {
                 license: repo.license.and_then(|l| l.spdx_id),
                 repository: Some(repo.html_url.clone()),
                 homepage: Some(repo.html_url),
+                keywords: Vec::new(),
+                msrv: None,
             })
             .collect()
     }
@@ -226,6 +220,8 @@ mod tests {
                 license: repo.license.and_then(|l| l.spdx_id),
                 repository: Some(repo.html_url.clone()),
                 homepage: Some(repo.html_url),
+                keywords: Vec::new(),
+                msrv: None,
             })
             .collect();
 
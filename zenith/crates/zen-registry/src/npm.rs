@@ -1,6 +1,6 @@
 //! npm registry client.
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 #[derive(serde::Deserialize)]
 struct NpmSearchResponse {
@@ -20,6 +20,8 @@ struct NpmPackage {
     links: Option<NpmLinks>,
     #[serde(default)]
     license: Option<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
 }
 
 #[derive(serde::Deserialize)]
@@ -50,7 +52,7 @@ impl RegistryClient {
             "https://registry.npmjs.org/-/v1/search?text={}&size={limit}",
             urlencoding::encode(query)
         );
-        let resp = check_response(self.http.get(&url).send().await?).await?;
+        let resp = self.get(&url).await?;
 
         let data: NpmSearchResponse = resp.json().await?;
         let names: Vec<&str> = data
@@ -75,6 +77,8 @@ impl RegistryClient {
                     license: obj.package.license,
                     repository: links.and_then(|l| l.repository.clone()),
                     homepage: links.and_then(|l| l.homepage.clone()),
+                    keywords: obj.package.keywords,
+                    msrv: None,
                 }
             })
             .collect();
@@ -127,6 +131,7 @@ mod tests {
                     "version": "4.21.2",
                     "description": "Fast, unopinionated, minimalist web framework",
                     "license": "MIT",
+                    "keywords": ["express", "framework", "web"],
                     "links": {
                         "repository": "https://github.com/expressjs/express",
                         "homepage": "https://expressjs.com"
@@ -176,6 +181,8 @@ mod tests {
                     license: obj.package.license,
                     repository: links.and_then(|l| l.repository.clone()),
                     homepage: links.and_then(|l| l.homepage.clone()),
+                    keywords: obj.package.keywords,
+                    msrv: None,
                 }
             })
             .collect();
@@ -183,5 +190,7 @@ mod tests {
         assert_eq!(packages[0].ecosystem, "npm");
         assert_eq!(packages[0].name, "express");
         assert!(packages[1].license.is_none());
+        assert_eq!(packages[0].keywords, vec!["express", "framework", "web"]);
+        assert!(packages[1].keywords.is_empty());
     }
 }
@@ -1,6 +1,6 @@
 //! Packagist (PHP) registry client.
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 #[derive(serde::Deserialize)]
 struct PackagistSearchResponse {
@@ -47,7 +47,7 @@ impl RegistryClient {
             "https://packagist.org/search.json?q={}&per_page={limit}",
             urlencoding::encode(query)
         );
-        let resp = check_response(self.http.get(&url).send().await?).await?;
+        let resp = self.get(&url).await?;
 
         let data: PackagistSearchResponse = resp.json().await?;
         let results: Vec<PackagistResult> = data.results.into_iter().take(limit).collect();
@@ -100,6 +100,8 @@ impl RegistryClient {
                 license,
                 repository: result.repository,
                 homepage: result.url,
+                keywords: Vec::new(),
+                msrv: None,
             })
             .collect();
 
@@ -185,6 +187,8 @@ mod tests {
                 license: Some("MIT".to_string()),
                 repository: r.repository,
                 homepage: r.url,
+                keywords: Vec::new(),
+                msrv: None,
             })
             .collect();
 
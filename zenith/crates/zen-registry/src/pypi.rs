@@ -6,7 +6,7 @@
 
 use std::collections::HashMap;
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 #[derive(serde::Deserialize)]
 struct PyPiResponse {
@@ -21,6 +21,8 @@ struct PyPiInfo {
     license: Option<String>,
     home_page: Option<String>,
     project_urls: Option<HashMap<String, String>>,
+    #[serde(default)]
+    keywords: Option<String>,
 }
 
 impl RegistryClient {
@@ -40,12 +42,10 @@ impl RegistryClient {
         _limit: usize,
     ) -> Result<Vec<PackageInfo>, RegistryError> {
         let url = format!("https://pypi.org/pypi/{}/json", urlencoding::encode(query));
-        let resp = self.http.get(&url).send().await?;
-
-        if resp.status() == 404 {
-            return Ok(Vec::new());
-        }
-        let resp = check_response(resp).await?;
+        let resp = match self.get(&url).await {
+            Err(RegistryError::Api { status: 404, .. }) => return Ok(Vec::new()),
+            other => other?,
+        };
 
         let data: PyPiResponse = resp.json().await?;
         let repo = data.info.project_urls.as_ref().and_then(|urls| {
@@ -64,10 +64,25 @@ impl RegistryClient {
             license: data.info.license,
             repository: repo,
             homepage: data.info.home_page,
+            keywords: parse_keywords(data.info.keywords.as_deref()),
+            msrv: None,
         }])
     }
 }
 
+/// Split `PyPI`'s comma-separated `info.keywords` string into a keyword list,
+/// trimming whitespace and dropping empty segments.
+fn parse_keywords(raw: Option<&str>) -> Vec<String> {
+    raw.map(|s| {
+        s.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,7 +97,8 @@ mod tests {
             "project_urls": {
                 "Source": "https://github.com/psf/requests",
                 "Documentation": "https://requests.readthedocs.io"
-            }
+            },
+            "keywords": "http, requests, humans"
         },
         "releases": {}
     }"#;
@@ -113,6 +129,8 @@ mod tests {
             license: data.info.license,
             repository: repo,
             homepage: data.info.home_page,
+            keywords: parse_keywords(data.info.keywords.as_deref()),
+            msrv: None,
         };
 
         assert_eq!(pkg.ecosystem, "pypi");
@@ -121,5 +139,16 @@ mod tests {
             pkg.repository.as_deref(),
             Some("https://github.com/psf/requests")
         );
+        assert_eq!(pkg.keywords, vec!["http", "requests", "humans"]);
+    }
+
+    #[test]
+    fn parse_keywords_splits_trims_and_drops_empty_segments() {
+        assert_eq!(
+            parse_keywords(Some("http,  requests ,, humans")),
+            vec!["http", "requests", "humans"]
+        );
+        assert!(parse_keywords(None).is_empty());
+        assert!(parse_keywords(Some("")).is_empty());
     }
 }
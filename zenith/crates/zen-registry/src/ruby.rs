@@ -1,6 +1,6 @@
 //! `RubyGems` registry client.
 
-use crate::{PackageInfo, RegistryClient, error::RegistryError, http::check_response};
+use crate::{PackageInfo, RegistryClient, error::RegistryError};
 
 #[derive(serde::Deserialize)]
 struct RubyGem {
@@ -30,7 +30,7 @@ impl RegistryClient {
             "https://rubygems.org/api/v1/search.json?query={}",
             urlencoding::encode(query)
         );
-        let resp = check_response(self.http.get(&url).send().await?).await?;
+        let resp = self.get(&url).await?;
 
         let data: Vec<RubyGem> = resp.json().await?;
         Ok(data
@@ -45,6 +45,8 @@ impl RegistryClient {
                 license: g.licenses.and_then(|l| l.into_iter().next()),
                 repository: g.source_code_uri,
                 homepage: g.homepage_uri,
+                keywords: Vec::new(),
+                msrv: None,
             })
             .collect())
     }
@@ -97,6 +99,8 @@ mod tests {
                 license: g.licenses.and_then(|l| l.into_iter().next()),
                 repository: g.source_code_uri,
                 homepage: g.homepage_uri,
+                keywords: Vec::new(),
+                msrv: None,
             })
             .collect();
 
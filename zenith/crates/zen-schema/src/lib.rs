@@ -14,10 +14,11 @@
 //! Consumer crates (zen-db, zen-hooks, zen-cli) depend on zen-schema for runtime validation.
 
 pub mod error;
+pub mod openapi;
 pub mod registry;
 
 pub use error::SchemaError;
-pub use registry::SchemaRegistry;
+pub use registry::{SchemaRegistry, SchemaValidationError, ValidationMode};
 
 #[cfg(test)]
 mod spike_schema_gen;
@@ -0,0 +1,307 @@
+//! `OpenAPI` 3.1 spec generation for Zenith's entity commands.
+//!
+//! External tooling (n8n, Zapier, …) that wants to call Zenith over HTTP
+//! instead of shelling out to `znt` needs a machine-readable description of
+//! the API surface. This module derives that surface from the same
+//! [`SchemaRegistry`] the CLI already validates against, so the two can't
+//! drift: each REST resource below mirrors a `znt <entity>` command group's
+//! create/get/list operations, and its request/response bodies `$ref` the
+//! registry's component schemas.
+
+use serde_json::{Map, Value, json};
+
+use crate::registry::SchemaRegistry;
+
+/// A REST resource exposed over HTTP, mirroring a `znt <entity>`
+/// create/get/list command group.
+struct Resource {
+    /// Plural path segment, e.g. `findings`.
+    path: &'static str,
+    /// [`SchemaRegistry`] name for the entity itself.
+    schema: &'static str,
+}
+
+const RESOURCES: &[Resource] = &[
+    Resource {
+        path: "findings",
+        schema: "finding",
+    },
+    Resource {
+        path: "hypotheses",
+        schema: "hypothesis",
+    },
+    Resource {
+        path: "insights",
+        schema: "insight",
+    },
+    Resource {
+        path: "issues",
+        schema: "issue",
+    },
+    Resource {
+        path: "tasks",
+        schema: "task",
+    },
+    Resource {
+        path: "research",
+        schema: "research_item",
+    },
+    Resource {
+        path: "studies",
+        schema: "study",
+    },
+];
+
+/// Generate an `OpenAPI` 3.1 document describing Zenith's entity commands as
+/// an HTTP API.
+///
+/// Each [`RESOURCES`] entry contributes `POST /{path}` (create), `GET
+/// /{path}/{id}` (get), and `GET /{path}` (list, with `limit`/`search` query
+/// params) — mirroring the `Create`/`Get`/`List` subcommands every entity
+/// command group exposes. Entries whose schema is missing from `registry`
+/// are skipped rather than panicking, since `generate_spec` may be called
+/// against a registry built for a different schema set.
+#[must_use]
+pub fn generate_spec(registry: &SchemaRegistry) -> Value {
+    let mut components_schemas = Map::new();
+    let mut paths = Map::new();
+
+    for resource in RESOURCES {
+        let Some(schema) = registry.get(resource.schema) else {
+            continue;
+        };
+
+        let normalized = normalize_schema(schema, &mut components_schemas);
+        components_schemas.insert(resource.schema.to_string(), normalized);
+
+        let schema_ref = json!({ "$ref": format!("#/components/schemas/{}", resource.schema) });
+        paths.insert(
+            format!("/{}", resource.path),
+            collection_path_item(resource, &schema_ref),
+        );
+        paths.insert(
+            format!("/{}/{{id}}", resource.path),
+            item_path_item(resource, &schema_ref),
+        );
+    }
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "Zenith API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "HTTP surface over Zenith's entity commands, for external tooling (n8n, Zapier, …) that can't shell out to znt.",
+        },
+        "paths": paths,
+        "components": { "schemas": components_schemas },
+    })
+}
+
+fn collection_path_item(resource: &Resource, schema_ref: &Value) -> Value {
+    let entity = resource.schema.replace('_', " ");
+    json!({
+        "post": {
+            "operationId": format!("create_{}", resource.schema),
+            "summary": format!("Create a {entity}"),
+            "requestBody": {
+                "required": true,
+                "content": { "application/json": { "schema": schema_ref } },
+            },
+            "responses": {
+                "201": {
+                    "description": "Created",
+                    "content": { "application/json": { "schema": schema_ref } },
+                },
+            },
+        },
+        "get": {
+            "operationId": format!("list_{}", resource.path),
+            "summary": format!("List {}", resource.path),
+            "parameters": [
+                { "name": "search", "in": "query", "required": false, "schema": { "type": "string" } },
+                { "name": "limit", "in": "query", "required": false, "schema": { "type": "integer" } },
+            ],
+            "responses": {
+                "200": {
+                    "description": "OK",
+                    "content": {
+                        "application/json": {
+                            "schema": { "type": "array", "items": schema_ref },
+                        },
+                    },
+                },
+            },
+        },
+    })
+}
+
+fn item_path_item(resource: &Resource, schema_ref: &Value) -> Value {
+    let entity = resource.schema.replace('_', " ");
+    json!({
+        "get": {
+            "operationId": format!("get_{}", resource.schema),
+            "summary": format!("Get a {entity} by ID"),
+            "parameters": [
+                { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } },
+            ],
+            "responses": {
+                "200": {
+                    "description": "OK",
+                    "content": { "application/json": { "schema": schema_ref } },
+                },
+                "404": { "description": "Not found" },
+            },
+        },
+    })
+}
+
+/// Convert a `schemars`-generated draft 2020-12 schema into the `OpenAPI` 3.0
+/// dialect most tooling (including the `openapiv3` crate) still expects:
+/// hoist `$defs` into `defs_out` (the caller's `components.schemas` map,
+/// keyed by definition name) and rewrite `$ref`s to point there, and collapse
+/// `"type": [T, "null"]` into `"type": T` plus `"nullable": true`.
+fn normalize_schema(value: &Value, defs_out: &mut Map<String, Value>) -> Value {
+    let Value::Object(obj) = value else {
+        return value.clone();
+    };
+
+    let mut out = Map::new();
+    for (key, val) in obj {
+        match key.as_str() {
+            "$schema" => {}
+            "$defs" => {
+                if let Value::Object(defs) = val {
+                    for (name, def_schema) in defs {
+                        let normalized = normalize_schema(def_schema, defs_out);
+                        defs_out.insert(name.clone(), normalized);
+                    }
+                }
+            }
+            "$ref" => {
+                let rewritten = val
+                    .as_str()
+                    .map(|r| r.replace("#/$defs/", "#/components/schemas/"))
+                    .unwrap_or_default();
+                out.insert(key.clone(), Value::String(rewritten));
+            }
+            "type" => {
+                if let Value::Array(types) = val {
+                    let mut non_null: Vec<Value> = types
+                        .iter()
+                        .filter(|t| t.as_str() != Some("null"))
+                        .cloned()
+                        .collect();
+                    if non_null.len() < types.len() {
+                        out.insert("nullable".to_string(), Value::Bool(true));
+                    }
+                    let collapsed = if non_null.len() == 1 {
+                        non_null.remove(0)
+                    } else {
+                        Value::Array(non_null)
+                    };
+                    out.insert(key.clone(), collapsed);
+                } else {
+                    out.insert(key.clone(), val.clone());
+                }
+            }
+            "properties" => {
+                if let Value::Object(props) = val {
+                    let normalized: Map<String, Value> = props
+                        .iter()
+                        .map(|(name, prop_schema)| {
+                            (name.clone(), normalize_schema(prop_schema, defs_out))
+                        })
+                        .collect();
+                    out.insert(key.clone(), Value::Object(normalized));
+                }
+            }
+            "items" | "additionalProperties" | "not" => {
+                out.insert(key.clone(), normalize_schema(val, defs_out));
+            }
+            "allOf" | "anyOf" | "oneOf" => {
+                if let Value::Array(variants) = val {
+                    let normalized: Vec<Value> = variants
+                        .iter()
+                        .map(|variant| normalize_schema(variant, defs_out))
+                        .collect();
+                    out.insert(key.clone(), Value::Array(normalized));
+                }
+            }
+            _ => {
+                out.insert(key.clone(), val.clone());
+            }
+        }
+    }
+
+    Value::Object(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::SchemaRegistry;
+
+    #[test]
+    fn spec_contains_findings_path_with_post_operation() {
+        let registry = SchemaRegistry::new();
+        let spec = generate_spec(&registry);
+
+        let post = &spec["paths"]["/findings"]["post"];
+        assert!(post.is_object(), "expected POST /findings, got: {spec:#}");
+        assert_eq!(post["operationId"], "create_finding");
+    }
+
+    #[test]
+    fn spec_contains_get_and_list_operations_for_each_resource() {
+        let registry = SchemaRegistry::new();
+        let spec = generate_spec(&registry);
+
+        for resource in RESOURCES {
+            assert!(
+                spec["paths"][format!("/{}", resource.path)]["get"].is_object(),
+                "missing list GET for {}",
+                resource.path
+            );
+            assert!(
+                spec["paths"][format!("/{}/{{id}}", resource.path)]["get"].is_object(),
+                "missing item GET for {}",
+                resource.path
+            );
+            assert!(
+                spec["components"]["schemas"][resource.schema].is_object(),
+                "missing component schema for {}",
+                resource.schema
+            );
+        }
+    }
+
+    #[test]
+    fn spec_is_valid_openapi_3_1_document() {
+        let registry = SchemaRegistry::new();
+        let spec = generate_spec(&registry);
+
+        let parsed: Result<openapiv3::OpenAPI, _> = serde_json::from_value(spec);
+        assert!(parsed.is_ok(), "spec did not parse as OpenAPI: {parsed:?}");
+    }
+
+    #[test]
+    fn normalize_schema_collapses_nullable_type_arrays() {
+        let mut defs = Map::new();
+        let input = json!({ "type": ["string", "null"] });
+
+        let normalized = normalize_schema(&input, &mut defs);
+
+        assert_eq!(normalized["type"], "string");
+        assert_eq!(normalized["nullable"], true);
+    }
+
+    #[test]
+    fn normalize_schema_rewrites_defs_refs_into_components_schemas() {
+        let mut defs = Map::new();
+        let input = json!({ "$ref": "#/$defs/Confidence" });
+
+        let normalized = normalize_schema(&input, &mut defs);
+
+        assert_eq!(normalized["$ref"], "#/components/schemas/Confidence");
+    }
+}
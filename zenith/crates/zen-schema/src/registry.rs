@@ -17,6 +17,64 @@ pub struct SchemaRegistry {
     schemas: HashMap<&'static str, serde_json::Value>,
 }
 
+/// A single schema validation failure, with its location in the instance.
+#[derive(Debug, Clone)]
+pub struct SchemaValidationError {
+    /// JSON Pointer to the offending value, e.g. `/data/confidence`.
+    pub instance_path: String,
+    /// Human-readable validation failure message.
+    pub message: String,
+}
+
+/// Whether validation tolerates fields not declared in a schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationMode {
+    /// Extra fields are ignored. The default, and the mode `validate` and
+    /// `validate_detailed` use.
+    #[default]
+    Permissive,
+    /// Extra fields fail validation, catching schema drift that permissive
+    /// mode lets through silently.
+    Strict,
+}
+
+impl ValidationMode {
+    /// Read `ZENITH_SCHEMA__STRICT` from the environment. `"true"` selects
+    /// [`ValidationMode::Strict`]; anything else (including unset) is
+    /// [`ValidationMode::Permissive`].
+    #[must_use]
+    pub fn from_env() -> Self {
+        if std::env::var("ZENITH_SCHEMA__STRICT").as_deref() == Ok("true") {
+            Self::Strict
+        } else {
+            Self::Permissive
+        }
+    }
+}
+
+/// Recursively set `additionalProperties: false` on every object schema
+/// (identified by the presence of a `properties` key) in `schema`, including
+/// nested `$defs`. Used by [`ValidationMode::Strict`] to catch fields that
+/// aren't declared anywhere in the schema.
+fn deny_additional_properties(schema: &serde_json::Value) -> serde_json::Value {
+    match schema {
+        serde_json::Value::Object(map) => {
+            let mut out = serde_json::Map::with_capacity(map.len() + 1);
+            for (key, value) in map {
+                out.insert(key.clone(), deny_additional_properties(value));
+            }
+            if out.contains_key("properties") {
+                out.insert("additionalProperties".to_string(), serde_json::json!(false));
+            }
+            serde_json::Value::Object(out)
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(deny_additional_properties).collect())
+        }
+        other => other.clone(),
+    }
+}
+
 /// Insert a schema into the map, converting the `schemars` output to a
 /// `serde_json::Value`. Panics if `serde_json::to_value` fails (should be
 /// infallible for valid `schemars` output).
@@ -67,7 +125,7 @@ impl SchemaRegistry {
         // --- Trail envelope (1) ---
         register!(schemas, "trail_operation", zen_core::trail::TrailOperation);
 
-        // --- CLI response types (6) ---
+        // --- CLI response types (7) ---
         register!(
             schemas,
             "finding_create_response",
@@ -94,6 +152,11 @@ impl SchemaRegistry {
             "rebuild_response",
             zen_core::responses::RebuildResponse
         );
+        register!(
+            schemas,
+            "activity_summary",
+            zen_core::responses::ActivitySummary
+        );
 
         // --- Audit detail types (4) ---
         register!(
@@ -133,23 +196,98 @@ impl SchemaRegistry {
     /// Returns `SchemaError::NotFound` if the schema name is unknown, or
     /// `SchemaError::ValidationFailed` if validation produces errors.
     pub fn validate(&self, name: &str, instance: &serde_json::Value) -> Result<(), SchemaError> {
+        let errors = self.validate_detailed(name, instance)?;
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError::ValidationFailed {
+                errors: errors.into_iter().map(|error| error.message).collect(),
+            })
+        }
+    }
+
+    /// Validate a JSON value against a named schema, keeping each failure's
+    /// location in the instance.
+    ///
+    /// Unlike [`SchemaRegistry::validate`], which collapses failures to
+    /// plain messages, this preserves the `jsonschema` instance path for
+    /// each error so callers can point users at the offending field.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaError::NotFound` if the schema name is unknown.
+    pub fn validate_detailed(
+        &self,
+        name: &str,
+        instance: &serde_json::Value,
+    ) -> Result<Vec<SchemaValidationError>, SchemaError> {
+        self.validate_detailed_with_mode(name, instance, ValidationMode::Permissive)
+    }
+
+    /// Validate a JSON value against a named schema in [`ValidationMode::Strict`],
+    /// rejecting fields not declared anywhere in the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaError::ValidationFailed` if validation produces errors,
+    /// or `SchemaError::NotFound` if the schema name is unknown.
+    pub fn validate_strict(
+        &self,
+        name: &str,
+        instance: &serde_json::Value,
+    ) -> Result<(), SchemaError> {
+        let errors = self.validate_detailed_strict(name, instance)?;
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError::ValidationFailed {
+                errors: errors.into_iter().map(|error| error.message).collect(),
+            })
+        }
+    }
+
+    /// Like [`SchemaRegistry::validate_strict`], but keeps each failure's
+    /// location in the instance instead of collapsing to plain messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SchemaError::NotFound` if the schema name is unknown.
+    pub fn validate_detailed_strict(
+        &self,
+        name: &str,
+        instance: &serde_json::Value,
+    ) -> Result<Vec<SchemaValidationError>, SchemaError> {
+        self.validate_detailed_with_mode(name, instance, ValidationMode::Strict)
+    }
+
+    fn validate_detailed_with_mode(
+        &self,
+        name: &str,
+        instance: &serde_json::Value,
+        mode: ValidationMode,
+    ) -> Result<Vec<SchemaValidationError>, SchemaError> {
         let schema = self
             .get(name)
             .ok_or_else(|| SchemaError::NotFound(name.to_string()))?;
+        let strict_schema;
+        let schema = match mode {
+            ValidationMode::Permissive => schema,
+            ValidationMode::Strict => {
+                strict_schema = deny_additional_properties(schema);
+                &strict_schema
+            }
+        };
 
         let validator = jsonschema::validator_for(schema)
             .map_err(|e| SchemaError::Generation(format!("{e}")))?;
 
-        let errors: Vec<String> = validator
+        Ok(validator
             .iter_errors(instance)
-            .map(|e| format!("{e}"))
-            .collect();
-
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(SchemaError::ValidationFailed { errors })
-        }
+            .map(|e| SchemaValidationError {
+                instance_path: e.instance_path.as_str().to_string(),
+                message: format!("{e}"),
+            })
+            .collect())
     }
 
     /// List all registered schema names.
@@ -188,8 +326,8 @@ mod tests {
     #[test]
     fn registry_has_expected_count() {
         let reg = registry();
-        // 15 entities + 1 trail + 6 responses + 4 audit details = 26
-        assert_eq!(reg.schema_count(), 26);
+        // 15 entities + 1 trail + 7 responses + 4 audit details = 27
+        assert_eq!(reg.schema_count(), 27);
     }
 
     #[test]
@@ -232,6 +370,25 @@ mod tests {
         assert!(reg.validate("finding", &json).is_ok());
     }
 
+    #[test]
+    fn validate_strict_rejects_unknown_field_but_permissive_allows_it() {
+        let reg = registry();
+        let op = TrailOperation {
+            v: 1,
+            ts: "2026-02-08T12:00:00Z".into(),
+            ses: "ses-00000000".into(),
+            op: TrailOp::Create,
+            entity: EntityType::Finding,
+            id: "fnd-test1234".into(),
+            data: serde_json::json!({"content": "test"}),
+        };
+        let mut json = serde_json::to_value(&op).unwrap();
+        json["debug_info"] = serde_json::json!("not part of the schema");
+
+        assert!(reg.validate("trail_operation", &json).is_ok());
+        assert!(reg.validate_strict("trail_operation", &json).is_err());
+    }
+
     #[test]
     fn validate_rejects_missing_required_field() {
         let reg = registry();
@@ -313,6 +470,7 @@ mod tests {
             "search_result",
             "search_results_response",
             "rebuild_response",
+            "activity_summary",
             "status_changed_detail",
             "linked_detail",
             "tagged_detail",
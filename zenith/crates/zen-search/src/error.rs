@@ -34,4 +34,11 @@ pub enum SearchError {
     /// Search budget (time or result count) exceeded.
     #[error("budget exceeded: {0}")]
     BudgetExceeded(String),
+
+    /// The lake was skipped at startup (`ZENITH_GENERAL__NO_LAKE=true`), so
+    /// no lake-backed search (indexing, vector search) is available.
+    #[error(
+        "the lake is disabled (ZENITH_GENERAL__NO_LAKE=true); lake-backed search is unavailable"
+    )]
+    LakeDisabled,
 }
@@ -1,14 +1,14 @@
 //! Decision graph analytics over zen-db `entity_links`.
 
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::convert::Infallible;
 
 use rustworkx_core::centrality::betweenness_centrality;
 use rustworkx_core::connectivity::connected_components;
 use rustworkx_core::dictmap::{DictMap, InitWithHasher};
-use rustworkx_core::petgraph::algo::toposort;
-use rustworkx_core::petgraph::graph::{DiGraph, NodeIndex};
+use rustworkx_core::petgraph::algo::{tarjan_scc, toposort};
+use rustworkx_core::petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
 use rustworkx_core::shortest_path::dijkstra;
 use zen_db::service::ZenService;
 
@@ -36,10 +36,35 @@ pub struct GraphAnalysis {
     pub edge_count: usize,
     pub components: usize,
     pub has_cycles: bool,
+    pub cycles: Vec<Vec<String>>,
     pub topological_order: Option<Vec<String>>,
     pub centrality: Vec<(String, f64)>,
 }
 
+/// D3-style node record produced by [`DecisionGraph::to_json`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GraphJsonNode {
+    pub id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+}
+
+/// D3-style link record produced by [`DecisionGraph::to_json`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GraphJsonLink {
+    pub source: String,
+    pub target: String,
+    pub relation: String,
+    pub weight: f64,
+}
+
+/// D3-style `{nodes, links}` export of a [`DecisionGraph`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GraphJson {
+    pub nodes: Vec<GraphJsonNode>,
+    pub links: Vec<GraphJsonLink>,
+}
+
 /// Directed graph built from `entity_links`.
 pub struct DecisionGraph {
     graph: DiGraph<GraphNode, GraphEdge>,
@@ -180,6 +205,55 @@ impl DecisionGraph {
         )
     }
 
+    /// Edge sequence connecting two node labels (`"type:id"`), found via
+    /// unweighted BFS over the edge set. `None` if either label is unknown
+    /// or no directed path connects them.
+    #[must_use]
+    pub fn path_between(&self, from: &str, to: &str) -> Option<Vec<GraphEdge>> {
+        let start = *self.id_to_index.get(from)?;
+        let goal = *self.id_to_index.get(to)?;
+
+        let mut visited = HashSet::new();
+        let mut incoming: HashMap<NodeIndex, (NodeIndex, EdgeIndex)> = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        visited.insert(start);
+        queue.push_back(start);
+
+        while let Some(node) = queue.pop_front() {
+            if node == goal {
+                break;
+            }
+            for edge in self.graph.edges(node) {
+                let next = edge.target();
+                if visited.insert(next) {
+                    incoming.insert(next, (node, edge.id()));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if start != goal && !incoming.contains_key(&goal) {
+            return None;
+        }
+
+        let mut edge_ids = Vec::new();
+        let mut current = goal;
+        while current != start {
+            let (prev, edge_id) = incoming[&current];
+            edge_ids.push(edge_id);
+            current = prev;
+        }
+        edge_ids.reverse();
+
+        Some(
+            edge_ids
+                .into_iter()
+                .map(|edge_id| self.graph[edge_id].clone())
+                .collect(),
+        )
+    }
+
     /// Weakly connected component count.
     #[must_use]
     pub fn connected_components(&self) -> usize {
@@ -192,6 +266,120 @@ impl DecisionGraph {
         toposort(&self.graph, None).is_err()
     }
 
+    /// Node-id sequences (`"type:id"`) forming a cycle, one entry per
+    /// strongly connected component with more than one node or a self-loop.
+    /// Acyclic components (singletons with no self-loop) are omitted.
+    #[must_use]
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        tarjan_scc(&self.graph)
+            .into_iter()
+            .filter(|scc| {
+                scc.len() > 1
+                    || scc
+                        .first()
+                        .is_some_and(|&idx| self.graph.contains_edge(idx, idx))
+            })
+            .map(|scc| {
+                scc.into_iter()
+                    .map(|idx| self.graph[idx].label.clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Render the graph as Graphviz DOT source, nodes labeled by entity
+    /// type/id and edges labeled by relation. Nodes with above-average
+    /// betweenness centrality are filled to highlight them, so the output
+    /// renders directly with `dot -Tsvg`.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph decision_graph {\n");
+
+        let centrality = self.centrality();
+        let mean_centrality = if centrality.is_empty() {
+            0.0
+        } else {
+            centrality.iter().map(|(_, score)| score).sum::<f64>() / centrality.len() as f64
+        };
+        let centrality_by_label: HashMap<&str, f64> = centrality
+            .iter()
+            .map(|(label, score)| (label.as_str(), *score))
+            .collect();
+
+        for idx in self.graph.node_indices() {
+            let node = &self.graph[idx];
+            let is_high_centrality = centrality_by_label
+                .get(node.label.as_str())
+                .is_some_and(|score| *score > mean_centrality);
+            let highlight = if is_high_centrality {
+                ", style=filled, fillcolor=\"#ffcc00\""
+            } else {
+                ""
+            };
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}: {}\"{}];\n",
+                escape_dot(&node.label),
+                escape_dot(&node.entity_type),
+                escape_dot(&node.entity_id),
+                highlight,
+            ));
+        }
+
+        for edge in self.graph.edge_indices() {
+            let (src, dst) = self
+                .graph
+                .edge_endpoints(edge)
+                .expect("edge index came from this graph");
+            let relation = &self.graph[edge].relation;
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                escape_dot(&self.graph[src].label),
+                escape_dot(&self.graph[dst].label),
+                escape_dot(relation),
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Render the graph as a D3-style `{nodes, links}` document.
+    #[must_use]
+    pub fn to_json(&self) -> GraphJson {
+        let nodes = self
+            .graph
+            .node_indices()
+            .map(|idx| {
+                let node = &self.graph[idx];
+                GraphJsonNode {
+                    id: node.label.clone(),
+                    entity_type: node.entity_type.clone(),
+                    entity_id: node.entity_id.clone(),
+                }
+            })
+            .collect();
+
+        let links = self
+            .graph
+            .edge_indices()
+            .map(|edge| {
+                let (src, dst) = self
+                    .graph
+                    .edge_endpoints(edge)
+                    .expect("edge index came from this graph");
+                let edge_data = &self.graph[edge];
+                GraphJsonLink {
+                    source: self.graph[src].label.clone(),
+                    target: self.graph[dst].label.clone(),
+                    relation: edge_data.relation.clone(),
+                    weight: edge_data.weight,
+                }
+            })
+            .collect();
+
+        GraphJson { nodes, links }
+    }
+
     /// Aggregate analysis with optional centrality budget.
     #[must_use]
     pub fn analyze(&self, max_nodes_for_centrality: usize) -> GraphAnalysis {
@@ -200,6 +388,7 @@ impl DecisionGraph {
             edge_count: self.graph.edge_count(),
             components: self.connected_components(),
             has_cycles: self.has_cycles(),
+            cycles: self.find_cycles(),
             topological_order: self.toposort(),
             centrality: if self.graph.node_count() <= max_nodes_for_centrality {
                 self.centrality()
@@ -214,6 +403,11 @@ fn node_key(entity_type: &str, entity_id: &str) -> String {
     format!("{entity_type}:{entity_id}")
 }
 
+/// Escape double quotes and backslashes for use inside a DOT quoted string.
+fn escape_dot(raw: &str) -> String {
+    raw.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 #[cfg(test)]
 mod tests {
     use zen_db::service::ZenService;
@@ -307,6 +501,62 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn path_between_traverses_finding_through_hypothesis_to_insight() {
+        let service = make_service().await;
+        insert_link(
+            &service,
+            "lnk-1",
+            "finding",
+            "fnd-1",
+            "hypothesis",
+            "hyp-1",
+            "informs",
+        )
+        .await;
+        insert_link(
+            &service,
+            "lnk-2",
+            "hypothesis",
+            "hyp-1",
+            "insight",
+            "ins-1",
+            "yields",
+        )
+        .await;
+
+        let graph = DecisionGraph::from_service(&service).await.unwrap();
+        let path = graph
+            .path_between("finding:fnd-1", "insight:ins-1")
+            .unwrap();
+
+        assert_eq!(path.len(), 2);
+        assert_eq!(path[0].relation, "informs");
+        assert_eq!(path[1].relation, "yields");
+    }
+
+    #[tokio::test]
+    async fn path_between_returns_none_when_disconnected() {
+        let service = make_service().await;
+        insert_link(
+            &service, "lnk-1", "decision", "dec-1", "finding", "fnd-1", "supports",
+        )
+        .await;
+        insert_link(
+            &service,
+            "lnk-2",
+            "task",
+            "tsk-1",
+            "task",
+            "tsk-2",
+            "depends_on",
+        )
+        .await;
+
+        let graph = DecisionGraph::from_service(&service).await.unwrap();
+        assert!(graph.path_between("decision:dec-1", "task:tsk-2").is_none());
+    }
+
     #[tokio::test]
     async fn cycle_detection_disables_toposort() {
         let service = make_service().await;
@@ -337,4 +587,118 @@ mod tests {
         assert!(analysis.has_cycles);
         assert!(analysis.topological_order.is_none());
     }
+
+    #[tokio::test]
+    async fn find_cycles_reports_only_the_cyclic_nodes() {
+        let service = make_service().await;
+        // Cycle: hyp-1 -> hyp-2 -> hyp-1
+        insert_link(
+            &service,
+            "lnk-1",
+            "hypothesis",
+            "hyp-1",
+            "hypothesis",
+            "hyp-2",
+            "validates",
+        )
+        .await;
+        insert_link(
+            &service,
+            "lnk-2",
+            "hypothesis",
+            "hyp-2",
+            "hypothesis",
+            "hyp-1",
+            "validates",
+        )
+        .await;
+        // Acyclic chain: decision -> finding
+        insert_link(
+            &service, "lnk-3", "decision", "dec-1", "finding", "fnd-1", "supports",
+        )
+        .await;
+
+        let graph = DecisionGraph::from_service(&service).await.unwrap();
+        let cycles = graph.find_cycles();
+
+        assert_eq!(cycles.len(), 1);
+        let mut cyclic_nodes = cycles[0].clone();
+        cyclic_nodes.sort();
+        assert_eq!(
+            cyclic_nodes,
+            vec![
+                "hypothesis:hyp-1".to_string(),
+                "hypothesis:hyp-2".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn to_dot_emits_expected_node_and_edge_declarations() {
+        let service = make_service().await;
+        insert_link(
+            &service, "lnk-1", "decision", "dec-1", "finding", "fnd-1", "supports",
+        )
+        .await;
+
+        let graph = DecisionGraph::from_service(&service).await.unwrap();
+        let dot = graph.to_dot();
+
+        assert!(dot.starts_with("digraph decision_graph {\n"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("\"decision:dec-1\" [label=\"decision: dec-1\"];"));
+        assert!(dot.contains("\"finding:fnd-1\" [label=\"finding: fnd-1\"];"));
+        assert!(dot.contains("\"decision:dec-1\" -> \"finding:fnd-1\" [label=\"supports\"];"));
+    }
+
+    #[tokio::test]
+    async fn to_dot_highlights_nodes_above_mean_centrality() {
+        let service = make_service().await;
+        insert_link(
+            &service, "lnk-1", "decision", "dec-1", "finding", "fnd-1", "supports",
+        )
+        .await;
+        insert_link(
+            &service,
+            "lnk-2",
+            "finding",
+            "fnd-1",
+            "hypothesis",
+            "hyp-1",
+            "informs",
+        )
+        .await;
+
+        let graph = DecisionGraph::from_service(&service).await.unwrap();
+        let dot = graph.to_dot();
+
+        assert!(dot.contains(
+            "\"finding:fnd-1\" [label=\"finding: fnd-1\", style=filled, fillcolor=\"#ffcc00\"];"
+        ));
+        assert!(dot.contains("\"decision:dec-1\" [label=\"decision: dec-1\"];"));
+        assert!(dot.contains("\"hypothesis:hyp-1\" [label=\"hypothesis: hyp-1\"];"));
+    }
+
+    #[tokio::test]
+    async fn to_json_emits_d3_style_nodes_and_links() {
+        let service = make_service().await;
+        insert_link(
+            &service, "lnk-1", "decision", "dec-1", "finding", "fnd-1", "supports",
+        )
+        .await;
+
+        let graph = DecisionGraph::from_service(&service).await.unwrap();
+        let json = graph.to_json();
+
+        assert_eq!(json.nodes.len(), 2);
+        assert_eq!(json.links.len(), 1);
+        assert!(
+            json.nodes
+                .iter()
+                .any(|n| n.id == "decision:dec-1" && n.entity_type == "decision")
+        );
+        assert_eq!(json.links[0].source, "decision:dec-1");
+        assert_eq!(json.links[0].target, "finding:fnd-1");
+        assert_eq!(json.links[0].relation, "supports");
+    }
 }
@@ -703,6 +703,7 @@ impl Runtime {
                     language: Some("rust".to_string()),
                     size_bytes: SAMPLE_SPAWN.len() as i32,
                     line_count: SAMPLE_SPAWN.lines().count() as i32,
+                    content_hash: None,
                 },
                 zen_lake::SourceFile {
                     ecosystem: "rust".to_string(),
@@ -713,6 +714,7 @@ impl Runtime {
                     language: Some("rust".to_string()),
                     size_bytes: SAMPLE_RUNTIME.len() as i32,
                     line_count: SAMPLE_RUNTIME.lines().count() as i32,
+                    content_hash: None,
                 },
             ])
             .expect("store source files");
@@ -944,6 +946,7 @@ impl Runtime {
                     language: Some("rust".to_string()),
                     size_bytes: 18,
                     line_count: 1,
+                    content_hash: None,
                 },
                 zen_lake::SourceFile {
                     ecosystem: "rust".to_string(),
@@ -954,6 +957,7 @@ impl Runtime {
                     language: Some("rust".to_string()),
                     size_bytes: 18,
                     line_count: 1,
+                    content_hash: None,
                 },
             ])
             .unwrap();
@@ -1097,6 +1101,7 @@ impl Runtime {
                     language: Some("rust".to_string()),
                     size_bytes: 18,
                     line_count: 1,
+                    content_hash: None,
                 },
                 zen_lake::SourceFile {
                     ecosystem: "rust".to_string(),
@@ -1107,6 +1112,7 @@ impl Runtime {
                     language: Some("rust".to_string()),
                     size_bytes: 18,
                     line_count: 1,
+                    content_hash: None,
                 },
             ])
             .unwrap();
@@ -1150,6 +1156,7 @@ impl Runtime {
                     language: Some("rust".to_string()),
                     size_bytes: 18,
                     line_count: 1,
+                    content_hash: None,
                 },
                 zen_lake::SourceFile {
                     ecosystem: "rust".to_string(),
@@ -1160,6 +1167,7 @@ impl Runtime {
                     language: Some("rust".to_string()),
                     size_bytes: 22,
                     line_count: 1,
+                    content_hash: None,
                 },
             ])
             .unwrap();
@@ -1432,6 +1440,7 @@ impl Runtime {
                 language: Some("rust".to_string()),
                 size_bytes: 55,
                 line_count: 3,
+                content_hash: None,
             }])
             .unwrap();
 
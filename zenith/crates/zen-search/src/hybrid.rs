@@ -33,6 +33,8 @@ pub struct HybridSearchResult {
     pub combined_score: f64,
     /// Source of the result.
     pub source: HybridSource,
+    /// Score breakdown, populated when `explain` is requested.
+    pub explanation: Option<ScoreBreakdown>,
 }
 
 /// Source of a hybrid search result.
@@ -46,6 +48,20 @@ pub enum HybridSource {
     Fts,
 }
 
+/// Breakdown of the score components behind a [`HybridSearchResult`], for
+/// `znt search --explain`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ScoreBreakdown {
+    /// Normalized vector similarity score, if the result matched via vector search.
+    pub vector_score: Option<f64>,
+    /// FTS5 relevance score, if the result matched via full-text search.
+    pub fts_score: Option<f64>,
+    /// Alpha-blended combined score.
+    pub combined_score: f64,
+    /// Which field the result matched on: `"name"`, `"content"`, or `"name+content"`.
+    pub matched_field: String,
+}
+
 /// Normalize a cosine similarity score from [-1, 1] to [0, 1].
 const fn normalize_vector_score(score: f64) -> f64 {
     f64::midpoint(score, 1.0)
@@ -62,12 +78,15 @@ const fn normalize_vector_score(score: f64) -> f64 {
 /// * `fts_results` — Results from FTS5 search.
 /// * `alpha` — Blending weight: `0.0` (FTS only) to `1.0` (vector only).
 /// * `limit` — Maximum number of results to return.
+/// * `explain` — When `true`, populate each result's `explanation` with a
+///   [`ScoreBreakdown`] of its scoring components.
 #[must_use]
 pub fn combine_results(
     vector_results: &[VectorSearchResult],
     fts_results: &[FtsSearchResult],
     alpha: f64,
     limit: u32,
+    explain: bool,
 ) -> Vec<HybridSearchResult> {
     let alpha = alpha.clamp(0.0, 1.0);
 
@@ -91,6 +110,7 @@ pub fn combine_results(
                 VectorSource::ApiSymbol => HybridSource::VectorSymbol,
                 VectorSource::DocChunk => HybridSource::VectorDocChunk,
             },
+            explanation: None,
         });
         entry.vector_score = Some(norm_score);
         entry.combined_score = alpha * norm_score + (1.0 - alpha) * entry.fts_score.unwrap_or(0.0);
@@ -109,6 +129,7 @@ pub fn combine_results(
             fts_score: None,
             combined_score: 0.0,
             source: HybridSource::Fts,
+            explanation: None,
         });
         entry.fts_score = Some(fr.relevance);
         entry.combined_score =
@@ -116,6 +137,21 @@ pub fn combine_results(
     }
 
     let mut results: Vec<HybridSearchResult> = merged.into_values().collect();
+
+    if explain {
+        for result in &mut results {
+            result.explanation = Some(ScoreBreakdown {
+                vector_score: result.vector_score,
+                fts_score: result.fts_score,
+                combined_score: result.combined_score,
+                matched_field: matched_field(
+                    result.vector_score.is_some(),
+                    result.fts_score.is_some(),
+                ),
+            });
+        }
+    }
+
     results.sort_by(|a, b| {
         b.combined_score
             .partial_cmp(&a.combined_score)
@@ -127,6 +163,15 @@ pub fn combine_results(
     results
 }
 
+/// Which field a hybrid result matched on, given which score components it has.
+fn matched_field(has_vector_score: bool, has_fts_score: bool) -> String {
+    match (has_vector_score, has_fts_score) {
+        (true, true) => "name+content".to_string(),
+        (true, false) => "name".to_string(),
+        (false, _) => "content".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,7 +214,7 @@ mod tests {
         ];
         let fts_results = vec![make_fts_result("f1", "unrelated", 1.0)];
 
-        let results = combine_results(&vec_results, &fts_results, 1.0, 10);
+        let results = combine_results(&vec_results, &fts_results, 1.0, 10, false);
 
         // With alpha=1.0, FTS scores contribute 0. Vector results should dominate.
         let spawn = results.iter().find(|r| r.name == "spawn").unwrap();
@@ -191,7 +236,7 @@ mod tests {
             make_fts_result("f2", "async", 0.5),
         ];
 
-        let results = combine_results(&vec_results, &fts_results, 0.0, 10);
+        let results = combine_results(&vec_results, &fts_results, 0.0, 10, false);
 
         // With alpha=0.0, vector scores contribute 0
         let spawn = results.iter().find(|r| r.name == "spawn").unwrap();
@@ -208,7 +253,7 @@ mod tests {
         let vec_results = vec![make_vector_result("v1", "spawn", 0.8)]; // normalized: (0.8+1)/2 = 0.9
         let fts_results = vec![make_fts_result("f1", "runtime", 0.6)];
 
-        let results = combine_results(&vec_results, &fts_results, 0.5, 10);
+        let results = combine_results(&vec_results, &fts_results, 0.5, 10, false);
 
         let spawn = results.iter().find(|r| r.name == "spawn").unwrap();
         let expected_spawn = 0.5 * normalize_vector_score(0.8);
@@ -233,7 +278,7 @@ mod tests {
         let vec_results = vec![make_vector_result("v1", "spawn", 0.8)];
         let fts_results = vec![make_fts_result("f1", "spawn", 0.6)];
 
-        let results = combine_results(&vec_results, &fts_results, 0.5, 10);
+        let results = combine_results(&vec_results, &fts_results, 0.5, 10, false);
 
         // Should be merged into one result
         let spawn_results: Vec<_> = results
@@ -259,7 +304,7 @@ mod tests {
             make_fts_result("f2", "fts_only", 0.7),
         ];
 
-        let results = combine_results(&vec_results, &fts_results, 0.5, 10);
+        let results = combine_results(&vec_results, &fts_results, 0.5, 10, false);
 
         let both = results.iter().find(|r| r.name == "both").unwrap();
         let vector_only = results.iter().find(|r| r.name == "vector_only").unwrap();
@@ -274,4 +319,38 @@ mod tests {
             "item in both should rank higher than FTS-only"
         );
     }
+
+    #[test]
+    fn explain_populates_score_breakdown_that_combines_to_reported_score() {
+        let alpha = 0.5;
+        let vec_results = vec![make_vector_result("v1", "spawn", 0.8)];
+        let fts_results = vec![make_fts_result("f1", "spawn", 0.6)];
+
+        let results = combine_results(&vec_results, &fts_results, alpha, 10, true);
+        let spawn = results.iter().find(|r| r.name == "spawn").unwrap();
+
+        let explanation = spawn.explanation.as_ref().expect("explanation populated");
+        assert_eq!(explanation.vector_score, spawn.vector_score);
+        assert_eq!(explanation.fts_score, spawn.fts_score);
+        assert_eq!(explanation.matched_field, "name+content");
+
+        let vector_score = explanation.vector_score.expect("vector component present");
+        let fts_score = explanation.fts_score.expect("fts component present");
+        let expected = alpha * vector_score + (1.0 - alpha) * fts_score;
+        assert!(
+            (explanation.combined_score - expected).abs() < f64::EPSILON,
+            "component scores should recombine to the reported blended score"
+        );
+        assert!((explanation.combined_score - spawn.combined_score).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn explain_false_leaves_explanation_none() {
+        let vec_results = vec![make_vector_result("v1", "spawn", 0.8)];
+        let fts_results = vec![make_fts_result("f1", "spawn", 0.6)];
+
+        let results = combine_results(&vec_results, &fts_results, 0.5, 10, false);
+        let spawn = results.iter().find(|r| r.name == "spawn").unwrap();
+        assert!(spawn.explanation.is_none());
+    }
 }
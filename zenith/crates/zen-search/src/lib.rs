@@ -20,7 +20,9 @@ pub mod walk;
 
 pub use error::SearchError;
 pub use fts::{FtsSearchFilters, FtsSearchResult};
-pub use graph::{DecisionGraph, GraphAnalysis, GraphEdge, GraphNode};
+pub use graph::{
+    DecisionGraph, GraphAnalysis, GraphEdge, GraphJson, GraphJsonLink, GraphJsonNode, GraphNode,
+};
 pub use grep::{GrepEngine, GrepMatch, GrepOptions, GrepResult, GrepStats, SymbolRef};
 pub use hybrid::{HybridSearchResult, HybridSource};
 pub use recursive::{
@@ -63,6 +65,9 @@ pub struct SearchFilters {
     pub entity_types: Vec<String>,
     pub limit: Option<u32>,
     pub min_score: Option<f64>,
+    pub exclude_deprecated: bool,
+    /// When `true`, hybrid results carry a `ScoreBreakdown` explaining their score.
+    pub explain: bool,
 }
 
 /// Unified result type for orchestrated search output.
@@ -135,9 +140,15 @@ impl<'a> SearchEngine<'a> {
                 let vf = VectorSearchFilters {
                     package: filters.package,
                     ecosystem: filters.ecosystem,
+                    source_types: if filters.kind.is_some() {
+                        vec![VectorSource::ApiSymbol]
+                    } else {
+                        Vec::new()
+                    },
                     kind: filters.kind,
                     limit,
                     min_score: filters.min_score.unwrap_or(0.0),
+                    exclude_deprecated: filters.exclude_deprecated,
                 };
 
                 let mut vector_results = vector::vector_search_symbols(self.lake, &embedding, &vf)?;
@@ -174,6 +185,8 @@ impl<'a> SearchEngine<'a> {
                     kind: filters.kind,
                     limit: limit.max(40),
                     min_score: 0.0,
+                    exclude_deprecated: filters.exclude_deprecated,
+                    source_types: Vec::new(),
                 };
                 let mut vector_results = vector::vector_search_symbols(self.lake, &embedding, &vf)?;
                 vector_results.extend(vector::vector_search_doc_chunks(
@@ -186,7 +199,13 @@ impl<'a> SearchEngine<'a> {
                 };
                 let fts_results = fts::fts_search(self.service, query, &ff).await?;
 
-                let combined = hybrid::combine_results(&vector_results, &fts_results, alpha, limit);
+                let combined = hybrid::combine_results(
+                    &vector_results,
+                    &fts_results,
+                    alpha,
+                    limit,
+                    filters.explain,
+                );
                 Ok(combined.into_iter().map(SearchResult::Hybrid).collect())
             }
             SearchMode::Recursive => {
@@ -367,6 +386,7 @@ mod tests {
                 language: Some("rust".to_string()),
                 size_bytes: 40,
                 line_count: 2,
+                content_hash: None,
             }])
             .expect("seed source files");
 
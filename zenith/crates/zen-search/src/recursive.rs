@@ -638,6 +638,7 @@ mod tests {
             language: Some("rust".to_string()),
             size_bytes: 64,
             line_count: 3,
+            content_hash: None,
         }];
         store.store_source_files(&files).unwrap();
 
@@ -68,6 +68,10 @@ pub struct VectorSearchFilters {
     pub limit: u32,
     /// Minimum cosine similarity score (results below are excluded).
     pub min_score: f64,
+    /// Exclude symbols marked deprecated in their source language.
+    pub exclude_deprecated: bool,
+    /// Restrict which tables are queried (empty = query all sources).
+    pub source_types: Vec<VectorSource>,
 }
 
 impl Default for VectorSearchFilters {
@@ -78,10 +82,19 @@ impl Default for VectorSearchFilters {
             kind: None,
             limit: 20,
             min_score: 0.0,
+            exclude_deprecated: false,
+            source_types: Vec::new(),
         }
     }
 }
 
+impl VectorSearchFilters {
+    /// Whether `source` should be queried, given `source_types` (empty = all sources).
+    fn includes_source(&self, source: &VectorSource) -> bool {
+        self.source_types.is_empty() || self.source_types.contains(source)
+    }
+}
+
 /// Format a float slice as a `DuckDB` array literal: `[0.1, 0.2, ...]`.
 fn vec_to_sql(v: &[f32]) -> String {
     use std::fmt::Write;
@@ -110,6 +123,10 @@ pub fn vector_search_symbols(
     query_embedding: &[f32],
     filters: &VectorSearchFilters,
 ) -> Result<Vec<VectorSearchResult>, SearchError> {
+    if !filters.includes_source(&VectorSource::ApiSymbol) {
+        return Ok(Vec::new());
+    }
+
     let embedding_sql = vec_to_sql(query_embedding);
 
     let mut where_clauses = vec!["embedding IS NOT NULL".to_string()];
@@ -127,6 +144,9 @@ pub fn vector_search_symbols(
         where_clauses.push("kind = ?".to_string());
         param_values.push(Box::new(kind.clone()));
     }
+    if filters.exclude_deprecated {
+        where_clauses.push("is_deprecated = FALSE".to_string());
+    }
 
     let where_sql = where_clauses.join(" AND ");
 
@@ -193,6 +213,10 @@ pub fn vector_search_doc_chunks(
     query_embedding: &[f32],
     filters: &VectorSearchFilters,
 ) -> Result<Vec<VectorSearchResult>, SearchError> {
+    if !filters.includes_source(&VectorSource::DocChunk) {
+        return Ok(Vec::new());
+    }
+
     let embedding_sql = vec_to_sql(query_embedding);
 
     let mut where_clauses = vec!["embedding IS NOT NULL".to_string()];
@@ -300,6 +324,7 @@ mod tests {
             line_start: Some(1),
             line_end: Some(10),
             visibility: Some("public".to_string()),
+            is_deprecated: false,
             is_async: false,
             is_unsafe: false,
             is_error_type: false,
@@ -446,6 +471,30 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[test]
+    fn source_types_restricts_to_api_symbols_only() {
+        let lake = ZenLake::open_in_memory().unwrap();
+        let emb = synthetic_embedding(1);
+        lake.store_symbols(&[sample_symbol("s1", "spawn", "function", "tokio", emb.clone())])
+            .unwrap();
+        lake.store_doc_chunks(&[sample_chunk("c1", 0, "tokio", emb.clone())])
+            .unwrap();
+
+        let filters = VectorSearchFilters {
+            source_types: vec![VectorSource::ApiSymbol],
+            ..Default::default()
+        };
+
+        let symbol_results = vector_search_symbols(&lake, &emb, &filters).unwrap();
+        assert!(!symbol_results.is_empty());
+
+        let chunk_results = vector_search_doc_chunks(&lake, &emb, &filters).unwrap();
+        assert!(
+            chunk_results.is_empty(),
+            "doc chunks should be excluded when source_types requests only ApiSymbol"
+        );
+    }
+
     #[test]
     fn doc_chunk_search() {
         let lake = ZenLake::open_in_memory().unwrap();
@@ -0,0 +1,198 @@
+//! On-disk TTL cache for resolved secret overrides.
+//!
+//! Lets `zenith` keep booting with the last successfully fetched secrets when
+//! the configured backend is unreachable (offline, expired network, backend
+//! outage), instead of failing config load outright.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::SecretError;
+
+const CACHE_DIR_NAME: &str = ".zenith/cache";
+const CACHE_FILE_NAME: &str = "secrets.json";
+const ENV_TTL_SECS: &str = "ZENITH_SECRETS__CACHE_TTL_SECS";
+const DEFAULT_TTL_SECS: u64 = 900;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedSecrets {
+    fetched_at: DateTime<Utc>,
+    values: Vec<(String, String)>,
+}
+
+impl CachedSecrets {
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        let ttl = chrono::TimeDelta::from_std(ttl).unwrap_or(chrono::TimeDelta::MAX);
+        Utc::now() - self.fetched_at < ttl
+    }
+}
+
+/// How long a cached fetch stays fresh before a resolve requires a live fetch.
+///
+/// Defaults to 900s (15 minutes). Override via `ZENITH_SECRETS__CACHE_TTL_SECS`.
+fn ttl() -> Duration {
+    std::env::var(ENV_TTL_SECS)
+        .ok()
+        .and_then(|raw| raw.parse().ok())
+        .map_or(Duration::from_secs(DEFAULT_TTL_SECS), Duration::from_secs)
+}
+
+/// Path to the on-disk secrets cache, under `~/.zenith/cache/secrets.json`.
+pub fn cache_path() -> Result<PathBuf, SecretError> {
+    dirs::home_dir()
+        .map(|home| home.join(CACHE_DIR_NAME).join(CACHE_FILE_NAME))
+        .ok_or_else(|| SecretError::Cache("home directory not found — cannot cache secrets".into()))
+}
+
+/// Read the cache at `path`, returning `None` if it's missing, unreadable, or stale.
+pub fn read_fresh(path: &Path) -> Option<Vec<(String, String)>> {
+    let cached = read(path)?;
+    cached.is_fresh(ttl()).then_some(cached.values)
+}
+
+/// Read the cache at `path` regardless of staleness, for the offline-grace fallback.
+pub fn read_any(path: &Path) -> Option<Vec<(String, String)>> {
+    read(path).map(|cached| cached.values)
+}
+
+fn read(path: &Path) -> Option<CachedSecrets> {
+    let raw = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+/// Overwrite the cache at `path` with a fresh fetch.
+pub fn write(path: &Path, values: &[(String, String)]) -> Result<(), SecretError> {
+    let cached = CachedSecrets {
+        fetched_at: Utc::now(),
+        values: values.to_vec(),
+    };
+    let raw = serde_json::to_string(&cached).map_err(|e| SecretError::Cache(e.to_string()))?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| SecretError::Cache(format!("mkdir {}: {e}", parent.display())))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(parent, fs::Permissions::from_mode(0o700))
+                .map_err(|e| SecretError::Cache(format!("chmod {}: {e}", parent.display())))?;
+        }
+    }
+
+    fs::write(path, raw)
+        .map_err(|e| SecretError::Cache(format!("write {}: {e}", path.display())))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+            .map_err(|e| SecretError::Cache(format!("chmod {}: {e}", path.display())))?;
+    }
+
+    Ok(())
+}
+
+/// Write a cache entry that is already `age_secs` old, for exercising the
+/// offline-grace fallback from outside this module without racing the real
+/// `ZENITH_SECRETS__CACHE_TTL_SECS` env var across parallel tests.
+#[cfg(test)]
+pub fn write_stale(path: &Path, values: &[(String, String)], age_secs: i64) {
+    let cached = CachedSecrets {
+        fetched_at: Utc::now() - chrono::TimeDelta::seconds(age_secs),
+        values: values.to_vec(),
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("mkdir cache dir");
+    }
+    fs::write(path, serde_json::to_string(&cached).unwrap()).expect("write stale cache");
+}
+
+/// Remove the cache at `path`, if present.
+pub fn invalidate(path: &Path) -> Result<(), SecretError> {
+    if path.exists() {
+        fs::remove_file(path)
+            .map_err(|e| SecretError::Cache(format!("remove {}: {e}", path.display())))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> Vec<(String, String)> {
+        vec![("ZENITH_FOO".to_string(), "bar".to_string())]
+    }
+
+    #[test]
+    fn fresh_cache_within_ttl() {
+        let cached = CachedSecrets {
+            fetched_at: Utc::now(),
+            values: values(),
+        };
+        assert!(cached.is_fresh(Duration::from_mins(15)));
+    }
+
+    #[test]
+    fn stale_cache_outside_ttl() {
+        let cached = CachedSecrets {
+            fetched_at: Utc::now() - chrono::TimeDelta::seconds(1000),
+            values: values(),
+        };
+        assert!(!cached.is_fresh(Duration::from_mins(15)));
+    }
+
+    #[test]
+    fn write_read_roundtrip_is_fresh_and_permissioned() {
+        let tmp = tempfile::TempDir::new().expect("tmp dir");
+        let path = tmp.path().join("cache").join("secrets.json");
+
+        write(&path, &values()).expect("write");
+        assert_eq!(read_fresh(&path), Some(values()));
+        assert_eq!(read_any(&path), Some(values()));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).expect("metadata").permissions().mode() & 0o777;
+            assert_eq!(mode, 0o600, "cache file should be 0600");
+        }
+    }
+
+    #[test]
+    fn read_fresh_is_none_once_stale_but_read_any_still_returns_it() {
+        let tmp = tempfile::TempDir::new().expect("tmp dir");
+        let path = tmp.path().join("secrets.json");
+        let cached = CachedSecrets {
+            fetched_at: Utc::now() - chrono::TimeDelta::seconds(2000),
+            values: values(),
+        };
+        fs::write(&path, serde_json::to_string(&cached).unwrap()).expect("write");
+
+        assert_eq!(read_fresh(&path), None);
+        assert_eq!(read_any(&path), Some(values()));
+    }
+
+    #[test]
+    fn read_fresh_is_none_when_missing() {
+        let tmp = tempfile::TempDir::new().expect("tmp dir");
+        let path = tmp.path().join("secrets.json");
+        assert_eq!(read_fresh(&path), None);
+        assert_eq!(read_any(&path), None);
+    }
+
+    #[test]
+    fn invalidate_removes_file_and_is_a_noop_when_absent() {
+        let tmp = tempfile::TempDir::new().expect("tmp dir");
+        let path = tmp.path().join("secrets.json");
+        fs::write(&path, "{}").unwrap();
+
+        invalidate(&path).expect("invalidate existing");
+        assert!(!path.exists());
+        invalidate(&path).expect("invalidate missing is a no-op");
+    }
+}
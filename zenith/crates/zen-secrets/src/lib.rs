@@ -1,10 +1,25 @@
 //! # zen-secrets
 //!
-//! External secret provider integrations for Zenith.
+//! External secret provider integrations for Zenith: Infisical,
+//! `HashiCorp` Vault, and 1Password Connect.
 
-use infisical::{AuthMethod, Client, secrets::ListSecretsRequest};
+mod cache;
+mod onepassword;
+mod vault;
+
+use std::future::Future;
+use std::path::Path;
+
+use infisical::{
+    AuthMethod, Client,
+    secrets::{CreateSecretRequest, GetSecretRequest, ListSecretsRequest, UpdateSecretRequest},
+};
 use thiserror::Error;
 
+/// Prefix required of any config key readable from, or writable to, an
+/// external secrets backend.
+const ZENITH_KEY_PREFIX: &str = "ZENITH_";
+
 const ENV_BACKEND: &str = "ZENITH_SECRETS__BACKEND";
 const ENV_INFISICAL_BASE_URL: &str = "ZENITH_INFISICAL__BASE_URL";
 const ENV_INFISICAL_CLIENT_ID: &str = "ZENITH_INFISICAL__CLIENT_ID";
@@ -24,6 +39,8 @@ pub enum SecretOverrides {
 enum Backend {
     None,
     Infisical,
+    Vault,
+    OnePassword,
 }
 
 impl Backend {
@@ -34,9 +51,20 @@ impl Backend {
         match normalized.as_str() {
             "" | "none" | "off" | "disabled" => Ok(Self::None),
             "infisical" => Ok(Self::Infisical),
+            "vault" => Ok(Self::Vault),
+            "onepassword" | "1password" => Ok(Self::OnePassword),
             value => Err(SecretError::UnsupportedBackend(value.to_string())),
         }
     }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Infisical => "infisical",
+            Self::Vault => "vault",
+            Self::OnePassword => "onepassword",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -71,29 +99,163 @@ pub enum SecretError {
     MissingEnvVar { name: &'static str },
     #[error("infisical error: {0}")]
     Infisical(#[from] infisical::InfisicalError),
+
+    /// HTTP transport error talking to Vault.
+    #[error("vault HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// Vault API returned a non-success status code.
+    #[error("vault API error ({status}): {message}")]
+    VaultApi {
+        /// HTTP status code returned by Vault.
+        status: u16,
+        /// Error message or response body.
+        message: String,
+    },
+
+    /// Failed to read the file at `VAULT_CACERT`.
+    #[error("failed to read VAULT_CACERT file: {0}")]
+    VaultCaCert(#[source] std::io::Error),
+
+    /// The 1Password Connect server could not be reached.
+    #[error("1Password Connect server unavailable: {0}")]
+    BackendUnavailable(String),
+
+    /// 1Password Connect API returned a non-success status code.
+    #[error("1Password Connect API error ({status}): {message}")]
+    OnePasswordApi {
+        /// HTTP status code returned by Connect.
+        status: u16,
+        /// Error message or response body.
+        message: String,
+    },
+
+    /// Malformed `op://vault/item/field` reference, or a vault/item lookup
+    /// that didn't match anything.
+    #[error("invalid 1Password reference: {0}")]
+    OnePasswordReference(String),
+
+    /// A resolved item didn't have the requested field.
+    #[error("1Password item '{item}' has no field '{field}'")]
+    OnePasswordField {
+        /// Title of the item that was resolved.
+        item: String,
+        /// Field label that was requested.
+        field: String,
+    },
+
+    /// Failed to read or write the on-disk secrets cache.
+    #[error("secrets cache error: {0}")]
+    Cache(String),
+
+    /// The configured backend doesn't support writing secrets back to it.
+    #[error("secrets backend '{0}' does not support writing secrets")]
+    WriteNotSupported(&'static str),
+
+    /// A key passed to [`store_secret`] didn't match the required
+    /// `ZENITH_*` naming convention.
+    #[error("key '{0}' does not match the required ZENITH_* naming convention")]
+    InvalidKey(String),
+}
+
+/// Outcome of a [`store_secret`] call: whether the secret already existed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SecretWriteOutcome {
+    Created,
+    Updated,
+}
+
+/// Delete the on-disk secrets cache, forcing the next resolve to hit the backend.
+///
+/// # Errors
+/// Returns `SecretError::Cache` if the cache file exists but can't be removed.
+pub fn invalidate_cache() -> Result<(), SecretError> {
+    cache::invalidate(&cache::cache_path()?)
 }
 
-fn required_env(name: &'static str) -> Result<String, SecretError> {
+pub(crate) fn required_env(name: &'static str) -> Result<String, SecretError> {
     std::env::var(name).map_err(|_| SecretError::MissingEnvVar { name })
 }
 
 /// Load secret key/value overrides from the configured external backend.
 ///
 /// Expected naming convention is exact config keys (e.g., `ZENITH_CLERK__SECRET_KEY`).
+///
+/// A fresh fetch is cached to disk; if the backend is unreachable, the last
+/// successful fetch is served instead (however stale) so config load doesn't
+/// fail outright on a transient outage.
+///
+/// # Errors
+/// Returns `SecretError` if the backend name is invalid, required backend
+/// settings are missing, the backend request fails and no cache exists to
+/// fall back on, or the cache path can't be resolved.
 pub async fn load_env_overrides() -> Result<SecretOverrides, SecretError> {
-    match Backend::from_env()? {
-        Backend::None => Ok(SecretOverrides::Disabled),
+    let backend = Backend::from_env()?;
+    if backend == Backend::None {
+        return Ok(SecretOverrides::Disabled);
+    }
+
+    let path = cache::cache_path()?;
+    let values = resolve_with_cache(&path, || fetch_from_backend(backend)).await?;
+    Ok(SecretOverrides::Values(values))
+}
+
+/// Resolve secret overrides through the on-disk cache, falling back to `fetch`.
+///
+/// Serves a fresh cache without calling `fetch` at all. On a successful fetch,
+/// the cache is refreshed. On a failed fetch, any existing cache is served
+/// (regardless of staleness) instead of propagating the error, so a backend
+/// outage doesn't take down config load; the fetch error only propagates when
+/// there's no cache to fall back on.
+async fn resolve_with_cache<F, Fut>(
+    path: &Path,
+    fetch: F,
+) -> Result<Vec<(String, String)>, SecretError>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<Vec<(String, String)>, SecretError>>,
+{
+    if let Some(values) = cache::read_fresh(path) {
+        return Ok(values);
+    }
+
+    match fetch().await {
+        Ok(values) => {
+            if let Err(error) = cache::write(path, &values) {
+                tracing::warn!(%error, "failed to write secrets cache");
+            }
+            Ok(values)
+        }
+        Err(error) => {
+            if let Some(values) = cache::read_any(path) {
+                tracing::warn!(%error, "secrets backend unavailable; serving cached secrets");
+                Ok(values)
+            } else {
+                Err(error)
+            }
+        }
+    }
+}
+
+async fn fetch_from_backend(backend: Backend) -> Result<Vec<(String, String)>, SecretError> {
+    match backend {
+        Backend::None => Ok(Vec::new()),
         Backend::Infisical => {
             let settings = InfisicalSettings::from_env()?;
-            let values = load_from_infisical(&settings).await?;
-            Ok(SecretOverrides::Values(values))
+            load_from_infisical(&settings).await
+        }
+        Backend::Vault => {
+            let settings = vault::VaultSettings::from_env()?;
+            vault::load_env_overrides(&settings).await
+        }
+        Backend::OnePassword => {
+            let settings = onepassword::OnePasswordSettings::from_env()?;
+            onepassword::load_env_overrides(&settings).await
         }
     }
 }
 
-async fn load_from_infisical(
-    settings: &InfisicalSettings,
-) -> Result<Vec<(String, String)>, SecretError> {
+async fn infisical_client(settings: &InfisicalSettings) -> Result<Client, SecretError> {
     let mut client = Client::builder()
         .base_url(&settings.base_url)
         .build()
@@ -106,6 +268,14 @@ async fn load_from_infisical(
         ))
         .await?;
 
+    Ok(client)
+}
+
+async fn load_from_infisical(
+    settings: &InfisicalSettings,
+) -> Result<Vec<(String, String)>, SecretError> {
+    let client = infisical_client(settings).await?;
+
     let request = ListSecretsRequest::builder(&settings.project_id, &settings.environment)
         .path(&settings.path)
         .recursive(true)
@@ -117,7 +287,7 @@ async fn load_from_infisical(
         .list(request)
         .await?
         .into_iter()
-        .filter(|secret| secret.secret_key.starts_with("ZENITH_"))
+        .filter(|secret| secret.secret_key.starts_with(ZENITH_KEY_PREFIX))
         .map(|secret| (secret.secret_key, secret.secret_value))
         .collect::<Vec<_>>();
 
@@ -126,9 +296,84 @@ async fn load_from_infisical(
     Ok(values)
 }
 
+/// Create or update `key` = `value` at the configured backend, so a
+/// teammate's locally-resolved secret can be pushed for everyone else to
+/// pick up.
+///
+/// Only the Infisical backend supports writes today.
+///
+/// # Errors
+/// Returns `SecretError::InvalidKey` if `key` doesn't start with `ZENITH_`,
+/// `SecretError::WriteNotSupported` if the configured backend can't be
+/// written to, or a backend-specific error if the request fails.
+pub async fn store_secret(key: &str, value: &str) -> Result<SecretWriteOutcome, SecretError> {
+    if !key.starts_with(ZENITH_KEY_PREFIX) {
+        return Err(SecretError::InvalidKey(key.to_string()));
+    }
+
+    match Backend::from_env()? {
+        Backend::Infisical => {
+            let settings = InfisicalSettings::from_env()?;
+            store_in_infisical(&settings, key, value).await
+        }
+        backend => Err(SecretError::WriteNotSupported(backend.name())),
+    }
+}
+
+async fn store_in_infisical(
+    settings: &InfisicalSettings,
+    key: &str,
+    value: &str,
+) -> Result<SecretWriteOutcome, SecretError> {
+    let client = infisical_client(settings).await?;
+
+    if infisical_secret_exists(&client, settings, key).await? {
+        let request =
+            UpdateSecretRequest::builder(key, &settings.project_id, &settings.environment)
+                .path(&settings.path)
+                .secret_value(value)
+                .build();
+        client.secrets().update(request).await?;
+        Ok(SecretWriteOutcome::Updated)
+    } else {
+        let request =
+            CreateSecretRequest::builder(key, value, &settings.project_id, &settings.environment)
+                .path(&settings.path)
+                .build();
+        client.secrets().create(request).await?;
+        Ok(SecretWriteOutcome::Created)
+    }
+}
+
+async fn infisical_secret_exists(
+    client: &Client,
+    settings: &InfisicalSettings,
+    key: &str,
+) -> Result<bool, SecretError> {
+    let request = GetSecretRequest::builder(key, &settings.project_id, &settings.environment)
+        .path(&settings.path)
+        .build();
+
+    match client.secrets().get(request).await {
+        Ok(_) => Ok(true),
+        Err(infisical::InfisicalError::HttpError { status, .. })
+            if status == reqwest::StatusCode::NOT_FOUND =>
+        {
+            Ok(false)
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Backend;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::{
+        Backend, InfisicalSettings, SecretError, SecretWriteOutcome, resolve_with_cache,
+        store_in_infisical,
+    };
 
     #[test]
     fn backend_defaults_to_none_when_missing() {
@@ -148,4 +393,166 @@ mod tests {
             Ok(())
         });
     }
+
+    fn values() -> Vec<(String, String)> {
+        vec![("ZENITH_FOO".to_string(), "bar".to_string())]
+    }
+
+    #[tokio::test]
+    async fn serves_fresh_cache_without_calling_fetch() {
+        let tmp = tempfile::TempDir::new().expect("tmp dir");
+        let path = tmp.path().join("secrets.json");
+        crate::cache::write(&path, &values()).expect("seed cache");
+
+        let result = resolve_with_cache(&path, || async {
+            panic!("fetch should not run when the cache is fresh")
+        })
+        .await
+        .expect("resolve");
+
+        assert_eq!(result, values());
+    }
+
+    #[tokio::test]
+    async fn fetches_and_writes_cache_when_none_exists() {
+        let tmp = tempfile::TempDir::new().expect("tmp dir");
+        let path = tmp.path().join("secrets.json");
+
+        let result = resolve_with_cache(&path, || async { Ok(values()) })
+            .await
+            .expect("resolve");
+
+        assert_eq!(result, values());
+        assert_eq!(crate::cache::read_any(&path), Some(values()));
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_stale_cache_when_fetch_fails() {
+        let tmp = tempfile::TempDir::new().expect("tmp dir");
+        let path = tmp.path().join("secrets.json");
+        crate::cache::write_stale(&path, &values(), 100_000);
+
+        let result = resolve_with_cache(&path, || async {
+            Err(SecretError::BackendUnavailable("offline".to_string()))
+        })
+        .await;
+
+        assert_eq!(result.expect("should fall back to stale cache"), values());
+    }
+
+    #[tokio::test]
+    async fn propagates_fetch_error_when_no_cache_exists() {
+        let tmp = tempfile::TempDir::new().expect("tmp dir");
+        let path = tmp.path().join("secrets.json");
+
+        let result = resolve_with_cache(&path, || async {
+            Err(SecretError::BackendUnavailable("offline".to_string()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(SecretError::BackendUnavailable(_))));
+    }
+
+    fn mock_secret(value: &str) -> serde_json::Value {
+        serde_json::json!({
+            "_id": "sec-1",
+            "workspace": "proj-1",
+            "version": 1,
+            "type": "shared",
+            "environment": "dev",
+            "secretKey": "ZENITH_TURSO__AUTH_TOKEN",
+            "secretValue": value,
+            "secretComment": "",
+        })
+    }
+
+    fn infisical_settings(server: &MockServer) -> InfisicalSettings {
+        InfisicalSettings {
+            base_url: server.uri(),
+            client_id: "client-1".to_string(),
+            client_secret: "secret-1".to_string(),
+            project_id: "proj-1".to_string(),
+            environment: "dev".to_string(),
+            path: "/".to_string(),
+        }
+    }
+
+    async fn mock_login(server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/api/v1/auth/universal-auth/login"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "accessToken": "token-123",
+                "expiresIn": 3600,
+                "accessTokenMaxTTL": 3600,
+                "tokenType": "Bearer",
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn store_secret_creates_when_missing() {
+        let server = MockServer::start().await;
+        mock_login(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/secrets/raw/ZENITH_TURSO__AUTH_TOKEN"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("not found"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/secrets/raw/ZENITH_TURSO__AUTH_TOKEN"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "secret": mock_secret("tok")
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let outcome = store_in_infisical(
+            &infisical_settings(&server),
+            "ZENITH_TURSO__AUTH_TOKEN",
+            "tok",
+        )
+        .await
+        .expect("create should succeed");
+
+        assert_eq!(outcome, SecretWriteOutcome::Created);
+    }
+
+    #[tokio::test]
+    async fn store_secret_updates_when_already_present() {
+        let server = MockServer::start().await;
+        mock_login(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/secrets/raw/ZENITH_TURSO__AUTH_TOKEN"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "secret": mock_secret("old")
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/api/v3/secrets/raw/ZENITH_TURSO__AUTH_TOKEN"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "secret": mock_secret("new")
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let outcome = store_in_infisical(
+            &infisical_settings(&server),
+            "ZENITH_TURSO__AUTH_TOKEN",
+            "new",
+        )
+        .await
+        .expect("update should succeed");
+
+        assert_eq!(outcome, SecretWriteOutcome::Updated);
+    }
+
+    #[tokio::test]
+    async fn store_secret_rejects_non_zenith_keys() {
+        let result = super::store_secret("TURSO_AUTH_TOKEN", "tok").await;
+        assert!(matches!(result, Err(SecretError::InvalidKey(_))));
+    }
 }
@@ -0,0 +1,369 @@
+//! 1Password Connect backend: resolve secrets from a Connect server either by
+//! listing `ZENITH_`-prefixed item titles in a vault, or by explicit
+//! `op://vault/item/field` references.
+
+use serde::Deserialize;
+
+use crate::{SecretError, required_env};
+
+const ENV_OP_HOST: &str = "ZENITH_OP__HOST";
+const ENV_OP_TOKEN: &str = "ZENITH_OP__TOKEN";
+const ENV_OP_VAULT: &str = "ZENITH_OP__VAULT";
+const ENV_OP_REFERENCES: &str = "ZENITH_OP__REFERENCES";
+
+#[derive(Debug, Clone)]
+pub struct OnePasswordSettings {
+    host: String,
+    token: String,
+    mode: ResolutionMode,
+}
+
+#[derive(Debug, Clone)]
+enum ResolutionMode {
+    /// List items in this vault whose title is `ZENITH_`-prefixed.
+    Vault(String),
+    /// Resolve each `(env key, op:// reference)` pair explicitly.
+    References(Vec<(String, OpReference)>),
+}
+
+#[derive(Debug, Clone)]
+struct OpReference {
+    vault: String,
+    item: String,
+    field: String,
+}
+
+impl OnePasswordSettings {
+    pub fn from_env() -> Result<Self, SecretError> {
+        Ok(Self {
+            host: required_env(ENV_OP_HOST)?,
+            token: required_env(ENV_OP_TOKEN)?,
+            mode: ResolutionMode::from_env()?,
+        })
+    }
+}
+
+impl ResolutionMode {
+    fn from_env() -> Result<Self, SecretError> {
+        if let Ok(raw) = std::env::var(ENV_OP_REFERENCES) {
+            let references = raw
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .map(parse_reference_pair)
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(Self::References(references));
+        }
+        if let Ok(vault) = std::env::var(ENV_OP_VAULT) {
+            return Ok(Self::Vault(vault));
+        }
+        Err(SecretError::MissingEnvVar {
+            name: "ZENITH_OP__VAULT or ZENITH_OP__REFERENCES",
+        })
+    }
+}
+
+fn parse_reference_pair(entry: &str) -> Result<(String, OpReference), SecretError> {
+    let (key, reference) = entry
+        .split_once('=')
+        .ok_or_else(|| SecretError::OnePasswordReference(entry.to_string()))?;
+    Ok((key.to_string(), parse_reference(reference)?))
+}
+
+fn parse_reference(reference: &str) -> Result<OpReference, SecretError> {
+    let rest = reference
+        .strip_prefix("op://")
+        .ok_or_else(|| SecretError::OnePasswordReference(reference.to_string()))?;
+    let mut parts = rest.splitn(3, '/');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(vault), Some(item), Some(field))
+            if !vault.is_empty() && !item.is_empty() && !field.is_empty() =>
+        {
+            Ok(OpReference {
+                vault: vault.to_string(),
+                item: item.to_string(),
+                field: field.to_string(),
+            })
+        }
+        _ => Err(SecretError::OnePasswordReference(reference.to_string())),
+    }
+}
+
+#[derive(Deserialize)]
+struct Vault {
+    id: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ItemSummary {
+    id: String,
+    title: String,
+}
+
+#[derive(Deserialize)]
+struct Item {
+    title: String,
+    fields: Vec<Field>,
+}
+
+#[derive(Deserialize)]
+struct Field {
+    label: String,
+    #[serde(default)]
+    value: Option<String>,
+}
+
+/// Resolve secrets from the configured Connect server.
+pub async fn load_env_overrides(
+    settings: &OnePasswordSettings,
+) -> Result<Vec<(String, String)>, SecretError> {
+    let http = reqwest::Client::new();
+
+    let mut values = match &settings.mode {
+        ResolutionMode::Vault(vault_name) => load_from_vault(&http, settings, vault_name).await?,
+        ResolutionMode::References(references) => {
+            load_from_references(&http, settings, references).await?
+        }
+    };
+    values.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(values)
+}
+
+async fn load_from_vault(
+    http: &reqwest::Client,
+    settings: &OnePasswordSettings,
+    vault_name: &str,
+) -> Result<Vec<(String, String)>, SecretError> {
+    let vault_id = find_vault_id(http, settings, vault_name).await?;
+    let items = list_items(http, settings, &vault_id).await?;
+
+    let mut values = Vec::new();
+    for summary in items
+        .into_iter()
+        .filter(|item| item.title.starts_with("ZENITH_"))
+    {
+        let item = get_item(http, settings, &vault_id, &summary.id).await?;
+        let value = field_value(&item, "value")?;
+        values.push((item.title, value));
+    }
+
+    Ok(values)
+}
+
+async fn load_from_references(
+    http: &reqwest::Client,
+    settings: &OnePasswordSettings,
+    references: &[(String, OpReference)],
+) -> Result<Vec<(String, String)>, SecretError> {
+    let mut values = Vec::with_capacity(references.len());
+    for (key, reference) in references {
+        let vault_id = find_vault_id(http, settings, &reference.vault).await?;
+        let item_id = find_item_id(http, settings, &vault_id, &reference.item).await?;
+        let item = get_item(http, settings, &vault_id, &item_id).await?;
+        let value = field_value(&item, &reference.field)?;
+        values.push((key.clone(), value));
+    }
+
+    Ok(values)
+}
+
+fn field_value(item: &Item, label: &str) -> Result<String, SecretError> {
+    item.fields
+        .iter()
+        .find(|field| field.label == label)
+        .and_then(|field| field.value.clone())
+        .ok_or_else(|| SecretError::OnePasswordField {
+            item: item.title.clone(),
+            field: label.to_string(),
+        })
+}
+
+async fn find_vault_id(
+    http: &reqwest::Client,
+    settings: &OnePasswordSettings,
+    name: &str,
+) -> Result<String, SecretError> {
+    let vaults: Vec<Vault> = send_json(http, settings, "/v1/vaults").await?;
+    vaults
+        .into_iter()
+        .find(|vault| vault.name == name)
+        .map(|vault| vault.id)
+        .ok_or_else(|| SecretError::OnePasswordReference(format!("vault '{name}' not found")))
+}
+
+async fn list_items(
+    http: &reqwest::Client,
+    settings: &OnePasswordSettings,
+    vault_id: &str,
+) -> Result<Vec<ItemSummary>, SecretError> {
+    send_json(http, settings, &format!("/v1/vaults/{vault_id}/items")).await
+}
+
+async fn find_item_id(
+    http: &reqwest::Client,
+    settings: &OnePasswordSettings,
+    vault_id: &str,
+    title: &str,
+) -> Result<String, SecretError> {
+    list_items(http, settings, vault_id)
+        .await?
+        .into_iter()
+        .find(|item| item.title == title)
+        .map(|item| item.id)
+        .ok_or_else(|| SecretError::OnePasswordReference(format!("item '{title}' not found")))
+}
+
+async fn get_item(
+    http: &reqwest::Client,
+    settings: &OnePasswordSettings,
+    vault_id: &str,
+    item_id: &str,
+) -> Result<Item, SecretError> {
+    send_json(
+        http,
+        settings,
+        &format!("/v1/vaults/{vault_id}/items/{item_id}"),
+    )
+    .await
+}
+
+async fn send_json<T: serde::de::DeserializeOwned>(
+    http: &reqwest::Client,
+    settings: &OnePasswordSettings,
+    path: &str,
+) -> Result<T, SecretError> {
+    let url = format!("{}{path}", settings.host.trim_end_matches('/'));
+    let resp = http
+        .get(&url)
+        .bearer_auth(&settings.token)
+        .send()
+        .await
+        .map_err(|error| {
+            if error.is_connect() || error.is_timeout() {
+                SecretError::BackendUnavailable(error.to_string())
+            } else {
+                SecretError::Http(error)
+            }
+        })?;
+
+    if !resp.status().is_success() {
+        return Err(SecretError::OnePasswordApi {
+            status: resp.status().as_u16(),
+            message: resp.text().await.unwrap_or_default(),
+        });
+    }
+
+    resp.json().await.map_err(SecretError::Http)
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn settings(server: &MockServer, mode: ResolutionMode) -> OnePasswordSettings {
+        OnePasswordSettings {
+            host: server.uri(),
+            token: "connect-token".to_string(),
+            mode,
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_zenith_prefixed_titles_from_a_vault_listing() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vaults"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "vault-1", "name": "Zenith CI" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vaults/vault-1/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "item-1", "title": "ZENITH_CLERK__SECRET_KEY" },
+                { "id": "item-2", "title": "unrelated" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vaults/vault-1/items/item-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "title": "ZENITH_CLERK__SECRET_KEY",
+                "fields": [{ "label": "value", "value": "sk_test_123" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let settings = settings(&server, ResolutionMode::Vault("Zenith CI".to_string()));
+        let values = load_env_overrides(&settings).await.unwrap();
+
+        assert_eq!(
+            values,
+            vec![(
+                "ZENITH_CLERK__SECRET_KEY".to_string(),
+                "sk_test_123".to_string()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_explicit_op_references() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vaults"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "vault-1", "name": "prod" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vaults/vault-1/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "item-1", "title": "stripe" }
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/vaults/vault-1/items/item-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "title": "stripe",
+                "fields": [{ "label": "credential", "value": "sk_live_123" }]
+            })))
+            .mount(&server)
+            .await;
+
+        let reference = parse_reference("op://prod/stripe/credential").unwrap();
+        let settings = settings(
+            &server,
+            ResolutionMode::References(vec![("ZENITH_STRIPE__SECRET_KEY".to_string(), reference)]),
+        );
+        let values = load_env_overrides(&settings).await.unwrap();
+
+        assert_eq!(
+            values,
+            vec![(
+                "ZENITH_STRIPE__SECRET_KEY".to_string(),
+                "sk_live_123".to_string()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn unreachable_connect_server_becomes_backend_unavailable() {
+        let settings = OnePasswordSettings {
+            host: "http://127.0.0.1:1".to_string(),
+            token: "connect-token".to_string(),
+            mode: ResolutionMode::Vault("Zenith CI".to_string()),
+        };
+
+        let error = load_env_overrides(&settings).await.unwrap_err();
+
+        assert!(matches!(error, SecretError::BackendUnavailable(_)));
+    }
+}
@@ -0,0 +1,257 @@
+//! `HashiCorp` Vault backend: KV v2 secret reads with token or `AppRole` auth.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{SecretError, required_env};
+
+const ENV_VAULT_ADDRESS: &str = "ZENITH_VAULT__ADDRESS";
+const ENV_VAULT_TOKEN: &str = "ZENITH_VAULT__TOKEN";
+const ENV_VAULT_ROLE_ID: &str = "ZENITH_VAULT__ROLE_ID";
+const ENV_VAULT_SECRET_ID: &str = "ZENITH_VAULT__SECRET_ID";
+const ENV_VAULT_MOUNT: &str = "ZENITH_VAULT__MOUNT";
+const ENV_VAULT_PATH: &str = "ZENITH_VAULT__PATH";
+const ENV_VAULT_CACERT: &str = "VAULT_CACERT";
+
+#[derive(Debug, Clone)]
+pub struct VaultSettings {
+    address: String,
+    mount: String,
+    path: String,
+    auth: VaultAuth,
+    ca_cert_path: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum VaultAuth {
+    Token(String),
+    AppRole { role_id: String, secret_id: String },
+}
+
+impl VaultSettings {
+    pub fn from_env() -> Result<Self, SecretError> {
+        Ok(Self {
+            address: required_env(ENV_VAULT_ADDRESS)?,
+            mount: std::env::var(ENV_VAULT_MOUNT).unwrap_or_else(|_| "secret".to_string()),
+            path: required_env(ENV_VAULT_PATH)?,
+            auth: VaultAuth::from_env()?,
+            ca_cert_path: std::env::var(ENV_VAULT_CACERT).ok(),
+        })
+    }
+}
+
+impl VaultAuth {
+    fn from_env() -> Result<Self, SecretError> {
+        if let Ok(token) = std::env::var(ENV_VAULT_TOKEN) {
+            return Ok(Self::Token(token));
+        }
+        Ok(Self::AppRole {
+            role_id: required_env(ENV_VAULT_ROLE_ID)?,
+            secret_id: required_env(ENV_VAULT_SECRET_ID)?,
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct AppRoleLoginRequest<'a> {
+    role_id: &'a str,
+    secret_id: &'a str,
+}
+
+#[derive(Deserialize)]
+struct AppRoleLoginResponse {
+    auth: AppRoleAuth,
+}
+
+#[derive(Deserialize)]
+struct AppRoleAuth {
+    client_token: String,
+}
+
+#[derive(Deserialize)]
+struct KvReadResponse {
+    data: KvReadData,
+}
+
+#[derive(Deserialize)]
+struct KvReadData {
+    data: HashMap<String, String>,
+}
+
+/// Read the KV v2 secret at `settings.path`, filtered to keys prefixed
+/// `ZENITH_`.
+pub async fn load_env_overrides(
+    settings: &VaultSettings,
+) -> Result<Vec<(String, String)>, SecretError> {
+    let http = build_client(settings.ca_cert_path.as_deref())?;
+    let token = resolve_token(&http, settings).await?;
+    let secrets = read_kv2_secret(&http, settings, &token).await?;
+
+    let mut values: Vec<(String, String)> = secrets
+        .into_iter()
+        .filter(|(key, _)| key.starts_with("ZENITH_"))
+        .collect();
+    values.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(values)
+}
+
+fn build_client(ca_cert_path: Option<&str>) -> Result<reqwest::Client, SecretError> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(path) = ca_cert_path {
+        let pem = std::fs::read(path).map_err(SecretError::VaultCaCert)?;
+        builder = builder.add_root_certificate(reqwest::Certificate::from_pem(&pem)?);
+    }
+    Ok(builder.build()?)
+}
+
+async fn resolve_token(
+    http: &reqwest::Client,
+    settings: &VaultSettings,
+) -> Result<String, SecretError> {
+    match &settings.auth {
+        VaultAuth::Token(token) => Ok(token.clone()),
+        VaultAuth::AppRole { role_id, secret_id } => {
+            approle_login(http, &settings.address, role_id, secret_id).await
+        }
+    }
+}
+
+async fn approle_login(
+    http: &reqwest::Client,
+    address: &str,
+    role_id: &str,
+    secret_id: &str,
+) -> Result<String, SecretError> {
+    let url = format!("{}/v1/auth/approle/login", address.trim_end_matches('/'));
+    let resp = http
+        .post(&url)
+        .json(&AppRoleLoginRequest { role_id, secret_id })
+        .send()
+        .await?;
+    let body: AppRoleLoginResponse = check_response(resp).await?.json().await?;
+    Ok(body.auth.client_token)
+}
+
+async fn read_kv2_secret(
+    http: &reqwest::Client,
+    settings: &VaultSettings,
+    token: &str,
+) -> Result<HashMap<String, String>, SecretError> {
+    let url = format!(
+        "{}/v1/{}/data/{}",
+        settings.address.trim_end_matches('/'),
+        settings.mount,
+        settings.path.trim_start_matches('/')
+    );
+    let resp = http.get(&url).header("X-Vault-Token", token).send().await?;
+    let body: KvReadResponse = check_response(resp).await?.json().await?;
+    Ok(body.data.data)
+}
+
+async fn check_response(resp: reqwest::Response) -> Result<reqwest::Response, SecretError> {
+    if !resp.status().is_success() {
+        return Err(SecretError::VaultApi {
+            status: resp.status().as_u16(),
+            message: resp.text().await.unwrap_or_default(),
+        });
+    }
+    Ok(resp)
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn settings(server: &MockServer, auth: VaultAuth) -> VaultSettings {
+        VaultSettings {
+            address: server.uri(),
+            mount: "secret".to_string(),
+            path: "zenith/ci".to_string(),
+            auth,
+            ca_cert_path: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn reads_kv2_secret_and_filters_zenith_prefixed_keys() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/zenith/ci"))
+            .and(header("X-Vault-Token", "s.mytoken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "data": {
+                        "ZENITH_CLERK__SECRET_KEY": "sk_test_123",
+                        "UNRELATED_KEY": "ignore-me",
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let settings = settings(&server, VaultAuth::Token("s.mytoken".to_string()));
+        let values = load_env_overrides(&settings).await.unwrap();
+
+        assert_eq!(
+            values,
+            vec![(
+                "ZENITH_CLERK__SECRET_KEY".to_string(),
+                "sk_test_123".to_string()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn approle_login_exchanges_role_and_secret_id_for_a_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/auth/approle/login"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "auth": { "client_token": "s.approle-token" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/zenith/ci"))
+            .and(header("X-Vault-Token", "s.approle-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "data": {} }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let settings = settings(
+            &server,
+            VaultAuth::AppRole {
+                role_id: "role-1".to_string(),
+                secret_id: "secret-1".to_string(),
+            },
+        );
+        let values = load_env_overrides(&settings).await.unwrap();
+
+        assert!(values.is_empty());
+    }
+
+    #[tokio::test]
+    async fn non_success_response_becomes_vault_api_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/secret/data/zenith/ci"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("permission denied"))
+            .mount(&server)
+            .await;
+
+        let settings = settings(&server, VaultAuth::Token("s.mytoken".to_string()));
+        let error = load_env_overrides(&settings).await.unwrap_err();
+
+        assert!(matches!(error, SecretError::VaultApi { status: 403, .. }));
+    }
+}